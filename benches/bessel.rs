@@ -0,0 +1,118 @@
+//! Benchmarks of the main evaluation paths
+//!
+//! Run with `cargo bench`; add `--features backend-rust` to include the Rust
+//! backend next to Amos. Each group compares the paths a user can choose
+//! between for the same work:
+//!
+//! * `single`: one value per call across the (ν, z) regimes, scaled and unscaled
+//! * `sequence`: one sequence call against repeated single calls
+//! * `batch`: the lane-wise slice kernels against a loop of single calls
+//! * `backend`: raw backend calls, one per compiled-in backend
+
+// The crate is marked deprecated in favour of its successor
+#![allow(deprecated)]
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use num_complex::Complex64;
+use zbessel_rs::{bessel, bessel_j, bessel_slice, Backend, BesselKind, Scaling};
+
+/// Representative regimes: name, order, argument
+const REGIMES: [(&str, f64, Complex64); 6] = [
+    ("small", 0.3, Complex64::new(0.5, 0.25)),
+    ("moderate", 1.0, Complex64::new(5.0, 2.0)),
+    ("large-z", 0.0, Complex64::new(150.0, 1.0)),
+    ("large-nu", 60.0, Complex64::new(10.0, 3.0)),
+    ("half-integer", 2.5, Complex64::new(3.0, -1.0)),
+    ("imaginary", 1.5, Complex64::new(0.0, 8.0)),
+];
+
+const KINDS: [BesselKind; 4] = [BesselKind::J, BesselKind::Y, BesselKind::I, BesselKind::K];
+
+fn single(c: &mut Criterion) {
+    let mut group = c.benchmark_group("single");
+    for kind in KINDS {
+        for (name, nu, z) in REGIMES {
+            for scaling in [Scaling::Unscaled, Scaling::Scaled] {
+                let id = BenchmarkId::new(format!("{:?}/{:?}", kind, scaling), name);
+                group.bench_with_input(id, &(nu, z), |b, &(nu, z)| {
+                    b.iter(|| bessel(kind, black_box(nu), black_box(z), scaling))
+                });
+            }
+        }
+    }
+    group.finish();
+}
+
+fn sequence(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sequence");
+    let z = Complex64::new(4.0, 1.0);
+    for n in [1, 10, 100] {
+        group.throughput(Throughput::Elements(n as u64));
+        for nu in [0.0, 0.3] {
+            let id = BenchmarkId::new(format!("sequence/nu={}", nu), n);
+            group.bench_function(id, |b| b.iter(|| bessel_j(black_box(z), nu, 1, n)));
+            let id = BenchmarkId::new(format!("single-calls/nu={}", nu), n);
+            group.bench_function(id, |b| {
+                b.iter(|| {
+                    (0..n)
+                        .map(|k| {
+                            bessel(
+                                BesselKind::J,
+                                nu + k as f64,
+                                black_box(z),
+                                Scaling::Unscaled,
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                })
+            });
+        }
+    }
+    group.finish();
+}
+
+fn batch(c: &mut Criterion) {
+    let mut group = c.benchmark_group("batch");
+    // Mixes the series, transition and asymptotic regions
+    let zs: Vec<Complex64> = (0..1024)
+        .map(|k| Complex64::from_polar(0.05 + 0.06 * k as f64, 0.01 * k as f64))
+        .collect();
+    group.throughput(Throughput::Elements(zs.len() as u64));
+    for kind in KINDS {
+        group.bench_function(BenchmarkId::new("slice", format!("{:?}", kind)), |b| {
+            b.iter(|| bessel_slice(kind, black_box(&zs), 1.0, Scaling::Unscaled))
+        });
+        group.bench_function(BenchmarkId::new("loop", format!("{:?}", kind)), |b| {
+            b.iter(|| {
+                zs.iter()
+                    .map(|&z| bessel(kind, 1.0, black_box(z), Scaling::Unscaled))
+                    .collect::<Vec<_>>()
+            })
+        });
+    }
+    group.finish();
+}
+
+fn backend_group<B: Backend>(c: &mut Criterion) {
+    let mut group = c.benchmark_group("backend");
+    for kind in KINDS {
+        for (name, nu, z) in REGIMES {
+            let id = BenchmarkId::new(format!("{}/{:?}", B::NAME, kind), name);
+            group.bench_function(id, |b| {
+                let (mut re, mut im) = ([0.0; 1], [0.0; 1]);
+                b.iter(|| B::bessel(kind, black_box(z), nu, 1, &mut re, &mut im))
+            });
+        }
+    }
+    group.finish();
+}
+
+fn backends(c: &mut Criterion) {
+    #[cfg(feature = "amos")]
+    backend_group::<zbessel_rs::Amos>(c);
+    #[cfg(feature = "backend-rust")]
+    backend_group::<zbessel_rs::RustBackend>(c);
+}
+
+criterion_group!(benches, single, sequence, batch, backends);
+criterion_main!(benches);
@@ -1,4 +1,6 @@
+#[cfg(feature = "bindgen")]
 use std::env;
+#[cfg(feature = "bindgen")]
 use std::path::PathBuf;
 
 fn main() {
@@ -8,6 +10,38 @@ fn main() {
     println!("cargo:rerun-if-changed=zbessel.h");
     println!("cargo:rerun-if-changed=zbessel.hh");
 
+    // Only the Amos backend needs the C++ sources
+    #[cfg(feature = "amos")]
+    build_amos();
+
+    #[cfg(feature = "capi")]
+    generate_header();
+}
+
+/// Write the C header for `src/capi.rs` to OUT_DIR
+#[cfg(feature = "capi")]
+fn generate_header() {
+    println!("cargo:rerun-if-changed=src/capi.rs");
+    let out_path = std::path::PathBuf::from(std::env::var("OUT_DIR").unwrap());
+    let config = cbindgen::Config {
+        language: cbindgen::Language::C,
+        header: Some("/* Generated by cbindgen from src/capi.rs; do not edit. */".into()),
+        include_guard: Some("ZBESSEL_RS_H".into()),
+        no_includes: true,
+        sys_includes: vec!["stddef.h".into(), "stdint.h".into()],
+        usize_is_size_t: true,
+        ..Default::default()
+    };
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src("src/capi.rs")
+        .generate()
+        .expect("Failed to generate C header")
+        .write_to_file(out_path.join("zbessel_rs.h"));
+}
+
+#[cfg(feature = "amos")]
+fn build_amos() {
     // Compile C++ source files
     let mut build = cc::Build::new();
     build
@@ -36,9 +70,45 @@ fn main() {
             .flag("-w");
     }
 
+    #[cfg(feature = "deterministic")]
+    deterministic_flags(&mut build);
+
     build.compile("zbessel");
 
-    // Generate Rust bindings using bindgen
+    // The crate uses the declarations checked in to src/sys.rs; bindgen only
+    // regenerates them for the compile-time comparison there
+    #[cfg(feature = "bindgen")]
+    generate_bindings();
+}
+
+/// Flags that make the floating-point results independent of the compiler
+///
+/// Each operation is rounded to double as written: no contraction of a * b + c
+/// into a fused multiply-add, no reassociation, and SSE2 instead of the x87
+/// unit's 80-bit registers on 32-bit x86.
+#[cfg(all(feature = "amos", feature = "deterministic"))]
+fn deterministic_flags(build: &mut cc::Build) {
+    let x86 = std::env::var("CARGO_CFG_TARGET_ARCH").as_deref() == Ok("x86");
+    if build.get_compiler().is_like_msvc() {
+        // /fp:precise contracts to FMA before Visual Studio 2022; /fp:strict never does
+        build.flag("/fp:strict");
+        if x86 {
+            build.flag("/arch:SSE2");
+        }
+    } else {
+        build
+            .flag("-ffp-contract=off")
+            .flag("-fno-fast-math")
+            .flag_if_supported("-fexcess-precision=standard");
+        if x86 {
+            build.flag("-msse2").flag("-mfpmath=sse");
+        }
+    }
+}
+
+/// Write bindgen's declarations for `zbessel.h` to OUT_DIR
+#[cfg(feature = "bindgen")]
+fn generate_bindings() {
     let bindings = bindgen::Builder::default()
         .header("zbessel.h")
         .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
@@ -0,0 +1,148 @@
+/* Generated by cbindgen from src/capi.rs; do not edit. */
+
+#ifndef ZBESSEL_RS_H
+#define ZBESSEL_RS_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+/**
+ * Success
+ */
+#define ZB_OK 0
+
+/**
+ * A required pointer was null
+ */
+#define ZB_NULL_POINTER -1
+
+/**
+ * An internal error was caught at the boundary
+ */
+#define ZB_PANIC -2
+
+/**
+ * Complex number with the layout of C99 `double _Complex`
+ */
+typedef struct zb_complex {
+  /**
+   * Real part
+   */
+  double re;
+  /**
+   * Imaginary part
+   */
+  double im;
+} zb_complex;
+
+/**
+ * J_nu(z), scaled by exp(-|Im z|) if `scaled` is nonzero
+ *
+ * # Safety
+ * `out` must be null or valid for a write.
+ */
+int32_t zb_bessel_j(double nu, struct zb_complex z, int32_t scaled, struct zb_complex *out);
+
+/**
+ * Y_nu(z), scaled by exp(-|Im z|) if `scaled` is nonzero
+ *
+ * # Safety
+ * `out` must be null or valid for a write.
+ */
+int32_t zb_bessel_y(double nu, struct zb_complex z, int32_t scaled, struct zb_complex *out);
+
+/**
+ * I_nu(z), scaled by exp(-|Re z|) if `scaled` is nonzero
+ *
+ * # Safety
+ * `out` must be null or valid for a write.
+ */
+int32_t zb_bessel_i(double nu, struct zb_complex z, int32_t scaled, struct zb_complex *out);
+
+/**
+ * K_nu(z), scaled by exp(z) if `scaled` is nonzero
+ *
+ * # Safety
+ * `out` must be null or valid for a write.
+ */
+int32_t zb_bessel_k(double nu, struct zb_complex z, int32_t scaled, struct zb_complex *out);
+
+/**
+ * H^(m)_nu(z) for `m` = 1 or 2, scaled by exp(-/+iz) if `scaled` is nonzero
+ *
+ * # Safety
+ * `out` must be null or valid for a write.
+ */
+int32_t zb_bessel_h(int32_t m,
+                    double nu,
+                    struct zb_complex z,
+                    int32_t scaled,
+                    struct zb_complex *out);
+
+/**
+ * J_nu at `n` points `z[0..n]`, written to `out[0..n]`
+ *
+ * # Safety
+ * `z` and `out` must be valid for `n` reads and writes.
+ */
+int32_t zb_bessel_j_batch(double nu,
+                          const struct zb_complex *z,
+                          size_t n,
+                          int32_t scaled,
+                          struct zb_complex *out);
+
+/**
+ * Y_nu at `n` points `z[0..n]`, written to `out[0..n]`
+ *
+ * # Safety
+ * `z` and `out` must be valid for `n` reads and writes.
+ */
+int32_t zb_bessel_y_batch(double nu,
+                          const struct zb_complex *z,
+                          size_t n,
+                          int32_t scaled,
+                          struct zb_complex *out);
+
+/**
+ * I_nu at `n` points `z[0..n]`, written to `out[0..n]`
+ *
+ * # Safety
+ * `z` and `out` must be valid for `n` reads and writes.
+ */
+int32_t zb_bessel_i_batch(double nu,
+                          const struct zb_complex *z,
+                          size_t n,
+                          int32_t scaled,
+                          struct zb_complex *out);
+
+/**
+ * K_nu at `n` points `z[0..n]`, written to `out[0..n]`
+ *
+ * # Safety
+ * `z` and `out` must be valid for `n` reads and writes.
+ */
+int32_t zb_bessel_k_batch(double nu,
+                          const struct zb_complex *z,
+                          size_t n,
+                          int32_t scaled,
+                          struct zb_complex *out);
+
+/**
+ * Ai(z), or Ai'(z) if `derivative` is nonzero, scaled by exp(zeta) if
+ * `scaled` is nonzero, where zeta = (2/3) z^(3/2)
+ *
+ * # Safety
+ * `out` must be null or valid for a write.
+ */
+int32_t zb_airy_ai(struct zb_complex z, int32_t derivative, int32_t scaled, struct zb_complex *out);
+
+/**
+ * Bi(z), or Bi'(z) if `derivative` is nonzero, scaled by exp(-|Re zeta|)
+ * if `scaled` is nonzero, where zeta = (2/3) z^(3/2)
+ *
+ * # Safety
+ * `out` must be null or valid for a write.
+ */
+int32_t zb_airy_bi(struct zb_complex z, int32_t derivative, int32_t scaled, struct zb_complex *out);
+
+#endif  /* ZBESSEL_RS_H */
@@ -0,0 +1,272 @@
+//! Zero-copy archives of grid results
+//!
+//! A [`GridArchive`] holds a [`BesselGrid`] together with what produced it:
+//! the function kind, scaling, orders, arguments, backend and the relative
+//! tolerance of the values. [`write_grid_archive`] writes it in the `rkyv`
+//! format, and [`read_grid_archive`] checks the bytes and returns a view
+//! into them without parsing or copying, so a precomputed table can be
+//! memory-mapped and shared by many processes:
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{bessel_j_grid, read_grid_archive, write_grid_archive, BesselKind, GridArchive, Scaling};
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let nus: Vec<f64> = (0..=10).map(f64::from).collect();
+//!     let zs: Vec<Complex64> = (1..=100).map(|k| Complex64::new(0.1 * k as f64, 0.0)).collect();
+//!     let grid = bessel_j_grid(&nus, &zs, Scaling::Unscaled)?;
+//!     let archive = GridArchive::new(BesselKind::J, Scaling::Unscaled, &nus, &zs, &grid)?;
+//!
+//!     let path = std::env::temp_dir().join("j.rkyv");
+//!     write_grid_archive(&archive, std::fs::File::create(&path)?)?;
+//!
+//!     // A memory map is page-aligned; a heap buffer must be aligned too
+//!     let mut bytes = rkyv::util::AlignedVec::<16>::new();
+//!     bytes.extend_from_slice(&std::fs::read(&path)?);
+//!     let table = read_grid_archive(&bytes)?;
+//!     assert_eq!(table.kind(), BesselKind::J);
+//!     println!("J_3({}) = {}", table.z(10), table.get(3, 10));
+//!     Ok(())
+//! }
+//! ```
+
+use crate::backend::{ActiveBackend, Backend};
+use crate::{BesselError, BesselGrid, BesselKind, Scaling};
+use num_complex::Complex64;
+use std::io::{self, Write};
+
+/// A grid of values with the metadata needed to use it elsewhere
+#[derive(Debug, Clone, PartialEq, rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)]
+pub struct GridArchive {
+    /// Kind of Bessel function
+    pub kind: BesselKind,
+    /// Scaling the values were computed with
+    pub scaling: Scaling,
+    /// Name of the backend that computed the values
+    pub backend: String,
+    /// Relative accuracy of the values; the Amos routines work to machine
+    /// precision
+    pub tolerance: f64,
+    /// Orders, one per row
+    pub nus: Vec<f64>,
+    /// Arguments as (re, im), one per column
+    pub zs: Vec<[f64; 2]>,
+    /// `values[i * cols + j]` as (re, im) is the function at order `i` and
+    /// argument `j`
+    pub values: Vec<[f64; 2]>,
+    /// Number of orders (rows)
+    pub rows: u64,
+    /// Number of arguments (columns)
+    pub cols: u64,
+    /// Number of computed values that experienced underflow
+    pub underflow_count: i32,
+}
+
+impl GridArchive {
+    /// Collect a grid and its metadata, recording the active backend
+    ///
+    /// # Parameters
+    /// * `kind` - Kind of Bessel function the grid holds
+    /// * `scaling` - Scaling the grid was computed with
+    /// * `nus` - Orders the grid was computed at
+    /// * `zs` - Arguments the grid was computed at
+    /// * `grid` - Values from [`bessel_grid`](crate::bessel_grid) or a variant
+    ///
+    /// # Errors
+    /// `InvalidParameter` if `nus` or `zs` does not match the grid shape
+    pub fn new(
+        kind: BesselKind,
+        scaling: Scaling,
+        nus: &[f64],
+        zs: &[Complex64],
+        grid: &BesselGrid,
+    ) -> Result<Self, BesselError> {
+        if nus.len() != grid.rows || zs.len() != grid.cols {
+            return Err(BesselError::InvalidParameter(format!(
+                "{} orders and {} arguments do not match a {} x {} grid",
+                nus.len(),
+                zs.len(),
+                grid.rows,
+                grid.cols
+            )));
+        }
+        Ok(GridArchive {
+            kind,
+            scaling,
+            backend: ActiveBackend::NAME.to_string(),
+            tolerance: f64::EPSILON,
+            nus: nus.to_vec(),
+            zs: zs.iter().map(|z| [z.re, z.im]).collect(),
+            values: grid.values.iter().map(|w| [w.re, w.im]).collect(),
+            rows: grid.rows as u64,
+            cols: grid.cols as u64,
+            underflow_count: grid.underflow_count,
+        })
+    }
+
+    /// The values as a [`BesselGrid`]
+    pub fn to_grid(&self) -> BesselGrid {
+        BesselGrid {
+            values: self
+                .values
+                .iter()
+                .map(|&[re, im]| Complex64::new(re, im))
+                .collect(),
+            rows: self.rows as usize,
+            cols: self.cols as usize,
+            underflow_count: self.underflow_count,
+        }
+    }
+}
+
+impl ArchivedGridArchive {
+    /// Kind of Bessel function
+    pub fn kind(&self) -> BesselKind {
+        match self.kind {
+            crate::kind::ArchivedBesselKind::J => BesselKind::J,
+            crate::kind::ArchivedBesselKind::Y => BesselKind::Y,
+            crate::kind::ArchivedBesselKind::I => BesselKind::I,
+            crate::kind::ArchivedBesselKind::K => BesselKind::K,
+            crate::kind::ArchivedBesselKind::H1 => BesselKind::H1,
+            crate::kind::ArchivedBesselKind::H2 => BesselKind::H2,
+        }
+    }
+
+    /// Scaling the values were computed with
+    pub fn scaling(&self) -> Scaling {
+        match self.scaling {
+            crate::ArchivedScaling::Unscaled => Scaling::Unscaled,
+            crate::ArchivedScaling::Scaled => Scaling::Scaled,
+        }
+    }
+
+    /// Shape as (orders, arguments)
+    pub fn dims(&self) -> (usize, usize) {
+        (
+            self.rows.to_native() as usize,
+            self.cols.to_native() as usize,
+        )
+    }
+
+    /// Order of row `i`
+    pub fn nu(&self, i: usize) -> f64 {
+        self.nus[i].to_native()
+    }
+
+    /// Argument of column `j`
+    pub fn z(&self, j: usize) -> Complex64 {
+        let [re, im] = &self.zs[j];
+        Complex64::new(re.to_native(), im.to_native())
+    }
+
+    /// Value at order index `i` and argument index `j`
+    ///
+    /// Panics if either index is out of range.
+    pub fn get(&self, i: usize, j: usize) -> Complex64 {
+        let (rows, cols) = self.dims();
+        assert!(
+            i < rows && j < cols,
+            "index ({}, {}) out of range for a {} x {} grid",
+            i,
+            j,
+            rows,
+            cols
+        );
+        let [re, im] = &self.values[i * cols + j];
+        Complex64::new(re.to_native(), im.to_native())
+    }
+}
+
+/// Write a grid archive in the `rkyv` format
+///
+/// # Parameters
+/// * `archive` - Grid and metadata from [`GridArchive::new`]
+/// * `writer` - Destination
+///
+/// # Errors
+/// Any error of the serializer or the writer
+pub fn write_grid_archive(archive: &GridArchive, mut writer: impl Write) -> io::Result<()> {
+    let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(archive).map_err(io::Error::other)?;
+    writer.write_all(&bytes)?;
+    writer.flush()
+}
+
+/// View the bytes of a grid archive in place
+///
+/// The bytes are validated once, so later lookups cannot read out of bounds.
+/// They must be aligned to 8 bytes, which memory maps and
+/// `rkyv::util::AlignedVec` are.
+///
+/// # Parameters
+/// * `bytes` - Bytes written by [`write_grid_archive`]
+///
+/// # Errors
+/// `InvalidData` if the bytes are misaligned, not a grid archive, or hold a
+/// grid whose values do not match its shape
+pub fn read_grid_archive(bytes: &[u8]) -> io::Result<&ArchivedGridArchive> {
+    let archive = rkyv::access::<ArchivedGridArchive, rkyv::rancor::Error>(bytes)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let (rows, cols) = archive.dims();
+    let consistent = archive.nus.len() == rows
+        && archive.zs.len() == cols
+        && rows.checked_mul(cols) == Some(archive.values.len());
+    if !consistent {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "grid archive values do not match its shape",
+        ));
+    }
+    Ok(archive)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bessel_grid;
+    use rkyv::util::AlignedVec;
+
+    #[test]
+    fn test_round_trip() {
+        let nus = [0.5, 1.5, 2.5];
+        let zs = [Complex64::new(1.0, 0.5), Complex64::new(3.0, -2.0)];
+        let grid = bessel_grid(BesselKind::K, &nus, &zs, Scaling::Scaled).unwrap();
+        let archive = GridArchive::new(BesselKind::K, Scaling::Scaled, &nus, &zs, &grid).unwrap();
+        assert_eq!(archive.to_grid(), grid);
+
+        let mut written = Vec::new();
+        write_grid_archive(&archive, &mut written).unwrap();
+        let mut bytes = AlignedVec::<16>::new();
+        bytes.extend_from_slice(&written);
+        let table = read_grid_archive(&bytes).unwrap();
+        assert_eq!(table.kind(), BesselKind::K);
+        assert_eq!(table.scaling(), Scaling::Scaled);
+        assert_eq!(table.backend.as_str(), ActiveBackend::NAME);
+        assert_eq!(table.dims(), (3, 2));
+        assert_eq!(table.nu(2), 2.5);
+        assert_eq!(table.z(1), zs[1]);
+        for i in 0..3 {
+            for j in 0..2 {
+                assert_eq!(table.get(i, j), grid.get(i, j));
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        let grid = bessel_grid(
+            BesselKind::J,
+            &[0.0],
+            &[Complex64::new(1.0, 0.0)],
+            Scaling::Unscaled,
+        )
+        .unwrap();
+        assert!(
+            GridArchive::new(BesselKind::J, Scaling::Unscaled, &[0.0, 1.0], &[], &grid).is_err()
+        );
+
+        let mut bytes = AlignedVec::<16>::new();
+        bytes.extend_from_slice(&[0xff; 64]);
+        let error = read_grid_archive(&bytes).err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,90 @@
+//! Fixed-size sequences returned as stack arrays
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{bessel_j_array, Scaling};
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let z = Complex64::new(1.0, 0.5);
+//!     let [j0, j1, j2, j3] = bessel_j_array::<4>(z, 0.0, Scaling::Unscaled)?;
+//!     println!("{} {} {} {}", j0, j1, j2, j3);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{bessel_into, BesselError, BesselKind, Scaling};
+use num_complex::Complex64;
+
+/// Calculate the orders ν, ν+1, ..., ν+N-1 of the given kind as an array
+///
+/// # Parameters
+/// * `kind` - Kind of Bessel function
+/// * `z` - Complex argument
+/// * `nu` - Order (real number)
+/// * `scaling` - Scaling option
+pub fn bessel_array<const N: usize>(
+    kind: BesselKind,
+    z: Complex64,
+    nu: f64,
+    scaling: Scaling,
+) -> Result<[Complex64; N], BesselError> {
+    let mut out = [Complex64::new(0.0, 0.0); N];
+    bessel_into(kind, z, nu, scaling, &mut out)?;
+    Ok(out)
+}
+
+/// Calculate J_ν(z), ..., J_{ν+N-1}(z) as an array
+pub fn bessel_j_array<const N: usize>(
+    z: Complex64,
+    nu: f64,
+    scaling: Scaling,
+) -> Result<[Complex64; N], BesselError> {
+    bessel_array(BesselKind::J, z, nu, scaling)
+}
+
+/// Calculate Y_ν(z), ..., Y_{ν+N-1}(z) as an array
+pub fn bessel_y_array<const N: usize>(
+    z: Complex64,
+    nu: f64,
+    scaling: Scaling,
+) -> Result<[Complex64; N], BesselError> {
+    bessel_array(BesselKind::Y, z, nu, scaling)
+}
+
+/// Calculate I_ν(z), ..., I_{ν+N-1}(z) as an array
+pub fn bessel_i_array<const N: usize>(
+    z: Complex64,
+    nu: f64,
+    scaling: Scaling,
+) -> Result<[Complex64; N], BesselError> {
+    bessel_array(BesselKind::I, z, nu, scaling)
+}
+
+/// Calculate K_ν(z), ..., K_{ν+N-1}(z) as an array
+pub fn bessel_k_array<const N: usize>(
+    z: Complex64,
+    nu: f64,
+    scaling: Scaling,
+) -> Result<[Complex64; N], BesselError> {
+    bessel_array(BesselKind::K, z, nu, scaling)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bessel_k;
+
+    #[test]
+    fn test_array_matches_sequence() {
+        let z = Complex64::new(2.0, -1.0);
+        let values = bessel_k_array::<5>(z, 0.5, Scaling::Unscaled).unwrap();
+        let expected = bessel_k(z, 0.5, 1, 5).unwrap();
+        assert_eq!(values.as_slice(), expected.values.as_slice());
+    }
+
+    #[test]
+    fn test_empty_array() {
+        let z = Complex64::new(1.0, 0.0);
+        assert!(bessel_i_array::<0>(z, 0.0, Scaling::Unscaled).is_err());
+    }
+}
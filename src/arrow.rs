@@ -0,0 +1,190 @@
+//! Kernels over Apache Arrow arrays
+//!
+//! Complex columns are `FixedSizeList<Float64, 2>` arrays whose two items
+//! are the real and imaginary parts. [`bessel_arrow`] evaluates one function
+//! of one order over such a column and [`bessel_arrow_real`] over a
+//! `Float64` column of real arguments; both return a complex column of the
+//! same length. A null slot, or a null real or imaginary part, gives a null
+//! result; the remaining points are evaluated in one batch with
+//! [`bessel_slice`](crate::bessel_slice), without boxing individual values.
+//!
+//! ```rust
+//! use arrow_array::{Array, Float64Array};
+//! use zbessel_rs::{bessel_arrow_real, BesselKind, Scaling};
+//!
+//! fn main() -> Result<(), zbessel_rs::BesselError> {
+//!     let x = Float64Array::from(vec![Some(1.0), None, Some(-2.5)]);
+//!     let j0 = bessel_arrow_real(BesselKind::J, 0.0, &x, Scaling::Unscaled)?;
+//!     assert_eq!(j0.len(), 3);
+//!     assert!(j0.is_null(1));
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{bessel_slice, BesselError, BesselKind, Scaling};
+use arrow_array::builder::{FixedSizeListBuilder, Float64Builder};
+use arrow_array::cast::AsArray;
+use arrow_array::types::Float64Type;
+use arrow_array::{Array, FixedSizeListArray, Float64Array};
+use arrow_schema::DataType;
+use num_complex::Complex64;
+
+/// Argument at each slot, `None` where the input is null
+type Arguments = Vec<Option<Complex64>>;
+
+/// The arguments of a `FixedSizeList<Float64, 2>` column
+fn complex_arguments(z: &FixedSizeListArray) -> Result<Arguments, BesselError> {
+    if z.value_length() != 2 || z.value_type() != DataType::Float64 {
+        return Err(BesselError::InvalidParameter(format!(
+            "expected FixedSizeList<Float64, 2>, got {}",
+            z.data_type()
+        )));
+    }
+    let parts = z.values().as_primitive::<Float64Type>();
+    Ok((0..z.len())
+        .map(|k| {
+            let re = z.value_offset(k) as usize;
+            let im = re + 1;
+            let valid = z.is_valid(k) && parts.is_valid(re) && parts.is_valid(im);
+            valid.then(|| Complex64::new(parts.value(re), parts.value(im)))
+        })
+        .collect())
+}
+
+/// Evaluate the non-null arguments in one batch and build the output column
+fn evaluate(
+    kind: BesselKind,
+    nu: f64,
+    arguments: &[Option<Complex64>],
+    scaling: Scaling,
+) -> Result<FixedSizeListArray, BesselError> {
+    let zs: Vec<Complex64> = arguments.iter().flatten().copied().collect();
+    let mut values = bessel_slice(kind, &zs, nu, scaling)?.into_iter();
+    let mut builder =
+        FixedSizeListBuilder::with_capacity(Float64Builder::new(), 2, arguments.len());
+    for argument in arguments {
+        match argument.and_then(|_| values.next()) {
+            Some(value) => {
+                builder.values().append_slice(&[value.re, value.im]);
+                builder.append(true);
+            }
+            None => {
+                builder.values().append_nulls(2);
+                builder.append(false);
+            }
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Calculate a Bessel function of the given kind over a complex column
+///
+/// # Parameters
+/// * `kind` - Kind of Bessel function
+/// * `nu` - Order (real number)
+/// * `z` - `FixedSizeList<Float64, 2>` column of (re, im) arguments
+/// * `scaling` - Scaling option, with the factor of [`bessel`](crate::bessel) for `kind`
+///
+/// # Returns
+/// A `FixedSizeList<Float64, 2>` column, null where `z` is; the first point
+/// that fails is reported as the error
+pub fn bessel_arrow(
+    kind: BesselKind,
+    nu: f64,
+    z: &FixedSizeListArray,
+    scaling: Scaling,
+) -> Result<FixedSizeListArray, BesselError> {
+    evaluate(kind, nu, &complex_arguments(z)?, scaling)
+}
+
+/// Calculate a Bessel function of the given kind over a real column
+///
+/// The result is complex, since Y, K and non-integer orders of J and I are
+/// complex for negative arguments.
+///
+/// # Parameters
+/// * `kind` - Kind of Bessel function
+/// * `nu` - Order (real number)
+/// * `x` - Real arguments
+/// * `scaling` - Scaling option, with the factor of [`bessel`](crate::bessel) for `kind`
+pub fn bessel_arrow_real(
+    kind: BesselKind,
+    nu: f64,
+    x: &Float64Array,
+    scaling: Scaling,
+) -> Result<FixedSizeListArray, BesselError> {
+    let arguments: Arguments = x
+        .iter()
+        .map(|x| x.map(|x| Complex64::new(x, 0.0)))
+        .collect();
+    evaluate(kind, nu, &arguments, scaling)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bessel;
+
+    fn column(values: &[Option<(Option<f64>, f64)>]) -> FixedSizeListArray {
+        let mut builder = FixedSizeListBuilder::new(Float64Builder::new(), 2);
+        for value in values {
+            match value {
+                Some((re, im)) => {
+                    builder.values().append_option(*re);
+                    builder.values().append_value(*im);
+                    builder.append(true);
+                }
+                None => {
+                    builder.values().append_nulls(2);
+                    builder.append(false);
+                }
+            }
+        }
+        builder.finish()
+    }
+
+    #[test]
+    fn test_null_propagation() {
+        let z = column(&[
+            Some((Some(1.5), -0.5)),
+            None,
+            Some((None, 2.0)),
+            Some((Some(-3.0), 0.0)),
+        ]);
+        let out = bessel_arrow(BesselKind::Y, 0.5, &z, Scaling::Unscaled).unwrap();
+        assert_eq!(out.len(), 4);
+        assert_eq!(out.null_count(), 2);
+        assert!(out.is_null(1) && out.is_null(2));
+        let parts = out.values().as_primitive::<Float64Type>();
+        for (k, z) in [
+            (0, Complex64::new(1.5, -0.5)),
+            (3, Complex64::new(-3.0, 0.0)),
+        ] {
+            let expected = bessel(BesselKind::Y, 0.5, z, Scaling::Unscaled).unwrap();
+            let value = Complex64::new(parts.value(2 * k), parts.value(2 * k + 1));
+            assert!((value - expected).norm() <= 1e-12 * expected.norm());
+        }
+
+        // Slices keep their offset into the values
+        let out = bessel_arrow(BesselKind::Y, 0.5, &z.slice(3, 1), Scaling::Unscaled).unwrap();
+        assert!(out.is_valid(0));
+        let x = Float64Array::from(vec![None, Some(-3.0)]);
+        let real = bessel_arrow_real(BesselKind::Y, 0.5, &x, Scaling::Unscaled).unwrap();
+        assert!(real.is_null(0));
+        assert_eq!(
+            real.value(1).as_primitive::<Float64Type>(),
+            out.value(0).as_primitive()
+        );
+    }
+
+    #[test]
+    fn test_arrow_errors() {
+        let mut builder = FixedSizeListBuilder::new(Float64Builder::new(), 3);
+        builder.values().append_slice(&[1.0, 2.0, 3.0]);
+        builder.append(true);
+        let wrong = builder.finish();
+        assert!(bessel_arrow(BesselKind::J, 0.0, &wrong, Scaling::Unscaled).is_err());
+        let x = Float64Array::from(vec![0.0]);
+        assert!(bessel_arrow_real(BesselKind::K, 0.0, &x, Scaling::Unscaled).is_err());
+    }
+}
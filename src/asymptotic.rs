@@ -0,0 +1,352 @@
+//! Hankel expansion for large arguments
+//!
+//! For Re z >= 0 and large |z| the functions follow from
+//!
+//! H1,2_ν(z) = sqrt(2/(πz)) e^{±i(z - νπ/2 - π/4)} Σ_{k<ℓ} (±i)^k a_k(ν) z^{-k}
+//!
+//! (DLMF 10.17.5-6), with J and Y from H1 and H2, I_ν(z) = e^{±iνπ/2}
+//! J_ν(∓iz) and K_ν(z) = sqrt(π/(2z)) e^{-z} Σ_{k<ℓ} a_k(ν) z^{-k}. The term
+//! count ℓ is the smallest with ℓ >= ν - 1/2 whose remainder bound
+//! (DLMF 10.17.14-15, taken over the whole right half-plane)
+//!
+//! 2 χ(ℓ) |a_ℓ(ν)| |z|^{-ℓ} exp(π |ν² - 1/4| / (2|z|))
+//!
+//! is below half an ulp; if no ℓ up to [`MAX_TERMS`] qualifies, or the
+//! result fails [`direct::accept`], the caller falls back to the general
+//! algorithm.
+//!
+//! The phase z - νπ/2 - π/4 is never formed in floating point, where it
+//! would lose the digits of z beyond 1/ε: e^{∓iπ(ν/2 + 1/4)} comes from
+//! [`cis_pi`], and e^{±iz} from [`cis`], which reduces Re z modulo π/2 in
+//! double-double arithmetic against a three-part π/2 before taking the sine
+//! and cosine of a remainder of at most π/4. The expansion therefore stays
+//! accurate, independently of the platform's `sin` and `cos` reduction, up to
+//! |Re z| = [`REDUCTION_LIMIT`], well past the range where Amos reports loss
+//! of precision; beyond it the reduction is left to the platform's `sin` and
+//! `cos`.
+
+use crate::gamma::ln_gamma;
+use crate::{direct, BesselKind};
+use num_complex::Complex64;
+use std::f64::consts::{FRAC_2_PI, FRAC_PI_2, PI};
+
+/// Smallest |z| tried; below it the expansion cannot reach full precision
+const MIN_MODULUS: f64 = 16.0;
+
+/// Most terms summed
+const MAX_TERMS: usize = 40;
+
+/// Largest |z| (and ν) Amos evaluates without reporting loss of precision
+pub(crate) fn argument_limit() -> f64 {
+    (0.5 * i32::MAX as f64).sqrt()
+}
+
+/// χ(ℓ) = π^{1/2} Γ(ℓ/2 + 1) / Γ(ℓ/2 + 1/2)
+fn chi(l: usize) -> f64 {
+    let half = 0.5 * l as f64;
+    PI.sqrt() * (ln_gamma(half + 1.0) - ln_gamma(half + 0.5)).exp()
+}
+
+/// Coefficients a_0(ν), ..., a_{ℓ-1}(ν) for the smallest admissible ℓ
+fn coefficients(nu: f64, r: f64) -> Option<Vec<f64>> {
+    let mu = 4.0 * nu * nu;
+    let growth = (0.5 * PI * (nu * nu - 0.25).abs() / r).exp();
+    let mut a = vec![1.0];
+    let mut scale = 1.0;
+    for l in 1..=MAX_TERMS {
+        let odd = (2 * l - 1) as f64;
+        let next = a[l - 1] * (mu - odd * odd) / (8.0 * l as f64);
+        scale /= r;
+        let bound = 2.0 * chi(l) * next.abs() * scale * growth;
+        if l as f64 >= nu - 0.5 && bound <= 0.5 * f64::EPSILON {
+            return Some(a);
+        }
+        a.push(next);
+    }
+    None
+}
+
+/// Whether z and ν are in the right half-plane and far enough out to try
+fn in_domain(z: Complex64, nu: f64) -> bool {
+    z.re >= 0.0 && nu >= 0.0 && z.norm() >= MIN_MODULUS
+}
+
+/// Whether the expansion reaches full precision at z and ν, where [`value`]
+/// applies unless the value is not representable
+pub(crate) fn applies(z: Complex64, nu: f64) -> bool {
+    in_domain(z, nu) && coefficients(nu, z.norm()).is_some()
+}
+
+/// π/2 as the unevaluated sum of three doubles
+const FRAC_PI_2_PARTS: [f64; 3] = [FRAC_PI_2, 6.123233995736766e-17, -1.4973849048591698e-33];
+
+/// |x| up to which [`cis`] reduces x itself; the quadrant count stays an exact
+/// integer below it
+pub(crate) const REDUCTION_LIMIT: f64 = 4_503_599_627_370_496.0;
+
+/// a·b as an unevaluated sum, exact
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    (p, a.mul_add(b, -p))
+}
+
+/// e^{ix}, with x reduced modulo π/2 in double-double arithmetic
+pub(crate) fn cis(x: f64) -> Complex64 {
+    if !(x.abs() > 0.25 * PI && x.abs() < REDUCTION_LIMIT) {
+        let (sin, cos) = x.sin_cos();
+        return Complex64::new(cos, sin);
+    }
+    let k = (x * FRAC_2_PI).round();
+    let [c1, c2, c3] = FRAC_PI_2_PARTS;
+    // x - k c1 is exact by Sterbenz's lemma, and k c1, k c2 are split exactly
+    let (p1, e1) = two_product(k, c1);
+    let (p2, e2) = two_product(k, c2);
+    let r = ((x - p1) - p2) - (e1 + e2 + k * c3);
+    let (sin, cos) = r.sin_cos();
+    match (k as i64).rem_euclid(4) {
+        0 => Complex64::new(cos, sin),
+        1 => Complex64::new(-sin, cos),
+        2 => Complex64::new(-cos, -sin),
+        _ => Complex64::new(sin, -cos),
+    }
+}
+
+/// e^w, with the imaginary part reduced by [`cis`]
+pub(crate) fn exp(w: Complex64) -> Complex64 {
+    w.re.exp() * cis(w.im)
+}
+
+/// e^{iπt}, with t reduced exactly first
+fn cis_pi(t: f64) -> Complex64 {
+    let (sin, cos) = (PI * (t % 2.0)).sin_cos();
+    Complex64::new(cos, sin)
+}
+
+/// e^{±i(z - νπ/2 - π/4) + shift} for `sign` = ±1, with the phase reduced
+/// by [`cis`] and [`cis_pi`]
+pub(crate) fn hankel_phase(z: Complex64, nu: f64, sign: f64, shift: Complex64) -> Complex64 {
+    exp(sign * Complex64::i() * z + shift) * cis_pi(-sign * (0.5 * nu + 0.25))
+}
+
+/// Accumulates prefactor · exp(exponent) · Σ a_k (u/z)^k and their magnitudes
+struct Pieces<'a> {
+    a: &'a [f64],
+    shift: Complex64,
+    value: Complex64,
+    size: f64,
+}
+
+impl Pieces<'_> {
+    fn add(&mut self, prefactor: Complex64, exponent: Complex64, u: Complex64) {
+        let mut power = Complex64::new(1.0, 0.0);
+        let mut sum = Complex64::new(0.0, 0.0);
+        let mut size = 0.0;
+        for &a in self.a {
+            let term = a * power;
+            sum += term;
+            size += term.norm();
+            power *= u;
+        }
+        let factor = prefactor * exp(exponent + self.shift);
+        self.value += factor * sum;
+        self.size += factor.norm() * size;
+    }
+
+    /// α H1_ν(w) + β H2_ν(w)
+    fn hankel(&mut self, nu: f64, w: Complex64, alpha: Complex64, beta: Complex64) {
+        let i = Complex64::i();
+        let root = (2.0 / PI).sqrt() / w.sqrt();
+        let phase = cis_pi(-0.5 * nu - 0.25);
+        if alpha != Complex64::new(0.0, 0.0) {
+            self.add(alpha * root * phase, i * w, i / w);
+        }
+        if beta != Complex64::new(0.0, 0.0) {
+            self.add(beta * root * phase.conj(), -i * w, -i / w);
+        }
+    }
+}
+
+/// F_ν(z) by the Hankel expansion, or `None` if it does not apply
+pub(crate) fn value(kind: BesselKind, z: Complex64, nu: f64, kode: i32) -> Option<Complex64> {
+    if !in_domain(z, nu) {
+        return None;
+    }
+    let a = coefficients(nu, z.norm())?;
+    let shift = if kode == 2 {
+        kind.log_scale(z)
+    } else {
+        Complex64::new(0.0, 0.0)
+    };
+    let mut pieces = Pieces {
+        a: &a,
+        shift,
+        value: Complex64::new(0.0, 0.0),
+        size: 0.0,
+    };
+    let zero = Complex64::new(0.0, 0.0);
+    let one = Complex64::new(1.0, 0.0);
+    let i = Complex64::i();
+    match kind {
+        BesselKind::H1 => pieces.hankel(nu, z, one, zero),
+        BesselKind::H2 => pieces.hankel(nu, z, zero, one),
+        BesselKind::J => pieces.hankel(nu, z, 0.5 * one, 0.5 * one),
+        BesselKind::Y => pieces.hankel(nu, z, -0.5 * i, 0.5 * i),
+        BesselKind::I => {
+            // Rotate into the right half-plane: I_ν(z) = e^{±iνπ/2} J_ν(∓iz)
+            let (w, phase) = if z.im >= 0.0 {
+                (-i * z, cis_pi(0.5 * nu))
+            } else {
+                (i * z, cis_pi(-0.5 * nu))
+            };
+            pieces.hankel(nu, w, 0.5 * phase, 0.5 * phase);
+        }
+        BesselKind::K => pieces.add((0.5 * PI / z).sqrt(), -z, 1.0 / z),
+    }
+    direct::accept(pieces.value, pieces.size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::direct::check_against_backend;
+
+    #[test]
+    fn test_expansion_matches_backend() {
+        let zs = [
+            Complex64::new(20.0, 0.0),
+            Complex64::new(35.0, 12.0),
+            Complex64::new(0.0, -60.0),
+            Complex64::new(150.0, -3.0),
+            Complex64::new(400.0, 400.0),
+            Complex64::new(1e4, 1.0),
+        ];
+        let nus = [0.0, 0.3, 1.0, 2.7, 9.0];
+        let used = check_against_backend(value, &BesselKind::ALL, &zs, &nus, 1e-12, |_, exact| {
+            exact.norm()
+        });
+        assert!(used > 250, "{}", used);
+    }
+
+    #[test]
+    fn test_huge_arguments() {
+        // Reference values from mpmath at the exact f64 arguments
+        let cases = [
+            (
+                BesselKind::J,
+                0.0,
+                (1e8, 0.0),
+                (3.206_029_534_041_208e-5, 0.0),
+            ),
+            (
+                BesselKind::Y,
+                0.0,
+                (1e8, 0.0),
+                (7.306_391_165_521_707e-5, 0.0),
+            ),
+            (
+                BesselKind::J,
+                0.0,
+                (1e300, 0.0),
+                (-7.860_673_062_724_093e-151, 0.0),
+            ),
+            (
+                BesselKind::Y,
+                0.0,
+                (1e300, 0.0),
+                (-1.368_136_045_034_248e-151, 0.0),
+            ),
+            (
+                BesselKind::J,
+                1.0,
+                (1e15, 0.0),
+                (2.446_866_512_377_132_6e-8, 0.0),
+            ),
+            (
+                BesselKind::Y,
+                1.0,
+                (1e15, 0.0),
+                (-6.156_638_646_885_009e-9, 0.0),
+            ),
+            (
+                BesselKind::J,
+                3.0,
+                (123456789012.5, 0.0),
+                (-4.595_590_285_282_605e-7, 0.0),
+            ),
+            (
+                BesselKind::Y,
+                3.0,
+                (123456789012.5, 0.0),
+                (-2.223_831_311_470_972_7e-6, 0.0),
+            ),
+            (
+                BesselKind::J,
+                0.0,
+                (1_234_567_890_123_456.8, 0.0),
+                (2.041_147_780_779_511_2e-8, 0.0),
+            ),
+            (
+                BesselKind::Y,
+                0.0,
+                (1_234_567_890_123_456.8, 0.0),
+                (-9.951_562_387_916_119e-9, 0.0),
+            ),
+            (
+                BesselKind::J,
+                2.5,
+                (1_234_567_890_123_456.8, 0.0),
+                (-7.396_277_124_034_223e-9, 0.0),
+            ),
+            (
+                BesselKind::J,
+                2.5,
+                (1e12, 3.0),
+                (4.909_977_850_735_303e-6, -6.326_115_558_493_112e-6),
+            ),
+        ];
+        for (kind, nu, (re, im), (exact_re, exact_im)) in cases {
+            let z = Complex64::new(re, im);
+            let exact = Complex64::new(exact_re, exact_im);
+            let result = crate::bessel(kind, nu, z, crate::Scaling::Unscaled).unwrap();
+            let error = (result - exact).norm() / exact.norm();
+            assert!(error < 1e-14, "{:?}_{}({}): {:e}", kind, nu, z, error);
+        }
+    }
+
+    #[test]
+    fn test_phase_reduction() {
+        // cos x and sin x from mpmath
+        let cases = [
+            (7.0, 0.753_902_254_343_304_6, 0.656_986_598_718_789_1),
+            (
+                -987654321.125,
+                -0.144_718_917_754_555_86,
+                -0.989_472_806_520_699_7,
+            ),
+            (
+                1_234_567_890_123_456.8,
+                0.945_469_849_079_267_2,
+                0.325_709_632_160_345_6,
+            ),
+            (
+                4_503_599_627_370_495.5,
+                -0.006_974_831_952_871_47,
+                0.999_975_675_563_775_5,
+            ),
+        ];
+        for (x, cos, sin) in cases {
+            let error = (cis(x) - Complex64::new(cos, sin)).norm();
+            assert!(error < 4.0 * f64::EPSILON, "{}: {:e}", x, error);
+        }
+    }
+
+    #[test]
+    fn test_term_count() {
+        // More terms are needed closer to the origin, and none qualify below
+        let near = coefficients(0.0, 20.0).unwrap().len();
+        let far = coefficients(0.0, 1e4).unwrap().len();
+        assert!(far < near && near <= MAX_TERMS);
+        assert!(coefficients(0.0, 5.0).is_none());
+        assert!(value(BesselKind::J, Complex64::new(-50.0, 1.0), 0.0, 1).is_none());
+    }
+}
@@ -0,0 +1,101 @@
+//! Backend calling the C++ translation of the Amos routines
+
+use super::Backend;
+use crate::BesselKind;
+use num_complex::Complex64;
+
+/// The C++ translation of the Amos Fortran routines
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Amos;
+
+/// Chunk length up to which zbesy work arrays live on the stack
+const STACK_WORK: usize = 32;
+
+impl Backend for Amos {
+    const NAME: &'static str = "amos";
+
+    fn bessel(
+        kind: BesselKind,
+        z: Complex64,
+        nu: f64,
+        kode: i32,
+        cyr: &mut [f64],
+        cyi: &mut [f64],
+    ) -> (i32, i32) {
+        let len = cyr.len();
+        if kind != BesselKind::Y {
+            return Self::bessel_with_work(kind, z, nu, kode, cyr, cyi, &mut []);
+        }
+        let mut stack = [0.0; 2 * STACK_WORK];
+        let mut heap;
+        let work = if len <= STACK_WORK {
+            &mut stack[..2 * len]
+        } else {
+            heap = vec![0.0; 2 * len];
+            &mut heap[..]
+        };
+        Self::bessel_with_work(kind, z, nu, kode, cyr, cyi, work)
+    }
+
+    fn bessel_with_work(
+        kind: BesselKind,
+        z: Complex64,
+        nu: f64,
+        kode: i32,
+        cyr: &mut [f64],
+        cyi: &mut [f64],
+        work: &mut [f64],
+    ) -> (i32, i32) {
+        use crate::sys::{zbesh, zbesi, zbesj, zbesk, zbesy};
+
+        assert_eq!(
+            cyr.len(),
+            cyi.len(),
+            "output arrays must have the same length"
+        );
+        let len = cyr.len();
+        let n = i32::try_from(len).expect("sequence length exceeds the Amos int range");
+        let mut nz = 0;
+        let (zr, zi) = (z.re, z.im);
+        let (cyr, cyi) = (cyr.as_mut_ptr(), cyi.as_mut_ptr());
+
+        let ierr = match kind {
+            BesselKind::J => unsafe { zbesj(zr, zi, nu, kode, n, cyr, cyi, &mut nz) },
+            BesselKind::I => unsafe { zbesi(zr, zi, nu, kode, n, cyr, cyi, &mut nz) },
+            BesselKind::K => unsafe { zbesk(zr, zi, nu, kode, n, cyr, cyi, &mut nz) },
+            BesselKind::H1 => unsafe { zbesh(zr, zi, nu, kode, 1, n, cyr, cyi, &mut nz) },
+            BesselKind::H2 => unsafe { zbesh(zr, zi, nu, kode, 2, n, cyr, cyi, &mut nz) },
+            BesselKind::Y => {
+                assert!(work.len() >= 2 * len, "zbesy needs 2n work values");
+                let (cwrkr, cwrki) = work.split_at_mut(len);
+                unsafe {
+                    zbesy(
+                        zr,
+                        zi,
+                        nu,
+                        kode,
+                        n,
+                        cyr,
+                        cyi,
+                        &mut nz,
+                        cwrkr.as_mut_ptr(),
+                        cwrki.as_mut_ptr(),
+                    )
+                }
+            }
+        };
+        (ierr, nz)
+    }
+
+    fn airy_ai(z: Complex64, id: i32, kode: i32) -> (i32, Complex64, i32) {
+        let (mut air, mut aii, mut nz) = (0.0, 0.0, 0);
+        let ierr = unsafe { crate::sys::zairy(z.re, z.im, id, kode, &mut air, &mut aii, &mut nz) };
+        (ierr, Complex64::new(air, aii), nz)
+    }
+
+    fn airy_bi(z: Complex64, id: i32, kode: i32) -> (i32, Complex64) {
+        let (mut bir, mut bii) = (0.0, 0.0);
+        let ierr = unsafe { crate::sys::zbiry(z.re, z.im, id, kode, &mut bir, &mut bii) };
+        (ierr, Complex64::new(bir, bii))
+    }
+}
@@ -0,0 +1,131 @@
+//! Backend abstraction over the numerical implementation
+//!
+//! Every public function evaluates through the [`Backend`] selected at compile
+//! time by a cargo feature:
+//!
+//! * [`Amos`] (feature `amos`, enabled by default) calls the C++ translation
+//!   of the Amos routines
+//! * [`RustBackend`] (feature `backend-rust`) is written in Rust and needs no
+//!   C++ toolchain; it is used when `amos` is disabled
+//!
+//! With the `tracing` feature the selected backend is wrapped in `Traced`,
+//! which reports every call with its inputs, IERR, NZ and duration.
+//!
+//! Backends work on the raw split-array interface; validation, negative
+//! orders and error reporting stay in the shared front end.
+//!
+//! Backends can also be called directly, for example to compare two
+//! implementations:
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{Backend, BesselKind};
+//!
+//! fn k0<B: Backend>(z: Complex64) -> Complex64 {
+//!     let (mut re, mut im) = ([0.0; 1], [0.0; 1]);
+//!     let (ierr, nz) = B::bessel(BesselKind::K, z, 0.0, 1, &mut re, &mut im);
+//!     assert_eq!((ierr, nz), (0, 0));
+//!     Complex64::new(re[0], im[0])
+//! }
+//!
+//! #[cfg(all(feature = "amos", feature = "backend-rust"))]
+//! {
+//!     use zbessel_rs::{Amos, RustBackend};
+//!     let z = Complex64::new(1.0, 1.0);
+//!     let (a, b) = (k0::<Amos>(z), k0::<RustBackend>(z));
+//!     assert!((a - b).norm() < 1e-13 * a.norm());
+//!     println!("{}: {}, {}: {}", Amos::NAME, a, RustBackend::NAME, b);
+//! }
+//! ```
+
+use crate::BesselKind;
+use num_complex::Complex64;
+
+#[cfg(feature = "amos")]
+mod amos;
+#[cfg(feature = "backend-rust")]
+mod pure;
+#[cfg(feature = "tracing")]
+mod traced;
+
+#[cfg(feature = "amos")]
+pub use amos::Amos;
+#[cfg(feature = "backend-rust")]
+pub use pure::RustBackend;
+#[cfg(feature = "tracing")]
+pub use traced::Traced;
+
+/// Numerical implementation of the Bessel and Airy functions
+///
+/// All methods follow the Amos conventions: `kode` is 1 (unscaled) or 2
+/// (scaled), and the first returned integer is the IERR code (0 normal, 1
+/// input error, 2 overflow, 3 reduced precision with values returned, 4 no
+/// precision, 5 no convergence). Inputs have already been validated and
+/// `nu` is non-negative.
+pub trait Backend {
+    /// Short name used in diagnostics
+    const NAME: &'static str;
+
+    /// Largest |z| and ν+n-1 evaluated; beyond it [`bessel`](Backend::bessel)
+    /// returns IERR 4. The default is the Amos bound, half the largest `int`
+    const RANGE_LIMIT: f64 = 0.5 * i32::MAX as f64;
+
+    /// Evaluate orders ν, ν+1, ..., ν+n-1 of `kind` into split output arrays
+    ///
+    /// `n` is the common length of `cyr` and `cyi`. Returns IERR and the number
+    /// of underflowed values.
+    fn bessel(
+        kind: BesselKind,
+        z: Complex64,
+        nu: f64,
+        kode: i32,
+        cyr: &mut [f64],
+        cyi: &mut [f64],
+    ) -> (i32, i32);
+
+    /// [`bessel`](Backend::bessel) with caller-provided scratch space
+    ///
+    /// `work` holds at least 2n values for backends that need work arrays
+    /// (Amos for Y); the default ignores it.
+    fn bessel_with_work(
+        kind: BesselKind,
+        z: Complex64,
+        nu: f64,
+        kode: i32,
+        cyr: &mut [f64],
+        cyi: &mut [f64],
+        work: &mut [f64],
+    ) -> (i32, i32) {
+        let _ = work;
+        Self::bessel(kind, z, nu, kode, cyr, cyi)
+    }
+
+    /// Evaluate Ai(z) (`id` = 0) or Ai'(z) (`id` = 1)
+    ///
+    /// Returns IERR, the value and the underflow indicator.
+    fn airy_ai(z: Complex64, id: i32, kode: i32) -> (i32, Complex64, i32);
+
+    /// Evaluate Bi(z) (`id` = 0) or Bi'(z) (`id` = 1)
+    ///
+    /// Returns IERR and the value.
+    fn airy_bi(z: Complex64, id: i32, kode: i32) -> (i32, Complex64);
+}
+
+/// Backend selected by the features
+#[cfg(feature = "amos")]
+pub(crate) type Selected = Amos;
+
+/// Backend selected by the features
+#[cfg(all(not(feature = "amos"), feature = "backend-rust"))]
+pub(crate) type Selected = RustBackend;
+
+/// Backend used by the public functions
+#[cfg(not(feature = "tracing"))]
+pub(crate) type ActiveBackend = Selected;
+
+/// Backend used by the public functions, reporting every call to `tracing`
+#[cfg(feature = "tracing")]
+pub(crate) type ActiveBackend = Traced<Selected>;
+
+#[cfg(not(any(feature = "amos", feature = "backend-rust")))]
+compile_error!("zbessel-rs needs a backend; enable the `amos` or `backend-rust` feature");
@@ -0,0 +1,123 @@
+//! Airy functions through the modified Bessel functions of order 1/3 and 2/3
+//!
+//! Maclaurin series for |z| <= 1, otherwise DLMF 9.6.1–9.6.3 with
+//! ζ = (2/3) z^{3/2}, continued past Re(ζ) = 0 where |arg z| > π/3.
+
+use super::{cis_pi, continued_k, ik, times_exp};
+use crate::extended::ScaledComplex;
+use num_complex::Complex64;
+use std::f64::consts::{FRAC_PI_2, PI};
+
+/// Ai(0)
+const C1: f64 = 0.355_028_053_887_817_2;
+/// -Ai'(0)
+const C2: f64 = 0.258_819_403_792_806_8;
+const SQRT_3: f64 = 1.732_050_807_568_877_2;
+
+/// Scaled value, its log scale factor and IERR (0 or 3), or the failing IERR
+type AiryResult = Result<(ScaledComplex, Complex64, i32), i32>;
+
+/// IERR for |z|: 4 beyond the Amos limit, 3 beyond its square root
+fn range_check(z: Complex64) -> Result<i32, i32> {
+    let limit = (0.5 * i32::MAX as f64).powf(2.0 / 3.0);
+    let az = z.norm();
+    if az > limit {
+        Err(4)
+    } else if az > limit.sqrt() {
+        Ok(3)
+    } else {
+        Ok(0)
+    }
+}
+
+/// The two Maclaurin series f, g (or f', g') with Ai = c1 f - c2 g
+fn maclaurin(z: Complex64, derivative: bool) -> (Complex64, Complex64) {
+    let z3 = z * z * z;
+    let (mut f_term, mut g_term) = if derivative {
+        (0.5 * z * z, Complex64::new(1.0, 0.0))
+    } else {
+        (Complex64::new(1.0, 0.0), z)
+    };
+    let (mut f, mut g) = (f_term, g_term);
+    for k in 1..100 {
+        let k3 = 3.0 * k as f64;
+        if derivative {
+            f_term *= z3 / ((k3 + 2.0) * k3);
+            g_term *= z3 / ((k3 - 2.0) * k3);
+        } else {
+            f_term *= z3 / ((k3 - 1.0) * k3);
+            g_term *= z3 / (k3 * (k3 + 1.0));
+        }
+        f += f_term;
+        g += g_term;
+        if f_term.norm() + g_term.norm() <= f64::EPSILON * (f.norm() + g.norm()) {
+            break;
+        }
+    }
+    (f, g)
+}
+
+/// ζ and the continuation m such that ζ = w e^{mπi} with Re(w) >= 0
+fn zeta(z: Complex64) -> (Complex64, Complex64, f64) {
+    let zeta = 2.0 / 3.0 * z * z.sqrt();
+    let theta = 1.5 * z.arg();
+    let m = if theta > FRAC_PI_2 {
+        1.0
+    } else if theta < -FRAC_PI_2 {
+        -1.0
+    } else {
+        0.0
+    };
+    let w = if m == 0.0 { zeta } else { -zeta };
+    (zeta, w, m)
+}
+
+/// e^{ζ} Ai(z) or e^{ζ} Ai'(z), with log scale ζ
+pub(super) fn ai(z: Complex64, derivative: bool) -> AiryResult {
+    let ierr = range_check(z)?;
+    let (zeta, w, m) = zeta(z);
+    if z.norm() <= 1.0 {
+        let (f, g) = maclaurin(z, derivative);
+        let value = times_exp(ScaledComplex::from(C1 * f - C2 * g), zeta);
+        return Ok((value, zeta, ierr));
+    }
+    let nu = if derivative { 2.0 / 3.0 } else { 1.0 / 3.0 };
+    let k = if m == 0.0 {
+        ik::k_sequence(nu, w, 1)?[0]
+    } else {
+        continued_k(nu, w, m, 1)?[0]
+    };
+    let prefactor = if derivative {
+        -z / (PI * SQRT_3)
+    } else {
+        (z / 3.0).sqrt() / PI
+    };
+    Ok((ik::mul(k, prefactor), zeta, ierr))
+}
+
+/// e^{-|Re ζ|} Bi(z) or e^{-|Re ζ|} Bi'(z), with log scale -|Re ζ|
+pub(super) fn bi(z: Complex64, derivative: bool) -> AiryResult {
+    let ierr = range_check(z)?;
+    let (zeta, w, m) = zeta(z);
+    let log_scale = Complex64::new(-zeta.re.abs(), 0.0);
+    if z.norm() <= 1.0 {
+        let (f, g) = maclaurin(z, derivative);
+        let value = times_exp(ScaledComplex::from(SQRT_3 * (C1 * f + C2 * g)), log_scale);
+        return Ok((value, log_scale, ierr));
+    }
+    let nu = if derivative { 2.0 / 3.0 } else { 1.0 / 3.0 };
+    // Bi = p [I_{-ν}(ζ) + I_ν(ζ)] = p [2 I_ν(ζ) + (2/π) sin(νπ) K_ν(ζ)], continued to w
+    let (sin, cos) = (nu * PI).sin_cos();
+    let i_coefficient = if m == 0.0 { 2.0 } else { 2.0 * cos };
+    let k_coefficient = 2.0 / PI * sin * cis_pi(-m * nu);
+    let i = ik::i_sequence(nu, w, 1)?[0];
+    let k = ik::k_sequence(nu, w, 1)?[0];
+    let i_part = ik::mul(i, i_coefficient * Complex64::from_polar(1.0, w.im));
+    let k_part = times_exp(ik::mul(k, k_coefficient), -w - w.re);
+    let prefactor = if derivative {
+        z / SQRT_3
+    } else {
+        (z / 3.0).sqrt()
+    };
+    Ok((ik::mul(ik::add(i_part, k_part), prefactor), log_scale, ierr))
+}
@@ -0,0 +1,345 @@
+//! Modified Bessel functions I_ν(w) and K_ν(w) for Re(w) >= 0
+//!
+//! Values are returned exponentially scaled, e^{-w} I_ν(w) and e^{w} K_ν(w),
+//! as [`ScaledComplex`] so that long recurrences cannot overflow. The
+//! algorithms are the classical set:
+//!
+//! * K: Temme's series (|w| <= 2) or Steed's continued fraction CF2 at the
+//!   order μ = ν - round(ν), followed by forward recurrence
+//! * I: power series for small |w|, otherwise the continued fraction CF1 for
+//!   I_{ν+1}/I_ν combined with the Wronskian and backward recurrence
+//! * both: Hankel's asymptotic expansion for large |w|
+
+use crate::continued_fraction::lentz;
+use crate::extended::{ldexp, ScaledComplex};
+use crate::gamma::ln_gamma;
+use crate::reflection::sin_cos_pi;
+use num_complex::Complex64;
+use std::f64::consts::PI;
+
+const EPS: f64 = f64::EPSILON;
+const MAX_ITER: usize = 200_000;
+
+/// IERR for an iteration that did not converge
+pub(super) const NO_CONVERGENCE: i32 = 5;
+
+/// Taylor coefficients of 1/Γ(1+x) (A&S 6.1.34, shifted by one)
+const INV_GAMMA_1P: [f64; 26] = [
+    1.0,
+    0.577_215_664_901_532_9,
+    -0.655_878_071_520_253_8,
+    -0.042_002_635_034_095_2,
+    0.166_538_611_382_291_5,
+    -0.042_197_734_555_544_3,
+    -0.009_621_971_527_877_0,
+    0.007_218_943_246_663_0,
+    -0.001_165_167_591_859_1,
+    -0.000_215_241_674_114_9,
+    0.000_128_050_282_388_2,
+    -0.000_020_134_854_780_7,
+    -0.000_001_250_493_482_1,
+    0.000_001_133_027_232_0,
+    -0.000_000_205_633_841_7,
+    0.000_000_006_116_095_0,
+    0.000_000_005_002_007_5,
+    -0.000_000_001_181_274_6,
+    0.000_000_000_104_342_7,
+    0.000_000_000_007_782_3,
+    -0.000_000_000_003_696_8,
+    0.000_000_000_000_510_0,
+    -0.000_000_000_000_020_6,
+    -0.000_000_000_000_005_4,
+    0.000_000_000_000_001_4,
+    0.000_000_000_000_000_1,
+];
+
+/// Largest component magnitude, the norm used for normalization
+fn magnitude(c: Complex64) -> f64 {
+    c.re.abs().max(c.im.abs())
+}
+
+/// c / w, divided by |w| twice since |w|² underflows for the smallest w
+fn over(c: f64, w: Complex64) -> Complex64 {
+    let r = w.norm();
+    (w.conj() / r) * (c / r)
+}
+
+fn scale(c: Complex64, k: i32) -> Complex64 {
+    Complex64::new(ldexp(c.re, k), ldexp(c.im, k))
+}
+
+/// Power-of-two shift bringing `mag` into [0.5, 1)
+fn shift_for(mag: f64) -> i32 {
+    if mag == 0.0 || !mag.is_finite() {
+        0
+    } else {
+        mag.log2().floor() as i32 + 1
+    }
+}
+
+/// a * c for a scaled value and a plain complex factor
+pub(super) fn mul(a: ScaledComplex, c: Complex64) -> ScaledComplex {
+    ScaledComplex::new(a.mantissa * c, a.exp2)
+}
+
+/// a + b for two scaled values
+pub(super) fn add(a: ScaledComplex, b: ScaledComplex) -> ScaledComplex {
+    if magnitude(a.mantissa) == 0.0 {
+        return b;
+    }
+    if magnitude(b.mantissa) == 0.0 {
+        return a;
+    }
+    let e = a.exp2.max(b.exp2);
+    ScaledComplex::new(
+        scale(a.mantissa, a.exp2 - e) + scale(b.mantissa, b.exp2 - e),
+        e,
+    )
+}
+
+/// Temme's Γ1, Γ2 and 1/Γ(1+μ), 1/Γ(1-μ) for |μ| <= 1/2
+fn temme_gammas(mu: f64) -> (f64, f64, f64, f64) {
+    // Γ1 = -Σ_{j odd} a_j μ^{j-1}, Γ2 = Σ_{j even} a_j μ^j with 1/Γ(1+μ) = Σ a_j μ^j
+    let mu2 = mu * mu;
+    let (mut gam1, mut gam2) = (0.0, 0.0);
+    let mut power = 1.0;
+    for pair in INV_GAMMA_1P.chunks(2) {
+        gam2 += pair[0] * power;
+        gam1 -= pair[1] * power;
+        power *= mu2;
+    }
+    (gam1, gam2, gam2 - mu * gam1, gam2 + mu * gam1)
+}
+
+/// Unscaled K_μ(w), K_{μ+1}(w) by Temme's series, |μ| <= 1/2, 0 < |w| <= 2
+fn temme(mu: f64, w: Complex64) -> Result<(Complex64, Complex64), i32> {
+    let x2 = 0.5 * w;
+    let pimu = PI * mu;
+    let fact = if mu.abs() < EPS {
+        1.0
+    } else {
+        pimu / pimu.sin()
+    };
+    let d = -x2.ln();
+    let e = mu * d;
+    let fact2 = if e.norm() < EPS {
+        Complex64::new(1.0, 0.0)
+    } else {
+        e.sinh() / e
+    };
+    let (gam1, gam2, gampl, gammi) = temme_gammas(mu);
+    let mut ff = fact * (gam1 * e.cosh() + gam2 * fact2 * d);
+    let mut sum = ff;
+    let e = e.exp();
+    let mut p = 0.5 * e / gampl;
+    let mut q = 0.5 / (e * gammi);
+    let mut c = Complex64::new(1.0, 0.0);
+    let d = x2 * x2;
+    let mut sum1 = p;
+    for i in 1..MAX_ITER {
+        let fi = i as f64;
+        ff = (fi * ff + p + q) / (fi * fi - mu * mu);
+        c *= d / fi;
+        p /= fi - mu;
+        q /= fi + mu;
+        let del = c * ff;
+        sum += del;
+        sum1 += c * (p - fi * ff);
+        if del.norm() < sum.norm() * EPS {
+            return Ok((sum, sum1 * over(2.0, w)));
+        }
+    }
+    Err(NO_CONVERGENCE)
+}
+
+/// Scaled e^w K_μ(w), e^w K_{μ+1}(w) by Steed's CF2, |μ| <= 1/2, |w| > 2
+fn steed_cf2(mu: f64, w: Complex64) -> Result<(Complex64, Complex64), i32> {
+    let one = Complex64::new(1.0, 0.0);
+    let mut b = 2.0 * (one + w);
+    let mut d = one / b;
+    let mut delh = d;
+    let mut h = d;
+    let mut q1 = Complex64::new(0.0, 0.0);
+    let mut q2 = one;
+    let a1 = 0.25 - mu * mu;
+    let mut q = Complex64::new(a1, 0.0);
+    let mut c = a1;
+    let mut a = -a1;
+    let mut s = one + q * delh;
+    for i in 2..MAX_ITER {
+        let fi = i as f64;
+        a -= 2.0 * (fi - 1.0);
+        c = -a * c / fi;
+        let qnew = (q1 - b * q2) / a;
+        q1 = q2;
+        q2 = qnew;
+        q += c * qnew;
+        b += 2.0;
+        d = one / (b + a * d);
+        delh = (b * d - 1.0) * delh;
+        h += delh;
+        let dels = q * delh;
+        s += dels;
+        if dels.norm() < s.norm() * EPS {
+            let h = a1 * h;
+            let kmu = (PI / (2.0 * w)).sqrt() / s;
+            let k1 = kmu * (mu + w + 0.5 - h) / w;
+            return Ok((kmu, k1));
+        }
+    }
+    Err(NO_CONVERGENCE)
+}
+
+/// Scaled e^{-w} I_ν(w) and e^{w} K_ν(w) from Hankel's expansion
+fn hankel_asymptotic(nu: f64, w: Complex64) -> (Complex64, Complex64) {
+    let mu4 = 4.0 * nu * nu;
+    let mut s_plus = Complex64::new(1.0, 0.0);
+    let mut s_minus = Complex64::new(1.0, 0.0);
+    let mut term = Complex64::new(1.0, 0.0);
+    let mut last = f64::INFINITY;
+    for k in 1..200 {
+        let odd = (2 * k - 1) as f64;
+        let next = term * ((mu4 - odd * odd) / (8.0 * k as f64)) / w;
+        let size = next.norm();
+        if size >= last {
+            break;
+        }
+        term = next;
+        last = size;
+        s_plus += term;
+        s_minus += if k % 2 == 0 { term } else { -term };
+        if size <= EPS * s_plus.norm().min(s_minus.norm()) {
+            break;
+        }
+    }
+    let k = (PI / (2.0 * w)).sqrt() * s_plus;
+    let sign = if w.im >= 0.0 { 1.0 } else { -1.0 };
+    let (sin, cos) = sin_cos_pi(nu);
+    let rotation = Complex64::new(-sin, sign * cos); // ±i e^{±iνπ}
+    let i = (s_minus + rotation * (-2.0 * w).exp() * s_plus) / (2.0 * PI * w).sqrt();
+    (i, k)
+}
+
+/// Whether Hankel's expansion is accurate for all orders up to `top`
+fn use_asymptotic(top: f64, w: Complex64) -> bool {
+    w.norm() >= (top * top).max(25.0)
+}
+
+/// Scaled e^{w} K_{ν+k}(w) for k = 0, ..., n-1; w must be nonzero
+pub(super) fn k_sequence(nu: f64, w: Complex64, n: usize) -> Result<Vec<ScaledComplex>, i32> {
+    let top = nu + (n - 1) as f64;
+    if use_asymptotic(top, w) {
+        return Ok((0..n)
+            .map(|k| ScaledComplex::from(hankel_asymptotic(nu + k as f64, w).1))
+            .collect());
+    }
+
+    let nl = (nu + 0.5).floor();
+    let mu = nu - nl;
+    let (mut prev, mut cur) = if w.norm() <= 2.0 {
+        let (k0, k1) = temme(mu, w)?;
+        let factor = w.exp();
+        (k0 * factor, k1 * factor)
+    } else {
+        steed_cf2(mu, w)?
+    };
+    if !(prev.re.is_finite() && prev.im.is_finite() && cur.re.is_finite() && cur.im.is_finite()) {
+        return Err(2);
+    }
+
+    let first = nl as usize;
+    let mut exp2 = 0i32;
+    let mut out = Vec::with_capacity(n);
+    let mut j = 0usize;
+    loop {
+        if j >= first {
+            out.push(ScaledComplex::new(prev, exp2));
+            if out.len() == n {
+                return Ok(out);
+            }
+        }
+        let shift = shift_for(magnitude(prev).max(magnitude(cur)));
+        prev = scale(prev, -shift);
+        cur = scale(cur, -shift);
+        exp2 += shift;
+        let next = prev + cur * over(2.0 * (mu + (j + 1) as f64), w);
+        prev = cur;
+        cur = next;
+        j += 1;
+    }
+}
+
+/// I_{a+1}(w) / I_a(w) by the continued fraction CF1 (modified Lentz)
+fn i_ratio(a: f64, w: Complex64) -> Result<Complex64, i32> {
+    let one = Complex64::new(1.0, 0.0);
+    let fraction = lentz(Complex64::new(0.0, 0.0), 2.0 * EPS, MAX_ITER, |j| {
+        (one, over(2.0 * (a + j as f64), w))
+    });
+    if fraction.converged {
+        Ok(fraction.value)
+    } else {
+        Err(NO_CONVERGENCE)
+    }
+}
+
+/// Scaled e^{-w} I_ν(w) from the power series
+fn i_series(nu: f64, w: Complex64) -> ScaledComplex {
+    let quarter = 0.25 * w * w;
+    let mut term = Complex64::new(1.0, 0.0);
+    let mut sum = term;
+    for k in 1..MAX_ITER {
+        let fk = k as f64;
+        term *= quarter / (fk * (nu + fk));
+        sum += term;
+        if term.norm() <= EPS * sum.norm() {
+            break;
+        }
+    }
+    let log_factor = nu * (0.5 * w).ln() - ln_gamma(nu + 1.0) - w;
+    ScaledComplex::from_log_factor(sum, log_factor)
+}
+
+/// Scaled e^{-w} I_{ν+k}(w) for k = 0, ..., n-1
+pub(super) fn i_sequence(nu: f64, w: Complex64, n: usize) -> Result<Vec<ScaledComplex>, i32> {
+    if w.norm() == 0.0 {
+        return Ok((0..n)
+            .map(|k| {
+                let value = if nu + k as f64 == 0.0 { 1.0 } else { 0.0 };
+                ScaledComplex::from(Complex64::new(value, 0.0))
+            })
+            .collect());
+    }
+    let top = nu + (n - 1) as f64;
+    if use_asymptotic(top, w) {
+        return Ok((0..n)
+            .map(|k| ScaledComplex::from(hankel_asymptotic(nu + k as f64, w).0))
+            .collect());
+    }
+    if w.norm() <= 2.0 || w.norm_sqr() <= nu + 1.0 {
+        return Ok((0..n).map(|k| i_series(nu + k as f64, w)).collect());
+    }
+
+    // Wronskian I_a K_{a+1} + I_{a+1} K_a = 1/w at the top order a
+    let k = k_sequence(nu, w, n + 1)?;
+    let ratio = i_ratio(top, w)?;
+    let denominator = add(k[n], mul(k[n - 1], ratio));
+    let mut upper = ScaledComplex::new(1.0 / (w * denominator.mantissa), -denominator.exp2);
+
+    let mut out = vec![upper; n];
+    let mut above = upper.mantissa * ratio;
+    let mut current = upper.mantissa;
+    let mut exp2 = upper.exp2;
+    for m in (0..n - 1).rev() {
+        let order = nu + (m + 1) as f64;
+        let below = above + current * over(2.0 * order, w);
+        above = current;
+        current = below;
+        let shift = shift_for(magnitude(above).max(magnitude(current)));
+        above = scale(above, -shift);
+        current = scale(current, -shift);
+        exp2 += shift;
+        upper = ScaledComplex::new(current, exp2);
+        out[m] = upper;
+    }
+    Ok(out)
+}
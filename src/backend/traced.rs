@@ -0,0 +1,216 @@
+//! Backend wrapper reporting every call to `tracing`
+//!
+//! With the `tracing` feature the public functions evaluate through
+//! [`Traced`] around the selected backend. Each call runs in a `DEBUG` span
+//! named `backend` carrying the routine, the kind, z, ν, `kode` and n (or
+//! `id` for the Airy functions), and closes with one event carrying IERR, NZ
+//! and the elapsed time: `TRACE` for a clean return, `DEBUG` when the call
+//! failed or underflowed. Filtering on `zbessel_rs=debug` therefore shows
+//! exactly the evaluations that degraded or failed, with their inputs:
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::bessel_k;
+//!
+//! // With a subscriber such as tracing-subscriber's `fmt().init()` installed,
+//! // this logs the zbesk call and its IERR=2
+//! assert!(bessel_k(Complex64::new(1e-300, 0.0), 200.0, 1, 1).is_err());
+//! ```
+//!
+//! When no subscriber is interested the spans are disabled and the calls are
+//! forwarded without timing them.
+
+use super::Backend;
+use crate::BesselKind;
+use num_complex::Complex64;
+use std::marker::PhantomData;
+use std::time::Instant;
+use tracing::Span;
+
+/// Backend `B` with every call wrapped in a `tracing` span and event
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Traced<B>(PhantomData<B>);
+
+/// Amos routine computing `kind`
+fn routine(kind: BesselKind) -> &'static str {
+    match kind {
+        BesselKind::J => "zbesj",
+        BesselKind::Y => "zbesy",
+        BesselKind::I => "zbesi",
+        BesselKind::K => "zbesk",
+        BesselKind::H1 | BesselKind::H2 => "zbesh",
+    }
+}
+
+fn bessel_span(
+    backend: &str,
+    kind: BesselKind,
+    z: Complex64,
+    nu: f64,
+    kode: i32,
+    n: usize,
+) -> Span {
+    tracing::debug_span!(
+        "backend",
+        backend,
+        routine = routine(kind),
+        ?kind,
+        z.re = z.re,
+        z.im = z.im,
+        nu,
+        kode,
+        n
+    )
+}
+
+fn airy_span(backend: &str, routine: &str, z: Complex64, id: i32, kode: i32) -> Span {
+    tracing::debug_span!(
+        "backend",
+        backend,
+        routine,
+        z.re = z.re,
+        z.im = z.im,
+        id,
+        kode
+    )
+}
+
+/// Run `call` in `span` and report its IERR and NZ, as given by `status`
+fn traced<T>(span: Span, call: impl FnOnce() -> T, status: impl Fn(&T) -> (i32, i32)) -> T {
+    if span.is_disabled() {
+        return call();
+    }
+    let _entered = span.enter();
+    let start = Instant::now();
+    let result = call();
+    let elapsed = start.elapsed();
+    let (ierr, nz) = status(&result);
+    if ierr == 0 && nz == 0 {
+        tracing::trace!(ierr, nz, ?elapsed, "backend call returned");
+    } else {
+        tracing::debug!(ierr, nz, ?elapsed, "backend call failed or underflowed");
+    }
+    result
+}
+
+impl<B: Backend> Backend for Traced<B> {
+    const NAME: &'static str = B::NAME;
+    const RANGE_LIMIT: f64 = B::RANGE_LIMIT;
+
+    fn bessel(
+        kind: BesselKind,
+        z: Complex64,
+        nu: f64,
+        kode: i32,
+        cyr: &mut [f64],
+        cyi: &mut [f64],
+    ) -> (i32, i32) {
+        let span = bessel_span(B::NAME, kind, z, nu, kode, cyr.len());
+        let call = || B::bessel(kind, z, nu, kode, cyr, cyi);
+        traced(span, call, |&status| status)
+    }
+
+    fn bessel_with_work(
+        kind: BesselKind,
+        z: Complex64,
+        nu: f64,
+        kode: i32,
+        cyr: &mut [f64],
+        cyi: &mut [f64],
+        work: &mut [f64],
+    ) -> (i32, i32) {
+        let span = bessel_span(B::NAME, kind, z, nu, kode, cyr.len());
+        let call = || B::bessel_with_work(kind, z, nu, kode, cyr, cyi, work);
+        traced(span, call, |&status| status)
+    }
+
+    fn airy_ai(z: Complex64, id: i32, kode: i32) -> (i32, Complex64, i32) {
+        let span = airy_span(B::NAME, "zairy", z, id, kode);
+        let call = || B::airy_ai(z, id, kode);
+        traced(span, call, |&(ierr, _, nz)| (ierr, nz))
+    }
+
+    fn airy_bi(z: Complex64, id: i32, kode: i32) -> (i32, Complex64) {
+        // zbiry has no underflow indicator
+        let span = airy_span(B::NAME, "zbiry", z, id, kode);
+        let call = || B::airy_bi(z, id, kode);
+        traced(span, call, |&(ierr, _)| (ierr, 0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::Selected;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Level, Metadata, Subscriber};
+
+    /// Subscriber counting spans and keeping the level and IERR of events
+    #[derive(Clone, Default)]
+    struct Recorder {
+        spans: Arc<AtomicUsize>,
+        events: Arc<Mutex<Vec<(Level, i64)>>>,
+    }
+
+    struct Ierr(i64);
+
+    impl Visit for Ierr {
+        fn record_i64(&mut self, field: &Field, value: i64) {
+            if field.name() == "ierr" {
+                self.0 = value;
+            }
+        }
+
+        fn record_debug(&mut self, _: &Field, _: &dyn std::fmt::Debug) {}
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            Id::from_u64(self.spans.fetch_add(1, Ordering::Relaxed) as u64 + 1)
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut ierr = Ierr(-1);
+            event.record(&mut ierr);
+            let level = *event.metadata().level();
+            self.events.lock().unwrap().push((level, ierr.0));
+        }
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    #[test]
+    fn test_calls_are_reported() {
+        let recorder = Recorder::default();
+        tracing::subscriber::with_default(recorder.clone(), || {
+            let (mut re, mut im) = ([0.0; 2], [0.0; 2]);
+            let z = Complex64::new(1.0, 1.0);
+            let clean = Traced::<Selected>::bessel(BesselKind::K, z, 0.5, 1, &mut re, &mut im);
+            assert_eq!(clean, (0, 0));
+            let tiny = Complex64::new(1e-300, 0.0);
+            let overflow =
+                Traced::<Selected>::bessel(BesselKind::K, tiny, 200.0, 1, &mut re, &mut im);
+            assert_eq!(overflow.0, 2);
+            assert_eq!(Traced::<Selected>::airy_bi(z, 0, 1).0, 0);
+        });
+        assert_eq!(recorder.spans.load(Ordering::Relaxed), 3);
+        let events = recorder.events.lock().unwrap();
+        assert_eq!(
+            *events,
+            [(Level::TRACE, 0), (Level::DEBUG, 2), (Level::TRACE, 0)]
+        );
+    }
+}
@@ -0,0 +1,352 @@
+//! Command-line tables of Bessel and Airy function values
+//!
+//! ```text
+//! zbessel j --nu 0..10 --z "1+0.5i" --scaled --format csv
+//! zbessel k --nu 0.5 --z 0.1..20:0.1
+//! zbessel h1 --nu 0,1,2 --re 0..5/51 --im -1..1/21 --format tsv
+//! zbessel ai --z -10..10/201 --derivative
+//! ```
+//!
+//! Every value of `--nu` is combined with every argument. Arguments come
+//! from `--z` (a list or a range on the real axis) or from `--re` and `--im`
+//! (a rectangle in the complex plane, the imaginary part varying fastest).
+//! Points where the function fails are written as NaN and reported on
+//! standard error.
+
+// The crate is marked deprecated in favour of its successor
+#![allow(deprecated)]
+
+use clap::{Parser, ValueEnum};
+use num_complex::Complex64;
+use std::io::{self, BufWriter, Write};
+use std::process::ExitCode;
+use zbessel_rs::{airy_ai, airy_bi, bessel, BesselError, BesselKind, Scaling};
+
+/// Compute tables of Bessel and Airy function values
+///
+/// Lists are comma separated (`0,0.5,2`). Ranges include both ends:
+/// `START..END` steps by 1, `START..END:STEP` by STEP, and `START..END/COUNT`
+/// gives COUNT evenly spaced points. Complex numbers are written `1+0.5i`.
+#[derive(Debug, Parser)]
+#[command(name = "zbessel", version)]
+struct Cli {
+    /// Function to tabulate
+    function: Function,
+
+    /// Orders (Bessel functions only)
+    #[arg(long, default_value = "0", allow_hyphen_values = true)]
+    nu: String,
+
+    /// Arguments: a list of complex numbers or a real range
+    #[arg(long, allow_hyphen_values = true, conflicts_with_all = ["re", "im"])]
+    z: Option<String>,
+
+    /// Real parts of a rectangular grid of arguments
+    #[arg(long, allow_hyphen_values = true)]
+    re: Option<String>,
+
+    /// Imaginary parts of a rectangular grid of arguments
+    #[arg(long, allow_hyphen_values = true)]
+    im: Option<String>,
+
+    /// Exponentially scaled values, as with `Scaling::Scaled`
+    #[arg(long)]
+    scaled: bool,
+
+    /// Derivative instead of the function (Airy functions only)
+    #[arg(long)]
+    derivative: bool,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Function {
+    J,
+    Y,
+    I,
+    K,
+    H1,
+    H2,
+    Ai,
+    Bi,
+}
+
+impl Function {
+    fn kind(self) -> Option<BesselKind> {
+        match self {
+            Function::J => Some(BesselKind::J),
+            Function::Y => Some(BesselKind::Y),
+            Function::I => Some(BesselKind::I),
+            Function::K => Some(BesselKind::K),
+            Function::H1 => Some(BesselKind::H1),
+            Function::H2 => Some(BesselKind::H2),
+            Function::Ai | Function::Bi => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Aligned columns with a header line
+    Table,
+    /// Comma-separated values with a header line
+    Csv,
+    /// Tab-separated values with a header line
+    Tsv,
+}
+
+/// Parse a list or an inclusive range of real numbers
+fn parse_reals(spec: &str) -> Result<Vec<f64>, String> {
+    let number = |s: &str| {
+        s.trim()
+            .parse::<f64>()
+            .map_err(|_| format!("'{}' is not a number", s.trim()))
+    };
+    let Some((start, rest)) = spec.split_once("..") else {
+        return spec.split(',').map(number).collect();
+    };
+    let start = number(start)?;
+    if let Some((end, count)) = rest.split_once('/') {
+        let end = number(end)?;
+        let count: usize = count
+            .trim()
+            .parse()
+            .map_err(|_| format!("'{}' is not a point count", count.trim()))?;
+        return Ok(match count {
+            0 => Vec::new(),
+            1 => vec![start],
+            _ => (0..count)
+                .map(|k| start + (end - start) * k as f64 / (count - 1) as f64)
+                .collect(),
+        });
+    }
+    let (end, step) = match rest.split_once(':') {
+        Some((end, step)) => (number(end)?, number(step)?),
+        None => (number(rest)?, 1.0),
+    };
+    if step.is_nan() || step <= 0.0 || !start.is_finite() || !end.is_finite() {
+        return Err(format!(
+            "'{}' is not a finite range with a positive step",
+            spec
+        ));
+    }
+    // Allow for rounding in the last step so that 0..1:0.1 ends at 1
+    let count = ((end - start) / step + 1e-9).floor();
+    if count < 0.0 {
+        return Ok(Vec::new());
+    }
+    Ok((0..=count as usize)
+        .map(|k| start + step * k as f64)
+        .collect())
+}
+
+/// Parse a list of complex numbers, or a range on the real axis
+fn parse_complex(spec: &str) -> Result<Vec<Complex64>, String> {
+    if spec.contains("..") {
+        return Ok(parse_reals(spec)?
+            .into_iter()
+            .map(|x| Complex64::new(x, 0.0))
+            .collect());
+    }
+    spec.split(',')
+        .map(|s| {
+            let s: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+            s.parse::<Complex64>()
+                .map_err(|_| format!("'{}' is not a complex number", s))
+        })
+        .collect()
+}
+
+/// The arguments selected by `--z` or by `--re` and `--im`
+fn arguments(cli: &Cli) -> Result<Vec<Complex64>, String> {
+    if let Some(z) = &cli.z {
+        return parse_complex(z);
+    }
+    if cli.re.is_none() && cli.im.is_none() {
+        return Err("give the arguments with --z, or a grid with --re and --im".to_string());
+    }
+    let re = parse_reals(cli.re.as_deref().unwrap_or("0"))?;
+    let im = parse_reals(cli.im.as_deref().unwrap_or("0"))?;
+    Ok(re
+        .iter()
+        .flat_map(|&x| im.iter().map(move |&y| Complex64::new(x, y)))
+        .collect())
+}
+
+/// One table row: order (for Bessel functions), argument and value
+struct Row {
+    nu: Option<f64>,
+    z: Complex64,
+    value: Result<Complex64, BesselError>,
+}
+
+fn evaluate(cli: &Cli) -> Result<Vec<Row>, String> {
+    let zs = arguments(cli)?;
+    let scaling = if cli.scaled {
+        Scaling::Scaled
+    } else {
+        Scaling::Unscaled
+    };
+    let Some(kind) = cli.function.kind() else {
+        let id = cli.derivative as i32;
+        let kode = scaling.kode();
+        return Ok(zs
+            .into_iter()
+            .map(|z| {
+                let value = match cli.function {
+                    Function::Ai => airy_ai(z, id, kode).map(|r| r.value),
+                    _ => airy_bi(z, id, kode),
+                };
+                Row { nu: None, z, value }
+            })
+            .collect());
+    };
+    if cli.derivative {
+        return Err("--derivative applies to the Airy functions only".to_string());
+    }
+    let nus = parse_reals(&cli.nu)?;
+    Ok(nus
+        .iter()
+        .flat_map(|&nu| {
+            zs.iter().map(move |&z| Row {
+                nu: Some(nu),
+                z,
+                value: bessel(kind, nu, z, scaling),
+            })
+        })
+        .collect())
+}
+
+/// Shortest round-trip form, in exponent notation when very large or small
+fn number(x: f64) -> String {
+    if x == 0.0 || !x.is_finite() || (1e-4..1e16).contains(&x.abs()) {
+        format!("{}", x)
+    } else {
+        format!("{:e}", x)
+    }
+}
+
+fn write_table(rows: &[Row], format: Format, out: &mut impl Write) -> io::Result<()> {
+    let has_nu = rows.first().is_some_and(|row| row.nu.is_some());
+    let mut header = vec!["z_re", "z_im", "value_re", "value_im"];
+    if has_nu {
+        header.insert(0, "nu");
+    }
+    let separator = match format {
+        Format::Csv => ",",
+        Format::Tsv => "\t",
+        Format::Table => "  ",
+    };
+    let cell = |text: String| match format {
+        Format::Table => format!("{:>24}", text),
+        Format::Csv | Format::Tsv => text,
+    };
+    let line: Vec<String> = header.iter().map(|h| cell(h.to_string())).collect();
+    writeln!(out, "{}", line.join(separator))?;
+    for row in rows {
+        let value = row
+            .value
+            .as_ref()
+            .copied()
+            .unwrap_or(Complex64::new(f64::NAN, f64::NAN));
+        let mut fields: Vec<f64> = vec![row.z.re, row.z.im, value.re, value.im];
+        if let Some(nu) = row.nu {
+            fields.insert(0, nu);
+        }
+        let line: Vec<String> = fields.iter().map(|&x| cell(number(x))).collect();
+        writeln!(out, "{}", line.join(separator))?;
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let rows = match evaluate(&cli) {
+        Ok(rows) => rows,
+        Err(message) => {
+            eprintln!("zbessel: {}", message);
+            return ExitCode::from(2);
+        }
+    };
+    let stdout = io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    if let Err(error) = write_table(&rows, cli.format, &mut out).and_then(|()| out.flush()) {
+        if error.kind() != io::ErrorKind::BrokenPipe {
+            eprintln!("zbessel: {}", error);
+            return ExitCode::FAILURE;
+        }
+    }
+    let failures: Vec<&Row> = rows.iter().filter(|row| row.value.is_err()).collect();
+    if let Some(first) = failures.first() {
+        eprintln!(
+            "zbessel: {} of {} values failed and were written as NaN; the first, at z = {}: {}",
+            failures.len(),
+            rows.len(),
+            first.z,
+            first.value.as_ref().unwrap_err()
+        );
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_specs() {
+        assert_eq!(parse_reals("0..3").unwrap(), vec![0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(
+            parse_reals("0..1:0.25").unwrap(),
+            vec![0.0, 0.25, 0.5, 0.75, 1.0]
+        );
+        assert_eq!(
+            parse_reals("-1..1/5").unwrap(),
+            vec![-1.0, -0.5, 0.0, 0.5, 1.0]
+        );
+        assert_eq!(parse_reals("0.5, 2").unwrap(), vec![0.5, 2.0]);
+        assert_eq!(parse_reals("0..1:0.1").unwrap().len(), 11);
+        assert!(parse_reals("0..1:0").is_err());
+        assert_eq!(
+            parse_complex("1+0.5i,2i,-3").unwrap(),
+            vec![
+                Complex64::new(1.0, 0.5),
+                Complex64::new(0.0, 2.0),
+                Complex64::new(-3.0, 0.0)
+            ]
+        );
+        assert!(parse_complex("1+").is_err());
+    }
+
+    #[test]
+    fn test_table() {
+        let cli = Cli::parse_from([
+            "zbessel", "j", "--nu", "0..1", "--re", "1..2", "--im", "0,1", "--format", "csv",
+        ]);
+        let rows = evaluate(&cli).unwrap();
+        assert_eq!(rows.len(), 8);
+        assert_eq!(rows[3].z, Complex64::new(2.0, 1.0));
+        let mut out = Vec::new();
+        write_table(&rows, cli.format, &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let mut lines = text.lines();
+        assert_eq!(lines.next(), Some("nu,z_re,z_im,value_re,value_im"));
+        let first: Vec<f64> = lines
+            .next()
+            .unwrap()
+            .split(',')
+            .map(|s| s.parse().unwrap())
+            .collect();
+        assert_eq!(first[..3], [0.0, 1.0, 0.0]);
+        assert!((first[3] - 0.765_197_686_557_966_6).abs() < 1e-15);
+
+        let airy = Cli::parse_from(["zbessel", "ai", "--z", "0", "--nu", "1"]);
+        assert!(evaluate(&airy).unwrap()[0].nu.is_none());
+        let misuse = Cli::parse_from(["zbessel", "k", "--z", "1", "--derivative"]);
+        assert!(evaluate(&misuse).is_err());
+    }
+}
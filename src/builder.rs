@@ -0,0 +1,135 @@
+//! Fluent builder API for Bessel function evaluation
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::Bessel;
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let z = Complex64::new(2.0, 1.0);
+//!
+//!     // J_0(z), J_1(z), ..., J_7(z) with exp(-|Im(z)|) scaling
+//!     let result = Bessel::j(0.0).scaled().sequence(8).eval(z)?;
+//!     assert_eq!(result.values.len(), 8);
+//!
+//!     // K_1.5(z) (single value, no scaling)
+//!     let k = Bessel::k(1.5).eval_single(z)?;
+//!     println!("K_1.5({}) = {}", z, k);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{bessel_i, bessel_j, bessel_k, bessel_y, BesselError, BesselResult};
+use num_complex::Complex64;
+
+type BesselFn = fn(Complex64, f64, i32, usize) -> Result<BesselResult, BesselError>;
+
+/// Builder describing a Bessel function evaluation
+///
+/// Created with [`Bessel::j`], [`Bessel::y`], [`Bessel::i`] or [`Bessel::k`], then
+/// configured with [`scaled`](Bessel::scaled) and [`sequence`](Bessel::sequence).
+/// The builder is `Copy`, so it can be configured once and evaluated at many points.
+#[derive(Debug, Clone, Copy)]
+pub struct Bessel {
+    func: BesselFn,
+    nu: f64,
+    kode: i32,
+    n: usize,
+}
+
+impl Bessel {
+    fn new(func: BesselFn, nu: f64) -> Self {
+        Bessel {
+            func,
+            nu,
+            kode: 1,
+            n: 1,
+        }
+    }
+
+    /// Bessel function of the first kind J_ν(z)
+    pub fn j(nu: f64) -> Self {
+        Self::new(bessel_j, nu)
+    }
+
+    /// Bessel function of the second kind Y_ν(z)
+    pub fn y(nu: f64) -> Self {
+        Self::new(bessel_y, nu)
+    }
+
+    /// Modified Bessel function of the first kind I_ν(z)
+    pub fn i(nu: f64) -> Self {
+        Self::new(bessel_i, nu)
+    }
+
+    /// Modified Bessel function of the second kind K_ν(z)
+    pub fn k(nu: f64) -> Self {
+        Self::new(bessel_k, nu)
+    }
+
+    /// Enable exponential scaling (kode=2)
+    ///
+    /// The scaling factor depends on the function: exp(-|Im(z)|) for J and Y,
+    /// exp(-|Re(z)|) for I and exp(z) for K.
+    pub fn scaled(self) -> Self {
+        self.scaling(true)
+    }
+
+    /// Enable or disable exponential scaling
+    pub fn scaling(mut self, scaled: bool) -> Self {
+        self.kode = if scaled { 2 } else { 1 };
+        self
+    }
+
+    /// Calculate `n` function values for orders ν, ν+1, ..., ν+n-1
+    pub fn sequence(mut self, n: usize) -> Self {
+        self.n = n;
+        self
+    }
+
+    /// Evaluate the configured sequence at `z`
+    pub fn eval(&self, z: Complex64) -> Result<BesselResult, BesselError> {
+        (self.func)(z, self.nu, self.kode, self.n)
+    }
+
+    /// Evaluate only the first order ν at `z`, ignoring the sequence length
+    pub fn eval_single(&self, z: Complex64) -> Result<Complex64, BesselError> {
+        let result = (self.func)(z, self.nu, self.kode, 1)?;
+        Ok(result.values[0])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_matches_positional_api() {
+        let z = Complex64::new(3.0, -2.0);
+
+        let built = Bessel::y(0.5).scaled().sequence(4).eval(z).unwrap();
+        let direct = bessel_y(z, 0.5, 2, 4).unwrap();
+
+        assert_eq!(built.values, direct.values);
+        assert_eq!(built.underflow_count, direct.underflow_count);
+    }
+
+    #[test]
+    fn test_builder_eval_single() {
+        let z = Complex64::new(10.0, 20.0);
+        let builder = Bessel::i(1.0).sequence(5);
+
+        let single = builder.eval_single(z).unwrap();
+        let sequence = builder.eval(z).unwrap();
+
+        let diff = (single - sequence.values[0]).norm() / single.norm();
+        assert!(diff < 1e-14, "eval_single mismatch: diff = {}", diff);
+        assert_eq!(sequence.values.len(), 5);
+    }
+
+    #[test]
+    fn test_builder_rejects_empty_sequence() {
+        let z = Complex64::new(1.0, 0.0);
+        assert!(Bessel::k(0.0).sequence(0).eval(z).is_err());
+    }
+}
@@ -0,0 +1,209 @@
+//! Memoization of single values
+//!
+//! Nested quadrature, boundary-element assembly and similar workloads evaluate
+//! the same (kind, ν, z) points many times. A [`CachedBessel`] remembers up to
+//! `capacity` results and evicts the least recently used one when full.
+//! Arguments are compared bit for bit, so an imaginary part of -0.0 (below
+//! the branch cut) is a different point from +0.0. Errors are not cached.
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{BesselKind, CachedBessel, Scaling};
+//!
+//! fn main() -> Result<(), zbessel_rs::BesselError> {
+//!     let mut cache = CachedBessel::new(1024);
+//!     let z = Complex64::new(1.5, 0.5);
+//!     let first = cache.eval(BesselKind::K, 0.3, z, Scaling::Unscaled)?;
+//!     let again = cache.eval(BesselKind::K, 0.3, z, Scaling::Unscaled)?;
+//!     assert_eq!(first, again);
+//!     assert_eq!((cache.hits(), cache.misses()), (1, 1));
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{bessel, BesselError, BesselKind, Order, Scaling};
+use num_complex::Complex64;
+use std::collections::{BTreeMap, HashMap};
+
+/// Kind, bits of ν, bits of z and kode
+type Key = (BesselKind, u64, u64, u64, i32);
+
+/// A value and the tick of its last use
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    value: Complex64,
+    tick: u64,
+}
+
+/// Least-recently-used cache of single Bessel function values
+#[derive(Debug, Clone)]
+pub struct CachedBessel {
+    capacity: usize,
+    entries: HashMap<Key, Entry>,
+    recency: BTreeMap<u64, Key>,
+    tick: u64,
+    hits: u64,
+    misses: u64,
+}
+
+impl CachedBessel {
+    /// Create a cache holding at most `capacity` values
+    ///
+    /// A capacity of 0 disables caching; every call is then evaluated.
+    pub fn new(capacity: usize) -> Self {
+        CachedBessel {
+            capacity,
+            entries: HashMap::with_capacity(capacity.min(1 << 16)),
+            recency: BTreeMap::new(),
+            tick: 0,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// [`bessel`](crate::bessel), answered from the cache when possible
+    ///
+    /// # Parameters
+    /// * `kind` - Which function to evaluate
+    /// * `nu` - Order, any real number (or an [`Order`])
+    /// * `z` - Complex argument
+    /// * `scaling` - Whether to apply the exponential scaling factor
+    pub fn eval(
+        &mut self,
+        kind: BesselKind,
+        nu: impl Into<Order>,
+        z: Complex64,
+        scaling: Scaling,
+    ) -> Result<Complex64, BesselError> {
+        let order = nu.into();
+        let key = (
+            kind,
+            order.value().to_bits(),
+            z.re.to_bits(),
+            z.im.to_bits(),
+            scaling.kode(),
+        );
+        self.tick += 1;
+        if let Some(entry) = self.entries.get_mut(&key) {
+            self.recency.remove(&entry.tick);
+            entry.tick = self.tick;
+            self.recency.insert(self.tick, key);
+            self.hits += 1;
+            return Ok(entry.value);
+        }
+
+        self.misses += 1;
+        let value = bessel(kind, order, z, scaling)?;
+        if self.capacity == 0 {
+            return Ok(value);
+        }
+        if self.entries.len() == self.capacity {
+            if let Some((_, oldest)) = self.recency.pop_first() {
+                self.entries.remove(&oldest);
+            }
+        }
+        let tick = self.tick;
+        self.entries.insert(key, Entry { value, tick });
+        self.recency.insert(tick, key);
+        Ok(value)
+    }
+
+    /// Maximum number of cached values
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of cached values
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether nothing is cached
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Calls answered from the cache
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Calls that had to be evaluated
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Drop all cached values and reset the counters
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_least_recently_used_is_evicted() {
+        let mut cache = CachedBessel::new(2);
+        let z = |x: f64| Complex64::new(x, 0.25);
+        let j = |cache: &mut CachedBessel, x| {
+            cache
+                .eval(BesselKind::J, 1.0, z(x), Scaling::Unscaled)
+                .unwrap()
+        };
+        assert_eq!(
+            j(&mut cache, 1.0),
+            bessel(BesselKind::J, 1.0, z(1.0), Scaling::Unscaled).unwrap()
+        );
+        j(&mut cache, 2.0);
+        // Touch 1.0 so that 2.0 becomes the oldest, then push it out
+        j(&mut cache, 1.0);
+        j(&mut cache, 3.0);
+        assert_eq!(cache.len(), 2);
+        assert_eq!((cache.hits(), cache.misses()), (1, 3));
+        j(&mut cache, 1.0);
+        assert_eq!(cache.hits(), 2);
+        j(&mut cache, 2.0);
+        assert_eq!(cache.misses(), 4);
+
+        // Scaling and the sign of zero are part of the key; errors are not stored
+        cache
+            .eval(BesselKind::J, 1.0, z(2.0), Scaling::Scaled)
+            .unwrap();
+        assert_eq!(cache.misses(), 5);
+        let cut = Complex64::new(-1.0, -0.0);
+        cache
+            .eval(BesselKind::K, 0.5, cut, Scaling::Unscaled)
+            .unwrap();
+        cache
+            .eval(BesselKind::K, 0.5, cut.conj(), Scaling::Unscaled)
+            .unwrap();
+        assert_eq!(cache.misses(), 7);
+        let overflow = z(1000.0);
+        assert!(cache
+            .eval(BesselKind::I, 0.0, overflow, Scaling::Unscaled)
+            .is_err());
+        assert!(cache
+            .eval(BesselKind::I, 0.0, overflow, Scaling::Unscaled)
+            .is_err());
+        assert_eq!(cache.len(), 2);
+
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(cache.hits(), 0);
+    }
+
+    #[test]
+    fn test_zero_capacity_passes_through() {
+        let mut cache = CachedBessel::new(0);
+        let z = Complex64::new(0.5, 0.5);
+        cache.eval(BesselKind::I, 2, z, Scaling::Scaled).unwrap();
+        cache.eval(BesselKind::I, 2, z, Scaling::Scaled).unwrap();
+        assert!(cache.is_empty());
+        assert_eq!(cache.misses(), 2);
+    }
+}
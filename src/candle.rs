@@ -0,0 +1,205 @@
+//! Bessel functions as differentiable candle tensor operations
+//!
+//! [`bessel_tensor`] applies J_ν, Y_ν, I_ν or K_ν element-wise to a real
+//! `f32` or `f64` tensor on the CPU. The operation records its gradient for
+//! candle's backward pass, with the exact derivative of the [`dual`]
+//! module, so Matérn-kernel Gaussian-process layers (K_ν) and von Mises
+//! losses (I_ν, usually scaled) can be trained with candle optimizers.
+//!
+//! ```rust
+//! use candle_core::{Device, Tensor, Var};
+//! use zbessel_rs::{bessel_tensor, BesselKind, Scaling};
+//!
+//! fn main() -> candle_core::Result<()> {
+//!     let x = Var::new(&[0.5f64, 1.0, 2.0], &Device::Cpu)?;
+//!     let k = bessel_tensor(BesselKind::K, 1.5, Scaling::Unscaled, x.as_tensor())?;
+//!     let grads = k.sum_all()?.backward()?;
+//!     // d/dx K_ν(x) = -(K_{ν-1}(x) + K_{ν+1}(x)) / 2
+//!     println!("{}", grads.get(&x).unwrap());
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [`dual`]: crate::dual
+
+use crate::dual::{self, Dual64};
+use crate::{real, BesselError, BesselKind, Scaling};
+use candle_core::backend::BackendStorage;
+use candle_core::{CpuStorage, CustomOp1, Error, Layout, Shape, Tensor};
+
+/// Element-wise Bessel function, or its derivative, of a real tensor
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct BesselOp {
+    kind: BesselKind,
+    nu: f64,
+    scaling: Scaling,
+    derivative: bool,
+}
+
+impl BesselOp {
+    /// The value or derivative at one element
+    ///
+    /// On the real axis the scaling factor of J and Y, exp(-abs(Im(x))), is 1.
+    fn eval(&self, x: f64) -> Result<f64, BesselError> {
+        let nu = self.nu;
+        let scaled = self.scaling == Scaling::Scaled;
+        if !self.derivative {
+            return match (self.kind, scaled) {
+                (BesselKind::J, _) => real::J(nu, x),
+                (BesselKind::Y, _) => real::Y(nu, x),
+                (BesselKind::I, false) => real::I(nu, x),
+                (BesselKind::I, true) => real::I_scaled(nu, x),
+                (BesselKind::K, false) => real::K(nu, x),
+                (BesselKind::K, true) => real::K_scaled(nu, x),
+                (BesselKind::H1 | BesselKind::H2, _) => Err(complex_kind(self.kind)),
+            };
+        }
+        let x = Dual64::variable(x);
+        let value = match (self.kind, scaled) {
+            (BesselKind::J, _) => dual::J(nu, x),
+            (BesselKind::Y, _) => dual::Y(nu, x),
+            (BesselKind::I, false) => dual::I(nu, x),
+            (BesselKind::I, true) => dual::I_scaled(nu, x),
+            (BesselKind::K, false) => dual::K(nu, x),
+            (BesselKind::K, true) => dual::K_scaled(nu, x),
+            (BesselKind::H1 | BesselKind::H2, _) => Err(complex_kind(self.kind)),
+        }?;
+        Ok(value.eps()[0])
+    }
+
+    fn map<T: Copy>(
+        &self,
+        data: &[T],
+        to_f64: impl Fn(T) -> f64,
+        from_f64: impl Fn(f64) -> T,
+    ) -> candle_core::Result<Vec<T>> {
+        data.iter()
+            .map(|&x| self.eval(to_f64(x)).map(&from_f64).map_err(Error::wrap))
+            .collect()
+    }
+}
+
+fn complex_kind(kind: BesselKind) -> BesselError {
+    BesselError::InvalidParameter(format!(
+        "{:?} is complex on the real axis; tensors support J, Y, I and K",
+        kind
+    ))
+}
+
+impl CustomOp1 for BesselOp {
+    fn name(&self) -> &'static str {
+        match (self.kind, self.derivative) {
+            (BesselKind::J, false) => "bessel-j",
+            (BesselKind::Y, false) => "bessel-y",
+            (BesselKind::I, false) => "bessel-i",
+            (BesselKind::K, false) => "bessel-k",
+            (_, false) => "bessel",
+            (_, true) => "bessel-derivative",
+        }
+    }
+
+    fn cpu_fwd(
+        &self,
+        storage: &CpuStorage,
+        layout: &Layout,
+    ) -> candle_core::Result<(CpuStorage, Shape)> {
+        let (start, end) = layout
+            .contiguous_offsets()
+            .ok_or_else(|| Error::msg("bessel_tensor needs a contiguous tensor"))?;
+        let values = match storage {
+            CpuStorage::F64(data) => CpuStorage::F64(self.map(&data[start..end], |x| x, |v| v)?),
+            CpuStorage::F32(data) => {
+                CpuStorage::F32(self.map(&data[start..end], f64::from, |v| v as f32)?)
+            }
+            other => {
+                return Err(Error::msg(format!(
+                    "bessel_tensor supports f32 and f64 tensors, got {:?}",
+                    other.dtype()
+                )))
+            }
+        };
+        Ok((values, layout.shape().clone()))
+    }
+
+    fn bwd(
+        &self,
+        arg: &Tensor,
+        _res: &Tensor,
+        grad_res: &Tensor,
+    ) -> candle_core::Result<Option<Tensor>> {
+        let derivative = arg.apply_op1_no_bwd(&BesselOp {
+            derivative: true,
+            ..*self
+        })?;
+        Ok(Some(grad_res.mul(&derivative)?))
+    }
+}
+
+/// Apply a Bessel function element-wise to a real tensor
+///
+/// # Parameters
+/// * `kind` - J, Y, I or K; the Hankel functions are complex and rejected
+/// * `nu` - Order (real number)
+/// * `scaling` - Scaling option: exp(-abs(x)) for I and exp(x) for K; no
+///   effect for J and Y
+/// * `x` - Arguments, an `f32` or `f64` tensor on the CPU
+///
+/// # Returns
+/// A tensor of the same shape and dtype whose gradient with respect to `x`
+/// is the derivative of the function
+///
+/// # Errors
+/// Any element outside the real domain of the function (see
+/// [`real`](crate::real)), or an overflow, fails the whole operation.
+pub fn bessel_tensor(
+    kind: BesselKind,
+    nu: f64,
+    scaling: Scaling,
+    x: &Tensor,
+) -> candle_core::Result<Tensor> {
+    let op = BesselOp {
+        kind,
+        nu,
+        scaling,
+        derivative: false,
+    };
+    x.contiguous()?.apply_op1(op)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candle_core::{Device, Var};
+
+    #[test]
+    fn test_values_and_gradients() {
+        let points = [0.5, 1.0, 2.5];
+        let x = Var::new(&points, &Device::Cpu).unwrap();
+        let i = bessel_tensor(BesselKind::I, 0.0, Scaling::Scaled, x.as_tensor()).unwrap();
+        let k = bessel_tensor(BesselKind::K, 0.5, Scaling::Unscaled, x.as_tensor()).unwrap();
+        let grads = (i + k).unwrap().sum_all().unwrap().backward().unwrap();
+        let grad: Vec<f64> = grads.get(&x).unwrap().to_vec1().unwrap();
+        for (&x, g) in points.iter().zip(grad) {
+            // (e^-x I_0)' = e^-x (I_1 - I_0), and K_{1/2}(x) = sqrt(π/(2x)) e^-x
+            let i_prime = real::I_scaled(1.0, x).unwrap() - real::I_scaled(0.0, x).unwrap();
+            let k_prime = -real::K(0.5, x).unwrap() * (1.0 + 0.5 / x);
+            assert!((g - (i_prime + k_prime)).abs() < 1e-14);
+        }
+
+        let x32 = Tensor::new(&[[1.0f32, 2.0], [3.0, 4.0]], &Device::Cpu).unwrap();
+        let j = bessel_tensor(BesselKind::J, 0.0, Scaling::Unscaled, &x32.t().unwrap()).unwrap();
+        assert_eq!(j.dims(), &[2, 2]);
+        let j: Vec<Vec<f32>> = j.to_vec2().unwrap();
+        // The transpose puts x = 3 in row 0, column 1
+        assert!((j[0][1] + 0.260_051_95).abs() < 1e-7);
+    }
+
+    #[test]
+    fn test_errors() {
+        let x = Tensor::new(&[1.0f64, -1.0], &Device::Cpu).unwrap();
+        assert!(bessel_tensor(BesselKind::K, 0.0, Scaling::Unscaled, &x).is_err());
+        assert!(bessel_tensor(BesselKind::H1, 0.0, Scaling::Unscaled, &x).is_err());
+        let ints = Tensor::new(&[1u32], &Device::Cpu).unwrap();
+        assert!(bessel_tensor(BesselKind::J, 0.0, Scaling::Unscaled, &ints).is_err());
+    }
+}
@@ -0,0 +1,359 @@
+//! C ABI for C, C++ and Fortran callers
+//!
+//! These `extern "C"` functions wrap the safe API with a fixed calling
+//! convention; the checked-in `include/zbessel_rs.h` declares them. With this
+//! feature the build script writes the header cbindgen generates to
+//! `OUT_DIR/zbessel_rs.h`, and a test fails when the checked-in copy differs
+//! from it. Build a shared library to link against with
+//!
+//! ```text
+//! cargo rustc --release --features capi --crate-type cdylib
+//! ```
+//!
+//! which leaves `libzbessel_rs.so`, `libzbessel_rs.dylib` or `zbessel_rs.dll`
+//! in `target/release`.
+//!
+//! Every function returns a status code and writes its result through an
+//! output pointer only on success:
+//!
+//! * `ZB_OK` (0) - success
+//! * 1 to 5 - the Amos IERR code of the failure (invalid input, overflow,
+//!   loss of precision, total loss of precision, no convergence); inputs
+//!   rejected before reaching Amos also give 1, and arguments or orders
+//!   beyond the validated range 4
+//! * `ZB_NULL_POINTER` (-1) - a required pointer was null
+//! * `ZB_PANIC` (-2) - an internal error that would otherwise unwind into C
+//!
+//! ```c
+//! #include "zbessel_rs.h"
+//!
+//! zb_complex z = {1.0, 0.5}, j0;
+//! if (zb_bessel_j(0.0, z, 0, &j0) == ZB_OK) {
+//!     printf("%g%+gi\n", j0.re, j0.im);
+//! }
+//! ```
+
+use crate::{airy_ai, airy_bi, bessel, bessel_slice, BesselError, BesselKind, Scaling};
+use num_complex::Complex64;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// Success
+pub const ZB_OK: i32 = 0;
+/// A required pointer was null
+pub const ZB_NULL_POINTER: i32 = -1;
+/// An internal error was caught at the boundary
+pub const ZB_PANIC: i32 = -2;
+
+/// Complex number with the layout of C99 `double _Complex`
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct zb_complex {
+    /// Real part
+    pub re: f64,
+    /// Imaginary part
+    pub im: f64,
+}
+
+impl From<zb_complex> for Complex64 {
+    fn from(z: zb_complex) -> Self {
+        Complex64::new(z.re, z.im)
+    }
+}
+
+impl From<Complex64> for zb_complex {
+    fn from(z: Complex64) -> Self {
+        zb_complex { re: z.re, im: z.im }
+    }
+}
+
+fn status(error: BesselError) -> i32 {
+    if error.is_out_of_validated_range() {
+        return 4;
+    }
+    error.ierr().unwrap_or(1)
+}
+
+fn scaling(scaled: i32) -> Scaling {
+    if scaled != 0 {
+        Scaling::Scaled
+    } else {
+        Scaling::Unscaled
+    }
+}
+
+/// Run `f` and write its value to `out`, translating errors and panics
+///
+/// # Safety
+/// `out` must be null or valid for a write.
+unsafe fn write_one(
+    out: *mut zb_complex,
+    f: impl FnOnce() -> Result<Complex64, BesselError>,
+) -> i32 {
+    if out.is_null() {
+        return ZB_NULL_POINTER;
+    }
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(value)) => {
+            *out = value.into();
+            ZB_OK
+        }
+        Ok(Err(error)) => status(error),
+        Err(_) => ZB_PANIC,
+    }
+}
+
+/// Evaluate `kind` at `n` points of `z` into `out`
+///
+/// # Safety
+/// `z` and `out` must be null or valid for `n` reads and writes.
+unsafe fn batch(
+    kind: BesselKind,
+    nu: f64,
+    z: *const zb_complex,
+    n: usize,
+    scaled: i32,
+    out: *mut zb_complex,
+) -> i32 {
+    if n == 0 {
+        return ZB_OK;
+    }
+    if z.is_null() || out.is_null() {
+        return ZB_NULL_POINTER;
+    }
+    let zs = std::slice::from_raw_parts(z, n);
+    let out = std::slice::from_raw_parts_mut(out, n);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let zs: Vec<Complex64> = zs.iter().map(|&z| z.into()).collect();
+        bessel_slice(kind, &zs, nu, scaling(scaled))
+    }));
+    match result {
+        Ok(Ok(values)) => {
+            for (slot, value) in out.iter_mut().zip(values) {
+                *slot = value.into();
+            }
+            ZB_OK
+        }
+        Ok(Err(error)) => status(error),
+        Err(_) => ZB_PANIC,
+    }
+}
+
+/// J_nu(z), scaled by exp(-|Im z|) if `scaled` is nonzero
+///
+/// # Safety
+/// `out` must be null or valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn zb_bessel_j(
+    nu: f64,
+    z: zb_complex,
+    scaled: i32,
+    out: *mut zb_complex,
+) -> i32 {
+    write_one(out, || bessel(BesselKind::J, nu, z.into(), scaling(scaled)))
+}
+
+/// Y_nu(z), scaled by exp(-|Im z|) if `scaled` is nonzero
+///
+/// # Safety
+/// `out` must be null or valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn zb_bessel_y(
+    nu: f64,
+    z: zb_complex,
+    scaled: i32,
+    out: *mut zb_complex,
+) -> i32 {
+    write_one(out, || bessel(BesselKind::Y, nu, z.into(), scaling(scaled)))
+}
+
+/// I_nu(z), scaled by exp(-|Re z|) if `scaled` is nonzero
+///
+/// # Safety
+/// `out` must be null or valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn zb_bessel_i(
+    nu: f64,
+    z: zb_complex,
+    scaled: i32,
+    out: *mut zb_complex,
+) -> i32 {
+    write_one(out, || bessel(BesselKind::I, nu, z.into(), scaling(scaled)))
+}
+
+/// K_nu(z), scaled by exp(z) if `scaled` is nonzero
+///
+/// # Safety
+/// `out` must be null or valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn zb_bessel_k(
+    nu: f64,
+    z: zb_complex,
+    scaled: i32,
+    out: *mut zb_complex,
+) -> i32 {
+    write_one(out, || bessel(BesselKind::K, nu, z.into(), scaling(scaled)))
+}
+
+/// H^(m)_nu(z) for `m` = 1 or 2, scaled by exp(-/+iz) if `scaled` is nonzero
+///
+/// # Safety
+/// `out` must be null or valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn zb_bessel_h(
+    m: i32,
+    nu: f64,
+    z: zb_complex,
+    scaled: i32,
+    out: *mut zb_complex,
+) -> i32 {
+    let kind = match m {
+        1 => BesselKind::H1,
+        2 => BesselKind::H2,
+        _ => return 1,
+    };
+    write_one(out, || bessel(kind, nu, z.into(), scaling(scaled)))
+}
+
+/// J_nu at `n` points `z[0..n]`, written to `out[0..n]`
+///
+/// # Safety
+/// `z` and `out` must be valid for `n` reads and writes.
+#[no_mangle]
+pub unsafe extern "C" fn zb_bessel_j_batch(
+    nu: f64,
+    z: *const zb_complex,
+    n: usize,
+    scaled: i32,
+    out: *mut zb_complex,
+) -> i32 {
+    batch(BesselKind::J, nu, z, n, scaled, out)
+}
+
+/// Y_nu at `n` points `z[0..n]`, written to `out[0..n]`
+///
+/// # Safety
+/// `z` and `out` must be valid for `n` reads and writes.
+#[no_mangle]
+pub unsafe extern "C" fn zb_bessel_y_batch(
+    nu: f64,
+    z: *const zb_complex,
+    n: usize,
+    scaled: i32,
+    out: *mut zb_complex,
+) -> i32 {
+    batch(BesselKind::Y, nu, z, n, scaled, out)
+}
+
+/// I_nu at `n` points `z[0..n]`, written to `out[0..n]`
+///
+/// # Safety
+/// `z` and `out` must be valid for `n` reads and writes.
+#[no_mangle]
+pub unsafe extern "C" fn zb_bessel_i_batch(
+    nu: f64,
+    z: *const zb_complex,
+    n: usize,
+    scaled: i32,
+    out: *mut zb_complex,
+) -> i32 {
+    batch(BesselKind::I, nu, z, n, scaled, out)
+}
+
+/// K_nu at `n` points `z[0..n]`, written to `out[0..n]`
+///
+/// # Safety
+/// `z` and `out` must be valid for `n` reads and writes.
+#[no_mangle]
+pub unsafe extern "C" fn zb_bessel_k_batch(
+    nu: f64,
+    z: *const zb_complex,
+    n: usize,
+    scaled: i32,
+    out: *mut zb_complex,
+) -> i32 {
+    batch(BesselKind::K, nu, z, n, scaled, out)
+}
+
+/// Ai(z), or Ai'(z) if `derivative` is nonzero, scaled by exp(zeta) if
+/// `scaled` is nonzero, where zeta = (2/3) z^(3/2)
+///
+/// # Safety
+/// `out` must be null or valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn zb_airy_ai(
+    z: zb_complex,
+    derivative: i32,
+    scaled: i32,
+    out: *mut zb_complex,
+) -> i32 {
+    let id = (derivative != 0) as i32;
+    write_one(out, || {
+        Ok(airy_ai(z.into(), id, scaling(scaled).kode())?.value)
+    })
+}
+
+/// Bi(z), or Bi'(z) if `derivative` is nonzero, scaled by exp(-|Re zeta|)
+/// if `scaled` is nonzero, where zeta = (2/3) z^(3/2)
+///
+/// # Safety
+/// `out` must be null or valid for a write.
+#[no_mangle]
+pub unsafe extern "C" fn zb_airy_bi(
+    z: zb_complex,
+    derivative: i32,
+    scaled: i32,
+    out: *mut zb_complex,
+) -> i32 {
+    let id = (derivative != 0) as i32;
+    write_one(out, || airy_bi(z.into(), id, scaling(scaled).kode()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ptr;
+
+    #[test]
+    fn test_status_codes() {
+        let z = zb_complex { re: 1.0, im: 0.5 };
+        let mut out = zb_complex { re: 0.0, im: 0.0 };
+        assert_eq!(unsafe { zb_bessel_j(0.0, z, 0, &mut out) }, ZB_OK);
+        assert_eq!(Complex64::from(out), crate::J(0.0, z.into()).unwrap());
+        assert_eq!(
+            unsafe { zb_bessel_k(0.0, z, 0, ptr::null_mut()) },
+            ZB_NULL_POINTER
+        );
+        let zero = zb_complex { re: 0.0, im: 0.0 };
+        assert_eq!(unsafe { zb_bessel_k(0.0, zero, 0, &mut out) }, ZB_OK);
+        assert_eq!(out.re, f64::INFINITY);
+        let nan = zb_complex {
+            re: f64::NAN,
+            im: 0.0,
+        };
+        assert_eq!(unsafe { zb_bessel_k(0.0, nan, 0, &mut out) }, 1);
+        assert_eq!(unsafe { zb_bessel_h(3, 0.0, z, 0, &mut out) }, 1);
+        assert_eq!(unsafe { zb_bessel_j(1e10, z, 0, &mut out) }, 4);
+        assert_eq!(unsafe { zb_airy_ai(zero, 0, 0, &mut out) }, ZB_OK);
+        assert!((out.re - 0.355_028_053_887_817_2).abs() < 1e-15);
+
+        let zs = [z, zb_complex { re: 4.0, im: -1.0 }];
+        let mut values = [zero; 2];
+        let status = unsafe { zb_bessel_y_batch(1.5, zs.as_ptr(), 2, 1, values.as_mut_ptr()) };
+        assert_eq!(status, ZB_OK);
+        let expected = bessel(BesselKind::Y, 1.5, zs[1].into(), Scaling::Scaled).unwrap();
+        assert!((Complex64::from(values[1]) - expected).norm() < 1e-14 * expected.norm());
+    }
+
+    #[test]
+    fn test_header_is_current() {
+        // build.rs regenerates the header; the checked-in copy must match
+        let generated = include_str!(concat!(env!("OUT_DIR"), "/zbessel_rs.h"));
+        let checked_in = include_str!("../include/zbessel_rs.h");
+        assert!(
+            generated == checked_in,
+            "include/zbessel_rs.h is out of date; copy it from {}",
+            concat!(env!("OUT_DIR"), "/zbessel_rs.h")
+        );
+    }
+}
@@ -0,0 +1,188 @@
+//! Combined evaluation of the cylinder and modified Bessel families with their derivatives
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{cyl_bessel_all, Scaling};
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let z = Complex64::new(5.0, 0.1);
+//!     let c = cyl_bessel_all(1.0, z, Scaling::Unscaled)?;
+//!
+//!     // Wronskian J Y' - J' Y = 2/(πz)
+//!     let w = c.j * c.yp - c.jp * c.y;
+//!     assert!((w - 2.0 / (std::f64::consts::PI * z)).norm() < 1e-14);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{bessel_with_derivative, BesselError, BesselKind, Scaling};
+use num_complex::Complex64;
+
+/// J_ν(z), Y_ν(z) and their derivatives at one (ν, z)
+///
+/// With [`Scaling::Scaled`] all four values are multiplied by
+/// `exp(log_scale)` with `log_scale = -|Im(z)|`; unscaled results have
+/// `log_scale = 0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CylinderBessel {
+    /// J_ν(z)
+    pub j: Complex64,
+    /// Y_ν(z)
+    pub y: Complex64,
+    /// J'_ν(z)
+    pub jp: Complex64,
+    /// Y'_ν(z)
+    pub yp: Complex64,
+    /// Natural logarithm of the scaling factor applied to all values
+    pub log_scale: f64,
+}
+
+/// Calculate J_ν(z), Y_ν(z), J'_ν(z) and Y'_ν(z) in one call
+///
+/// Each family is evaluated once for the orders ν and ν+1 and the derivatives
+/// follow from the recurrence relations; Y is assembled by Amos from the pair of
+/// Hankel functions, while J comes from zbesj so it stays accurate near its zeros.
+///
+/// # Parameters
+/// * `nu` - Order (real number)
+/// * `z` - Complex argument
+/// * `scaling` - Scaling option (scaling factor: exp(-abs(Im(z))))
+pub fn cyl_bessel_all(
+    nu: f64,
+    z: Complex64,
+    scaling: Scaling,
+) -> Result<CylinderBessel, BesselError> {
+    let (j, jp) = bessel_with_derivative(BesselKind::J, nu, z, scaling)?;
+    let (y, yp) = bessel_with_derivative(BesselKind::Y, nu, z, scaling)?;
+    let log_scale = match scaling {
+        Scaling::Unscaled => 0.0,
+        Scaling::Scaled => -z.im.abs(),
+    };
+
+    Ok(CylinderBessel {
+        j,
+        y,
+        jp,
+        yp,
+        log_scale,
+    })
+}
+
+/// I_ν(z), K_ν(z) and their derivatives at one (ν, z)
+///
+/// With [`Scaling::Scaled`] the I values are multiplied by
+/// `exp(i_log_scale)` with `i_log_scale = -|Re(z)|`, and the K values by
+/// `exp(k_log_scale)` with `k_log_scale = z`; unscaled results have both set to zero.
+/// The true values are recovered as `i * exp(-i_log_scale)` and `k * exp(-k_log_scale)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ModifiedBessel {
+    /// I_ν(z)
+    pub i: Complex64,
+    /// K_ν(z)
+    pub k: Complex64,
+    /// I'_ν(z)
+    pub ip: Complex64,
+    /// K'_ν(z)
+    pub kp: Complex64,
+    /// Natural logarithm of the scaling factor applied to `i` and `ip`
+    pub i_log_scale: f64,
+    /// Natural logarithm of the scaling factor applied to `k` and `kp`
+    pub k_log_scale: Complex64,
+}
+
+/// Calculate I_ν(z), K_ν(z), I'_ν(z) and K'_ν(z) in one call
+///
+/// Each family is evaluated once for the orders ν and ν+1 and the derivatives
+/// follow from the recurrence relations.
+///
+/// # Parameters
+/// * `nu` - Order (real number)
+/// * `z` - Complex argument
+/// * `scaling` - Scaling option (scaling factors: exp(-abs(Re(z))) for I, exp(z) for K)
+pub fn mod_bessel_all(
+    nu: f64,
+    z: Complex64,
+    scaling: Scaling,
+) -> Result<ModifiedBessel, BesselError> {
+    let (i, ip) = bessel_with_derivative(BesselKind::I, nu, z, scaling)?;
+    let (k, kp) = bessel_with_derivative(BesselKind::K, nu, z, scaling)?;
+    let (i_log_scale, k_log_scale) = match scaling {
+        Scaling::Unscaled => (0.0, Complex64::new(0.0, 0.0)),
+        Scaling::Scaled => (-z.re.abs(), z),
+    };
+
+    Ok(ModifiedBessel {
+        i,
+        k,
+        ip,
+        kp,
+        i_log_scale,
+        k_log_scale,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{J, Y};
+
+    #[test]
+    fn test_cyl_bessel_all_values() {
+        let z = Complex64::new(3.0, -2.0);
+        let c = cyl_bessel_all(0.5, z, Scaling::Unscaled).unwrap();
+        assert_eq!(c.log_scale, 0.0);
+        assert!((c.j - J(0.5, z).unwrap()).norm() < 1e-14 * c.j.norm());
+        assert!((c.y - Y(0.5, z).unwrap()).norm() < 1e-14 * c.y.norm());
+    }
+
+    #[test]
+    fn test_cyl_bessel_all_scaled() {
+        let z = Complex64::new(10.0, 40.0);
+        let unscaled = cyl_bessel_all(2.0, z, Scaling::Unscaled).unwrap();
+        let scaled = cyl_bessel_all(2.0, z, Scaling::Scaled).unwrap();
+        assert_eq!(scaled.log_scale, -40.0);
+
+        let factor = scaled.log_scale.exp();
+        for (s, u) in [
+            (scaled.j, unscaled.j),
+            (scaled.y, unscaled.y),
+            (scaled.jp, unscaled.jp),
+            (scaled.yp, unscaled.yp),
+        ] {
+            assert!((s - u * factor).norm() < 1e-13 * s.norm());
+        }
+    }
+
+    #[test]
+    fn test_mod_bessel_all_wronskian() {
+        // I K' - I' K = -1/z
+        let z = Complex64::new(2.0, 1.5);
+        let m = mod_bessel_all(1.3, z, Scaling::Unscaled).unwrap();
+        let w = m.i * m.kp - m.ip * m.k;
+        assert!((w + 1.0 / z).norm() < 1e-14);
+    }
+
+    #[test]
+    fn test_mod_bessel_all_scaled() {
+        let z = Complex64::new(30.0, -5.0);
+        let unscaled = mod_bessel_all(0.5, z, Scaling::Unscaled).unwrap();
+        let scaled = mod_bessel_all(0.5, z, Scaling::Scaled).unwrap();
+        assert_eq!(scaled.i_log_scale, -30.0);
+        assert_eq!(scaled.k_log_scale, z);
+
+        let i_factor = scaled.i_log_scale.exp();
+        let k_factor = scaled.k_log_scale.exp();
+        assert!((scaled.i - unscaled.i * i_factor).norm() < 1e-13 * scaled.i.norm());
+        assert!((scaled.ip - unscaled.ip * i_factor).norm() < 1e-13 * scaled.ip.norm());
+        assert!((scaled.k - unscaled.k * k_factor).norm() < 1e-13 * scaled.k.norm());
+        assert!((scaled.kp - unscaled.kp * k_factor).norm() < 1e-13 * scaled.kp.norm());
+
+        // The scaled Wronskian carries the product of both factors
+        let w = scaled.i * scaled.kp - scaled.ip * scaled.k;
+        let expected = -1.0 / z * i_factor * k_factor;
+        assert!((w - expected).norm() < 1e-13 * expected.norm());
+    }
+}
@@ -0,0 +1,9 @@
+//! Interfaces mirroring other Bessel function libraries
+//!
+//! These modules exist for porting code written against another library:
+//! the calls can be translated one to one first and moved to the idiomatic
+//! API of the crate root afterwards.
+//!
+//! * [`slatec`] - the SLATEC/Netlib Amos routines `ZBESJ`, `ZBESY`, ...
+
+pub mod slatec;
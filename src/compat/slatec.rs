@@ -0,0 +1,306 @@
+//! Safe functions with the calling conventions of the SLATEC Amos routines
+//!
+//! Each function takes the arguments of its Fortran namesake in the same
+//! order (real and imaginary parts separately, `KODE`, `N`, output arrays)
+//! and returns the `NZ` and `IERR` out-parameters as a tuple, so a call
+//!
+//! ```text
+//!       CALL ZBESJ(ZR, ZI, FNU, KODE, N, CYR, CYI, NZ, IERR)
+//! ```
+//!
+//! becomes `let (nz, ierr) = zbesj(zr, zi, fnu, kode, n, &mut cyr, &mut cyi);`.
+//! Inputs are checked as in SLATEC, and `IERR` has the SLATEC meanings:
+//!
+//! * 0 - normal return
+//! * 1 - input error, no computation
+//! * 2 - overflow, no computation
+//! * 3 - loss of more than half of the significant digits, values returned
+//! * 4 - complete loss of significance, no computation
+//! * 5 - algorithm did not terminate, no computation
+//!
+//! Unlike the crate root there are no extensions: negative orders are input
+//! errors, as are non-finite arguments and output arrays shorter than `N`.
+//!
+//! ```rust
+//! use zbessel_rs::compat::slatec::{zairy, zbesj};
+//!
+//! let (mut cyr, mut cyi) = ([0.0; 3], [0.0; 3]);
+//! let (nz, ierr) = zbesj(1.0, 0.5, 0.0, 1, 3, &mut cyr, &mut cyi);
+//! assert_eq!((nz, ierr), (0, 0));
+//!
+//! let (air, aii, nz, ierr) = zairy(0.0, 0.0, 0, 1);
+//! assert_eq!((nz, ierr), (0, 0));
+//! assert!((air - 0.3550280538878172).abs() < 1e-15 && aii == 0.0);
+//! ```
+
+use crate::backend::{ActiveBackend, Backend};
+use crate::BesselKind;
+use num_complex::Complex64;
+
+/// Output length, or `None` for any SLATEC input error of the sequence routines
+fn check_sequence(
+    zr: f64,
+    zi: f64,
+    fnu: f64,
+    kode: i32,
+    n: i32,
+    zero_allowed: bool,
+    outputs: &[&[f64]],
+) -> Option<usize> {
+    let finite = zr.is_finite() && zi.is_finite() && fnu.is_finite();
+    let at_origin = zr == 0.0 && zi == 0.0;
+    if !finite || (at_origin && !zero_allowed) || fnu < 0.0 || !(1..=2).contains(&kode) || n < 1 {
+        return None;
+    }
+    let n = n as usize;
+    outputs.iter().all(|output| output.len() >= n).then_some(n)
+}
+
+/// Whether the arguments of `ZAIRY` and `ZBIRY` pass the SLATEC checks
+fn airy_inputs_valid(zr: f64, zi: f64, id: i32, kode: i32) -> bool {
+    zr.is_finite() && zi.is_finite() && (0..=1).contains(&id) && (1..=2).contains(&kode)
+}
+
+/// Evaluate `kind` into the first `n` elements of `cyr` and `cyi`
+#[allow(clippy::too_many_arguments)]
+fn sequence(
+    kind: BesselKind,
+    zr: f64,
+    zi: f64,
+    fnu: f64,
+    kode: i32,
+    n: i32,
+    cyr: &mut [f64],
+    cyi: &mut [f64],
+) -> (i32, i32) {
+    let zero_allowed = matches!(kind, BesselKind::J | BesselKind::I);
+    let Some(n) = check_sequence(zr, zi, fnu, kode, n, zero_allowed, &[cyr, cyi]) else {
+        return (0, 1);
+    };
+    let z = Complex64::new(zr, zi);
+    let (ierr, nz) = ActiveBackend::bessel(kind, z, fnu, kode, &mut cyr[..n], &mut cyi[..n]);
+    (nz, ierr)
+}
+
+/// Bessel functions J_{fnu+k}(z), k = 0, ..., n-1 (SLATEC `ZBESJ`)
+///
+/// # Parameters
+/// * `zr`, `zi` - Real and imaginary parts of z
+/// * `fnu` - Order of the first member of the sequence (fnu >= 0)
+/// * `kode` - Scaling option (1: no scaling, 2: exp(-abs(Im(z))) scaling)
+/// * `n` - Number of members of the sequence (n >= 1)
+/// * `cyr`, `cyi` - Real and imaginary parts of the results, length >= n
+///
+/// # Returns
+/// `(NZ, IERR)`: the number of components set to zero by underflow and the
+/// error flag
+pub fn zbesj(
+    zr: f64,
+    zi: f64,
+    fnu: f64,
+    kode: i32,
+    n: i32,
+    cyr: &mut [f64],
+    cyi: &mut [f64],
+) -> (i32, i32) {
+    sequence(BesselKind::J, zr, zi, fnu, kode, n, cyr, cyi)
+}
+
+/// Bessel functions Y_{fnu+k}(z), k = 0, ..., n-1 (SLATEC `ZBESY`)
+///
+/// # Parameters
+/// * `zr`, `zi` - Real and imaginary parts of z (z != 0)
+/// * `fnu` - Order of the first member of the sequence (fnu >= 0)
+/// * `kode` - Scaling option (1: no scaling, 2: exp(-abs(Im(z))) scaling)
+/// * `n` - Number of members of the sequence (n >= 1)
+/// * `cyr`, `cyi` - Real and imaginary parts of the results, length >= n
+/// * `cwrkr`, `cwrki` - Work arrays of length >= n, as in SLATEC; their
+///   contents are unspecified afterwards
+///
+/// # Returns
+/// `(NZ, IERR)`: the number of components set to zero by underflow and the
+/// error flag
+#[allow(clippy::too_many_arguments)]
+pub fn zbesy(
+    zr: f64,
+    zi: f64,
+    fnu: f64,
+    kode: i32,
+    n: i32,
+    cyr: &mut [f64],
+    cyi: &mut [f64],
+    cwrkr: &mut [f64],
+    cwrki: &mut [f64],
+) -> (i32, i32) {
+    if check_sequence(zr, zi, fnu, kode, n, false, &[cwrkr, cwrki]).is_none() {
+        return (0, 1);
+    }
+    sequence(BesselKind::Y, zr, zi, fnu, kode, n, cyr, cyi)
+}
+
+/// Modified Bessel functions I_{fnu+k}(z), k = 0, ..., n-1 (SLATEC `ZBESI`)
+///
+/// # Parameters
+/// * `zr`, `zi` - Real and imaginary parts of z
+/// * `fnu` - Order of the first member of the sequence (fnu >= 0)
+/// * `kode` - Scaling option (1: no scaling, 2: exp(-abs(Re(z))) scaling)
+/// * `n` - Number of members of the sequence (n >= 1)
+/// * `cyr`, `cyi` - Real and imaginary parts of the results, length >= n
+///
+/// # Returns
+/// `(NZ, IERR)`: the number of components set to zero by underflow and the
+/// error flag
+pub fn zbesi(
+    zr: f64,
+    zi: f64,
+    fnu: f64,
+    kode: i32,
+    n: i32,
+    cyr: &mut [f64],
+    cyi: &mut [f64],
+) -> (i32, i32) {
+    sequence(BesselKind::I, zr, zi, fnu, kode, n, cyr, cyi)
+}
+
+/// Modified Bessel functions K_{fnu+k}(z), k = 0, ..., n-1 (SLATEC `ZBESK`)
+///
+/// # Parameters
+/// * `zr`, `zi` - Real and imaginary parts of z (z != 0)
+/// * `fnu` - Order of the first member of the sequence (fnu >= 0)
+/// * `kode` - Scaling option (1: no scaling, 2: exp(z) scaling)
+/// * `n` - Number of members of the sequence (n >= 1)
+/// * `cyr`, `cyi` - Real and imaginary parts of the results, length >= n
+///
+/// # Returns
+/// `(NZ, IERR)`: the number of components set to zero by underflow and the
+/// error flag
+pub fn zbesk(
+    zr: f64,
+    zi: f64,
+    fnu: f64,
+    kode: i32,
+    n: i32,
+    cyr: &mut [f64],
+    cyi: &mut [f64],
+) -> (i32, i32) {
+    sequence(BesselKind::K, zr, zi, fnu, kode, n, cyr, cyi)
+}
+
+/// Hankel functions H^(m)_{fnu+k}(z), k = 0, ..., n-1 (SLATEC `ZBESH`)
+///
+/// # Parameters
+/// * `zr`, `zi` - Real and imaginary parts of z (z != 0)
+/// * `fnu` - Order of the first member of the sequence (fnu >= 0)
+/// * `kode` - Scaling option (1: no scaling, 2: exp(-iz) scaling for m = 1
+///   and exp(iz) for m = 2)
+/// * `m` - Kind of Hankel function (1 or 2)
+/// * `n` - Number of members of the sequence (n >= 1)
+/// * `cyr`, `cyi` - Real and imaginary parts of the results, length >= n
+///
+/// # Returns
+/// `(NZ, IERR)`: the number of components set to zero by underflow and the
+/// error flag
+#[allow(clippy::too_many_arguments)]
+pub fn zbesh(
+    zr: f64,
+    zi: f64,
+    fnu: f64,
+    kode: i32,
+    m: i32,
+    n: i32,
+    cyr: &mut [f64],
+    cyi: &mut [f64],
+) -> (i32, i32) {
+    let kind = match m {
+        1 => BesselKind::H1,
+        2 => BesselKind::H2,
+        _ => return (0, 1),
+    };
+    sequence(kind, zr, zi, fnu, kode, n, cyr, cyi)
+}
+
+/// Airy function Ai(z) or its derivative (SLATEC `ZAIRY`)
+///
+/// # Parameters
+/// * `zr`, `zi` - Real and imaginary parts of z
+/// * `id` - Differentiation option (0: Ai(z), 1: Ai'(z))
+/// * `kode` - Scaling option (1: no scaling, 2: exp(zeta) scaling where
+///   zeta=(2/3)*z^(3/2))
+///
+/// # Returns
+/// `(AIR, AII, NZ, IERR)`: the real and imaginary parts of the result, the
+/// underflow indicator and the error flag
+pub fn zairy(zr: f64, zi: f64, id: i32, kode: i32) -> (f64, f64, i32, i32) {
+    if !airy_inputs_valid(zr, zi, id, kode) {
+        return (0.0, 0.0, 0, 1);
+    }
+    let (ierr, value, nz) = ActiveBackend::airy_ai(Complex64::new(zr, zi), id, kode);
+    (value.re, value.im, nz, ierr)
+}
+
+/// Airy function Bi(z) or its derivative (SLATEC `ZBIRY`)
+///
+/// # Parameters
+/// * `zr`, `zi` - Real and imaginary parts of z
+/// * `id` - Differentiation option (0: Bi(z), 1: Bi'(z))
+/// * `kode` - Scaling option (1: no scaling, 2: exp(-abs(Re(zeta))) scaling
+///   where zeta=(2/3)*z^(3/2))
+///
+/// # Returns
+/// `(BIR, BII, IERR)`: the real and imaginary parts of the result and the
+/// error flag
+pub fn zbiry(zr: f64, zi: f64, id: i32, kode: i32) -> (f64, f64, i32) {
+    if !airy_inputs_valid(zr, zi, id, kode) {
+        return (0.0, 0.0, 1);
+    }
+    let (ierr, value) = ActiveBackend::airy_bi(Complex64::new(zr, zi), id, kode);
+    (value.re, value.im, ierr)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_crate_root() {
+        let z = Complex64::new(1.5, -0.75);
+        let (mut cyr, mut cyi) = ([0.0; 4], [0.0; 4]);
+        let (mut cwrkr, mut cwrki) = ([0.0; 4], [0.0; 4]);
+        let expected = crate::bessel_y(z, 0.25, 2, 4).unwrap().values;
+        let (nz, ierr) = zbesy(
+            z.re, z.im, 0.25, 2, 4, &mut cyr, &mut cyi, &mut cwrkr, &mut cwrki,
+        );
+        assert_eq!((nz, ierr), (0, 0));
+        for k in 0..4 {
+            // The crate root takes special paths for some orders
+            let error = (Complex64::new(cyr[k], cyi[k]) - expected[k]).norm();
+            assert!(error < 1e-14 * expected[k].norm());
+        }
+
+        let (nz, ierr) = zbesh(z.re, z.im, 1.0, 1, 2, 1, &mut cyr, &mut cyi);
+        assert_eq!((nz, ierr), (0, 0));
+        let h2 = crate::bessel_h(z, 1.0, 1, 2, 1).unwrap().values[0];
+        assert!((Complex64::new(cyr[0], cyi[0]) - h2).norm() < 1e-14 * h2.norm());
+
+        let (bir, bii, ierr) = zbiry(z.re, z.im, 1, 1);
+        assert_eq!(ierr, 0);
+        assert_eq!(Complex64::new(bir, bii), crate::airy_bi(z, 1, 1).unwrap());
+    }
+
+    #[test]
+    fn test_input_errors() {
+        let (mut cyr, mut cyi) = ([0.0; 2], [0.0; 2]);
+        assert_eq!(zbesj(1.0, 0.0, -0.5, 1, 1, &mut cyr, &mut cyi), (0, 1));
+        assert_eq!(zbesj(1.0, 0.0, 0.0, 3, 1, &mut cyr, &mut cyi), (0, 1));
+        assert_eq!(zbesi(1.0, 0.0, 0.0, 1, 0, &mut cyr, &mut cyi), (0, 1));
+        assert_eq!(zbesi(1.0, 0.0, 0.0, 1, 3, &mut cyr, &mut cyi), (0, 1));
+        assert_eq!(zbesk(0.0, 0.0, 0.0, 1, 1, &mut cyr, &mut cyi), (0, 1));
+        assert_eq!(zbesh(1.0, 0.0, 0.0, 1, 3, 1, &mut cyr, &mut cyi), (0, 1));
+        assert_eq!(zairy(f64::NAN, 0.0, 0, 1).3, 1);
+        assert_eq!(zbiry(1.0, 0.0, 2, 1).2, 1);
+
+        // J and I are defined at the origin
+        assert_eq!(zbesj(0.0, 0.0, 0.0, 1, 2, &mut cyr, &mut cyi), (0, 0));
+        assert_eq!((cyr, cyi), ([1.0, 0.0], [0.0, 0.0]));
+    }
+}
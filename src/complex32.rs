@@ -0,0 +1,281 @@
+//! Single-precision (`Complex32`) entry points
+//!
+//! These functions present a 32-bit API for graphics and DSP code that keeps
+//! its data in `f32` buffers. The computation itself runs through the f64
+//! backend, and the results are rounded to `f32` at the end; values outside
+//! the `f32` range become infinite or zero. The single-value functions are the
+//! generic ones from the crate root instantiated for `f32`.
+//!
+//! ```rust
+//! use num_complex::Complex32;
+//! use zbessel_rs::complex32;
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let z = Complex32::new(1.0, 0.5);
+//!
+//!     let j0 = complex32::J(0.0, z)?;
+//!     let orders = complex32::bessel_k(z, 0.0, 1, 4)?;
+//!     println!("J_0({}) = {}, K_0..3 = {:?}", z, j0, orders.values);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use crate::BesselError;
+use num_complex::{Complex32, Complex64};
+
+/// Structure representing the result of single-precision Bessel function calculations
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BesselResult32 {
+    /// Calculated function values
+    pub values: Vec<Complex32>,
+    /// Number of function values that experienced underflow
+    pub underflow_count: i32,
+}
+
+fn widen(z: Complex32) -> Complex64 {
+    Complex64::new(z.re as f64, z.im as f64)
+}
+
+fn narrow(z: Complex64) -> Complex32 {
+    Complex32::new(z.re as f32, z.im as f32)
+}
+
+fn narrow_result(result: crate::BesselResult) -> BesselResult32 {
+    BesselResult32 {
+        values: result.values.into_iter().map(narrow).collect(),
+        underflow_count: result.underflow_count,
+    }
+}
+
+/// Calculate complex Bessel function J_ν(z) in single precision
+///
+/// # Parameters
+/// * `z` - Complex argument
+/// * `nu` - Order (real number)
+/// * `kode` - Scaling option (1: no scaling, 2: exp(-abs(Im(z))) scaling)
+/// * `n` - Number of function values to calculate
+pub fn bessel_j(z: Complex32, nu: f32, kode: i32, n: usize) -> Result<BesselResult32, BesselError> {
+    crate::bessel_j(widen(z), nu as f64, kode, n).map(narrow_result)
+}
+
+/// Calculate complex Bessel function Y_ν(z) in single precision
+///
+/// # Parameters
+/// * `z` - Complex argument
+/// * `nu` - Order (real number)
+/// * `kode` - Scaling option (1: no scaling, 2: exp(-abs(Im(z))) scaling)
+/// * `n` - Number of function values to calculate
+pub fn bessel_y(z: Complex32, nu: f32, kode: i32, n: usize) -> Result<BesselResult32, BesselError> {
+    crate::bessel_y(widen(z), nu as f64, kode, n).map(narrow_result)
+}
+
+/// Calculate complex modified Bessel function I_ν(z) in single precision
+///
+/// # Parameters
+/// * `z` - Complex argument
+/// * `nu` - Order (real number)
+/// * `kode` - Scaling option (1: no scaling, 2: exp(-abs(Re(z))) scaling)
+/// * `n` - Number of function values to calculate
+pub fn bessel_i(z: Complex32, nu: f32, kode: i32, n: usize) -> Result<BesselResult32, BesselError> {
+    crate::bessel_i(widen(z), nu as f64, kode, n).map(narrow_result)
+}
+
+/// Calculate complex modified Bessel function K_ν(z) in single precision
+///
+/// # Parameters
+/// * `z` - Complex argument
+/// * `nu` - Order (real number)
+/// * `kode` - Scaling option (1: no scaling, 2: exp(z) scaling)
+/// * `n` - Number of function values to calculate
+pub fn bessel_k(z: Complex32, nu: f32, kode: i32, n: usize) -> Result<BesselResult32, BesselError> {
+    crate::bessel_k(widen(z), nu as f64, kode, n).map(narrow_result)
+}
+
+/// Calculate Bessel function J_ν(z) in single precision (single value, no scaling)
+///
+/// # Parameters
+/// * `nu` - Order (real number)
+/// * `z` - Complex argument
+///
+/// # Returns
+/// Complex value of J_ν(z)
+#[allow(non_snake_case)]
+pub fn J(nu: f32, z: Complex32) -> Result<Complex32, BesselError> {
+    crate::J(nu, z)
+}
+
+/// Calculate Bessel function Y_ν(z) in single precision (single value, no scaling)
+///
+/// # Parameters
+/// * `nu` - Order (real number)
+/// * `z` - Complex argument
+///
+/// # Returns
+/// Complex value of Y_ν(z)
+#[allow(non_snake_case)]
+pub fn Y(nu: f32, z: Complex32) -> Result<Complex32, BesselError> {
+    crate::Y(nu, z)
+}
+
+/// Calculate modified Bessel function I_ν(z) in single precision (single value, no scaling)
+///
+/// # Parameters
+/// * `nu` - Order (real number)
+/// * `z` - Complex argument
+///
+/// # Returns
+/// Complex value of I_ν(z)
+#[allow(non_snake_case)]
+pub fn I(nu: f32, z: Complex32) -> Result<Complex32, BesselError> {
+    crate::I(nu, z)
+}
+
+/// Calculate modified Bessel function K_ν(z) in single precision (single value, no scaling)
+///
+/// # Parameters
+/// * `nu` - Order (real number)
+/// * `z` - Complex argument
+///
+/// # Returns
+/// Complex value of K_ν(z)
+#[allow(non_snake_case)]
+pub fn K(nu: f32, z: Complex32) -> Result<Complex32, BesselError> {
+    crate::K(nu, z)
+}
+
+/// Calculate Bessel function J_ν(z) in single precision with scaling (single value)
+///
+/// # Parameters
+/// * `nu` - Order (real number)
+/// * `z` - Complex argument
+///
+/// # Returns
+/// Complex value of J_ν(z) with exp(-abs(Im(z))) scaling
+#[allow(non_snake_case)]
+pub fn J_scaled(nu: f32, z: Complex32) -> Result<Complex32, BesselError> {
+    crate::J_scaled(nu, z)
+}
+
+/// Calculate Bessel function Y_ν(z) in single precision with scaling (single value)
+///
+/// # Parameters
+/// * `nu` - Order (real number)
+/// * `z` - Complex argument
+///
+/// # Returns
+/// Complex value of Y_ν(z) with exp(-abs(Im(z))) scaling
+#[allow(non_snake_case)]
+pub fn Y_scaled(nu: f32, z: Complex32) -> Result<Complex32, BesselError> {
+    crate::Y_scaled(nu, z)
+}
+
+/// Calculate modified Bessel function I_ν(z) in single precision with scaling (single value)
+///
+/// # Parameters
+/// * `nu` - Order (real number)
+/// * `z` - Complex argument
+///
+/// # Returns
+/// Complex value of I_ν(z) with exp(-abs(Re(z))) scaling
+#[allow(non_snake_case)]
+pub fn I_scaled(nu: f32, z: Complex32) -> Result<Complex32, BesselError> {
+    crate::I_scaled(nu, z)
+}
+
+/// Calculate modified Bessel function K_ν(z) in single precision with scaling (single value)
+///
+/// # Parameters
+/// * `nu` - Order (real number)
+/// * `z` - Complex argument
+///
+/// # Returns
+/// Complex value of K_ν(z) with exp(z) scaling
+#[allow(non_snake_case)]
+pub fn K_scaled(nu: f32, z: Complex32) -> Result<Complex32, BesselError> {
+    crate::K_scaled(nu, z)
+}
+
+/// Calculate Airy function Ai(z) in single precision (no scaling)
+///
+/// # Parameters
+/// * `z` - Complex argument
+///
+/// # Returns
+/// Complex value of Ai(z)
+#[allow(non_snake_case)]
+pub fn Ai(z: Complex32) -> Result<Complex32, BesselError> {
+    crate::Ai(z)
+}
+
+/// Calculate Airy function Ai(z) in single precision with scaling
+///
+/// # Parameters
+/// * `z` - Complex argument
+///
+/// # Returns
+/// Complex value of Ai(z) with exp(zeta) scaling where zeta=(2/3)*z^(3/2)
+#[allow(non_snake_case)]
+pub fn Ai_scaled(z: Complex32) -> Result<Complex32, BesselError> {
+    crate::Ai_scaled(z)
+}
+
+/// Calculate Airy function Bi(z) in single precision (no scaling)
+///
+/// # Parameters
+/// * `z` - Complex argument
+///
+/// # Returns
+/// Complex value of Bi(z)
+#[allow(non_snake_case)]
+pub fn Bi(z: Complex32) -> Result<Complex32, BesselError> {
+    crate::Bi(z)
+}
+
+/// Calculate Airy function Bi(z) in single precision with scaling
+///
+/// # Parameters
+/// * `z` - Complex argument
+///
+/// # Returns
+/// Complex value of Bi(z) with exp(-abs(Re(zeta))) scaling where zeta=(2/3)*z^(3/2)
+#[allow(non_snake_case)]
+pub fn Bi_scaled(z: Complex32) -> Result<Complex32, BesselError> {
+    crate::Bi_scaled(z)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_precision_matches_f64() {
+        let z = Complex32::new(10.0, 20.0);
+        let expected = crate::I(1.0, Complex64::new(10.0, 20.0)).unwrap();
+        let result = I(1.0, z).unwrap();
+        assert!((widen(result) - expected).norm() < 1e-6 * expected.norm());
+
+        let ai = Ai(z).unwrap();
+        let expected = crate::Ai(Complex64::new(10.0, 20.0)).unwrap();
+        assert!((widen(ai) - expected).norm() < 1e-6 * expected.norm());
+    }
+
+    #[test]
+    fn test_single_precision_sequence() {
+        let z = Complex32::new(2.0, 1.0);
+        let result = bessel_j(z, 0.0, 1, 5).unwrap();
+        let expected = crate::bessel_j(widen(z), 0.0, 1, 5).unwrap();
+        assert_eq!(result.values.len(), 5);
+        for (a, e) in result.values.iter().zip(&expected.values) {
+            assert_eq!(*a, narrow(*e));
+        }
+    }
+
+    #[test]
+    fn test_out_of_range_values_saturate() {
+        // I_0(100) ~ 1e42 overflows f32
+        let value = I(0.0, Complex32::new(100.0, 0.0)).unwrap();
+        assert!(value.re.is_infinite());
+    }
+}
@@ -0,0 +1,100 @@
+//! Condition numbers of the Bessel functions
+//!
+//! The condition number of f with respect to a variable x is
+//! |x ∂f/∂x / f|: a relative perturbation δ in x changes f by a relative
+//! amount of about κ·δ. Since the inputs themselves carry a relative
+//! rounding error of ε, about log10(κ) digits of any computed value are
+//! uncertain. κ grows without bound near the zeros of a function, which is why
+//! values there have few correct digits whatever the algorithm:
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{condition_number, BesselKind};
+//!
+//! fn main() -> Result<(), zbessel_rs::BesselError> {
+//!     // Close to the first zero of J_0, 2.404825557695773
+//!     let z = Complex64::new(2.4048, 0.0);
+//!     let kappa = condition_number(BesselKind::J, 0.0, z)?;
+//!     assert!(kappa.z > 1e4);
+//!     println!("about {:.1} digits are uncertain", kappa.digits());
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{bessel_with_derivative, BesselError, BesselKind, Scaling};
+use num_complex::Complex64;
+
+/// Relative sensitivity of a function value to its argument and order
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConditionNumber {
+    /// |z f'(z) / f(z)|, from the derivative recurrence
+    pub z: f64,
+    /// |ν (∂f/∂ν) / f|, from a central difference in ν
+    pub nu: f64,
+}
+
+impl ConditionNumber {
+    /// Decimal digits made uncertain by rounding of z and ν, log10 of the
+    /// larger condition number (at least 0)
+    pub fn digits(&self) -> f64 {
+        self.z.max(self.nu).max(1.0).log10()
+    }
+}
+
+/// Estimate the condition numbers of F_ν(z) with respect to z and ν
+///
+/// # Parameters
+/// * `kind` - Kind of Bessel function
+/// * `nu` - Order (real number)
+/// * `z` - Complex argument
+///
+/// # Returns
+/// The condition numbers; both are infinite at a zero of the function
+///
+/// # Errors
+/// Any error of evaluating the function at `z` and orders near `nu`
+pub fn condition_number(
+    kind: BesselKind,
+    nu: f64,
+    z: Complex64,
+) -> Result<ConditionNumber, BesselError> {
+    let (f, fp) = bessel_with_derivative(kind, nu, z, Scaling::Unscaled)?;
+    // A step of ε^(1/3) balances truncation against rounding in the difference
+    let h = f64::EPSILON.cbrt() * nu.abs().max(1.0);
+    let eval = |nu| Ok::<_, BesselError>(kind.eval_impl(z, nu, 1, 1, false)?.values[0]);
+    let df_dnu = (eval(nu + h)? - eval(nu - h)?) / (2.0 * h);
+    Ok(ConditionNumber {
+        z: (z * fp / f).norm(),
+        nu: (nu * df_dnu / f).norm(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_condition_number() {
+        // κ_z(J_0, x) = |x J_1(x) / J_0(x)|
+        let x = 1.0;
+        let kappa = condition_number(BesselKind::J, 0.0, Complex64::new(x, 0.0)).unwrap();
+        let (j0, j1) = (
+            crate::real::J(0.0, x).unwrap(),
+            crate::real::J(1.0, x).unwrap(),
+        );
+        assert!((kappa.z - x * j1 / j0).abs() < 1e-14);
+        assert_eq!(kappa.nu, 0.0);
+
+        // ∂K_ν/∂ν at ν = 1/2 is sqrt(π/(2z)) E1(2z) e^z (DLMF 10.38.7), so
+        // κ_ν = E1(2) e² / 2 at z = 1
+        let kappa = condition_number(BesselKind::K, 0.5, Complex64::new(1.0, 0.0)).unwrap();
+        let e1_2 = 0.048_900_510_708_061_12;
+        let expected = 0.5 * e1_2 * std::f64::consts::E.powi(2);
+        assert!((kappa.nu - expected).abs() < 1e-6 * expected);
+
+        // Approaching a zero the value loses more digits
+        let zero = 2.404_825_557_695_773;
+        let near = condition_number(BesselKind::J, 0.0, Complex64::new(zero + 1e-9, 0.0));
+        assert!(near.unwrap().digits() > 8.0);
+    }
+}
@@ -0,0 +1,166 @@
+//! Analytic continuation onto other sheets of the Riemann surface
+//!
+//! The library functions return the principal branch, |arg z| ≤ π with the
+//! sign of a zero imaginary part choosing the side of the cut.
+//! [`bessel_continued`] evaluates F_ν(z e^{mπi}), the function continued
+//! through m half-turns about the origin, from principal values at z with
+//! the connection formulas (DLMF 10.11.1-10.11.4, 10.34.1, 10.34.2), where
+//! U_k = sin(kνπ)/sin(νπ):
+//!
+//! * J_ν(z e^{mπi}) = e^{mνπi} J_ν(z), and likewise for I
+//! * Y_ν(z e^{mπi}) = e^{-mνπi} Y_ν(z) + 2i cos(νπ) U_m J_ν(z)
+//! * K_ν(z e^{mπi}) = e^{-mνπi} K_ν(z) - πi U_m I_ν(z)
+//! * H^(1)_ν(z e^{mπi}) = -U_{m-1} H^(1)_ν(z) - e^{-νπi} U_m H^(2)_ν(z)
+//! * H^(2)_ν(z e^{mπi}) = U_{m+1} H^(2)_ν(z) + e^{νπi} U_m H^(1)_ν(z)
+//!
+//! U_k is a polynomial in cos(νπ), so the formulas hold at integer orders too.
+//! Continuing past the cut from above takes m = 1 with z just above the
+//! positive real axis:
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{bessel, bessel_continued, BesselKind, Scaling};
+//!
+//! fn main() -> Result<(), zbessel_rs::BesselError> {
+//!     // arg = π + 0.1, just past the cut on the sheet above the principal one
+//!     let z = Complex64::from_polar(3.0, 0.1);
+//!     let past = bessel_continued(BesselKind::K, 0.5, z, 1)?;
+//!     // The principal value at the same point is on the other side of the cut
+//!     let principal = bessel(BesselKind::K, 0.5, -z, Scaling::Unscaled)?;
+//!     assert!((past - principal).norm() > 1.0);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::reflection::sin_cos_pi;
+use crate::{bessel, BesselError, BesselKind, Scaling};
+use num_complex::Complex64;
+use std::f64::consts::PI;
+
+/// sin(kνπ)/sin(νπ), continuous at integer ν
+fn sin_ratio(k: i32, nu: f64) -> f64 {
+    let (s, c) = sin_cos_pi(nu);
+    if s != 0.0 {
+        return sin_cos_pi(f64::from(k) * nu).0 / s;
+    }
+    // The limit at cos(νπ) = ±1 is k cos(νπ)^(k-1)
+    let sign = if c < 0.0 && k % 2 == 0 { -1.0 } else { 1.0 };
+    sign * f64::from(k)
+}
+
+/// Calculate a Bessel function continued through m half-turns about the origin
+///
+/// # Parameters
+/// * `kind` - Kind of Bessel function
+/// * `nu` - Order (real number)
+/// * `z` - Complex argument (nonzero), taken on the principal sheet
+/// * `m` - Number of half-turns; the result is F_ν(z e^{mπi})
+///
+/// # Returns
+/// Complex value of the unscaled function on the sheet reached from `z`
+///
+/// # Errors
+/// `InvalidParameter` if `z` is 0, where every sheet meets; otherwise any
+/// error of the principal values at `z`
+pub fn bessel_continued(
+    kind: BesselKind,
+    nu: f64,
+    z: Complex64,
+    m: i32,
+) -> Result<Complex64, BesselError> {
+    if z == Complex64::new(0.0, 0.0) {
+        return Err(BesselError::InvalidParameter(
+            "continuation needs z != 0".to_string(),
+        ));
+    }
+    let eval = |kind| bessel(kind, nu, z, Scaling::Unscaled);
+    if m == 0 {
+        return eval(kind);
+    }
+    let (s, c) = sin_cos_pi(f64::from(m) * nu);
+    let turn = Complex64::new(c, s);
+    let i = Complex64::i();
+    let u = sin_ratio(m, nu);
+    let partner = |kind| -> Result<Complex64, BesselError> {
+        if u == 0.0 {
+            Ok(Complex64::new(0.0, 0.0))
+        } else {
+            eval(kind)
+        }
+    };
+    let (s_nu, c_nu) = sin_cos_pi(nu);
+    let value = match kind {
+        BesselKind::J | BesselKind::I => turn * eval(kind)?,
+        BesselKind::Y => {
+            turn.conj() * eval(BesselKind::Y)? + 2.0 * i * c_nu * u * partner(BesselKind::J)?
+        }
+        BesselKind::K => turn.conj() * eval(BesselKind::K)? - PI * i * u * partner(BesselKind::I)?,
+        BesselKind::H1 => {
+            -sin_ratio(m - 1, nu) * eval(BesselKind::H1)?
+                - Complex64::new(c_nu, -s_nu) * u * partner(BesselKind::H2)?
+        }
+        BesselKind::H2 => {
+            sin_ratio(m + 1, nu) * eval(BesselKind::H2)?
+                + Complex64::new(c_nu, s_nu) * u * partner(BesselKind::H1)?
+        }
+    };
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KINDS: [BesselKind; 6] = [
+        BesselKind::J,
+        BesselKind::Y,
+        BesselKind::I,
+        BesselKind::K,
+        BesselKind::H1,
+        BesselKind::H2,
+    ];
+
+    #[test]
+    fn test_matches_principal_branch() {
+        // Half a turn from arg ±2.5 lands back on the principal sheet
+        for nu in [0.0, 0.3, 1.0, 2.5, -1.7] {
+            for (arg, m) in [(2.5, -1), (-2.5, 1)] {
+                let z = Complex64::from_polar(1.7, arg);
+                let w = z * Complex64::from_polar(1.0, f64::from(m) * PI);
+                for kind in KINDS {
+                    let expected = bessel(kind, nu, w, Scaling::Unscaled).unwrap();
+                    let value = bessel_continued(kind, nu, z, m).unwrap();
+                    assert!(
+                        (value - expected).norm() < 1e-13 * expected.norm().max(1.0),
+                        "{:?}_{} at {} turned {}: {} vs {}",
+                        kind,
+                        nu,
+                        z,
+                        m,
+                        value,
+                        expected
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_full_turns() {
+        let z = Complex64::new(0.8, 1.1);
+        for kind in KINDS {
+            // At integer order J and I are entire, while Y, K and the Hankel
+            // functions pick up log terms after a full turn
+            let value = bessel_continued(kind, 2.0, z, 2).unwrap();
+            let principal = bessel(kind, 2.0, z, Scaling::Unscaled).unwrap();
+            let unchanged = matches!(kind, BesselKind::J | BesselKind::I);
+            assert_eq!((value - principal).norm() < 1e-13, unchanged, "{:?}", kind);
+        }
+        // Y_n(z e^{2πi}) = Y_n(z) + 4i J_n(z)
+        let y = bessel_continued(BesselKind::Y, 1.0, z, 2).unwrap();
+        let expected = bessel(BesselKind::Y, 1.0, z, Scaling::Unscaled).unwrap()
+            + 4.0 * Complex64::i() * bessel(BesselKind::J, 1.0, z, Scaling::Unscaled).unwrap();
+        assert!((y - expected).norm() < 1e-14);
+        assert!(bessel_continued(BesselKind::K, 1.0, Complex64::new(0.0, 0.0), 1).is_err());
+    }
+}
@@ -0,0 +1,116 @@
+//! Continued fractions by the modified Lentz method
+//!
+//! [`lentz`] evaluates f = b_0 + a_1 / (b_1 + a_2 / (b_2 + ...)) from the
+//! front, multiplying f by one correction factor Δ_j per term and stopping
+//! once |Δ_j - 1| is below the tolerance. Zero denominators are replaced by
+//! a tiny number as Lentz and Thompson–Barnett suggest. The Rust backend
+//! uses it for the ratios I_{ν+1}/I_ν; the same primitive gives the
+//! logarithmic derivatives of other functions.
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::continued_fraction::lentz;
+//!
+//! // J_1(z) / J_0(z) = 1 / (2/z - 1 / (4/z - 1 / (6/z - ...)))
+//! let z = Complex64::new(1.5, 0.5);
+//! let fraction = lentz(Complex64::new(0.0, 0.0), 1e-15, 1000, |j| {
+//!     let a = if j == 1 { 1.0 } else { -1.0 };
+//!     (Complex64::new(a, 0.0), 2.0 * j as f64 / z)
+//! });
+//! assert!(fraction.converged);
+//! println!("J1/J0 = {} after {} terms", fraction.value, fraction.iterations);
+//! ```
+
+use num_complex::Complex64;
+
+/// Stand-in for a zero denominator
+const TINY: f64 = 1e-30;
+
+/// Value of a continued fraction with convergence diagnostics
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lentz {
+    /// The approximation after the last term
+    pub value: Complex64,
+    /// Number of terms a_j, b_j used
+    pub iterations: usize,
+    /// |Δ - 1| for the last correction factor Δ, an estimate of the relative
+    /// error of `value`
+    pub last_change: f64,
+    /// Whether `last_change` fell below the tolerance
+    pub converged: bool,
+}
+
+/// Evaluate b_0 + a_1 / (b_1 + a_2 / (b_2 + ...))
+///
+/// # Parameters
+/// * `b0` - Leading term
+/// * `tolerance` - Stop once |Δ_j - 1| < `tolerance`
+/// * `max_iterations` - Largest number of terms used
+/// * `term` - (a_j, b_j) for j = 1, 2, ...
+pub fn lentz(
+    b0: Complex64,
+    tolerance: f64,
+    max_iterations: usize,
+    mut term: impl FnMut(usize) -> (Complex64, Complex64),
+) -> Lentz {
+    let tiny = Complex64::new(TINY, 0.0);
+    let nonzero = |x: Complex64| if x.norm() == 0.0 { tiny } else { x };
+    let mut f = nonzero(b0);
+    let mut c = f;
+    let mut d = Complex64::new(0.0, 0.0);
+    let mut last_change = f64::INFINITY;
+    for j in 1..=max_iterations {
+        let (a, b) = term(j);
+        d = 1.0 / nonzero(b + a * d);
+        c = nonzero(b + a / c);
+        let delta = c * d;
+        f *= delta;
+        last_change = (delta - 1.0).norm();
+        if last_change < tolerance {
+            return Lentz {
+                value: f,
+                iterations: j,
+                last_change,
+                converged: true,
+            };
+        }
+    }
+    Lentz {
+        value: f,
+        iterations: max_iterations,
+        last_change,
+        converged: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bessel_i;
+
+    #[test]
+    fn test_bessel_ratio() {
+        // I_{ν+1}(z) / I_ν(z) = 1 / (2(ν+1)/z + 1 / (2(ν+2)/z + ...))
+        let (nu, z) = (0.3, Complex64::new(2.0, -3.0));
+        let fraction = lentz(Complex64::new(0.0, 0.0), 2.0 * f64::EPSILON, 10_000, |j| {
+            (Complex64::new(1.0, 0.0), 2.0 * (nu + j as f64) / z)
+        });
+        assert!(fraction.converged && fraction.last_change < 2.0 * f64::EPSILON);
+        let values = bessel_i(z, nu, 1, 2).unwrap().values;
+        let exact = values[1] / values[0];
+        assert!((fraction.value - exact).norm() < 1e-14 * exact.norm());
+    }
+
+    #[test]
+    fn test_diagnostics() {
+        // The golden ratio 1 + 1/(1 + 1/(1 + ...)) converges slowly
+        let one = Complex64::new(1.0, 0.0);
+        let fraction = lentz(one, 1e-15, 5, |_| (one, one));
+        assert!(!fraction.converged);
+        assert_eq!(fraction.iterations, 5);
+        assert!(fraction.last_change > 1e-3);
+        let fraction = lentz(one, 1e-15, 100, |_| (one, one));
+        assert!(fraction.converged);
+        assert!((fraction.value.re - 0.5 * (1.0 + 5.0_f64.sqrt())).abs() < 1e-15);
+    }
+}
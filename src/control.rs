@@ -0,0 +1,291 @@
+//! Progress reporting and cancellation for long batch evaluations
+//!
+//! [`bessel_slice_controlled`] and [`bessel_grid_controlled`] work like
+//! [`bessel_slice`](crate::bessel_slice) and [`bessel_grid`](crate::bessel_grid)
+//! but evaluate in steps. After each step they report a [`Progress`] to the
+//! callback of a [`BatchControl`], then check its [`CancelToken`]. A
+//! cancelled job stops at the next step boundary and returns what it has
+//! computed so far as a [`Partial`].
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{bessel_slice_controlled, BatchControl, BesselKind, CancelToken, Scaling};
+//!
+//! fn main() -> Result<(), zbessel_rs::BesselError> {
+//!     let zs: Vec<Complex64> = (0..100_000).map(|k| Complex64::new(1e-3 * k as f64, 0.5)).collect();
+//!     let token = CancelToken::new();
+//!     let stop = token.clone();
+//!     let mut control = BatchControl::new()
+//!         .step(10_000)
+//!         .cancel_token(token)
+//!         .on_progress(|p| {
+//!             // Give up after 30% of the work
+//!             if p.completed * 10 >= p.total * 3 {
+//!                 stop.cancel();
+//!             }
+//!         });
+//!     let partial = bessel_slice_controlled(BesselKind::J, &zs, 0.0, Scaling::Unscaled, &mut control)?;
+//!     assert!(partial.cancelled);
+//!     assert_eq!(partial.completed, 30_000);
+//!     assert_eq!(partial.values.len(), 30_000);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::grid::fill_grid;
+use crate::slice::fill_slice;
+use crate::{BesselError, BesselGrid, BesselKind, Scaling};
+use num_complex::Complex64;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Points per step of a slice evaluation unless set with [`BatchControl::step`]
+const DEFAULT_STEP: usize = 4096;
+
+/// Shared flag asking a batch evaluation to stop
+///
+/// Clones share the flag, so one clone can be handed to the evaluation and
+/// another kept by whoever decides to cancel it, possibly on another thread.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    /// A token that is not cancelled
+    pub fn new() -> Self {
+        CancelToken::default()
+    }
+
+    /// Ask every evaluation holding a clone of this token to stop
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether [`cancel`](Self::cancel) has been called on any clone
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Amount of work done, passed to the progress callback after each step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    /// Units of work completed (points of a slice, arguments of a grid)
+    pub completed: usize,
+    /// Total units of work
+    pub total: usize,
+}
+
+/// Result of a batch evaluation that may have been cancelled
+#[derive(Debug, Clone, PartialEq)]
+pub struct Partial<T> {
+    /// The values computed
+    pub values: T,
+    /// Units of work completed
+    pub completed: usize,
+    /// Whether the evaluation stopped early because of a [`CancelToken`]
+    pub cancelled: bool,
+}
+
+/// Progress callback, cancellation token and step size for a batch evaluation
+#[derive(Default)]
+pub struct BatchControl<'a> {
+    progress: Option<Box<dyn FnMut(Progress) + 'a>>,
+    cancel: Option<CancelToken>,
+    step: Option<usize>,
+}
+
+impl fmt::Debug for BatchControl<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BatchControl")
+            .field("progress", &self.progress.is_some())
+            .field("cancel", &self.cancel)
+            .field("step", &self.step)
+            .finish()
+    }
+}
+
+impl<'a> BatchControl<'a> {
+    /// No callback and no cancellation
+    pub fn new() -> Self {
+        BatchControl::default()
+    }
+
+    /// Call `f` after each step
+    pub fn on_progress(mut self, f: impl FnMut(Progress) + 'a) -> Self {
+        self.progress = Some(Box::new(f));
+        self
+    }
+
+    /// Stop at the next step boundary once `token` is cancelled
+    pub fn cancel_token(mut self, token: CancelToken) -> Self {
+        self.cancel = Some(token);
+        self
+    }
+
+    /// Evaluate slices `points` points per step (at least 1)
+    ///
+    /// Grids always advance one argument per step.
+    pub fn step(mut self, points: usize) -> Self {
+        self.step = Some(points.max(1));
+        self
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancel.as_ref().is_some_and(CancelToken::is_cancelled)
+    }
+
+    fn report(&mut self, completed: usize, total: usize) {
+        if let Some(f) = &mut self.progress {
+            f(Progress { completed, total });
+        }
+    }
+}
+
+/// [`bessel_slice`](crate::bessel_slice) with progress reporting and cancellation
+///
+/// # Parameters
+/// * `kind` - Kind of Bessel function
+/// * `zs` - Complex arguments
+/// * `nu` - Order (real number)
+/// * `scaling` - Scaling option
+/// * `control` - Progress callback, cancellation token and step size
+///
+/// # Returns
+/// The values at the first `completed` points of `zs`
+pub fn bessel_slice_controlled(
+    kind: BesselKind,
+    zs: &[Complex64],
+    nu: f64,
+    scaling: Scaling,
+    control: &mut BatchControl<'_>,
+) -> Result<Partial<Vec<Complex64>>, BesselError> {
+    let step = control.step.unwrap_or(DEFAULT_STEP);
+    let mut values = Vec::with_capacity(zs.len());
+    for chunk in zs.chunks(step) {
+        if control.is_cancelled() {
+            break;
+        }
+        let start = values.len();
+        values.resize(start + chunk.len(), Complex64::new(0.0, 0.0));
+        fill_slice(kind, chunk, nu, scaling, &mut values[start..])?;
+        control.report(values.len(), zs.len());
+    }
+    Ok(Partial {
+        completed: values.len(),
+        cancelled: values.len() < zs.len(),
+        values,
+    })
+}
+
+/// [`bessel_grid`](crate::bessel_grid) with progress reporting and cancellation
+///
+/// The grid advances one argument (column) per step. If cancelled, the grid
+/// keeps its full shape; the first `completed` columns hold values and the
+/// rest are NaN.
+///
+/// # Parameters
+/// * `kind` - Kind of Bessel function
+/// * `nus` - Orders (any real numbers, in any order)
+/// * `zs` - Complex arguments
+/// * `scaling` - Scaling option
+/// * `control` - Progress callback and cancellation token
+pub fn bessel_grid_controlled(
+    kind: BesselKind,
+    nus: &[f64],
+    zs: &[Complex64],
+    scaling: Scaling,
+    control: &mut BatchControl<'_>,
+) -> Result<Partial<BesselGrid>, BesselError> {
+    let cols = zs.len();
+    let (grid, completed) = fill_grid(kind, nus, zs, scaling, |j| {
+        if j > 0 {
+            control.report(j, cols);
+        }
+        !control.is_cancelled()
+    })?;
+    if completed == cols {
+        control.report(completed, cols);
+    }
+    Ok(Partial {
+        values: grid,
+        completed,
+        cancelled: completed < cols,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bessel_grid, bessel_slice};
+
+    #[test]
+    fn test_uncancelled_matches_plain_calls() {
+        let zs: Vec<Complex64> = (0..1000)
+            .map(|k| Complex64::new(0.01 * k as f64 + 0.1, -0.3))
+            .collect();
+        let mut reports = Vec::new();
+        let mut control = BatchControl::new()
+            .step(300)
+            .on_progress(|p| reports.push(p.completed));
+        let partial =
+            bessel_slice_controlled(BesselKind::K, &zs, 0.5, Scaling::Scaled, &mut control)
+                .unwrap();
+        drop(control);
+        assert!(!partial.cancelled);
+        assert_eq!(
+            partial.values,
+            bessel_slice(BesselKind::K, &zs, 0.5, Scaling::Scaled).unwrap()
+        );
+        assert_eq!(reports, [300, 600, 900, 1000]);
+
+        let nus = [0.0, 1.0, 2.5];
+        let partial = bessel_grid_controlled(
+            BesselKind::J,
+            &nus,
+            &zs[..50],
+            Scaling::Unscaled,
+            &mut BatchControl::new(),
+        )
+        .unwrap();
+        assert_eq!(partial.completed, 50);
+        assert_eq!(
+            partial.values,
+            bessel_grid(BesselKind::J, &nus, &zs[..50], Scaling::Unscaled).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_cancellation_keeps_partial_results() {
+        let zs: Vec<Complex64> = (1..=40).map(|k| Complex64::new(k as f64, 0.0)).collect();
+        let token = CancelToken::new();
+        let stop = token.clone();
+        let mut control = BatchControl::new().cancel_token(token).on_progress(|p| {
+            if p.completed == 10 {
+                stop.cancel();
+            }
+        });
+        let partial = bessel_grid_controlled(
+            BesselKind::Y,
+            &[0.0, 3.0],
+            &zs,
+            Scaling::Unscaled,
+            &mut control,
+        )
+        .unwrap();
+        assert!(partial.cancelled);
+        assert_eq!(partial.completed, 10);
+        let grid = partial.values;
+        assert_eq!(grid.get(1, 9), crate::Y(3.0, zs[9]).unwrap());
+        assert!(grid.get(0, 10).re.is_nan());
+
+        // A token cancelled up front stops before any work
+        let token = CancelToken::new();
+        token.cancel();
+        let mut control = BatchControl::new().cancel_token(token);
+        let partial =
+            bessel_slice_controlled(BesselKind::J, &zs, 0.0, Scaling::Unscaled, &mut control)
+                .unwrap();
+        assert!(partial.cancelled && partial.values.is_empty());
+    }
+}
@@ -0,0 +1,200 @@
+//! Sampled curves of real-argument functions, drawn with plotters
+//!
+//! [`Curve::sample`] evaluates J_ν, Y_ν or Ai over a real interval with a
+//! step that follows the local period of oscillation: at least
+//! [`POINTS_PER_PERIOD`] points per period of sin(√(x² - ν²)) for J and Y,
+//! and of the phase (2/3)|x|^(3/2) of Ai for negative x, so that the curve
+//! stays smooth far out on the oscillating side without oversampling the
+//! monotone side. [`draw_curves`] draws several curves, with axes and a
+//! legend, on any plotters drawing area:
+//!
+//! ```rust
+//! use plotters::prelude::*;
+//! use zbessel_rs::{draw_curves, Curve};
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let path = std::env::temp_dir().join("bessel.svg");
+//!     let area = SVGBackend::new(&path, (800, 400)).into_drawing_area();
+//!     area.fill(&WHITE)?;
+//!     let curves = [Curve::J(0.0), Curve::Y(0.0), Curve::Ai];
+//!     draw_curves(&area, &curves, (-10.0, 20.0), (-1.0, 1.0))?;
+//!     area.present()?;
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{real, BesselError};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use std::f64::consts::PI;
+
+/// Fewest points per local period of oscillation
+pub const POINTS_PER_PERIOD: f64 = 24.0;
+
+/// Fewest points over the whole interval
+const MIN_POINTS: f64 = 256.0;
+
+/// A real function of x to sample and draw
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Curve {
+    /// J_ν(x) for the given order ν
+    J(f64),
+    /// Y_ν(x) for the given order ν
+    Y(f64),
+    /// Airy function Ai(x)
+    Ai,
+}
+
+impl Curve {
+    /// Value at `x`, as computed by [`real`]
+    pub fn eval(self, x: f64) -> Result<f64, BesselError> {
+        match self {
+            Curve::J(nu) => real::J(nu, x),
+            Curve::Y(nu) => real::Y(nu, x),
+            Curve::Ai => real::Ai(x),
+        }
+    }
+
+    /// Local period of oscillation at `x`, infinite where monotone
+    fn period(self, x: f64) -> f64 {
+        match self {
+            Curve::J(nu) | Curve::Y(nu) if x.abs() > nu.abs() => {
+                // The phase √(x² - ν²) - ν arccos(ν/x) has derivative √(x² - ν²)/x
+                2.0 * PI * x.abs() / (x * x - nu * nu).sqrt()
+            }
+            Curve::Ai if x < 0.0 => 2.0 * PI / (-x).sqrt(),
+            _ => f64::INFINITY,
+        }
+    }
+
+    /// Sample the curve over `range`
+    ///
+    /// Returns the points as segments: points where the function fails or is
+    /// infinite, such as Y at x ≤ 0 or J of non-integer order at x < 0, end a
+    /// segment and are left out.
+    ///
+    /// # Parameters
+    /// * `range` - Interval (start, end) with start < end
+    pub fn sample(self, range: (f64, f64)) -> Vec<Vec<(f64, f64)>> {
+        let (start, end) = range;
+        let mut segments = Vec::new();
+        if !start.is_finite() || !end.is_finite() || start >= end {
+            return segments;
+        }
+        let max_step = (end - start) / MIN_POINTS;
+        let mut segment = Vec::new();
+        let mut x = start;
+        loop {
+            match self.eval(x) {
+                Ok(y) if y.is_finite() => segment.push((x, y)),
+                _ if !segment.is_empty() => segments.push(std::mem::take(&mut segment)),
+                _ => {}
+            }
+            if x >= end {
+                break;
+            }
+            let step = max_step.min(self.period(x) / POINTS_PER_PERIOD);
+            x = (x + step).min(end);
+        }
+        if !segment.is_empty() {
+            segments.push(segment);
+        }
+        segments
+    }
+
+    /// Legend text, such as `J_0(x)`
+    fn label(self) -> String {
+        match self {
+            Curve::J(nu) => format!("J_{}(x)", nu),
+            Curve::Y(nu) => format!("Y_{}(x)", nu),
+            Curve::Ai => "Ai(x)".to_string(),
+        }
+    }
+}
+
+/// Draw curves with axes and a legend on a plotters drawing area
+///
+/// Parts of a curve outside `y_range`, such as Y near 0, are held at the
+/// edge of the chart.
+///
+/// # Parameters
+/// * `area` - Drawing area of any plotters backend
+/// * `curves` - Functions to draw, colored in turn from plotters' `Palette99`
+/// * `x_range` - Interval of x to sample and show
+/// * `y_range` - Interval of values to show
+///
+/// # Errors
+/// Any error of the backend
+pub fn draw_curves<DB: DrawingBackend>(
+    area: &DrawingArea<DB, Shift>,
+    curves: &[Curve],
+    x_range: (f64, f64),
+    y_range: (f64, f64),
+) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+    let mut chart = ChartBuilder::on(area)
+        .margin(10)
+        .x_label_area_size(30)
+        .y_label_area_size(40)
+        .build_cartesian_2d(x_range.0..x_range.1, y_range.0..y_range.1)?;
+    chart.configure_mesh().x_desc("x").draw()?;
+
+    for (index, &curve) in curves.iter().enumerate() {
+        let color = Palette99::pick(index).to_rgba();
+        for (k, segment) in curve.sample(x_range).into_iter().enumerate() {
+            let clipped = segment
+                .into_iter()
+                .map(|(x, y)| (x, y.clamp(y_range.0, y_range.1)));
+            let series = chart.draw_series(LineSeries::new(clipped, color.stroke_width(2)))?;
+            if k == 0 {
+                series
+                    .label(curve.label())
+                    .legend(move |(x, y)| PathElement::new([(x, y), (x + 20, y)], color));
+            }
+        }
+    }
+    chart
+        .configure_series_labels()
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample() {
+        // Y_0 fails for x ≤ 0, leaving one segment from the first positive point
+        let segments = Curve::Y(0.0).sample((-1.0, 100.0));
+        assert_eq!(segments.len(), 1);
+        let points = &segments[0];
+        assert!(points[0].0 > 0.0 && points.last().unwrap().0 == 100.0);
+        // Steps near x = 100 are at most 2π/24
+        let near_end = points.iter().filter(|p| p.0 > 90.0).count();
+        assert!(near_end as f64 >= 10.0 * POINTS_PER_PERIOD / (2.0 * PI));
+        for &(x, y) in points.iter().step_by(97) {
+            assert_eq!(y, real::Y(0.0, x).unwrap());
+        }
+
+        // Ai oscillates faster for more negative x
+        let ai = Curve::Ai.sample((-50.0, 5.0));
+        let left = ai[0].iter().filter(|p| p.0 < -45.0).count();
+        let right = ai[0].iter().filter(|p| p.0 > 0.0).count();
+        assert!(left > 4 * right);
+        assert!(Curve::J(0.0).sample((1.0, 0.0)).is_empty());
+    }
+
+    #[test]
+    fn test_draw() {
+        let mut svg = String::new();
+        {
+            let area = SVGBackend::with_string(&mut svg, (400, 300)).into_drawing_area();
+            let curves = [Curve::J(0.5), Curve::Y(1.0), Curve::Ai];
+            draw_curves(&area, &curves, (-5.0, 15.0), (-1.0, 1.0)).unwrap();
+            area.present().unwrap();
+        }
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("J_0.5(x)") && svg.contains("Ai(x)"));
+    }
+}
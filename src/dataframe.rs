@@ -0,0 +1,285 @@
+//! Polars expressions
+//!
+//! [`BesselExpr`] adds a `zbessel()` namespace to Polars expressions, so
+//! Bessel functions can be used inside lazy queries like any built-in
+//! function:
+//!
+//! * a numeric column gives a `Float64` column, null where the input is
+//!   null or the function is not real there (Y and K at a negative argument,
+//!   J and I there for non-integer orders, and the Hankel functions)
+//! * a struct column with `Float64` fields `re` and `im` is read as complex
+//!   and gives a struct column of the same form, null where either part is
+//!   null
+//!
+//! Each chunk of non-null arguments is evaluated with
+//! [`bessel_slice`](crate::bessel_slice); an evaluation error fails the query
+//! with a `ComputeError`.
+//!
+//! ```rust
+//! use polars::prelude::*;
+//! use zbessel_rs::BesselExpr;
+//!
+//! fn main() -> PolarsResult<()> {
+//!     // Matérn covariance with ν = 3/2 needs K_{3/2}(d)
+//!     let df = df!("d" => [0.5, 1.0, 2.0])?;
+//!     let out = df
+//!         .lazy()
+//!         .with_column(col("d").zbessel().k(1.5).alias("k"))
+//!         .collect()?;
+//!     assert_eq!(out.column("k")?.dtype(), &DataType::Float64);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{bessel_slice, BesselError, BesselKind, Scaling};
+use num_complex::Complex64;
+use polars::prelude::*;
+
+/// Adds the [`zbessel`](BesselExpr::zbessel) namespace to [`Expr`]
+pub trait BesselExpr {
+    /// Bessel functions of this expression
+    fn zbessel(self) -> BesselNamespace;
+}
+
+impl BesselExpr for Expr {
+    fn zbessel(self) -> BesselNamespace {
+        BesselNamespace(self)
+    }
+}
+
+/// Bessel functions of an expression, created by [`BesselExpr::zbessel`]
+#[derive(Debug, Clone)]
+pub struct BesselNamespace(Expr);
+
+fn compute_error(error: BesselError) -> PolarsError {
+    PolarsError::ComputeError(error.to_string().into())
+}
+
+/// Evaluate the non-null arguments in one batch, keeping the nulls in place
+fn evaluate(
+    kind: BesselKind,
+    nu: f64,
+    scaling: Scaling,
+    arguments: &[Option<Complex64>],
+) -> PolarsResult<Vec<Option<Complex64>>> {
+    let zs: Vec<Complex64> = arguments.iter().flatten().copied().collect();
+    let mut values = bessel_slice(kind, &zs, nu, scaling)
+        .map_err(compute_error)?
+        .into_iter();
+    Ok(arguments
+        .iter()
+        .map(|argument| argument.and_then(|_| values.next()))
+        .collect())
+}
+
+/// Whether F_ν(x) is real, as for the functions of the [`real`](crate::real)
+/// module: off the negative axis, or there for J and I of integer order
+fn is_real(kind: BesselKind, nu: f64, x: f64) -> bool {
+    let negative = x < 0.0;
+    match kind {
+        BesselKind::J | BesselKind::I => !negative || nu.fract() == 0.0,
+        BesselKind::Y | BesselKind::K => !negative,
+        BesselKind::H1 | BesselKind::H2 => false,
+    }
+}
+
+fn real_column(kind: BesselKind, nu: f64, scaling: Scaling, c: &Column) -> PolarsResult<Column> {
+    let series = c.as_materialized_series().cast(&DataType::Float64)?;
+    // Points where the function is complex are null without evaluating them
+    let arguments: Vec<Option<Complex64>> = series
+        .f64()?
+        .into_iter()
+        .map(|x| {
+            x.filter(|&x| is_real(kind, nu, x))
+                .map(|x| Complex64::new(x, 0.0))
+        })
+        .collect();
+    let values = evaluate(kind, nu, scaling, &arguments)?;
+    // The imaginary parts are zero up to rounding
+    let real = values.into_iter().map(|value| value.map(|v| v.re));
+    Ok(Float64Chunked::from_iter_options(c.name().clone(), real)
+        .into_series()
+        .into())
+}
+
+fn complex_column(kind: BesselKind, nu: f64, scaling: Scaling, c: &Column) -> PolarsResult<Column> {
+    let series = c.as_materialized_series();
+    let parts = series.struct_()?;
+    let re = parts.field_by_name("re")?.cast(&DataType::Float64)?;
+    let im = parts.field_by_name("im")?.cast(&DataType::Float64)?;
+    let missing = series.is_null();
+    let arguments: Vec<Option<Complex64>> = re
+        .f64()?
+        .into_iter()
+        .zip(im.f64()?)
+        .zip(&missing)
+        .map(|((re, im), missing)| match (re, im, missing) {
+            (Some(re), Some(im), Some(false)) => Some(Complex64::new(re, im)),
+            _ => None,
+        })
+        .collect();
+    let values = evaluate(kind, nu, scaling, &arguments)?;
+    let re = Float64Chunked::from_iter_options("re".into(), values.iter().map(|v| v.map(|v| v.re)));
+    let im = Float64Chunked::from_iter_options("im".into(), values.iter().map(|v| v.map(|v| v.im)));
+    let fields = [re.into_series(), im.into_series()];
+    let out = StructChunked::from_series(c.name().clone(), values.len(), fields.iter())?;
+    Ok(out.into_series().into())
+}
+
+impl BesselNamespace {
+    /// A Bessel function of the given kind and order
+    ///
+    /// # Parameters
+    /// * `kind` - Kind of Bessel function
+    /// * `nu` - Order (real number)
+    /// * `scaling` - Scaling option, with the factor of [`bessel`](crate::bessel) for `kind`
+    pub fn bessel(self, kind: BesselKind, nu: f64, scaling: Scaling) -> Expr {
+        self.0.map(
+            move |c| {
+                let out = match c.dtype() {
+                    DataType::Struct(_) => complex_column(kind, nu, scaling, &c)?,
+                    _ => real_column(kind, nu, scaling, &c)?,
+                };
+                Ok(Some(out))
+            },
+            GetOutput::map_field(|field| {
+                Ok(match field.dtype() {
+                    DataType::Struct(_) => field.clone(),
+                    _ => Field::new(field.name().clone(), DataType::Float64),
+                })
+            }),
+        )
+    }
+
+    /// J_ν
+    pub fn j(self, nu: f64) -> Expr {
+        self.bessel(BesselKind::J, nu, Scaling::Unscaled)
+    }
+
+    /// Y_ν
+    pub fn y(self, nu: f64) -> Expr {
+        self.bessel(BesselKind::Y, nu, Scaling::Unscaled)
+    }
+
+    /// I_ν
+    pub fn i(self, nu: f64) -> Expr {
+        self.bessel(BesselKind::I, nu, Scaling::Unscaled)
+    }
+
+    /// K_ν
+    pub fn k(self, nu: f64) -> Expr {
+        self.bessel(BesselKind::K, nu, Scaling::Unscaled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bessel;
+
+    #[test]
+    fn test_real_column() {
+        let df = df!("x" => [Some(1.5), None, Some(-2.0), Some(4.0)]).unwrap();
+        let out = df
+            .lazy()
+            .select([
+                col("x").zbessel().j(0.0).alias("j"),
+                col("x").zbessel().y(1.0).alias("y"),
+            ])
+            .collect()
+            .unwrap();
+        let j: Vec<Option<f64>> = out
+            .column("j")
+            .unwrap()
+            .f64()
+            .unwrap()
+            .into_iter()
+            .collect();
+        let y: Vec<Option<f64>> = out
+            .column("y")
+            .unwrap()
+            .f64()
+            .unwrap()
+            .into_iter()
+            .collect();
+        let expected = bessel(
+            BesselKind::J,
+            0.0,
+            Complex64::new(4.0, 0.0),
+            Scaling::Unscaled,
+        );
+        assert!((j[3].unwrap() - expected.unwrap().re).abs() < 1e-15);
+        assert!(j[1].is_none() && j[2].is_some());
+        // Y at a negative argument is complex
+        assert!(y[2].is_none() && y[0].is_some());
+
+        // Integer orders are real on the negative axis, whatever the rounding
+        // in the imaginary part
+        let df = df!("x" => [-3.7, -12.0]).unwrap();
+        let out = df
+            .lazy()
+            .select([
+                col("x").zbessel().j(2.0).alias("j2"),
+                col("x").zbessel().j(5.0).alias("j5"),
+                col("x").zbessel().j(0.5).alias("j"),
+            ])
+            .collect()
+            .unwrap();
+        let column = |name: &str| -> Vec<Option<f64>> {
+            out.column(name)
+                .unwrap()
+                .f64()
+                .unwrap()
+                .into_iter()
+                .collect()
+        };
+        assert_eq!(column("j2")[0], Some(crate::real::J(2.0, -3.7).unwrap()));
+        assert_eq!(column("j5")[1], Some(crate::real::J(5.0, -12.0).unwrap()));
+        assert_eq!(column("j"), [None, None]);
+    }
+
+    #[test]
+    fn test_complex_column() {
+        let re = Series::new("re".into(), [1.0, 2.0, 3.0]);
+        let im = Series::new("im".into(), [Some(0.5), None, Some(-1.0)]);
+        let z = StructChunked::from_series("z".into(), 3, [re, im].iter()).unwrap();
+        let df = DataFrame::new(vec![z.into_series().into()]).unwrap();
+        let out = df
+            .lazy()
+            .select([col("z")
+                .zbessel()
+                .bessel(BesselKind::K, 0.5, Scaling::Scaled)])
+            .collect()
+            .unwrap();
+        let values = out
+            .column("z")
+            .unwrap()
+            .as_materialized_series()
+            .struct_()
+            .unwrap()
+            .clone();
+        let re = values.field_by_name("re").unwrap();
+        let im = values.field_by_name("im").unwrap();
+        assert!(re.f64().unwrap().get(1).is_none());
+        let expected = bessel(
+            BesselKind::K,
+            0.5,
+            Complex64::new(3.0, -1.0),
+            Scaling::Scaled,
+        )
+        .unwrap();
+        let value = Complex64::new(
+            re.f64().unwrap().get(2).unwrap(),
+            im.f64().unwrap().get(2).unwrap(),
+        );
+        assert!((value - expected).norm() < 1e-14 * expected.norm());
+
+        // Errors fail the query: I_0(10^6) overflows
+        let df = df!("x" => [1e6]).unwrap();
+        assert!(df
+            .lazy()
+            .select([col("x").zbessel().i(0.0)])
+            .collect()
+            .is_err());
+    }
+}
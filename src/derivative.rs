@@ -0,0 +1,130 @@
+//! Function value and derivative pairs
+//!
+//! Both values come from a single sequence evaluation at orders ν and ν+1,
+//! using the recurrence relations (DLMF 10.6.2, 10.29.2)
+//!
+//! * C'_ν(z) = (ν/z) C_ν(z) − C_{ν+1}(z) for C = J, Y, H^(1), H^(2)
+//! * I'_ν(z) = (ν/z) I_ν(z) + I_{ν+1}(z)
+//! * K'_ν(z) = (ν/z) K_ν(z) − K_{ν+1}(z)
+//!
+//! With scaling, both the value and the derivative carry the same scaling factor.
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::j_jp;
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let z = Complex64::new(2.0, 0.5);
+//!     let (j, jp) = j_jp(1.0, z)?;
+//!     println!("J_1({}) = {}, J_1'({}) = {}", z, j, z, jp);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{BesselError, BesselKind, Scaling};
+use num_complex::Complex64;
+
+/// Calculate a Bessel function of the given kind and its derivative with respect to z
+///
+/// # Parameters
+/// * `kind` - Kind of Bessel function
+/// * `nu` - Order (real number)
+/// * `z` - Complex argument
+/// * `scaling` - Scaling option, applied to both values
+///
+/// # Returns
+/// `(f, f')` where f is the function value and f' its derivative
+pub fn bessel_with_derivative(
+    kind: BesselKind,
+    nu: f64,
+    z: Complex64,
+    scaling: Scaling,
+) -> Result<(Complex64, Complex64), BesselError> {
+    let result = kind.eval_impl(z, nu, scaling.kode(), 2, false)?;
+    let (f, f_next) = (result.values[0], result.values[1]);
+
+    // For ν = 0 the (ν/z) term vanishes; skip it so z = 0 does not give 0/0
+    let ratio_term = if nu == 0.0 {
+        Complex64::new(0.0, 0.0)
+    } else {
+        nu / z * f
+    };
+    let derivative = match kind {
+        BesselKind::I => ratio_term + f_next,
+        _ => ratio_term - f_next,
+    };
+
+    Ok((f, derivative))
+}
+
+/// Calculate J_ν(z) and J'_ν(z) (no scaling)
+pub fn j_jp(nu: f64, z: Complex64) -> Result<(Complex64, Complex64), BesselError> {
+    bessel_with_derivative(BesselKind::J, nu, z, Scaling::Unscaled)
+}
+
+/// Calculate Y_ν(z) and Y'_ν(z) (no scaling)
+pub fn y_yp(nu: f64, z: Complex64) -> Result<(Complex64, Complex64), BesselError> {
+    bessel_with_derivative(BesselKind::Y, nu, z, Scaling::Unscaled)
+}
+
+/// Calculate I_ν(z) and I'_ν(z) (no scaling)
+pub fn i_ip(nu: f64, z: Complex64) -> Result<(Complex64, Complex64), BesselError> {
+    bessel_with_derivative(BesselKind::I, nu, z, Scaling::Unscaled)
+}
+
+/// Calculate K_ν(z) and K'_ν(z) (no scaling)
+pub fn k_kp(nu: f64, z: Complex64) -> Result<(Complex64, Complex64), BesselError> {
+    bessel_with_derivative(BesselKind::K, nu, z, Scaling::Unscaled)
+}
+
+/// Calculate H^(1)_ν(z) and H^(1)'_ν(z) (no scaling)
+pub fn h1_h1p(nu: f64, z: Complex64) -> Result<(Complex64, Complex64), BesselError> {
+    bessel_with_derivative(BesselKind::H1, nu, z, Scaling::Unscaled)
+}
+
+/// Calculate H^(2)_ν(z) and H^(2)'_ν(z) (no scaling)
+pub fn h2_h2p(nu: f64, z: Complex64) -> Result<(Complex64, Complex64), BesselError> {
+    bessel_with_derivative(BesselKind::H2, nu, z, Scaling::Unscaled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{I, J, K, Y};
+
+    #[test]
+    fn test_derivatives_of_order_zero() {
+        // J_0' = -J_1, Y_0' = -Y_1, I_0' = I_1, K_0' = -K_1
+        let z = Complex64::new(3.0, 2.0);
+        let close = |a: Complex64, b: Complex64| (a - b).norm() < 1e-14 * b.norm();
+        assert!(close(j_jp(0.0, z).unwrap().1, -J(1.0, z).unwrap()));
+        assert!(close(y_yp(0.0, z).unwrap().1, -Y(1.0, z).unwrap()));
+        assert!(close(i_ip(0.0, z).unwrap().1, I(1.0, z).unwrap()));
+        assert!(close(k_kp(0.0, z).unwrap().1, -K(1.0, z).unwrap()));
+    }
+
+    #[test]
+    fn test_derivative_against_finite_difference() {
+        let z = Complex64::new(1.5, -0.5);
+        let h = 1e-6;
+        for nu in [0.5, 1.0, 2.3] {
+            let (_, jp) = j_jp(nu, z).unwrap();
+            let fd = (J(nu, z + h).unwrap() - J(nu, z - h).unwrap()) / (2.0 * h);
+            assert!((jp - fd).norm() < 1e-8, "J'_{} mismatch", nu);
+
+            let (_, kp) = k_kp(nu, z).unwrap();
+            let fd = (K(nu, z + h).unwrap() - K(nu, z - h).unwrap()) / (2.0 * h);
+            assert!((kp - fd).norm() < 1e-8, "K'_{} mismatch", nu);
+        }
+    }
+
+    #[test]
+    fn test_hankel_wronskian() {
+        // H1 H2' - H1' H2 = -4i/(πz)
+        let z = Complex64::new(2.0, 1.0);
+        let (h1, h1p) = h1_h1p(0.7, z).unwrap();
+        let (h2, h2p) = h2_h2p(0.7, z).unwrap();
+        let expected = Complex64::new(0.0, -4.0) / (std::f64::consts::PI * z);
+        assert!((h1 * h2p - h1p * h2 - expected).norm() < 1e-14);
+    }
+}
@@ -0,0 +1,148 @@
+//! Evaluation in Rust without a backend call
+//!
+//! Tries, per order, the exact values at z = 0, the real-axis kernels for
+//! orders 0 and 1, the half-integer closed forms, the power series near the
+//! origin and the Hankel expansion for large arguments; orders too large for Amos go to the uniform
+//! expansions instead, and arguments too large for it to the Hankel
+//! expansion alone. Every path either returns an accurate value or declines,
+//! in which case the caller evaluates through the backend as usual.
+
+use crate::{
+    asymptotic, clear_real_axis, half_integer, modified, origin, rational, series, uniform,
+    BesselKind, BesselResult,
+};
+use num_complex::Complex64;
+
+/// F_ν(z) computed in Rust, or `None` if no direct path applies
+pub(crate) fn value(kind: BesselKind, z: Complex64, nu: f64, kode: i32) -> Option<Complex64> {
+    let value = evaluate(kind, z, nu, kode)?;
+    let mut im = [value.im];
+    clear_real_axis(kind, z, &mut im);
+    Some(Complex64::new(value.re, im[0]))
+}
+
+/// The first of the paths that applies, before the real-axis cleanup
+fn evaluate(kind: BesselKind, z: Complex64, nu: f64, kode: i32) -> Option<Complex64> {
+    if z == Complex64::new(0.0, 0.0) {
+        return Some(origin::value(kind, nu));
+    }
+    if nu > asymptotic::argument_limit() {
+        return uniform::value(kind, z, nu, kode);
+    }
+    // Beyond this Amos loses precision; only the Hankel expansion, whose
+    // phase is reduced exactly, stays accurate
+    if z.norm() > asymptotic::argument_limit() {
+        return asymptotic::value(kind, z, nu, kode);
+    }
+    rational::value(kind, z, nu, kode)
+        .or_else(|| modified::value(kind, z, nu, kode))
+        .or_else(|| half_integer::value(kind, z, nu, kode))
+        .or_else(|| series::value(kind, z, nu, kode))
+        .or_else(|| asymptotic::value(kind, z, nu, kode))
+}
+
+/// Largest ratio of the summed term magnitudes to the result
+const MAX_GROWTH: f64 = 16.0;
+
+/// Accept a value whose terms had total magnitude `size`, unless it is not
+/// finite, underflowed, or lost more than a factor [`MAX_GROWTH`] to
+/// cancellation
+pub(crate) fn accept(value: Complex64, size: f64) -> Option<Complex64> {
+    let magnitude = value.norm();
+    let usable = value.re.is_finite()
+        && value.im.is_finite()
+        && magnitude > f64::MIN_POSITIVE
+        && size <= MAX_GROWTH * magnitude;
+    usable.then_some(value)
+}
+
+/// Write the orders ν, ..., ν+out.len()-1 into `out`, or return `None` if any
+/// of them has no direct path (`out` is then unspecified)
+pub(crate) fn fill(
+    kind: BesselKind,
+    z: Complex64,
+    nu: f64,
+    kode: i32,
+    out: &mut [Complex64],
+) -> Option<()> {
+    for (k, slot) in out.iter_mut().enumerate() {
+        *slot = value(kind, z, nu + k as f64, kode)?;
+    }
+    Some(())
+}
+
+/// Orders ν, ..., ν+n-1 computed in Rust, or `None` if any of them has no
+/// direct path
+pub(crate) fn sequence(
+    kind: BesselKind,
+    z: Complex64,
+    nu: f64,
+    kode: i32,
+    n: usize,
+) -> Option<BesselResult> {
+    let values = (0..n)
+        .map(|k| value(kind, z, nu + k as f64, kode))
+        .collect::<Option<Vec<_>>>()?;
+    Some(BesselResult {
+        values,
+        underflow_count: 0,
+        overflow_count: 0,
+        lost_digits: lost_digits(nu, n),
+    })
+}
+
+/// Digits lost by the direct paths: none to the size of |z|, whose phases are
+/// reduced exactly, but log10 ν to the relative error ν·ε of the uniform
+/// expansions beyond [`asymptotic::argument_limit`]
+fn lost_digits(nu: f64, n: usize) -> f64 {
+    let top = nu + n.saturating_sub(1) as f64;
+    if top > asymptotic::argument_limit() {
+        top.log10()
+    } else {
+        0.0
+    }
+}
+
+/// Compare the direct path `path` with the backend at every kind, argument,
+/// order and scaling of a grid, and return the number of points it evaluated
+///
+/// Each value must be within `tolerance` of the backend's, relative to
+/// `scale(z, exact)`, or equal to it.
+#[cfg(test)]
+pub(crate) fn check_against_backend(
+    path: fn(BesselKind, Complex64, f64, i32) -> Option<Complex64>,
+    kinds: &[BesselKind],
+    zs: &[Complex64],
+    nus: &[f64],
+    tolerance: f64,
+    scale: impl Fn(Complex64, Complex64) -> f64,
+) -> usize {
+    use crate::backend::{ActiveBackend, Backend};
+
+    let mut used = 0;
+    for &kind in kinds {
+        for &z in zs {
+            for &nu in nus {
+                for kode in 1..=2 {
+                    let Some(fast) = path(kind, z, nu, kode) else {
+                        continue;
+                    };
+                    used += 1;
+                    let (mut re, mut im) = ([0.0], [0.0]);
+                    let (ierr, _) = ActiveBackend::bessel(kind, z, nu, kode, &mut re, &mut im);
+                    let context = format!("{:?}_{}({}) kode {}", kind, nu, z, kode);
+                    assert_eq!(ierr, 0, "{}", context);
+                    let exact = Complex64::new(re[0], im[0]);
+                    let error = (fast - exact).norm() / scale(z, exact);
+                    assert!(
+                        error < tolerance || fast == exact,
+                        "{}: {:e}",
+                        context,
+                        error
+                    );
+                }
+            }
+        }
+    }
+    used
+}
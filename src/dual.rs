@@ -0,0 +1,364 @@
+//! Dual numbers for forward-mode automatic differentiation
+//!
+//! A [`Dual<N>`] carries a value and its gradient with respect to `N`
+//! variables. Arithmetic propagates the gradient by the chain rule, and the
+//! functions of this module mirror [`real`](crate::real) with exact
+//! derivatives from the neighbouring orders (DLMF 10.6.1, 10.29.1):
+//!
+//! * C'_ν(x) = (C_{ν-1}(x) − C_{ν+1}(x)) / 2 for C = J, Y
+//! * I'_ν(x) = (I_{ν-1}(x) + I_{ν+1}(x)) / 2
+//! * K'_ν(x) = −(K_{ν-1}(x) + K_{ν+1}(x)) / 2
+//!
+//! and Ai'(x), Bi'(x) from the Airy routines, so optimizers get derivatives
+//! to working precision instead of finite differences.
+//!
+//! ```rust
+//! use zbessel_rs::dual::{self, Dual, Dual64};
+//!
+//! fn main() -> Result<(), zbessel_rs::BesselError> {
+//!     // d/dx J_0(x) = -J_1(x)
+//!     let x = Dual64::variable(2.0);
+//!     let j0 = dual::J(0.0, x)?;
+//!     assert!((j0.eps()[0] + zbessel_rs::real::J(1.0, 2.0)?).abs() < 1e-15);
+//!
+//!     // Gradient of K_0(a b) + a in (a, b)
+//!     let (a, b) = (Dual::<2>::variable_at(1.5, 0), Dual::<2>::variable_at(0.5, 1));
+//!     let f = dual::K(0.0, a * b)? + a;
+//!     println!("f = {}, grad f = {:?}", f.re(), f.eps());
+//!     Ok(())
+//! }
+//! ```
+
+#![allow(non_snake_case)]
+
+use crate::{real, BesselError};
+use num_complex::Complex64;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A value with its derivatives with respect to `N` variables
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dual<const N: usize> {
+    re: f64,
+    eps: [f64; N],
+}
+
+/// Dual number with a single derivative
+pub type Dual64 = Dual<1>;
+
+impl<const N: usize> Dual<N> {
+    /// Value `re` with derivatives `eps`
+    pub fn new(re: f64, eps: [f64; N]) -> Self {
+        Dual { re, eps }
+    }
+
+    /// A constant: all derivatives zero
+    pub fn constant(re: f64) -> Self {
+        Dual { re, eps: [0.0; N] }
+    }
+
+    /// The `index`-th independent variable, with value `re`
+    ///
+    /// # Panics
+    /// If `index >= N`
+    pub fn variable_at(re: f64, index: usize) -> Self {
+        let mut eps = [0.0; N];
+        eps[index] = 1.0;
+        Dual { re, eps }
+    }
+
+    /// Value
+    pub fn re(&self) -> f64 {
+        self.re
+    }
+
+    /// Derivatives with respect to the `N` variables
+    pub fn eps(&self) -> [f64; N] {
+        self.eps
+    }
+
+    /// f(self) given f(re) and f'(re)
+    fn chain(self, value: f64, derivative: f64) -> Self {
+        Dual {
+            re: value,
+            eps: self.eps.map(|e| derivative * e),
+        }
+    }
+
+    /// Exponential
+    pub fn exp(self) -> Self {
+        let value = self.re.exp();
+        self.chain(value, value)
+    }
+
+    /// Natural logarithm
+    pub fn ln(self) -> Self {
+        self.chain(self.re.ln(), 1.0 / self.re)
+    }
+
+    /// Square root
+    pub fn sqrt(self) -> Self {
+        let value = self.re.sqrt();
+        self.chain(value, 0.5 / value)
+    }
+
+    /// Integer power
+    pub fn powi(self, n: i32) -> Self {
+        let derivative = if n == 0 {
+            0.0
+        } else {
+            n as f64 * self.re.powi(n - 1)
+        };
+        self.chain(self.re.powi(n), derivative)
+    }
+
+    /// Sine
+    pub fn sin(self) -> Self {
+        self.chain(self.re.sin(), self.re.cos())
+    }
+
+    /// Cosine
+    pub fn cos(self) -> Self {
+        self.chain(self.re.cos(), -self.re.sin())
+    }
+}
+
+impl Dual64 {
+    /// The independent variable, with value `re`
+    pub fn variable(re: f64) -> Self {
+        Dual { re, eps: [1.0] }
+    }
+}
+
+impl<const N: usize> From<f64> for Dual<N> {
+    fn from(re: f64) -> Self {
+        Dual::constant(re)
+    }
+}
+
+impl<const N: usize> Add for Dual<N> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Dual {
+            re: self.re + rhs.re,
+            eps: std::array::from_fn(|i| self.eps[i] + rhs.eps[i]),
+        }
+    }
+}
+
+impl<const N: usize> Sub for Dual<N> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Dual {
+            re: self.re - rhs.re,
+            eps: std::array::from_fn(|i| self.eps[i] - rhs.eps[i]),
+        }
+    }
+}
+
+impl<const N: usize> Mul for Dual<N> {
+    type Output = Self;
+    // The product rule
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self {
+        Dual {
+            re: self.re * rhs.re,
+            eps: std::array::from_fn(|i| self.eps[i] * rhs.re + self.re * rhs.eps[i]),
+        }
+    }
+}
+
+impl<const N: usize> Div for Dual<N> {
+    type Output = Self;
+    // The quotient rule
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        let re = self.re / rhs.re;
+        Dual {
+            re,
+            eps: std::array::from_fn(|i| (self.eps[i] - re * rhs.eps[i]) / rhs.re),
+        }
+    }
+}
+
+impl<const N: usize> Neg for Dual<N> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Dual {
+            re: -self.re,
+            eps: self.eps.map(|e| -e),
+        }
+    }
+}
+
+impl<const N: usize> Add<f64> for Dual<N> {
+    type Output = Self;
+    fn add(self, rhs: f64) -> Self {
+        Dual {
+            re: self.re + rhs,
+            eps: self.eps,
+        }
+    }
+}
+
+impl<const N: usize> Sub<f64> for Dual<N> {
+    type Output = Self;
+    fn sub(self, rhs: f64) -> Self {
+        Dual {
+            re: self.re - rhs,
+            eps: self.eps,
+        }
+    }
+}
+
+impl<const N: usize> Mul<f64> for Dual<N> {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self {
+        Dual {
+            re: self.re * rhs,
+            eps: self.eps.map(|e| e * rhs),
+        }
+    }
+}
+
+impl<const N: usize> Div<f64> for Dual<N> {
+    type Output = Self;
+    fn div(self, rhs: f64) -> Self {
+        Dual {
+            re: self.re / rhs,
+            eps: self.eps.map(|e| e / rhs),
+        }
+    }
+}
+
+/// Calculate J_ν(x) and its derivatives for a dual argument
+///
+/// # Parameters
+/// * `nu` - Order (real number)
+/// * `x` - Real dual argument (x < 0 requires an integer order)
+pub fn J<const N: usize>(nu: f64, x: Dual<N>) -> Result<Dual<N>, BesselError> {
+    let derivative = (real::J(nu - 1.0, x.re)? - real::J(nu + 1.0, x.re)?) / 2.0;
+    Ok(x.chain(real::J(nu, x.re)?, derivative))
+}
+
+/// Calculate Y_ν(x) and its derivatives for a dual argument
+///
+/// # Parameters
+/// * `nu` - Order (real number)
+/// * `x` - Real dual argument (x > 0)
+pub fn Y<const N: usize>(nu: f64, x: Dual<N>) -> Result<Dual<N>, BesselError> {
+    let derivative = (real::Y(nu - 1.0, x.re)? - real::Y(nu + 1.0, x.re)?) / 2.0;
+    Ok(x.chain(real::Y(nu, x.re)?, derivative))
+}
+
+/// Calculate I_ν(x) and its derivatives for a dual argument
+///
+/// # Parameters
+/// * `nu` - Order (real number)
+/// * `x` - Real dual argument (x < 0 requires an integer order)
+pub fn I<const N: usize>(nu: f64, x: Dual<N>) -> Result<Dual<N>, BesselError> {
+    let derivative = (real::I(nu - 1.0, x.re)? + real::I(nu + 1.0, x.re)?) / 2.0;
+    Ok(x.chain(real::I(nu, x.re)?, derivative))
+}
+
+/// Calculate K_ν(x) and its derivatives for a dual argument
+///
+/// # Parameters
+/// * `nu` - Order (real number)
+/// * `x` - Real dual argument (x > 0)
+pub fn K<const N: usize>(nu: f64, x: Dual<N>) -> Result<Dual<N>, BesselError> {
+    let derivative = -(real::K(nu - 1.0, x.re)? + real::K(nu + 1.0, x.re)?) / 2.0;
+    Ok(x.chain(real::K(nu, x.re)?, derivative))
+}
+
+/// Calculate exp(-|x|) I_ν(x) and its derivatives for a dual argument
+///
+/// # Parameters
+/// * `nu` - Order (real number)
+/// * `x` - Real dual argument (x < 0 requires an integer order)
+pub fn I_scaled<const N: usize>(nu: f64, x: Dual<N>) -> Result<Dual<N>, BesselError> {
+    let value = real::I_scaled(nu, x.re)?;
+    let neighbours = (real::I_scaled(nu - 1.0, x.re)? + real::I_scaled(nu + 1.0, x.re)?) / 2.0;
+    Ok(x.chain(value, neighbours - x.re.signum() * value))
+}
+
+/// Calculate exp(x) K_ν(x) and its derivatives for a dual argument
+///
+/// # Parameters
+/// * `nu` - Order (real number)
+/// * `x` - Real dual argument (x > 0)
+pub fn K_scaled<const N: usize>(nu: f64, x: Dual<N>) -> Result<Dual<N>, BesselError> {
+    let value = real::K_scaled(nu, x.re)?;
+    let neighbours = (real::K_scaled(nu - 1.0, x.re)? + real::K_scaled(nu + 1.0, x.re)?) / 2.0;
+    Ok(x.chain(value, value - neighbours))
+}
+
+/// Calculate Ai(x) and its derivatives for a dual argument
+///
+/// # Parameters
+/// * `x` - Real dual argument
+pub fn Ai<const N: usize>(x: Dual<N>) -> Result<Dual<N>, BesselError> {
+    let derivative = crate::airy_ai(Complex64::new(x.re, 0.0), 1, 1)?.value.re;
+    Ok(x.chain(real::Ai(x.re)?, derivative))
+}
+
+/// Calculate Bi(x) and its derivatives for a dual argument
+///
+/// # Parameters
+/// * `x` - Real dual argument
+pub fn Bi<const N: usize>(x: Dual<N>) -> Result<Dual<N>, BesselError> {
+    let derivative = crate::airy_bi(Complex64::new(x.re, 0.0), 1, 1)?.re;
+    Ok(x.chain(real::Bi(x.re)?, derivative))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Central difference of `f` at `x`
+    fn numeric(f: impl Fn(f64) -> f64, x: f64) -> f64 {
+        let h = 1e-5 * x.abs().max(1.0);
+        (f(x + h) - f(x - h)) / (2.0 * h)
+    }
+
+    #[test]
+    fn test_derivatives() {
+        let x = Dual64::variable(1.7);
+        let check = |dual: Result<Dual64, BesselError>, f: fn(f64) -> f64| {
+            let dual = dual.unwrap();
+            assert_eq!(dual.re(), f(1.7));
+            let expected = numeric(f, 1.7);
+            assert!((dual.eps()[0] - expected).abs() < 1e-8 * expected.abs().max(1.0));
+        };
+        check(J(0.3, x), |x| real::J(0.3, x).unwrap());
+        check(Y(2.0, x), |x| real::Y(2.0, x).unwrap());
+        check(I(0.5, x), |x| real::I(0.5, x).unwrap());
+        check(K(1.25, x), |x| real::K(1.25, x).unwrap());
+        check(I_scaled(3.0, x), |x| real::I_scaled(3.0, x).unwrap());
+        check(K_scaled(0.0, x), |x| real::K_scaled(0.0, x).unwrap());
+        check(Ai(-x), |x| real::Ai(-x).unwrap());
+        check(Bi(x), |x| real::Bi(x).unwrap());
+
+        // J_1'(0) = 1/2, where the ν/x form of the recurrence would give 0/0
+        let j1 = J(1.0, Dual64::variable(0.0)).unwrap();
+        assert!((j1.eps()[0] - 0.5).abs() < 1e-15);
+        // J_0'(x) = -J_1(x) exactly
+        let j0 = J(0.0, Dual64::variable(-3.0)).unwrap();
+        assert_eq!(j0.eps()[0], -real::J(1.0, -3.0).unwrap());
+    }
+
+    #[test]
+    fn test_gradient() {
+        // f(a, b) = ln(a) I_1(a b) / b
+        let (a, b) = (
+            Dual::<2>::variable_at(2.0, 0),
+            Dual::<2>::variable_at(0.75, 1),
+        );
+        let f = a.ln() * I(1.0, a * b).unwrap() / b;
+        let at = |a: f64, b: f64| a.ln() * real::I(1.0, a * b).unwrap() / b;
+        assert_eq!(f.re(), at(2.0, 0.75));
+        let df_da = numeric(|a| at(a, 0.75), 2.0);
+        let df_db = numeric(|b| at(2.0, b), 0.75);
+        assert!((f.eps()[0] - df_da).abs() < 1e-9);
+        assert!((f.eps()[1] - df_db).abs() < 1e-9);
+    }
+}
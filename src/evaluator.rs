@@ -0,0 +1,104 @@
+//! Evaluator objects that capture the kind, order and scaling
+//!
+//! Stable Rust does not allow implementing the `Fn` traits for user types, so
+//! an [`Evaluator`] hands out a closure with [`into_fn`](Evaluator::into_fn)
+//! that can be passed straight to integrators, root finders or plotting code.
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{BesselError, BesselKind, Evaluator, Scaling};
+//!
+//! fn trapezoid(f: impl Fn(Complex64) -> Result<Complex64, BesselError>) -> Result<f64, BesselError> {
+//!     let h = 0.01;
+//!     let mut sum = 0.0;
+//!     for k in 0..100 {
+//!         let (a, b) = (k as f64 * h, (k + 1) as f64 * h);
+//!         sum += 0.5 * h * (f(Complex64::new(a, 0.0))?.re + f(Complex64::new(b, 0.0))?.re);
+//!     }
+//!     Ok(sum)
+//! }
+//!
+//! fn main() -> Result<(), BesselError> {
+//!     let j1 = Evaluator::new(BesselKind::J, 1.0, Scaling::Unscaled);
+//!     // ∫_0^1 J_1(x) dx = 1 - J_0(1)
+//!     let integral = trapezoid(j1.into_fn())?;
+//!     assert!((integral - 0.234802).abs() < 1e-4);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{bessel, real, BesselError, BesselKind, Scaling};
+use num_complex::Complex64;
+
+/// A Bessel function with fixed kind, order and scaling
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Evaluator {
+    kind: BesselKind,
+    nu: f64,
+    scaling: Scaling,
+}
+
+impl Evaluator {
+    /// Create an evaluator for `kind` of order `nu`
+    pub fn new(kind: BesselKind, nu: f64, scaling: Scaling) -> Self {
+        Evaluator { kind, nu, scaling }
+    }
+
+    /// Evaluate at the complex argument `z`
+    pub fn eval(&self, z: Complex64) -> Result<Complex64, BesselError> {
+        bessel(self.kind, self.nu, z, self.scaling)
+    }
+
+    /// Evaluate at the real argument `x` with a real result
+    ///
+    /// Uses the [`real`] module, so arguments where the value is complex are
+    /// rejected, as are the Hankel functions. For J and Y at real `x` the
+    /// scaling factor is 1.
+    pub fn eval_real(&self, x: f64) -> Result<f64, BesselError> {
+        match (self.kind, self.scaling) {
+            (BesselKind::J, _) => real::J(self.nu, x),
+            (BesselKind::Y, _) => real::Y(self.nu, x),
+            (BesselKind::I, Scaling::Unscaled) => real::I(self.nu, x),
+            (BesselKind::I, Scaling::Scaled) => real::I_scaled(self.nu, x),
+            (BesselKind::K, Scaling::Unscaled) => real::K(self.nu, x),
+            (BesselKind::K, Scaling::Scaled) => real::K_scaled(self.nu, x),
+            (BesselKind::H1 | BesselKind::H2, _) => Err(BesselError::InvalidParameter(format!(
+                "{:?} is complex for real arguments; use the complex evaluator",
+                self.kind
+            ))),
+        }
+    }
+
+    /// Closure evaluating at a complex argument
+    pub fn into_fn(self) -> impl Fn(Complex64) -> Result<Complex64, BesselError> + Copy {
+        move |z| self.eval(z)
+    }
+
+    /// Closure evaluating at a real argument, see [`eval_real`](Evaluator::eval_real)
+    pub fn into_real_fn(self) -> impl Fn(f64) -> Result<f64, BesselError> + Copy {
+        move |x| self.eval_real(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluator_closures() {
+        let k = Evaluator::new(BesselKind::K, 0.5, Scaling::Scaled);
+        let f = k.into_fn();
+        let z = Complex64::new(1.0, 2.0);
+        assert_eq!(
+            f(z).unwrap(),
+            bessel(BesselKind::K, 0.5, z, Scaling::Scaled).unwrap()
+        );
+
+        let g = k.into_real_fn();
+        assert_eq!(g(3.0).unwrap(), real::K_scaled(0.5, 3.0).unwrap());
+        assert!(g(-1.0).is_err());
+
+        let h = Evaluator::new(BesselKind::H1, 0.0, Scaling::Unscaled).into_real_fn();
+        assert!(h(1.0).is_err());
+    }
+}
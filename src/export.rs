@@ -0,0 +1,325 @@
+//! Writing grid results for post-processing elsewhere
+//!
+//! A [`BesselGrid`] can be written as CSV, as a NumPy `.npy` array or as an
+//! `.npz` archive holding the values together with the orders and
+//! arguments, so results load directly into Python:
+//!
+//! ```python
+//! data = numpy.load("j.npz")
+//! values = data["values"]                    # (orders, arguments, 2)
+//! w = values.view(numpy.complex128)[..., 0]  # re/im layout only
+//! matplotlib.pyplot.plot(data["z"][:, 0], abs(w).T)
+//! ```
+//!
+//! Each value is written as a pair of float64 columns, either its real and
+//! imaginary parts or its magnitude and phase, chosen by [`ExportLayout`].
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use std::fs::File;
+//! use zbessel_rs::{bessel_j_grid, write_grid_csv, write_grid_npz, ExportLayout, Scaling};
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let nus = [0.0, 1.0, 2.0];
+//!     let zs: Vec<Complex64> = (1..=50).map(|k| Complex64::new(0.2 * k as f64, 0.0)).collect();
+//!     let grid = bessel_j_grid(&nus, &zs, Scaling::Unscaled)?;
+//!
+//!     let dir = std::env::temp_dir();
+//!     let csv = File::create(dir.join("j.csv"))?;
+//!     write_grid_csv(&grid, &nus, &zs, ExportLayout::MagnitudePhase, csv)?;
+//!     let npz = File::create(dir.join("j.npz"))?;
+//!     write_grid_npz(&grid, &nus, &zs, ExportLayout::ReIm, npz)?;
+//!     Ok(())
+//! }
+//! ```
+
+use crate::BesselGrid;
+use num_complex::Complex64;
+use std::io::{self, BufWriter, Write};
+
+/// How each complex value is split into two real columns
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ExportLayout {
+    /// Real and imaginary parts
+    #[default]
+    ReIm,
+    /// Magnitude |w| and phase arg w in radians, in (-π, π]
+    MagnitudePhase,
+}
+
+impl ExportLayout {
+    fn split(self, w: Complex64) -> [f64; 2] {
+        match self {
+            ExportLayout::ReIm => [w.re, w.im],
+            ExportLayout::MagnitudePhase => [w.norm(), w.arg()],
+        }
+    }
+
+    fn names(self) -> [&'static str; 2] {
+        match self {
+            ExportLayout::ReIm => ["re", "im"],
+            ExportLayout::MagnitudePhase => ["magnitude", "phase"],
+        }
+    }
+}
+
+fn check_axes(grid: &BesselGrid, nus: &[f64], zs: &[Complex64]) -> io::Result<()> {
+    if nus.len() != grid.rows || zs.len() != grid.cols {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "{} orders and {} arguments do not match a {} x {} grid",
+                nus.len(),
+                zs.len(),
+                grid.rows,
+                grid.cols
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Write a grid as CSV, one line per (order, argument) pair
+///
+/// The header is `nu,z_re,z_im,re,im` (or `...,magnitude,phase`), and lines
+/// run through the arguments for each order in turn. Numbers are written in
+/// their shortest round-trip form.
+///
+/// # Parameters
+/// * `grid` - Values from [`bessel_grid`](crate::bessel_grid) or a variant
+/// * `nus` - Orders the grid was computed at
+/// * `zs` - Arguments the grid was computed at
+/// * `layout` - Columns to write for each value
+/// * `writer` - Destination, buffered internally
+///
+/// # Errors
+/// `InvalidInput` if `nus` or `zs` does not match the grid shape, and any
+/// error of the writer
+pub fn write_grid_csv(
+    grid: &BesselGrid,
+    nus: &[f64],
+    zs: &[Complex64],
+    layout: ExportLayout,
+    writer: impl Write,
+) -> io::Result<()> {
+    check_axes(grid, nus, zs)?;
+    let mut out = BufWriter::new(writer);
+    let [a, b] = layout.names();
+    writeln!(out, "nu,z_re,z_im,{},{}", a, b)?;
+    for (i, &nu) in nus.iter().enumerate() {
+        for (&z, &w) in zs.iter().zip(grid.row(i)) {
+            let [a, b] = layout.split(w);
+            writeln!(out, "{:?},{:?},{:?},{:?},{:?}", nu, z.re, z.im, a, b)?;
+        }
+    }
+    out.flush()
+}
+
+/// A little-endian float64 array in NumPy format version 1.0
+fn npy(shape: &[usize], data: impl IntoIterator<Item = f64>) -> Vec<u8> {
+    let dims: Vec<String> = shape.iter().map(|d| d.to_string()).collect();
+    let shape = match dims.len() {
+        1 => format!("({},)", dims[0]),
+        _ => format!("({})", dims.join(", ")),
+    };
+    let mut header = format!(
+        "{{'descr': '<f8', 'fortran_order': False, 'shape': {}, }}",
+        shape
+    );
+    // The magic, version, length and header fill a multiple of 64 bytes
+    let padding = 63 - (10 + header.len()) % 64;
+    header.push_str(&" ".repeat(padding));
+    header.push('\n');
+
+    let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+    bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    for x in data {
+        bytes.extend_from_slice(&x.to_le_bytes());
+    }
+    bytes
+}
+
+fn values_npy(grid: &BesselGrid, layout: ExportLayout) -> Vec<u8> {
+    npy(
+        &[grid.rows, grid.cols, 2],
+        grid.values.iter().flat_map(|&w| layout.split(w)),
+    )
+}
+
+/// Write the grid values as a NumPy `.npy` array
+///
+/// The array has shape (orders, arguments, 2) and dtype float64, the last
+/// axis holding the two columns of `layout`. With [`ExportLayout::ReIm`],
+/// `numpy.load(path).view(numpy.complex128)[..., 0]` is the complex grid.
+///
+/// # Parameters
+/// * `grid` - Values from [`bessel_grid`](crate::bessel_grid) or a variant
+/// * `layout` - Columns to write for each value
+/// * `writer` - Destination
+///
+/// # Errors
+/// Any error of the writer
+pub fn write_grid_npy(
+    grid: &BesselGrid,
+    layout: ExportLayout,
+    mut writer: impl Write,
+) -> io::Result<()> {
+    writer.write_all(&values_npy(grid, layout))?;
+    writer.flush()
+}
+
+/// CRC-32 (IEEE) of `data`, as zip entries require
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xEDB8_8320 & (crc & 1).wrapping_neg());
+        }
+    }
+    !crc
+}
+
+/// Write an uncompressed zip archive of the named files
+fn write_zip(files: &[(&str, Vec<u8>)], mut out: impl Write) -> io::Result<()> {
+    let too_large = || io::Error::new(io::ErrorKind::InvalidInput, "archive exceeds 4 GiB");
+    let mut central = Vec::new();
+    let mut offset = 0u32;
+    for (name, data) in files {
+        let crc = crc32(data);
+        let size = u32::try_from(data.len()).map_err(|_| too_large())?;
+        // Version 2.0, no flags, stored, zero DOS time and date
+        let mut common = Vec::with_capacity(26);
+        common.extend_from_slice(&20u16.to_le_bytes());
+        common.extend_from_slice(&[0; 8]);
+        common.extend_from_slice(&crc.to_le_bytes());
+        common.extend_from_slice(&size.to_le_bytes());
+        common.extend_from_slice(&size.to_le_bytes());
+        common.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        common.extend_from_slice(&0u16.to_le_bytes());
+
+        out.write_all(&0x0403_4b50u32.to_le_bytes())?;
+        out.write_all(&common)?;
+        out.write_all(name.as_bytes())?;
+        out.write_all(data)?;
+
+        central.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+        central.extend_from_slice(&20u16.to_le_bytes());
+        central.extend_from_slice(&common);
+        // Comment length, disk, internal and external attributes
+        central.extend_from_slice(&[0; 10]);
+        central.extend_from_slice(&offset.to_le_bytes());
+        central.extend_from_slice(name.as_bytes());
+
+        offset = (30 + name.len() as u64 + size as u64)
+            .checked_add(offset as u64)
+            .and_then(|end| u32::try_from(end).ok())
+            .ok_or_else(too_large)?;
+    }
+    out.write_all(&central)?;
+    out.write_all(&0x0605_4b50u32.to_le_bytes())?;
+    out.write_all(&[0; 4])?;
+    out.write_all(&(files.len() as u16).to_le_bytes())?;
+    out.write_all(&(files.len() as u16).to_le_bytes())?;
+    out.write_all(&(central.len() as u32).to_le_bytes())?;
+    out.write_all(&offset.to_le_bytes())?;
+    out.write_all(&0u16.to_le_bytes())?;
+    out.flush()
+}
+
+/// Write the grid with its orders and arguments as a NumPy `.npz` archive
+///
+/// The archive holds `values`, as written by [`write_grid_npy`], `nu` with
+/// shape (orders,) and `z` with shape (arguments, 2) holding the real and
+/// imaginary parts of each argument.
+///
+/// # Parameters
+/// * `grid` - Values from [`bessel_grid`](crate::bessel_grid) or a variant
+/// * `nus` - Orders the grid was computed at
+/// * `zs` - Arguments the grid was computed at
+/// * `layout` - Columns to write for each value
+/// * `writer` - Destination
+///
+/// # Errors
+/// `InvalidInput` if `nus` or `zs` does not match the grid shape or the
+/// archive would exceed 4 GiB, and any error of the writer
+pub fn write_grid_npz(
+    grid: &BesselGrid,
+    nus: &[f64],
+    zs: &[Complex64],
+    layout: ExportLayout,
+    writer: impl Write,
+) -> io::Result<()> {
+    check_axes(grid, nus, zs)?;
+    let files = [
+        ("values.npy", values_npy(grid, layout)),
+        ("nu.npy", npy(&[nus.len()], nus.iter().copied())),
+        (
+            "z.npy",
+            npy(&[zs.len(), 2], zs.iter().flat_map(|z| [z.re, z.im])),
+        ),
+    ];
+    write_zip(&files, BufWriter::new(writer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> BesselGrid {
+        BesselGrid {
+            values: vec![
+                Complex64::new(1.0, 0.0),
+                Complex64::new(0.0, -2.0),
+                Complex64::new(-0.5, 0.25),
+                Complex64::new(3.0, 4.0),
+            ],
+            rows: 2,
+            cols: 2,
+            underflow_count: 0,
+        }
+    }
+
+    #[test]
+    fn test_csv() {
+        let zs = [Complex64::new(1.0, 0.0), Complex64::new(2.5, 1.0)];
+        let mut out = Vec::new();
+        write_grid_csv(
+            &grid(),
+            &[0.0, 0.5],
+            &zs,
+            ExportLayout::MagnitudePhase,
+            &mut out,
+        )
+        .unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], "nu,z_re,z_im,magnitude,phase");
+        assert_eq!(lines[1], "0.0,1.0,0.0,1.0,0.0");
+        assert_eq!(lines[4], "0.5,2.5,1.0,5.0,0.9272952180016122");
+        assert!(write_grid_csv(&grid(), &[0.0], &zs, ExportLayout::ReIm, Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_npy_and_npz() {
+        let mut out = Vec::new();
+        write_grid_npy(&grid(), ExportLayout::ReIm, &mut out).unwrap();
+        assert_eq!(&out[..8], b"\x93NUMPY\x01\x00");
+        let header_len = u16::from_le_bytes([out[8], out[9]]) as usize;
+        assert_eq!((10 + header_len) % 64, 0);
+        let header = std::str::from_utf8(&out[10..10 + header_len]).unwrap();
+        assert!(header.contains("'shape': (2, 2, 2)"));
+        assert_eq!(out.len(), 10 + header_len + 8 * 8);
+        let last = f64::from_le_bytes(out[out.len() - 8..].try_into().unwrap());
+        assert_eq!(last, 4.0);
+
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        let zs = [Complex64::new(1.0, 0.0), Complex64::new(2.0, 0.0)];
+        let mut zip = Vec::new();
+        write_grid_npz(&grid(), &[0.0, 1.0], &zs, ExportLayout::ReIm, &mut zip).unwrap();
+        assert_eq!(&zip[..4], b"PK\x03\x04");
+        assert_eq!(&zip[zip.len() - 22..zip.len() - 18], b"PK\x05\x06");
+    }
+}
@@ -0,0 +1,90 @@
+//! Extension trait for calling the functions as methods on `Complex64`
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::BesselExt;
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let z = Complex64::new(1.0, 0.5);
+//!
+//!     let ratio = z.bessel_j(2.5)? / z.bessel_j(1.5)?;
+//!     let w = z.bessel_k(0.0)? * z.airy_ai()?;
+//!     println!("{} {}", ratio, w);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{bessel, Ai, BesselError, BesselKind, Bi, Scaling, I, J, K, Y};
+use num_complex::Complex64;
+
+/// Method-call syntax for the single-value functions (no scaling)
+///
+/// `z.bessel_j(nu)` is equivalent to `J(nu, z)`, `z.airy_ai()` to `Ai(z)`, and so on.
+pub trait BesselExt {
+    /// Bessel function of the first kind J_ν(self)
+    fn bessel_j(self, nu: f64) -> Result<Complex64, BesselError>;
+    /// Bessel function of the second kind Y_ν(self)
+    fn bessel_y(self, nu: f64) -> Result<Complex64, BesselError>;
+    /// Modified Bessel function of the first kind I_ν(self)
+    fn bessel_i(self, nu: f64) -> Result<Complex64, BesselError>;
+    /// Modified Bessel function of the second kind K_ν(self)
+    fn bessel_k(self, nu: f64) -> Result<Complex64, BesselError>;
+    /// Hankel function of the first kind H^(1)_ν(self)
+    fn hankel_h1(self, nu: f64) -> Result<Complex64, BesselError>;
+    /// Hankel function of the second kind H^(2)_ν(self)
+    fn hankel_h2(self, nu: f64) -> Result<Complex64, BesselError>;
+    /// Airy function Ai(self)
+    fn airy_ai(self) -> Result<Complex64, BesselError>;
+    /// Airy function Bi(self)
+    fn airy_bi(self) -> Result<Complex64, BesselError>;
+}
+
+impl BesselExt for Complex64 {
+    fn bessel_j(self, nu: f64) -> Result<Complex64, BesselError> {
+        J(nu, self)
+    }
+
+    fn bessel_y(self, nu: f64) -> Result<Complex64, BesselError> {
+        Y(nu, self)
+    }
+
+    fn bessel_i(self, nu: f64) -> Result<Complex64, BesselError> {
+        I(nu, self)
+    }
+
+    fn bessel_k(self, nu: f64) -> Result<Complex64, BesselError> {
+        K(nu, self)
+    }
+
+    fn hankel_h1(self, nu: f64) -> Result<Complex64, BesselError> {
+        bessel(BesselKind::H1, nu, self, Scaling::Unscaled)
+    }
+
+    fn hankel_h2(self, nu: f64) -> Result<Complex64, BesselError> {
+        bessel(BesselKind::H2, nu, self, Scaling::Unscaled)
+    }
+
+    fn airy_ai(self) -> Result<Complex64, BesselError> {
+        Ai(self)
+    }
+
+    fn airy_bi(self) -> Result<Complex64, BesselError> {
+        Bi(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_methods_match_functions() {
+        let z = Complex64::new(10.0, 20.0);
+
+        assert_eq!(z.bessel_j(1.0).unwrap(), J(1.0, z).unwrap());
+        assert_eq!(z.bessel_k(0.0).unwrap(), K(0.0, z).unwrap());
+        assert_eq!(z.airy_ai().unwrap(), Ai(z).unwrap());
+        assert_eq!(z.airy_bi().unwrap(), Bi(z).unwrap());
+    }
+}
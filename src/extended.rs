@@ -0,0 +1,242 @@
+//! Exponent-split results for values outside the f64 range
+//!
+//! The scaled (kode=2) Amos results stay representable far beyond the point
+//! where the unscaled values overflow or underflow. The functions here combine
+//! the scaled value with the analytic scaling exponent into a
+//! [`ScaledComplex`], a complex mantissa with a separate power-of-two exponent.
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::I_extended;
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     // I_0(2000) ≈ 10^866 overflows f64
+//!     let value = I_extended(0.0, Complex64::new(2000.0, 0.0))?;
+//!     assert!(value.to_complex64().re.is_infinite());
+//!
+//!     let log10 = value.ln().re / std::f64::consts::LN_10;
+//!     assert!((log10 - 866.54).abs() < 0.01);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{BesselError, BesselKind};
+use num_complex::Complex64;
+use std::f64::consts::LN_2;
+
+/// Complex value `mantissa * 2^exp2` with an unbounded range
+///
+/// The mantissa is normalized so that the larger of its component magnitudes
+/// lies in [0.5, 1), unless the value is zero or not finite.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScaledComplex {
+    /// Complex mantissa
+    pub mantissa: Complex64,
+    /// Power-of-two exponent
+    pub exp2: i32,
+}
+
+/// x * 2^k without intermediate overflow of the power of two
+pub(crate) fn ldexp(x: f64, k: i32) -> f64 {
+    if x == 0.0 || k == 0 {
+        return x;
+    }
+    let k = k.clamp(-2200, 2200);
+    let half = k / 2;
+    x * 2f64.powi(half) * 2f64.powi(k - half)
+}
+
+impl ScaledComplex {
+    /// Create a normalized value from `mantissa * 2^exp2`
+    pub fn new(mantissa: Complex64, exp2: i32) -> Self {
+        let magnitude = mantissa.re.abs().max(mantissa.im.abs());
+        if magnitude == 0.0 || !magnitude.is_finite() {
+            return ScaledComplex { mantissa, exp2 };
+        }
+        let shift = magnitude.log2().floor() as i32 + 1;
+        ScaledComplex {
+            mantissa: Complex64::new(ldexp(mantissa.re, -shift), ldexp(mantissa.im, -shift)),
+            exp2: exp2.saturating_add(shift),
+        }
+    }
+
+    /// Create the value `mantissa * exp(log_factor)`
+    pub(crate) fn from_log_factor(mantissa: Complex64, log_factor: Complex64) -> Self {
+        let log2 = log_factor.re / LN_2;
+        let exp2 = log2.floor();
+        let factor = Complex64::from_polar(2f64.powf(log2 - exp2), log_factor.im);
+        Self::new(mantissa * factor, exp2 as i32)
+    }
+
+    /// Convert to a plain complex number, overflowing to infinity or underflowing to zero
+    pub fn to_complex64(self) -> Complex64 {
+        Complex64::new(
+            ldexp(self.mantissa.re, self.exp2),
+            ldexp(self.mantissa.im, self.exp2),
+        )
+    }
+
+    /// Whether [`to_complex64`](ScaledComplex::to_complex64) is exact up to rounding
+    pub fn is_in_f64_range(self) -> bool {
+        let zero = self.mantissa.re == 0.0 && self.mantissa.im == 0.0;
+        zero || (-1021..=1024).contains(&self.exp2)
+    }
+
+    /// Principal natural logarithm, representable for any nonzero value
+    pub fn ln(self) -> Complex64 {
+        self.mantissa.ln() + self.exp2 as f64 * LN_2
+    }
+}
+
+impl From<Complex64> for ScaledComplex {
+    fn from(value: Complex64) -> Self {
+        ScaledComplex::new(value, 0)
+    }
+}
+
+/// Calculate a Bessel function of the given kind as a [`ScaledComplex`]
+///
+/// # Parameters
+/// * `kind` - Kind of Bessel function
+/// * `nu` - Order (real number)
+/// * `z` - Complex argument
+pub fn bessel_extended(
+    kind: BesselKind,
+    nu: f64,
+    z: Complex64,
+) -> Result<ScaledComplex, BesselError> {
+    let scaled = kind.eval_impl(z, nu, 2, 1, false)?.values[0];
+    Ok(ScaledComplex::from_log_factor(scaled, -kind.log_scale(z)))
+}
+
+/// Calculate J_ν(z) as a [`ScaledComplex`]
+#[allow(non_snake_case)]
+pub fn J_extended(nu: f64, z: Complex64) -> Result<ScaledComplex, BesselError> {
+    bessel_extended(BesselKind::J, nu, z)
+}
+
+/// Calculate Y_ν(z) as a [`ScaledComplex`]
+#[allow(non_snake_case)]
+pub fn Y_extended(nu: f64, z: Complex64) -> Result<ScaledComplex, BesselError> {
+    bessel_extended(BesselKind::Y, nu, z)
+}
+
+/// Calculate I_ν(z) as a [`ScaledComplex`]
+#[allow(non_snake_case)]
+pub fn I_extended(nu: f64, z: Complex64) -> Result<ScaledComplex, BesselError> {
+    bessel_extended(BesselKind::I, nu, z)
+}
+
+/// Calculate K_ν(z) as a [`ScaledComplex`]
+#[allow(non_snake_case)]
+pub fn K_extended(nu: f64, z: Complex64) -> Result<ScaledComplex, BesselError> {
+    bessel_extended(BesselKind::K, nu, z)
+}
+
+/// Calculate H^(1)_ν(z) as a [`ScaledComplex`]
+#[allow(non_snake_case)]
+pub fn H1_extended(nu: f64, z: Complex64) -> Result<ScaledComplex, BesselError> {
+    bessel_extended(BesselKind::H1, nu, z)
+}
+
+/// Calculate H^(2)_ν(z) as a [`ScaledComplex`]
+#[allow(non_snake_case)]
+pub fn H2_extended(nu: f64, z: Complex64) -> Result<ScaledComplex, BesselError> {
+    bessel_extended(BesselKind::H2, nu, z)
+}
+
+/// Scaled and best-effort unscaled value from a single evaluation
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BothScalings {
+    /// Scaled (kode=2) value
+    pub scaled: Complex64,
+    /// Unscaled value, infinite or zero when outside the f64 range
+    pub unscaled: Complex64,
+    /// Whether `unscaled` is the true value rather than an overflow or underflow
+    pub unscaled_in_range: bool,
+}
+
+/// Calculate a Bessel function of the given kind both scaled and unscaled
+///
+/// Only the scaled value is computed by Amos; the unscaled value is derived
+/// from it and saturates to infinity or zero instead of returning an overflow
+/// error.
+///
+/// # Parameters
+/// * `kind` - Kind of Bessel function
+/// * `nu` - Order (real number)
+/// * `z` - Complex argument
+pub fn bessel_both_scalings(
+    kind: BesselKind,
+    nu: f64,
+    z: Complex64,
+) -> Result<BothScalings, BesselError> {
+    let scaled = kind.eval_impl(z, nu, 2, 1, false)?.values[0];
+    let extended = ScaledComplex::from_log_factor(scaled, -kind.log_scale(z));
+    Ok(BothScalings {
+        scaled,
+        unscaled: extended.to_complex64(),
+        unscaled_in_range: extended.is_in_f64_range(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bessel, Scaling};
+
+    #[test]
+    fn test_extended_matches_unscaled_in_range() {
+        let z = Complex64::new(3.0, -4.0);
+        for kind in BesselKind::ALL {
+            let direct = bessel(kind, 1.5, z, Scaling::Unscaled).unwrap();
+            let extended = bessel_extended(kind, 1.5, z).unwrap().to_complex64();
+            let diff = (extended - direct).norm() / direct.norm();
+            assert!(diff < 1e-14, "{:?}: diff = {}", kind, diff);
+        }
+    }
+
+    #[test]
+    fn test_extended_beyond_f64_range() {
+        // I_0(x) ~ e^x / sqrt(2πx) * (1 + 1/(8x) + 9/(128x^2))
+        let x = 1000.0;
+        let value = I_extended(0.0, Complex64::new(x, 0.0)).unwrap();
+        let expected = x - 0.5 * (2.0 * std::f64::consts::PI * x).ln()
+            + (1.0 + 1.0 / (8.0 * x) + 9.0 / (128.0 * x * x)).ln();
+        assert!((value.ln().re - expected).abs() < 1e-9);
+
+        // K_0(x) I_0(x) ~ 1/(2x) with K_0 underflowing; J_0(iy) = I_0(y)
+        let k = K_extended(0.0, Complex64::new(1000.0, 0.0)).unwrap();
+        assert_eq!(k.to_complex64(), Complex64::new(0.0, 0.0));
+        assert!((k.ln().re + value.ln().re + (2.0 * x).ln()).abs() < 1e-6);
+
+        let j = J_extended(0.0, Complex64::new(0.0, x)).unwrap();
+        assert!((j.ln() - value.ln()).norm() < 1e-10);
+    }
+
+    #[test]
+    fn test_scaled_complex_normalization() {
+        let value = ScaledComplex::new(Complex64::new(6.0, -1.0), 3);
+        assert_eq!(value.mantissa, Complex64::new(0.75, -0.125));
+        assert_eq!(value.exp2, 6);
+        assert_eq!(value.to_complex64(), Complex64::new(48.0, -8.0));
+    }
+
+    #[test]
+    fn test_both_scalings() {
+        let z = Complex64::new(2.0, 30.0);
+        let both = bessel_both_scalings(BesselKind::J, 0.0, z).unwrap();
+        let scaled = bessel(BesselKind::J, 0.0, z, Scaling::Scaled).unwrap();
+        let unscaled = bessel(BesselKind::J, 0.0, z, Scaling::Unscaled).unwrap();
+        assert_eq!(both.scaled, scaled);
+        assert!((both.unscaled - unscaled).norm() / unscaled.norm() < 1e-14);
+        assert!(both.unscaled_in_range);
+
+        let both = bessel_both_scalings(BesselKind::I, 0.0, Complex64::new(1000.0, 0.0)).unwrap();
+        assert!(both.scaled.re.is_finite());
+        assert!(both.unscaled.re.is_infinite());
+        assert!(!both.unscaled_in_range);
+    }
+}
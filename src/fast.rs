@@ -0,0 +1,143 @@
+//! Unchecked single-value functions for tight inner loops
+//!
+//! These skip the parameter validation, negative-order handling and error
+//! formatting of the checked API and return the Amos result directly. They
+//! are meant for callers that have already validated their inputs:
+//!
+//! * `nu` must be non-negative (checked with `debug_assert!` only)
+//! * a NaN or infinite `nu` or `z` gives NaN without calling the backend
+//! * if the Amos routine fails (IERR other than 0 or 3) the result is NaN
+//! * IERR 3 (reduced precision) still returns the computed value
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::fast;
+//!
+//! let mut sum = Complex64::new(0.0, 0.0);
+//! for k in 1..=100 {
+//!     let z = Complex64::new(0.1 * k as f64, 0.5);
+//!     sum += fast::J_unchecked(0.0, z);
+//! }
+//! assert!(sum.re.is_finite());
+//! ```
+
+use crate::backend::{ActiveBackend, Backend};
+use crate::{clear_real_axis, direct, reflection, BesselKind};
+use num_complex::Complex64;
+
+const NAN: Complex64 = Complex64::new(f64::NAN, f64::NAN);
+
+fn eval_unchecked(kind: BesselKind, nu: f64, z: Complex64, kode: i32) -> Complex64 {
+    if !nu.is_finite() || !z.is_finite() {
+        return NAN;
+    }
+    debug_assert!(nu >= 0.0, "order must be non-negative, got {}", nu);
+
+    if reflection::below_cut(z) {
+        let kind = reflection::conjugate_kind(kind);
+        return eval_unchecked(kind, nu, z.conj(), kode).conj();
+    }
+
+    if let Some(value) = direct::value(kind, z, nu, kode) {
+        return value;
+    }
+
+    let (mut cyr, mut cyi) = ([0.0], [0.0]);
+    let (ierr, _) = ActiveBackend::bessel(kind, z, nu, kode, &mut cyr, &mut cyi);
+    debug_assert_ne!(ierr, 1, "invalid input to {:?}_{}({})", kind, nu, z);
+    clear_real_axis(kind, z, &mut cyi);
+
+    match ierr {
+        0 | 3 => Complex64::new(cyr[0], cyi[0]),
+        _ => NAN,
+    }
+}
+
+/// Calculate J_ν(z) without validation (ν >= 0, NaN on failure)
+#[allow(non_snake_case)]
+#[inline]
+pub fn J_unchecked(nu: f64, z: Complex64) -> Complex64 {
+    eval_unchecked(BesselKind::J, nu, z, 1)
+}
+
+/// Calculate Y_ν(z) without validation (ν >= 0, NaN on failure)
+#[allow(non_snake_case)]
+#[inline]
+pub fn Y_unchecked(nu: f64, z: Complex64) -> Complex64 {
+    eval_unchecked(BesselKind::Y, nu, z, 1)
+}
+
+/// Calculate I_ν(z) without validation (ν >= 0, NaN on failure)
+#[allow(non_snake_case)]
+#[inline]
+pub fn I_unchecked(nu: f64, z: Complex64) -> Complex64 {
+    eval_unchecked(BesselKind::I, nu, z, 1)
+}
+
+/// Calculate K_ν(z) without validation (ν >= 0, NaN on failure)
+#[allow(non_snake_case)]
+#[inline]
+pub fn K_unchecked(nu: f64, z: Complex64) -> Complex64 {
+    eval_unchecked(BesselKind::K, nu, z, 1)
+}
+
+/// Calculate exp(-|Im(z)|) J_ν(z) without validation (ν >= 0, NaN on failure)
+#[allow(non_snake_case)]
+#[inline]
+pub fn J_scaled_unchecked(nu: f64, z: Complex64) -> Complex64 {
+    eval_unchecked(BesselKind::J, nu, z, 2)
+}
+
+/// Calculate exp(-|Im(z)|) Y_ν(z) without validation (ν >= 0, NaN on failure)
+#[allow(non_snake_case)]
+#[inline]
+pub fn Y_scaled_unchecked(nu: f64, z: Complex64) -> Complex64 {
+    eval_unchecked(BesselKind::Y, nu, z, 2)
+}
+
+/// Calculate exp(-|Re(z)|) I_ν(z) without validation (ν >= 0, NaN on failure)
+#[allow(non_snake_case)]
+#[inline]
+pub fn I_scaled_unchecked(nu: f64, z: Complex64) -> Complex64 {
+    eval_unchecked(BesselKind::I, nu, z, 2)
+}
+
+/// Calculate exp(z) K_ν(z) without validation (ν >= 0, NaN on failure)
+#[allow(non_snake_case)]
+#[inline]
+pub fn K_scaled_unchecked(nu: f64, z: Complex64) -> Complex64 {
+    eval_unchecked(BesselKind::K, nu, z, 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{I_scaled, J, K, Y};
+
+    #[test]
+    fn test_unchecked_matches_checked() {
+        let z = Complex64::new(2.5, -1.5);
+        let pairs = [
+            (J_unchecked(1.5, z), J(1.5, z).unwrap()),
+            (Y_unchecked(0.0, z), Y(0.0, z).unwrap()),
+            (K_unchecked(2.0, z), K(2.0, z).unwrap()),
+            (I_scaled_unchecked(0.5, z), I_scaled(0.5, z).unwrap()),
+        ];
+        for (fast, checked) in pairs {
+            assert_eq!(fast, checked);
+        }
+    }
+
+    #[test]
+    fn test_unchecked_failure_is_nan() {
+        // I_0(1000) overflows
+        let i = I_unchecked(0.0, Complex64::new(1000.0, 0.0));
+        assert!(i.re.is_nan() && i.im.is_nan());
+        // Non-finite inputs never reach the backend, which gave J = 0 here
+        let j = J_unchecked(1.0, Complex64::new(1.0, f64::NAN));
+        assert!(j.re.is_nan() && j.im.is_nan());
+        assert!(K_scaled_unchecked(f64::NAN, Complex64::new(1.0, 0.0))
+            .re
+            .is_nan());
+    }
+}
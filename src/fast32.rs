@@ -0,0 +1,257 @@
+//! Reduced-precision single-value functions in `f32`
+//!
+//! For graphics and audio code that needs throughput rather than the last
+//! digits, these functions aim for a relative error of about 1e-6 (measured
+//! against the envelope for the oscillating J and Y) instead of full double
+//! precision:
+//!
+//! * J and I for |z| <= 2 sum the power series in `f32` until the terms drop
+//!   below `f32::EPSILON`
+//! * all kinds for Re z > 0 and |z| >= max(12, ν²) sum the Hankel expansion
+//!   in `f32` with as few terms as that tolerance allows; the phase and the
+//!   exponential factor are formed in `f64`, so large arguments keep their
+//!   accuracy
+//! * everything else is computed by [`fast`](crate::fast) and rounded
+//!
+//! Like [`fast`](crate::fast), they skip validation: `nu` must be
+//! non-negative (checked with `debug_assert!` only), and failures and NaN or
+//! infinite inputs give NaN.
+//!
+//! ```rust
+//! use num_complex::Complex32;
+//! use zbessel_rs::fast32;
+//!
+//! let mut sum = Complex32::new(0.0, 0.0);
+//! for k in 1..=100 {
+//!     sum += fast32::J(0.0, Complex32::new(0.1 * k as f32, 0.5));
+//! }
+//! assert!(sum.re.is_finite());
+//! ```
+
+use crate::asymptotic::hankel_phase;
+use crate::fast;
+use crate::gamma::ln_gamma;
+use num_complex::{Complex32, Complex64};
+use std::f64::consts::{FRAC_2_PI, PI};
+
+/// Largest |z| summed by the power series
+const SERIES_RADIUS: f32 = 2.0;
+
+/// Smallest |z| summed by the Hankel expansion
+const MIN_ASYMPTOTIC: f32 = 12.0;
+
+/// Most terms in either sum
+const MAX_TERMS: usize = 30;
+
+fn widen(z: Complex32) -> Complex64 {
+    Complex64::new(z.re as f64, z.im as f64)
+}
+
+fn narrow(z: Complex64) -> Complex32 {
+    Complex32::new(z.re as f32, z.im as f32)
+}
+
+/// J_ν (sign -1) or I_ν (sign +1) by the power series
+fn series(z: Complex32, nu: f32, sign: f32) -> Complex32 {
+    let q = z * z * (0.25 * sign);
+    let mut term = Complex32::new(1.0, 0.0);
+    let mut sum = term;
+    for k in 1..MAX_TERMS {
+        let k = k as f32;
+        term *= q / (k * (nu + k));
+        sum += term;
+        if term.norm() <= f32::EPSILON * sum.norm() {
+            break;
+        }
+    }
+    // (z/2)^ν / Γ(ν+1), on the side of the cut given by the sign of Im z
+    let z = widen(z);
+    let nu = nu as f64;
+    sum * narrow((nu * (0.5 * z).ln() - ln_gamma(nu + 1.0)).exp())
+}
+
+/// Σ a_k(ν) u^k and Σ a_k(ν) (-u)^k, stopped at `f32` precision
+fn hankel_sums(u: Complex32, nu: f32) -> (Complex64, Complex64) {
+    let mu = 4.0 * nu * nu;
+    let mut term = Complex32::new(1.0, 0.0);
+    let (mut plus, mut minus) = (term, term);
+    for k in 1..MAX_TERMS {
+        let odd = (2 * k - 1) as f32;
+        let c = (mu - odd * odd) / (8.0 * k as f32);
+        let next = term * u * c;
+        if c == 0.0 || next.norm() >= term.norm() {
+            break;
+        }
+        term = next;
+        plus += term;
+        minus += if k % 2 == 0 { term } else { -term };
+        if term.norm() <= f32::EPSILON * plus.norm().min(minus.norm()) {
+            break;
+        }
+    }
+    (widen(plus), widen(minus))
+}
+
+/// Which function a kernel evaluates
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    J,
+    Y,
+    I,
+    K,
+}
+
+fn asymptotic(kind: Kind, nu: f32, z: Complex32) -> Complex32 {
+    let i = Complex64::i();
+    let modified = matches!(kind, Kind::I | Kind::K);
+    let u = if modified {
+        Complex32::new(1.0, 0.0) / z
+    } else {
+        Complex32::i() / z
+    };
+    let (plus, minus) = hankel_sums(u, nu);
+    let (z, nu) = (widen(z), nu as f64);
+    let root = (FRAC_2_PI / z).sqrt();
+    let zero = Complex64::new(0.0, 0.0);
+    let (e1, e2) = (
+        hankel_phase(z, nu, 1.0, zero),
+        hankel_phase(z, nu, -1.0, zero),
+    );
+    let value = match kind {
+        Kind::J => 0.5 * root * (e1 * plus + e2 * minus),
+        Kind::Y => -0.5 * i * root * (e1 * plus - e2 * minus),
+        Kind::K => 0.5 * PI * root * (-z).exp() * plus,
+        Kind::I => {
+            // DLMF 10.40.5 with the upper sign for Im z >= 0
+            let (sin, cos) = (PI * (nu % 2.0)).sin_cos();
+            let side = if z.im >= 0.0 {
+                i * Complex64::new(cos, sin)
+            } else {
+                -i * Complex64::new(cos, -sin)
+            };
+            0.5 * root * (z.exp() * minus + side * (-z).exp() * plus)
+        }
+    };
+    narrow(value)
+}
+
+fn eval(kind: Kind, nu: f32, z: Complex32) -> Complex32 {
+    if !nu.is_finite() || !z.is_finite() {
+        return Complex32::new(f32::NAN, f32::NAN);
+    }
+    debug_assert!(nu >= 0.0, "order must be non-negative, got {}", nu);
+    let r = z.norm();
+    if r <= SERIES_RADIUS && r > 0.0 {
+        match kind {
+            Kind::J => return series(z, nu, -1.0),
+            Kind::I => return series(z, nu, 1.0),
+            Kind::Y | Kind::K => {}
+        }
+    }
+    if z.re > 0.0 && r >= MIN_ASYMPTOTIC.max(nu * nu) {
+        return asymptotic(kind, nu, z);
+    }
+    let (nu, z) = (nu as f64, widen(z));
+    narrow(match kind {
+        Kind::J => fast::J_unchecked(nu, z),
+        Kind::Y => fast::Y_unchecked(nu, z),
+        Kind::I => fast::I_unchecked(nu, z),
+        Kind::K => fast::K_unchecked(nu, z),
+    })
+}
+
+/// J_ν(z) to about 1e-6 (ν >= 0, NaN on failure)
+#[allow(non_snake_case)]
+#[inline]
+pub fn J(nu: f32, z: Complex32) -> Complex32 {
+    eval(Kind::J, nu, z)
+}
+
+/// Y_ν(z) to about 1e-6 (ν >= 0, NaN on failure)
+#[allow(non_snake_case)]
+#[inline]
+pub fn Y(nu: f32, z: Complex32) -> Complex32 {
+    eval(Kind::Y, nu, z)
+}
+
+/// I_ν(z) to about 1e-6 (ν >= 0, NaN on failure)
+#[allow(non_snake_case)]
+#[inline]
+pub fn I(nu: f32, z: Complex32) -> Complex32 {
+    eval(Kind::I, nu, z)
+}
+
+/// K_ν(z) to about 1e-6 (ν >= 0, NaN on failure)
+#[allow(non_snake_case)]
+#[inline]
+pub fn K(nu: f32, z: Complex32) -> Complex32 {
+    eval(Kind::K, nu, z)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bessel, BesselKind, Scaling};
+
+    type Function = fn(f32, Complex32) -> Complex32;
+
+    #[test]
+    fn test_accuracy_across_regimes() {
+        let functions: [(Function, BesselKind); 4] = [
+            (J, BesselKind::J),
+            (Y, BesselKind::Y),
+            (I, BesselKind::I),
+            (K, BesselKind::K),
+        ];
+        for (f, kind) in functions {
+            for &nu in &[0.0_f32, 0.5, 1.0, 2.7] {
+                for &r in &[0.2_f32, 1.9, 6.0, 15.0, 40.0, 3000.0] {
+                    for k in 0..12 {
+                        let angle = std::f32::consts::PI * (k as f32 - 5.5) / 6.0;
+                        let z = Complex32::from_polar(r, angle);
+                        let Ok(exact) = bessel(kind, nu as f64, widen(z), Scaling::Unscaled) else {
+                            continue;
+                        };
+                        let scale = match kind {
+                            BesselKind::J | BesselKind::Y => exact
+                                .norm()
+                                .max((z.im.abs() as f64).exp() / (r as f64).max(1.0).sqrt()),
+                            _ => exact.norm(),
+                        };
+                        if !scale.is_finite() || scale > f32::MAX as f64 || scale < 1e-30 {
+                            continue;
+                        }
+                        let error = (widen(f(nu, z)) - exact).norm() / scale;
+                        assert!(error < 2e-6, "{:?}_{}({}) {:e}", kind, nu, z, error);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_sign_of_zero_on_cut() {
+        // The side of the cut follows the sign of Im z = ±0, as in `bessel`
+        let functions: [(Function, BesselKind); 4] = [
+            (J, BesselKind::J),
+            (Y, BesselKind::Y),
+            (I, BesselKind::I),
+            (K, BesselKind::K),
+        ];
+        for (f, kind) in functions {
+            for z in [Complex32::new(-1.0, 0.0), Complex32::new(-1.0, -0.0)] {
+                let exact = bessel(kind, 0.3, widen(z), Scaling::Unscaled).unwrap();
+                let error = (widen(f(0.3, z)) - exact).norm() / exact.norm();
+                assert!(error < 2e-6, "{:?}({}) {:e}", kind, z, error);
+            }
+        }
+    }
+
+    #[test]
+    fn test_failure_is_nan() {
+        // Overflow in the f64 fallback
+        assert!(I(0.0, Complex32::new(-800.0, 0.0)).re.is_nan());
+        assert!(J(0.0, Complex32::new(f32::INFINITY, 0.0)).re.is_nan());
+        assert!(K(f32::NAN, Complex32::new(1.0, 0.0)).im.is_nan());
+    }
+}
@@ -0,0 +1,281 @@
+//! Pre-flight classification of evaluations
+//!
+//! [`can_evaluate`] estimates ln|F_ν(z)| from the leading terms of the Debye
+//! expansions, as the Amos overflow tests do, and compares it and the
+//! argument and order with the limits of [`machine_parameters`]. Batch
+//! planners can sort points into those to evaluate unscaled, those that need
+//! scaling and those to skip without paying for failed evaluations:
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{can_evaluate, BesselKind, Feasibility, Scaling};
+//!
+//! let kind = BesselKind::I;
+//! let small = Complex64::new(10.0, 0.0);
+//! let large = Complex64::new(1000.0, 0.0);
+//! assert_eq!(can_evaluate(kind, 0.0, small, Scaling::Unscaled), Feasibility::Feasible);
+//! assert_eq!(can_evaluate(kind, 0.0, large, Scaling::Unscaled), Feasibility::NeedsScaling);
+//! assert_eq!(can_evaluate(kind, 0.0, large, Scaling::Scaled), Feasibility::Feasible);
+//! ```
+
+use crate::reflection::{below_cut, conjugate_kind};
+use crate::{asymptotic, direct};
+use crate::{machine_parameters, uniform, validate_inputs, BesselKind, Scaling};
+use num_complex::Complex64;
+use std::f64::consts::PI;
+
+/// Margin in ln|F| below ELIM kept by the classification, for the error of
+/// the leading-order estimate and the tests of the continuation formulas,
+/// which overflow about e^3 earlier
+const MARGIN: f64 = 7.0;
+
+/// Outcome expected from an evaluation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Feasibility {
+    /// Evaluates with the requested scaling
+    Feasible,
+    /// The unscaled value overflows (IERR=2), the scaled one does not
+    NeedsScaling,
+    /// Amos reports reduced precision (IERR=3) for |z| or ν above
+    /// [`precision_limit`](crate::MachineParameters::precision_limit); the
+    /// values are returned only with
+    /// [`allow_accuracy_loss`](crate::BesselOptions::allow_accuracy_loss)
+    ReducedPrecision,
+    /// Neither scaling evaluates: the inputs are invalid, the scaled value
+    /// overflows too, or |z| or ν is beyond the validated range
+    Impossible,
+}
+
+/// Classify the evaluation of F_ν(z) before performing it
+///
+/// Points close to the overflow threshold are classified conservatively, as
+/// needing scaling, so that a [`Feasibility::Feasible`] point evaluates. The
+/// crate's own expansions for large |z| and ν evaluate some points past the
+/// precision limit in full, but decline others, near zeros or where the
+/// terms do not converge fast enough; such points are only classified as
+/// feasible after the expansion has been summed and accepted, a few dozen
+/// terms. Points beyond the range of the backend are impossible, even where
+/// the expansion would reach them. The overflow tests are those of the Amos
+/// routines, which the Rust backend passes at least as often.
+///
+/// # Parameters
+/// * `kind` - Kind of Bessel function
+/// * `nu` - Order (real number)
+/// * `z` - Complex argument
+/// * `scaling` - Scaling option
+///
+/// # Returns
+/// The expected outcome of [`bessel`](crate::bessel) with these inputs
+pub fn can_evaluate(kind: BesselKind, nu: f64, z: Complex64, scaling: Scaling) -> Feasibility {
+    if validate_inputs(z, nu, scaling.kode()).is_err() {
+        return Feasibility::Impossible;
+    }
+    // The limits at the origin are returned exactly, infinite or not
+    if z == Complex64::new(0.0, 0.0) {
+        return Feasibility::Feasible;
+    }
+    let machine = machine_parameters();
+    let limit = machine.exponent_limit - MARGIN;
+    let unscaled = log_magnitude(kind, nu, z, Scaling::Unscaled);
+    let scaled = log_magnitude(kind, nu, z, Scaling::Scaled);
+
+    // Past the precision limit the crate's expansions return the values
+    // they accept in full, and the backend the rest with IERR=3
+    let order = nu.abs();
+    let beyond = |limit| z.norm() > limit || order > limit;
+    let expanded = uniform::applies(z, order) || asymptotic::applies(z, order);
+    let precision = |log: f64, kode: i32| {
+        if beyond(machine.range_limit) {
+            Feasibility::Impossible
+        } else if !beyond(machine.precision_limit)
+            || (expanded && log >= -limit && direct_accepts(kind, nu, z, kode))
+        {
+            Feasibility::Feasible
+        } else {
+            Feasibility::ReducedPrecision
+        }
+    };
+    match scaling {
+        Scaling::Unscaled if unscaled <= limit => precision(unscaled, 1),
+        Scaling::Unscaled if scaled <= limit => match precision(scaled, 2) {
+            Feasibility::Impossible => Feasibility::Impossible,
+            _ => Feasibility::NeedsScaling,
+        },
+        Scaling::Scaled if scaled <= limit => precision(scaled, 2),
+        _ => Feasibility::Impossible,
+    }
+}
+
+/// Whether the direct paths evaluate F_ν(z) without the backend, for the
+/// functions of order |ν| that [`bessel`](crate::bessel) combines it from
+/// below the cut and at negative orders
+fn direct_accepts(kind: BesselKind, nu: f64, z: Complex64, kode: i32) -> bool {
+    let (kind, z) = if below_cut(z) {
+        (conjugate_kind(kind), z.conj())
+    } else {
+        (kind, z)
+    };
+    // The reflection formulas add the second solution at non-integer orders
+    let partner = match kind {
+        _ if nu >= 0.0 || nu.fract() == 0.0 => None,
+        BesselKind::J => Some(BesselKind::Y),
+        BesselKind::Y => Some(BesselKind::J),
+        BesselKind::I => Some(BesselKind::K),
+        BesselKind::K | BesselKind::H1 | BesselKind::H2 => None,
+    };
+    std::iter::once(kind)
+        .chain(partner)
+        .all(|kind| direct::value(kind, z, nu.abs(), kode).is_some())
+}
+
+/// Estimated ln|I_ν(w)| and ln|K_ν(w)| for Re(w) >= 0 and ν >= 0, from the
+/// leading terms of DLMF 10.41.3-4 written to stay finite as ν → 0
+fn log_ik(nu: f64, w: Complex64) -> (f64, f64) {
+    let s = (nu * nu + w * w).sqrt();
+    // ν η(w/ν), whose logarithm vanishes with ν even where w² underflows
+    let exponent = if nu == 0.0 {
+        s.re
+    } else {
+        (s + nu * (w / (nu + s)).ln()).re
+    };
+    // The prefactors grow without bound only where the expansions fail
+    let size = s.norm().max(1.0).ln();
+    (
+        exponent - 0.5 * ((2.0 * PI).ln() + size),
+        -exponent + 0.5 * ((0.5 * PI).ln() - size),
+    )
+}
+
+/// Estimated ln|K_ν(v)| for any v, continued to the left half-plane by
+/// DLMF 10.34.2, where the I term dominates
+///
+/// Scaled, the estimate is of e^v K_ν(v), bounded by the K_ν(-v) e^{-v} the
+/// continuation is computed from.
+fn log_k(nu: f64, v: Complex64, scaling: Scaling) -> f64 {
+    let shift = match scaling {
+        Scaling::Unscaled => 0.0,
+        Scaling::Scaled => v.re,
+    };
+    if v.re >= 0.0 {
+        log_ik(nu, v).1 + shift
+    } else {
+        let (i, k) = log_ik(nu, -v);
+        (i + PI.ln() + shift).max(k - shift)
+    }
+}
+
+/// Estimated ln|F_ν(z)| of the function with the given scaling
+fn log_magnitude(kind: BesselKind, nu: f64, z: Complex64, scaling: Scaling) -> f64 {
+    // Orders below zero mix in the second solution, except at integers
+    let mixed = nu < 0.0 && nu.fract() != 0.0;
+    let nu = nu.abs();
+    let i = Complex64::i();
+    let two_over_pi = (2.0 / PI).ln();
+    // The Hankel functions are K_ν at ∓iz, and Y_ν is combined from both
+    let h1 = log_k(nu, -i * z, scaling) + two_over_pi;
+    let h2 = log_k(nu, i * z, scaling) + two_over_pi;
+    let shift = match scaling {
+        Scaling::Unscaled => 0.0,
+        Scaling::Scaled => kind.log_scale(z).re,
+    };
+    // |I_ν(w)| = |I_ν(-w)|, and J_ν(z) is I_ν at -iz
+    let right = |w: Complex64| if w.re >= 0.0 { w } else { -w };
+    let i_z = log_ik(nu, right(z)).0 + shift;
+    let (i_iz, k_iz) = log_ik(nu, right(i * z));
+    let (i_iz, k_iz) = (i_iz + shift, k_iz + shift + two_over_pi);
+    match kind {
+        BesselKind::J if !mixed => i_iz,
+        BesselKind::Y if scaling == Scaling::Scaled => h1.max(h2),
+        BesselKind::J | BesselKind::Y => i_iz.max(k_iz),
+        BesselKind::I if !mixed => i_z,
+        BesselKind::I => i_z.max(log_k(nu, z, Scaling::Unscaled) + shift + two_over_pi),
+        BesselKind::K => log_k(nu, z, scaling),
+        BesselKind::H1 => h1,
+        BesselKind::H2 => h2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asymptotic::argument_limit;
+    use crate::bessel;
+
+    #[test]
+    fn test_estimates_track_values() {
+        // ln|F| from the estimate against the evaluated value
+        let cases = [
+            (BesselKind::I, 0.0, Complex64::new(600.0, 0.0)),
+            (BesselKind::K, 150.0, Complex64::new(3.0, 1.0)),
+            (BesselKind::Y, 40.0, Complex64::new(0.5, 0.0)),
+            (BesselKind::J, 2.5, Complex64::new(1.0, -500.0)),
+            (BesselKind::H2, 7.0, Complex64::new(20.0, 400.0)),
+        ];
+        for (kind, nu, z) in cases {
+            let value = bessel(kind, nu, z, Scaling::Unscaled).unwrap();
+            let error = log_magnitude(kind, nu, z, Scaling::Unscaled) - value.norm().ln();
+            assert!(error.abs() < 1.0, "{:?}_{}({}): {}", kind, nu, z, error);
+        }
+    }
+
+    #[test]
+    fn test_ranges() {
+        let z = Complex64::new(1.0, 1.0);
+        let scaled = Scaling::Scaled;
+        assert_eq!(
+            can_evaluate(BesselKind::J, f64::NAN, z, scaled),
+            Feasibility::Impossible
+        );
+        assert_eq!(
+            can_evaluate(BesselKind::K, 0.0, 0.0 * z, scaled),
+            Feasibility::Feasible
+        );
+        // Y_ν overflows near the origin whatever the scaling
+        assert_eq!(
+            can_evaluate(BesselKind::Y, 300.0, z, scaled),
+            Feasibility::Impossible
+        );
+        let far = Complex64::new(-1e5, 1.0);
+        let expected = if argument_limit() < machine_parameters().range_limit {
+            Feasibility::ReducedPrecision
+        } else {
+            Feasibility::Impossible
+        };
+        assert_eq!(can_evaluate(BesselKind::J, 0.0, far, scaled), expected);
+        // The Hankel expansion reaches far along the positive real axis, but
+        // not near its zeros, nor beyond the range of the backend
+        let far = Complex64::new(1e8, 0.0);
+        let expected = if far.re <= machine_parameters().range_limit {
+            Feasibility::Feasible
+        } else {
+            Feasibility::Impossible
+        };
+        assert_eq!(can_evaluate(BesselKind::J, 0.0, far, scaled), expected);
+        assert!(bessel(BesselKind::J, 0.0, far, scaled).is_ok());
+        let far = Complex64::new(1e12, 0.0);
+        assert_eq!(
+            can_evaluate(BesselKind::J, 0.0, far, scaled),
+            Feasibility::Impossible
+        );
+        // Points where the expansions apply but decline
+        let declined = [
+            (BesselKind::J, 2.5, Complex64::new(1e5, 0.0)),
+            (BesselKind::Y, 0.3, Complex64::new(4e4, 0.0)),
+            (BesselKind::J, 4e4, Complex64::new(2e9, 0.0)),
+            (BesselKind::Y, 1e5, Complex64::new(0.0, 2e9)),
+        ];
+        for (kind, nu, z) in declined {
+            for scaling in [Scaling::Unscaled, scaled] {
+                if can_evaluate(kind, nu, z, scaling) == Feasibility::Feasible {
+                    assert!(
+                        bessel(kind, nu, z, scaling).is_ok(),
+                        "{:?}_{}({})",
+                        kind,
+                        nu,
+                        z
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! All integer orders J_0(x), ..., J_N(x) from one FFT
+//!
+//! The Jacobi–Anger expansion e^{ix sin θ} = Σ_n J_n(x) e^{inθ} makes the
+//! Bessel functions of real argument the Fourier coefficients of a function
+//! that costs one complex exponential per sample. Sampling it at M equally
+//! spaced angles and taking one FFT gives every order at once; M is a power
+//! of two large enough that the aliased orders beyond |x| are below
+//! rounding. The cost is O(M log M) with M of the order of N + |x|, against
+//! O(N) separate recurrence steps through the generic path, which pays off
+//! when N is in the thousands.
+//!
+//! The error is absolute: about |x| ε from the phase x sin θ, so orders far
+//! beyond |x|, where J_n(x) is tiny, have no correct digits and should be
+//! taken from [`bessel_j`](crate::bessel_j) if needed.
+//!
+//! ```rust
+//! use zbessel_rs::all_jn_fft;
+//!
+//! fn main() -> Result<(), zbessel_rs::BesselError> {
+//!     // FM synthesis: sideband amplitudes J_n(β) for modulation index 250
+//!     let amplitudes = all_jn_fft(250.0, 2000)?;
+//!     assert_eq!(amplitudes.len(), 2001);
+//!     assert!(amplitudes[1500].abs() < 1e-13);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::asymptotic::argument_limit;
+use crate::BesselError;
+use num_complex::Complex64;
+use std::f64::consts::TAU;
+
+/// Largest number of orders (N + 1)
+const MAX_ORDERS: usize = 1 << 24;
+
+/// In-place forward FFT of a power-of-two length, X_k = Σ_j x_j e^{-2πijk/M}
+fn fft(data: &mut [Complex64], twiddles: &[Complex64]) {
+    let m = data.len();
+    let bits = m.trailing_zeros();
+    for i in 0..m {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+    let mut len = 2;
+    while len <= m {
+        let half = len / 2;
+        let stride = m / len;
+        for start in (0..m).step_by(len) {
+            for k in 0..half {
+                let a = data[start + k];
+                let b = data[start + k + half] * twiddles[k * stride];
+                data[start + k] = a + b;
+                data[start + k + half] = a - b;
+            }
+        }
+        len *= 2;
+    }
+}
+
+/// Calculate J_0(x), ..., J_N(x) for real x with one FFT
+///
+/// # Parameters
+/// * `x` - Real argument, |x| up to about 3.3e4
+/// * `n` - Highest order N
+///
+/// # Returns
+/// `n + 1` values, `values[k] = J_k(x)`, accurate to about |x| ε absolutely
+pub fn all_jn_fft(x: f64, n: usize) -> Result<Vec<f64>, BesselError> {
+    if !x.is_finite() || x.abs() > argument_limit() {
+        return Err(BesselError::InvalidParameter(format!(
+            "x must be finite with |x| <= {}, got {}",
+            argument_limit(),
+            x
+        )));
+    }
+    if n >= MAX_ORDERS {
+        return Err(BesselError::InvalidParameter(format!(
+            "n must be less than {}, got {}",
+            MAX_ORDERS, n
+        )));
+    }
+
+    // Orders beyond |x| + 10 |x|^{1/3} + 40 are below ε
+    let ax = x.abs();
+    let negligible = (ax + 10.0 * ax.cbrt() + 40.0).ceil() as usize;
+    let m = (n + negligible + 1).next_power_of_two().max(4);
+
+    // e^{-iθ_k} for θ_k = 2πk/M, k < M/2; sin θ_{k+M/2} = -sin θ_k
+    let twiddles: Vec<Complex64> = (0..m / 2)
+        .map(|k| {
+            let (sin, cos) = (TAU * k as f64 / m as f64).sin_cos();
+            Complex64::new(cos, -sin)
+        })
+        .collect();
+    let mut samples = vec![Complex64::new(0.0, 0.0); m];
+    for (k, w) in twiddles.iter().enumerate() {
+        let sin = -w.im;
+        samples[k] = Complex64::from_polar(1.0, x * sin);
+        samples[k + m / 2] = Complex64::from_polar(1.0, -x * sin);
+    }
+
+    fft(&mut samples, &twiddles);
+    let scale = 1.0 / m as f64;
+    Ok(samples[..=n].iter().map(|c| c.re * scale).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bessel_j;
+
+    #[test]
+    fn test_fft_matches_sequence() {
+        for &x in &[0.5, 7.0, -37.3, 400.0, 3000.0] {
+            let n = 300;
+            let values = all_jn_fft(x, n).unwrap();
+            let z = Complex64::new(x, 0.0);
+            let expected = bessel_j(z, 0.0, 1, n + 1).unwrap();
+            for (k, (value, exact)) in values.iter().zip(&expected.values).enumerate() {
+                let tolerance = 1e-15 * (x.abs() + 10.0);
+                assert!(
+                    (value - exact.re).abs() < tolerance,
+                    "J_{}({}): {} vs {}",
+                    k,
+                    x,
+                    value,
+                    exact.re
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_fft_errors() {
+        assert!(all_jn_fft(f64::NAN, 10).is_err());
+        assert!(all_jn_fft(1e6, 10).is_err());
+        assert_eq!(all_jn_fft(0.0, 2).unwrap(), [1.0, 0.0, 0.0]);
+    }
+}
@@ -0,0 +1,85 @@
+//! Floating-point types accepted by the generic front-end
+//!
+//! The simple functions ([`J`](crate::J), [`Y`](crate::Y), ..., [`Jn`](crate::Jn), ...)
+//! are generic over [`BesselFloat`], so code can stay generic over precision:
+//!
+//! ```rust
+//! use num_complex::Complex;
+//! use zbessel_rs::{BesselError, BesselFloat, J};
+//!
+//! fn j0_squared<T: BesselFloat>(z: Complex<T>) -> Result<Complex<T>, BesselError> {
+//!     let j0 = J(T::zero(), z)?;
+//!     Ok(j0 * j0)
+//! }
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let single = j0_squared(Complex::new(1.0f32, 0.5))?;
+//!     let double = j0_squared(Complex::new(1.0f64, 0.5))?;
+//!     println!("{} {}", single, double);
+//!     Ok(())
+//! }
+//! ```
+
+use num_complex::{Complex, Complex64};
+use num_traits::Float;
+use std::fmt::{Debug, Display};
+
+/// Floating-point type the Bessel functions can be evaluated in
+///
+/// The computation runs in f64; implementations only describe how to convert
+/// to and from it.
+pub trait BesselFloat: Float + Debug + Display + Send + Sync + 'static {
+    /// Convert to f64 for the computation
+    fn into_f64(self) -> f64;
+
+    /// Convert a computed f64 value back, rounding to the nearest representable value
+    fn round_from_f64(x: f64) -> Self;
+}
+
+impl BesselFloat for f64 {
+    fn into_f64(self) -> f64 {
+        self
+    }
+
+    fn round_from_f64(x: f64) -> Self {
+        x
+    }
+}
+
+impl BesselFloat for f32 {
+    fn into_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn round_from_f64(x: f64) -> Self {
+        x as f32
+    }
+}
+
+pub(crate) fn to_complex64<T: BesselFloat>(z: Complex<T>) -> Complex64 {
+    Complex64::new(z.re.into_f64(), z.im.into_f64())
+}
+
+pub(crate) fn from_complex64<T: BesselFloat>(z: Complex64) -> Complex<T> {
+    Complex::new(T::round_from_f64(z.re), T::round_from_f64(z.im))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Ai, Jn, K};
+    use num_complex::Complex32;
+
+    #[test]
+    fn test_generic_functions_in_both_precisions() {
+        let z64 = Complex64::new(3.0, -1.0);
+        let z32 = Complex32::new(3.0, -1.0);
+
+        let k64 = K(0.5f64, z64).unwrap();
+        let k32 = K(0.5f32, z32).unwrap();
+        assert_eq!(k32, from_complex64(k64));
+
+        assert_eq!(Jn(-3, z32).unwrap(), from_complex64(Jn(-3, z64).unwrap()));
+        assert_eq!(Ai(z32).unwrap(), from_complex64(Ai(z64).unwrap()));
+    }
+}
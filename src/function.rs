@@ -0,0 +1,120 @@
+//! Kind-generic trait for writing code over Bessel function families
+//!
+//! Each kind has a zero-sized marker type implementing [`BesselFunction`], so
+//! generic numerical code can pick the radial basis at compile time.
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{BesselError, BesselFunction, Jnu, Knu};
+//!
+//! /// Sum of c_k F_k(z) over the orders 0, 1, ..., len-1
+//! fn expand<B: BesselFunction>(coeffs: &[f64], z: Complex64) -> Result<Complex64, BesselError> {
+//!     let values = B::sequence(0.0, z, coeffs.len())?;
+//!     Ok(coeffs.iter().zip(&values).map(|(c, f)| c * f).sum())
+//! }
+//!
+//! fn main() -> Result<(), BesselError> {
+//!     let z = Complex64::new(1.5, 0.5);
+//!     println!("{}", expand::<Jnu>(&[1.0, 0.5, 0.25], z)?);
+//!     println!("{}", expand::<Knu>(&[1.0, 0.5, 0.25], z)?);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{bessel, check_max_n, BesselError, BesselKind, BesselResult, Scaling, DEFAULT_MAX_N};
+use num_complex::Complex64;
+
+/// A Bessel function family selected at compile time
+pub trait BesselFunction {
+    /// The kind evaluated by this family
+    const KIND: BesselKind;
+
+    /// Evaluate F_ν(z) (no scaling)
+    fn eval(nu: f64, z: Complex64) -> Result<Complex64, BesselError> {
+        bessel(Self::KIND, nu, z, Scaling::Unscaled)
+    }
+
+    /// Evaluate the scaled F_ν(z); see [`bessel`] for the scaling factors
+    fn eval_scaled(nu: f64, z: Complex64) -> Result<Complex64, BesselError> {
+        bessel(Self::KIND, nu, z, Scaling::Scaled)
+    }
+
+    /// Evaluate F_ν(z), ..., F_{ν+n-1}(z) (no scaling, n up to [`DEFAULT_MAX_N`])
+    fn sequence(nu: f64, z: Complex64, n: usize) -> Result<BesselResult, BesselError> {
+        check_max_n(n, DEFAULT_MAX_N)?;
+        Self::KIND.eval_impl(z, nu, Scaling::Unscaled.kode(), n, false)
+    }
+}
+
+/// Marker for J_ν(z)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Jnu;
+
+/// Marker for Y_ν(z)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Ynu;
+
+/// Marker for I_ν(z)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Inu;
+
+/// Marker for K_ν(z)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Knu;
+
+/// Marker for H^(1)_ν(z)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct H1nu;
+
+/// Marker for H^(2)_ν(z)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct H2nu;
+
+impl BesselFunction for Jnu {
+    const KIND: BesselKind = BesselKind::J;
+}
+
+impl BesselFunction for Ynu {
+    const KIND: BesselKind = BesselKind::Y;
+}
+
+impl BesselFunction for Inu {
+    const KIND: BesselKind = BesselKind::I;
+}
+
+impl BesselFunction for Knu {
+    const KIND: BesselKind = BesselKind::K;
+}
+
+impl BesselFunction for H1nu {
+    const KIND: BesselKind = BesselKind::H1;
+}
+
+impl BesselFunction for H2nu {
+    const KIND: BesselKind = BesselKind::H2;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn first_and_sequence<B: BesselFunction>(z: Complex64) -> (Complex64, BesselResult) {
+        (B::eval(0.5, z).unwrap(), B::sequence(0.5, z, 3).unwrap())
+    }
+
+    #[test]
+    fn test_markers_dispatch_to_their_kind() {
+        let z = Complex64::new(2.0, -1.0);
+        let (j, j_seq) = first_and_sequence::<Jnu>(z);
+        let (k, k_seq) = first_and_sequence::<Knu>(z);
+
+        assert_eq!(j, bessel(BesselKind::J, 0.5, z, Scaling::Unscaled).unwrap());
+        assert_eq!(k, bessel(BesselKind::K, 0.5, z, Scaling::Unscaled).unwrap());
+        assert!((j_seq[0] - j).norm() / j.norm() < 1e-14);
+        assert!((k_seq[0] - k).norm() / k.norm() < 1e-14);
+        assert_eq!(
+            H2nu::eval_scaled(0.5, z).unwrap(),
+            bessel(BesselKind::H2, 0.5, z, Scaling::Scaled).unwrap()
+        );
+    }
+}
@@ -0,0 +1,39 @@
+//! Log-gamma for the power-series prefactors (z/2)^ν / Γ(ν+1)
+
+use std::f64::consts::PI;
+
+/// ln Γ(x) for x > 0 (Stirling series after shifting x above 15)
+pub(crate) fn ln_gamma(x: f64) -> f64 {
+    let mut x = x;
+    let mut shift = 0.0;
+    while x < 15.0 {
+        shift += x.ln();
+        x += 1.0;
+    }
+    let inv = 1.0 / x;
+    let inv2 = inv * inv;
+    let series = inv
+        * (1.0 / 12.0
+            + inv2
+                * (-1.0 / 360.0
+                    + inv2
+                        * (1.0 / 1260.0
+                            + inv2
+                                * (-1.0 / 1680.0
+                                    + inv2 * (1.0 / 1188.0 + inv2 * (-691.0 / 360360.0))))));
+    (x - 0.5) * x.ln() - x + 0.5 * (2.0 * PI).ln() + series - shift
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ln_gamma() {
+        assert!(ln_gamma(1.0).abs() < 1e-15);
+        assert!((ln_gamma(0.5) - 0.5 * PI.ln()).abs() < 1e-13);
+        // ln(9!) = ln(362880)
+        assert!((ln_gamma(10.0) - 362880f64.ln()).abs() < 1e-13);
+        assert!((ln_gamma(200.5) - 860.582_203_509_782_5).abs() < 1e-10);
+    }
+}
@@ -0,0 +1,287 @@
+//! GPU batch evaluation (feature `gpu`)
+//!
+//! A [`GpuBatch`] runs a `wgpu` compute shader over a buffer of
+//! single-precision arguments. The kernels cover the power series near the
+//! origin and the Hankel expansion for large |z|; arguments in between, and
+//! those the shader cannot place on the right side of a branch cut, are
+//! recomputed on the CPU through the f64 backend and rounded to `f32`. Values
+//! outside the `f32` range become infinite or zero.
+//!
+//! ```rust,no_run
+//! use num_complex::Complex32;
+//! use zbessel_rs::{GpuBatch, GpuFunction};
+//!
+//! fn main() -> Result<(), zbessel_rs::BesselError> {
+//!     let gpu = GpuBatch::new()?;
+//!     let zs: Vec<Complex32> = (0..1_000_000)
+//!         .map(|k| Complex32::new(0.001 * k as f32, 0.5))
+//!         .collect();
+//!     let values = gpu.eval(GpuFunction::J0, &zs)?;
+//!     println!("J0({}) = {}", zs[500], values[500]);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{bessel, validate_argument, BesselError, BesselKind, Scaling};
+use num_complex::{Complex32, Complex64};
+use wgpu::util::DeviceExt;
+
+/// Invocations per workgroup, as declared in the shader
+const WORKGROUP: usize = 64;
+
+/// Functions with GPU kernels
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GpuFunction {
+    /// J_0(z)
+    J0,
+    /// J_1(z)
+    J1,
+    /// I_0(z)
+    I0,
+    /// K_0(z)
+    K0,
+}
+
+impl GpuFunction {
+    /// Index of the kernel in the shader
+    fn id(self) -> u32 {
+        match self {
+            GpuFunction::J0 => 0,
+            GpuFunction::J1 => 1,
+            GpuFunction::I0 => 2,
+            GpuFunction::K0 => 3,
+        }
+    }
+
+    /// Kind and order for the CPU fallback
+    fn kind_and_order(self) -> (BesselKind, f64) {
+        match self {
+            GpuFunction::J0 => (BesselKind::J, 0.0),
+            GpuFunction::J1 => (BesselKind::J, 1.0),
+            GpuFunction::I0 => (BesselKind::I, 0.0),
+            GpuFunction::K0 => (BesselKind::K, 0.0),
+        }
+    }
+}
+
+fn unavailable(reason: impl std::fmt::Display) -> BesselError {
+    BesselError::InvalidParameter(format!("GPU evaluation unavailable: {}", reason))
+}
+
+/// A GPU device with the compiled Bessel kernels
+#[derive(Debug)]
+pub struct GpuBatch {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    pipeline: wgpu::ComputePipeline,
+    chunk: usize,
+}
+
+impl GpuBatch {
+    /// Open the default adapter and compile the kernels
+    ///
+    /// Fails if no adapter with compute support is available.
+    pub fn new() -> Result<Self, BesselError> {
+        let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor {
+            backends: wgpu::Backends::all(),
+            ..Default::default()
+        });
+        let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::HighPerformance,
+            ..Default::default()
+        }))
+        .ok_or_else(|| unavailable("no adapter found"))?;
+        let (device, queue) = pollster::block_on(adapter.request_device(
+            &wgpu::DeviceDescriptor {
+                label: Some("zbessel"),
+                required_limits: adapter.limits(),
+                ..Default::default()
+            },
+            None,
+        ))
+        .map_err(unavailable)?;
+
+        let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("zbessel"),
+            source: wgpu::ShaderSource::Wgsl(include_str!("gpu.wgsl").into()),
+        });
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("zbessel"),
+            layout: None,
+            module: &module,
+            entry_point: Some("main"),
+            compilation_options: Default::default(),
+            cache: None,
+        });
+
+        // One dispatch per chunk, within the buffer and workgroup limits
+        let limits = device.limits();
+        let chunk = (limits.max_storage_buffer_binding_size as usize / 8)
+            .min(limits.max_compute_workgroups_per_dimension as usize * WORKGROUP)
+            .min(1 << 22);
+        Ok(GpuBatch {
+            device,
+            queue,
+            pipeline,
+            chunk,
+        })
+    }
+
+    /// Evaluate `function` at every point of `zs`
+    ///
+    /// # Parameters
+    /// * `function` - Which function to evaluate
+    /// * `zs` - Complex arguments
+    pub fn eval(
+        &self,
+        function: GpuFunction,
+        zs: &[Complex32],
+    ) -> Result<Vec<Complex32>, BesselError> {
+        for z in zs {
+            validate_argument(Complex64::new(z.re as f64, z.im as f64))?;
+        }
+        let mut values = Vec::with_capacity(zs.len());
+        for chunk in zs.chunks(self.chunk) {
+            self.dispatch(function, chunk, &mut values);
+        }
+
+        // Points the shader left to the CPU come back as NaN
+        let (kind, nu) = function.kind_and_order();
+        for (value, z) in values.iter_mut().zip(zs) {
+            if value.is_nan() {
+                let z = Complex64::new(z.re as f64, z.im as f64);
+                let exact = bessel(kind, nu, z, Scaling::Unscaled)?;
+                *value = Complex32::new(exact.re as f32, exact.im as f32);
+            }
+        }
+        Ok(values)
+    }
+
+    /// Run the kernel over `zs` and append the results to `out`
+    fn dispatch(&self, function: GpuFunction, zs: &[Complex32], out: &mut Vec<Complex32>) {
+        let input: Vec<f32> = zs.iter().flat_map(|z| [z.re, z.im]).collect();
+        let bytes = (input.len() * 4) as u64;
+        let params = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("params"),
+                contents: bytemuck::cast_slice(&[function.id(), zs.len() as u32]),
+                usage: wgpu::BufferUsages::UNIFORM,
+            });
+        let input = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("input"),
+                contents: bytemuck::cast_slice(&input),
+                usage: wgpu::BufferUsages::STORAGE,
+            });
+        let output = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("output"),
+            size: bytes,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let staging = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("staging"),
+            size: bytes,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &self.pipeline.get_bind_group_layout(0),
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: params.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: input.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: output.as_entire_binding(),
+                },
+            ],
+        });
+
+        let mut encoder = self.device.create_command_encoder(&Default::default());
+        {
+            let mut pass = encoder.begin_compute_pass(&Default::default());
+            pass.set_pipeline(&self.pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups(zs.len().div_ceil(WORKGROUP) as u32, 1, 1);
+        }
+        encoder.copy_buffer_to_buffer(&output, 0, &staging, 0, bytes);
+        self.queue.submit(Some(encoder.finish()));
+
+        let slice = staging.slice(..);
+        slice.map_async(wgpu::MapMode::Read, |_| {});
+        self.device.poll(wgpu::Maintain::Wait);
+        let data = slice.get_mapped_range();
+        let floats: &[f32] = bytemuck::cast_slice(&data);
+        out.extend(floats.chunks_exact(2).map(|c| Complex32::new(c[0], c[1])));
+        drop(data);
+        staging.unmap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kernels_match_cpu() {
+        let Ok(gpu) = GpuBatch::new() else {
+            eprintln!("no GPU adapter, skipping");
+            return;
+        };
+        // Series, transition (CPU) and asymptotic regimes in all quadrants
+        let mut zs = Vec::new();
+        for &r in &[0.3_f32, 1.5, 2.9, 5.0, 9.5, 20.0, 45.0] {
+            for k in 0..16 {
+                let angle = std::f32::consts::PI * (k as f32 + 0.25) / 8.0;
+                zs.push(Complex32::new(r * angle.cos(), r * angle.sin()));
+            }
+        }
+        zs.push(Complex32::new(-4.0, 0.0));
+        for function in [
+            GpuFunction::J0,
+            GpuFunction::J1,
+            GpuFunction::I0,
+            GpuFunction::K0,
+        ] {
+            let values = gpu.eval(function, &zs).unwrap();
+            let (kind, nu) = function.kind_and_order();
+            for (value, z) in values.iter().zip(&zs) {
+                let z = Complex64::new(z.re as f64, z.im as f64);
+                let exact = bessel(kind, nu, z, Scaling::Unscaled).unwrap();
+                // J oscillates: measure against its envelope e^{|Im z|}
+                let scale = match kind {
+                    BesselKind::J => exact
+                        .norm()
+                        .max(z.im.abs().exp() / z.norm().max(1.0).sqrt()),
+                    _ => exact.norm(),
+                };
+                let value = Complex64::new(value.re as f64, value.im as f64);
+                assert!(
+                    (value - exact).norm() < 2e-5 * scale,
+                    "{:?} z={} {} vs {}",
+                    function,
+                    z,
+                    value,
+                    exact
+                );
+            }
+        }
+        let origin = gpu
+            .eval(GpuFunction::K0, &[Complex32::new(0.0, 0.0)])
+            .unwrap();
+        assert_eq!(origin[0].re, f32::INFINITY);
+        assert!(gpu
+            .eval(GpuFunction::J0, &[Complex32::new(f32::NAN, 0.0)])
+            .is_err());
+    }
+}
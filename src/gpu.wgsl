@@ -0,0 +1,194 @@
+// Batch kernels for J0, J1, I0 and K0 in single precision.
+//
+// Each invocation handles one argument by the power series (|z| <= SERIES,
+// or K0_SERIES for K0) or the Hankel expansion (|z| >= ASYMPTOTIC).
+// Everything else is written as NaN and recomputed on the CPU by the caller.
+
+struct Params {
+    function: u32,
+    count: u32,
+}
+
+@group(0) @binding(0) var<uniform> params: Params;
+@group(0) @binding(1) var<storage, read> input: array<vec2<f32>>;
+@group(0) @binding(2) var<storage, read_write> output: array<vec2<f32>>;
+
+const J0: u32 = 0u;
+const J1: u32 = 1u;
+const I0: u32 = 2u;
+const K0: u32 = 3u;
+
+const SERIES: f32 = 3.0;
+// K0 cancels against the logarithm sooner
+const K0_SERIES: f32 = 2.0;
+const ASYMPTOTIC: f32 = 9.0;
+const MAX_TERMS: i32 = 40;
+const EPSILON: f32 = 5.96e-8;
+const PI: f32 = 3.14159265358979;
+const EULER_GAMMA: f32 = 0.577215664901533;
+
+fn mul(a: vec2<f32>, b: vec2<f32>) -> vec2<f32> {
+    return vec2<f32>(a.x * b.x - a.y * b.y, a.x * b.y + a.y * b.x);
+}
+
+fn div(a: vec2<f32>, b: vec2<f32>) -> vec2<f32> {
+    let d = dot(b, b);
+    return vec2<f32>(a.x * b.x + a.y * b.y, a.y * b.x - a.x * b.y) / d;
+}
+
+fn cexp(a: vec2<f32>) -> vec2<f32> {
+    return exp(a.x) * vec2<f32>(cos(a.y), sin(a.y));
+}
+
+fn clog(a: vec2<f32>) -> vec2<f32> {
+    return vec2<f32>(log(length(a)), atan2(a.y, a.x));
+}
+
+fn csqrt(a: vec2<f32>) -> vec2<f32> {
+    let r = length(a);
+    let re = sqrt(0.5 * (r + abs(a.x)));
+    if (re == 0.0) {
+        return vec2<f32>(0.0, 0.0);
+    }
+    if (a.x >= 0.0) {
+        return vec2<f32>(re, 0.5 * a.y / re);
+    }
+    return vec2<f32>(0.5 * abs(a.y) / re, select(-re, re, a.y >= 0.0));
+}
+
+fn nan() -> vec2<f32> {
+    let bits = 0x7fc00000u;
+    return vec2<f32>(bitcast<f32>(bits), bitcast<f32>(bits));
+}
+
+// Σ_k w^k / (k! (k+m)!) for m = 0, 1, with the harmonic-weighted sum
+// Σ_k H_k w^k / (k!)² in .zw when `harmonic` is set
+fn ascending(w: vec2<f32>, m: f32, harmonic: bool) -> vec4<f32> {
+    var term = vec2<f32>(1.0, 0.0);
+    var sum = term;
+    var weighted = vec2<f32>(0.0, 0.0);
+    var h = 0.0;
+    for (var k = 1; k < MAX_TERMS; k++) {
+        let kf = f32(k);
+        term = mul(term, w) / (kf * (kf + m));
+        sum += term;
+        if (harmonic) {
+            h += 1.0 / kf;
+            weighted += h * term;
+        }
+        if (length(term) * max(h, 1.0) <= EPSILON * length(sum)) {
+            break;
+        }
+    }
+    return vec4<f32>(sum, weighted);
+}
+
+fn series(function: u32, z: vec2<f32>) -> vec2<f32> {
+    let half = 0.5 * z;
+    let square = mul(half, half);
+    switch function {
+        case J0: {
+            return ascending(-square, 0.0, false).xy;
+        }
+        case J1: {
+            return mul(half, ascending(-square, 1.0, false).xy);
+        }
+        case I0: {
+            return ascending(square, 0.0, false).xy;
+        }
+        default: {
+            let sums = ascending(square, 0.0, true);
+            let shift = clog(half) + vec2<f32>(EULER_GAMMA, 0.0);
+            return sums.zw - mul(shift, sums.xy);
+        }
+    }
+}
+
+// Σ_k a_k(ν) u^k with μ = 4ν², stopped at the smallest term
+fn hankel_sum(mu: f32, u: vec2<f32>) -> vec2<f32> {
+    var term = vec2<f32>(1.0, 0.0);
+    var sum = term;
+    var size = 1.0;
+    for (var k = 1; k < MAX_TERMS; k++) {
+        let odd = f32(2 * k - 1);
+        let next = mul(term, u) * ((mu - odd * odd) / (8.0 * f32(k)));
+        let next_size = length(next);
+        if (next_size >= size) {
+            break;
+        }
+        term = next;
+        size = next_size;
+        sum += term;
+        if (size <= EPSILON * length(sum)) {
+            break;
+        }
+    }
+    return sum;
+}
+
+// Hankel expansion for Re z >= 0
+fn asymptotic(function: u32, z: vec2<f32>) -> vec2<f32> {
+    let t = div(vec2<f32>(1.0, 0.0), z);
+    let i = vec2<f32>(0.0, 1.0);
+    switch function {
+        case J0, J1: {
+            let nu = f32(function);
+            let mu = 4.0 * nu * nu;
+            let chi = z - vec2<f32>((0.5 * nu + 0.25) * PI, 0.0);
+            let plus = mul(cexp(mul(i, chi)), hankel_sum(mu, mul(i, t)));
+            let minus = mul(cexp(-mul(i, chi)), hankel_sum(mu, -mul(i, t)));
+            let root = csqrt(div(vec2<f32>(0.5 / PI, 0.0), z));
+            return mul(root, plus + minus);
+        }
+        case I0: {
+            let root = csqrt(div(vec2<f32>(0.5 / PI, 0.0), z));
+            let rising = mul(cexp(z), hankel_sum(0.0, -t));
+            let side = select(-i, i, z.y >= 0.0);
+            let falling = mul(side, mul(cexp(-z), hankel_sum(0.0, t)));
+            return mul(root, rising + falling);
+        }
+        default: {
+            let root = csqrt(div(vec2<f32>(0.5 * PI, 0.0), z));
+            return mul(root, mul(cexp(-z), hankel_sum(0.0, t)));
+        }
+    }
+}
+
+fn evaluate(function: u32, z: vec2<f32>) -> vec2<f32> {
+    let r = length(z);
+    if (function == K0) {
+        // The origin is singular and the cut is left to the CPU
+        if (r == 0.0 || (z.y == 0.0 && z.x < 0.0)) {
+            return nan();
+        }
+        if (r <= K0_SERIES) {
+            return series(function, z);
+        }
+        if (r >= ASYMPTOTIC && z.x >= 0.0) {
+            return asymptotic(function, z);
+        }
+        return nan();
+    }
+    // J0 and I0 are even and J1 is odd, so work in the right half-plane
+    let flip = z.x < 0.0;
+    let w = select(z, -z, flip);
+    var value = nan();
+    if (r <= SERIES) {
+        value = series(function, w);
+    } else if (r >= ASYMPTOTIC) {
+        value = asymptotic(function, w);
+    }
+    if (flip && function == J1) {
+        value = -value;
+    }
+    return value;
+}
+
+@compute @workgroup_size(64)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let k = id.x;
+    if (k >= params.count) {
+        return;
+    }
+    output[k] = evaluate(params.function, input[k]);
+}
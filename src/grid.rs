@@ -0,0 +1,178 @@
+//! Evaluation over a grid of orders × arguments
+//!
+//! Dispersion diagrams and mode searches need a function at every pair
+//! (ν, z) of an order list and an argument list. [`bessel_grid`] evaluates
+//! each argument with [`bessel_span`](crate::bessel_span), so orders that
+//! are integer steps apart (such as 0, 1, ..., 50 or 0.5, 2.5, 4.5, ...)
+//! cost one sequence call per argument instead of one call per pair.
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{bessel_j_grid, Scaling};
+//!
+//! fn main() -> Result<(), zbessel_rs::BesselError> {
+//!     let nus: Vec<f64> = (0..=20).map(f64::from).collect();
+//!     let zs: Vec<Complex64> = (1..=100).map(|k| Complex64::new(0.2 * k as f64, 0.0)).collect();
+//!
+//!     // 100 sequence calls for 2100 values
+//!     let grid = bessel_j_grid(&nus, &zs, Scaling::Unscaled)?;
+//!     assert_eq!(grid.dims(), (21, 100));
+//!     println!("J_3({}) = {}", zs[10], grid.get(3, 10));
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{bessel_span, BesselError, BesselKind, Scaling};
+use num_complex::Complex64;
+
+/// Values of a function over orders × arguments, stored row-major
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BesselGrid {
+    /// `values[i * cols + j]` is the function at order `i` and argument `j`
+    pub values: Vec<Complex64>,
+    /// Number of orders (rows)
+    pub rows: usize,
+    /// Number of arguments (columns)
+    pub cols: usize,
+    /// Number of computed values that experienced underflow
+    pub underflow_count: i32,
+}
+
+impl BesselGrid {
+    /// Shape as (orders, arguments)
+    pub fn dims(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Value at order index `i` and argument index `j`
+    ///
+    /// Panics if either index is out of range.
+    pub fn get(&self, i: usize, j: usize) -> Complex64 {
+        assert!(
+            i < self.rows && j < self.cols,
+            "index ({}, {}) out of range for a {} x {} grid",
+            i,
+            j,
+            self.rows,
+            self.cols
+        );
+        self.values[i * self.cols + j]
+    }
+
+    /// Values at order index `i` across all arguments
+    pub fn row(&self, i: usize) -> &[Complex64] {
+        &self.values[i * self.cols..(i + 1) * self.cols]
+    }
+}
+
+/// Calculate a Bessel function of the given kind at every pair of `nus` × `zs`
+///
+/// # Parameters
+/// * `kind` - Kind of Bessel function
+/// * `nus` - Orders (any real numbers, in any order)
+/// * `zs` - Complex arguments
+/// * `scaling` - Scaling option
+pub fn bessel_grid(
+    kind: BesselKind,
+    nus: &[f64],
+    zs: &[Complex64],
+    scaling: Scaling,
+) -> Result<BesselGrid, BesselError> {
+    fill_grid(kind, nus, zs, scaling, |_| true).map(|(grid, _)| grid)
+}
+
+/// Evaluate the grid column by column while `proceed(j)` allows column `j`
+///
+/// Returns the grid, with NaN in the columns not evaluated, and the number
+/// of columns evaluated.
+pub(crate) fn fill_grid(
+    kind: BesselKind,
+    nus: &[f64],
+    zs: &[Complex64],
+    scaling: Scaling,
+    mut proceed: impl FnMut(usize) -> bool,
+) -> Result<(BesselGrid, usize), BesselError> {
+    if nus.is_empty() || zs.is_empty() {
+        return Err(BesselError::InvalidParameter(
+            "nus and zs must not be empty".to_string(),
+        ));
+    }
+    let len = nus.len().checked_mul(zs.len()).ok_or_else(|| {
+        BesselError::InvalidParameter(format!(
+            "grid of {} x {} values is too large",
+            nus.len(),
+            zs.len()
+        ))
+    })?;
+
+    let cols = zs.len();
+    let mut values = vec![Complex64::new(f64::NAN, f64::NAN); len];
+    let mut underflow_count = 0;
+    let mut completed = 0;
+    for (j, &z) in zs.iter().enumerate() {
+        if !proceed(j) {
+            break;
+        }
+        let column = bessel_span(kind, z, nus, scaling)?;
+        underflow_count += column.underflow_count;
+        for (i, value) in column.values.into_iter().enumerate() {
+            values[i * cols + j] = value;
+        }
+        completed = j + 1;
+    }
+
+    let grid = BesselGrid {
+        values,
+        rows: nus.len(),
+        cols,
+        underflow_count,
+    };
+    Ok((grid, completed))
+}
+
+/// Calculate J_ν(z) at every pair of `nus` × `zs`
+///
+/// See [`bessel_grid`].
+pub fn bessel_j_grid(
+    nus: &[f64],
+    zs: &[Complex64],
+    scaling: Scaling,
+) -> Result<BesselGrid, BesselError> {
+    bessel_grid(BesselKind::J, nus, zs, scaling)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bessel;
+
+    #[test]
+    fn test_grid_matches_single_values() {
+        let nus = [0.0, 1.0, 2.0, 3.0, 0.5, 7.25];
+        let zs: Vec<Complex64> = (0..9)
+            .map(|k| Complex64::new(0.7 * k as f64 - 2.0, 0.4 - 0.1 * k as f64))
+            .collect();
+        for kind in [BesselKind::J, BesselKind::K] {
+            let grid = bessel_grid(kind, &nus, &zs, Scaling::Unscaled).unwrap();
+            assert_eq!(grid.dims(), (6, 9));
+            for (i, &nu) in nus.iter().enumerate() {
+                for (j, &z) in zs.iter().enumerate() {
+                    let expected = bessel(kind, nu, z, Scaling::Unscaled).unwrap();
+                    let diff = (grid.get(i, j) - expected).norm() / expected.norm();
+                    assert!(diff < 1e-13, "{:?}_{}({}): diff = {:e}", kind, nu, z, diff);
+                }
+                assert_eq!(grid.row(i)[4], grid.get(i, 4));
+            }
+        }
+    }
+
+    #[test]
+    fn test_grid_errors() {
+        let z = Complex64::new(1.0, 0.0);
+        assert!(bessel_j_grid(&[], &[z], Scaling::Unscaled).is_err());
+        assert!(bessel_j_grid(&[0.0], &[], Scaling::Unscaled).is_err());
+        let zs = [z, Complex64::new(1000.0, 0.0)];
+        assert!(bessel_grid(BesselKind::I, &[0.0], &zs, Scaling::Unscaled).is_err());
+    }
+}
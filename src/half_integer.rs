@@ -0,0 +1,147 @@
+//! Closed forms for half-integer orders
+//!
+//! For ν = m + 1/2 the Hankel asymptotic series terminates after m + 1 terms
+//! and becomes exact (the spherical Bessel functions):
+//!
+//! * H1,2_ν(z) = sqrt(2/(πz)) (∓i)^{m+1} e^{±iz} Σ_k a_k (±i/z)^k
+//! * K_ν(z) = sqrt(π/(2z)) e^{-z} Σ_k a_k z^{-k}
+//! * I_ν(z) = (2πz)^{-1/2} (e^z Σ_k a_k (-1/z)^k + (-1)^{m+1} e^{-z} Σ_k a_k z^{-k})
+//!
+//! with a_k = (m+k)! / (k! (m-k)! 2^k), and J, Y from H1 and H2. A value is
+//! only taken from these forms when [`direct::accept`] does; otherwise the
+//! caller falls back to the general algorithm.
+
+use crate::{direct, BesselKind};
+use num_complex::Complex64;
+use std::f64::consts::PI;
+
+/// Largest m (order m + 1/2) evaluated in closed form
+const MAX_M: usize = 64;
+
+/// m for ν = m + 1/2 within range, otherwise `None`
+fn half_integer_index(nu: f64) -> Option<usize> {
+    let m = nu - 0.5;
+    (m >= 0.0 && m.fract() == 0.0 && m <= MAX_M as f64).then_some(m as usize)
+}
+
+/// Σ_k a_k u^k and the largest |a_k u^k|
+fn terminating_sum(m: usize, u: Complex64) -> (Complex64, f64) {
+    let mut term = Complex64::new(1.0, 0.0);
+    let mut sum = term;
+    let mut largest = 1.0_f64;
+    for k in 0..m {
+        let ratio = ((m + k + 1) * (m - k)) as f64 / (2 * (k + 1)) as f64;
+        term *= u * ratio;
+        sum += term;
+        largest = largest.max(term.norm());
+    }
+    (sum, largest)
+}
+
+/// Accumulates prefactor · exp(exponent) · Σ terms and their magnitudes
+struct Pieces {
+    m: usize,
+    shift: Complex64,
+    value: Complex64,
+    size: f64,
+}
+
+impl Pieces {
+    fn add(&mut self, prefactor: Complex64, exponent: Complex64, u: Complex64) {
+        let (sum, largest) = terminating_sum(self.m, u);
+        let factor = prefactor * (exponent + self.shift).exp();
+        self.value += factor * sum;
+        self.size += factor.norm() * largest;
+    }
+}
+
+/// (-i)^k
+fn minus_i_pow(k: usize) -> Complex64 {
+    [
+        Complex64::new(1.0, 0.0),
+        Complex64::new(0.0, -1.0),
+        Complex64::new(-1.0, 0.0),
+        Complex64::new(0.0, 1.0),
+    ][k % 4]
+}
+
+/// F_ν(z) for ν = m + 1/2 in closed form, or `None` if it does not apply
+pub(crate) fn value(kind: BesselKind, z: Complex64, nu: f64, kode: i32) -> Option<Complex64> {
+    let m = half_integer_index(nu)?;
+    if z.re == 0.0 && z.im == 0.0 {
+        return None;
+    }
+    // Put the negative real axis on the upper side of the cut, as Amos does
+    let z = Complex64::new(z.re, if z.im == 0.0 { 0.0 } else { z.im });
+    let shift = if kode == 2 {
+        kind.log_scale(z)
+    } else {
+        Complex64::new(0.0, 0.0)
+    };
+    let mut pieces = Pieces {
+        m,
+        shift,
+        value: Complex64::new(0.0, 0.0),
+        size: 0.0,
+    };
+    let i = Complex64::i();
+    let root = (2.0 / PI).sqrt() / z.sqrt();
+    let h1 = root * minus_i_pow(m + 1);
+    let h2 = root * minus_i_pow(m + 1).conj();
+    match kind {
+        BesselKind::H1 => pieces.add(h1, i * z, i / z),
+        BesselKind::H2 => pieces.add(h2, -i * z, -i / z),
+        BesselKind::J => {
+            pieces.add(0.5 * h1, i * z, i / z);
+            pieces.add(0.5 * h2, -i * z, -i / z);
+        }
+        BesselKind::Y => {
+            pieces.add(-0.5 * i * h1, i * z, i / z);
+            pieces.add(0.5 * i * h2, -i * z, -i / z);
+        }
+        BesselKind::K => pieces.add(0.5 * PI * root, -z, 1.0 / z),
+        BesselKind::I => {
+            let sign = if m % 2 == 0 { -1.0 } else { 1.0 };
+            pieces.add(0.5 * root, z, -1.0 / z);
+            pieces.add(0.5 * sign * root, -z, 1.0 / z);
+        }
+    }
+    direct::accept(pieces.value, pieces.size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::direct::check_against_backend;
+
+    #[test]
+    fn test_closed_forms_match_backend() {
+        let zs = [
+            Complex64::new(0.3, 0.0),
+            Complex64::new(2.0, 1.0),
+            Complex64::new(-7.5, 0.0),
+            Complex64::new(-4.0, -3.0),
+            Complex64::new(0.0, 12.0),
+            Complex64::new(35.0, -2.0),
+        ];
+        let nus = [0.5, 1.5, 4.5, 20.5];
+        let used = check_against_backend(value, &BesselKind::ALL, &zs, &nus, 1e-12, |_, exact| {
+            exact.norm()
+        });
+        assert!(used > 200);
+    }
+
+    #[test]
+    fn test_spherical_forms() {
+        let z = Complex64::new(1.0, 0.5);
+        let root = (2.0 / (PI * z)).sqrt();
+        let j = value(BesselKind::J, z, 0.5, 1).unwrap();
+        assert!((j - root * z.sin()).norm() < 1e-15);
+        let y = value(BesselKind::Y, z, 0.5, 1).unwrap();
+        assert!((y + root * z.cos()).norm() < 1e-15);
+        // Orders that are not half-integers, or out of range, do not qualify
+        assert!(value(BesselKind::J, z, 1.0, 1).is_none());
+        assert!(value(BesselKind::J, z, 100.5, 1).is_none());
+        assert!(value(BesselKind::J, Complex64::new(0.0, 0.0), 0.5, 1).is_none());
+    }
+}
@@ -0,0 +1,270 @@
+//! Mixed-precision batches: an `f32` seed refined in `f64`
+//!
+//! [`bessel_slice_hybrid`] targets a relative error of about 1e-12 (against
+//! the envelope for the oscillating J, Y, H1 and H2) rather than full double
+//! precision. In the power-series region (J and I, |z| <= 2) and the Hankel
+//! region (Re z > 0, |z| >= max(16, ν²)) it evaluates each sum twice:
+//!
+//! * the seed pass generates every term in `f32` down to 1e-13 of the sum,
+//!   which fixes the term count and the size of the result
+//! * the refinement pass regenerates in `f64` only the leading terms larger
+//!   than [`HEAD`] times the seed and keeps the `f32` terms of the tail,
+//!   whose relative error of about 1e-6 no longer matters at that size
+//!
+//! The phase and exponential factors are formed in `f64` as in
+//! [`bessel_slice`](crate::bessel_slice). Points outside both regions or on
+//! the negative real axis, and sums that do not reach the tolerance, go
+//! through [`bessel`] unchanged.
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{bessel_slice_hybrid, BesselKind, Scaling, J};
+//!
+//! fn main() -> Result<(), zbessel_rs::BesselError> {
+//!     let zs: Vec<Complex64> = (1..=1000).map(|k| Complex64::new(0.5 * k as f64, 0.1)).collect();
+//!     let values = bessel_slice_hybrid(BesselKind::J, &zs, 1.5, Scaling::Unscaled)?;
+//!     assert!((values[900] - J(1.5, zs[900])?).norm() < 1e-12);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::asymptotic::hankel_phase;
+use crate::gamma::ln_gamma;
+use crate::{bessel, BesselError, BesselKind, Scaling};
+use num_complex::{Complex32, Complex64};
+use std::f64::consts::{FRAC_2_PI, PI};
+
+/// Largest |z| summed by the power series
+const SERIES_RADIUS: f64 = 2.0;
+
+/// Smallest |z| summed by the Hankel expansion
+const MIN_ASYMPTOTIC: f64 = 16.0;
+
+/// Most terms in either sum
+const MAX_TERMS: usize = 40;
+
+/// Size of the last seed term relative to the sum
+const TOLERANCE: f32 = 1e-13;
+
+/// Terms above this fraction of the seed are regenerated in `f64`
+const HEAD: f64 = 1e-7;
+
+fn widen(z: Complex32) -> Complex64 {
+    Complex64::new(z.re as f64, z.im as f64)
+}
+
+fn narrow(z: Complex64) -> Complex32 {
+    Complex32::new(z.re as f32, z.im as f32)
+}
+
+/// Seed terms t_0 = 1, t_k = t_{k-1} ratio(k) in `f32`, until the last is
+/// below [`TOLERANCE`] times Σ t_k and, if `alternate`, Σ (-1)^k t_k
+///
+/// Returns `None` if the terms start growing or [`MAX_TERMS`] is reached.
+fn seed(mut ratio: impl FnMut(usize) -> Complex32, alternate: bool) -> Option<Vec<Complex32>> {
+    let mut terms = vec![Complex32::new(1.0, 0.0)];
+    let (mut plus, mut minus) = (terms[0], terms[0]);
+    for k in 1..MAX_TERMS {
+        let previous = terms[k - 1];
+        let term = previous * ratio(k);
+        if term.norm() > previous.norm() && alternate {
+            return None;
+        }
+        terms.push(term);
+        plus += term;
+        minus += if k % 2 == 0 { term } else { -term };
+        let size = if alternate {
+            plus.norm().min(minus.norm())
+        } else {
+            plus.norm()
+        };
+        if term.norm() <= TOLERANCE * size {
+            return Some(terms);
+        }
+    }
+    None
+}
+
+/// Σ sign^k t_k with the leading terms regenerated from `ratio` in `f64`
+fn refine(terms: &[Complex32], mut ratio: impl FnMut(usize) -> Complex64, sign: f64) -> Complex64 {
+    let signed = |k: usize, t: Complex64| if sign < 0.0 && k % 2 == 1 { -t } else { t };
+    let size: f64 = terms
+        .iter()
+        .enumerate()
+        .map(|(k, &t)| signed(k, widen(t)))
+        .sum::<Complex64>()
+        .norm();
+    let mut term = Complex64::new(1.0, 0.0);
+    let mut sum = term;
+    for (k, &seeded) in terms.iter().enumerate().skip(1) {
+        if (seeded.norm() as f64) > HEAD * size {
+            term *= ratio(k);
+            sum += signed(k, term);
+        } else {
+            sum += signed(k, widen(seeded));
+        }
+    }
+    sum
+}
+
+/// J_ν (sign -1) or I_ν (sign +1) by the power series, unscaled
+fn series(z: Complex64, nu: f64, sign: f64) -> Option<Complex64> {
+    let q = 0.25 * sign * z * z;
+    let q32 = narrow(q);
+    let nu32 = nu as f32;
+    let terms = seed(|k| q32 / (k as f32 * (nu32 + k as f32)), false)?;
+    let sum = refine(&terms, |k| q / (k as f64 * (nu + k as f64)), 1.0);
+    // (z/2)^ν / Γ(ν+1)
+    Some(sum * (nu * (0.5 * z).ln() - ln_gamma(nu + 1.0)).exp())
+}
+
+/// Σ a_k(ν) u^k and Σ a_k(ν) (-u)^k
+fn hankel_sums(u: Complex64, nu: f64) -> Option<(Complex64, Complex64)> {
+    let mu = 4.0 * nu * nu;
+    let c = |k: usize| {
+        let odd = (2 * k - 1) as f64;
+        (mu - odd * odd) / (8.0 * k as f64)
+    };
+    let u32 = narrow(u);
+    let terms = seed(|k| u32 * c(k) as f32, true)?;
+    let plus = refine(&terms, |k| u * c(k), 1.0);
+    let minus = refine(&terms, |k| u * c(k), -1.0);
+    Some((plus, minus))
+}
+
+fn asymptotic(kind: BesselKind, z: Complex64, nu: f64, shift: Complex64) -> Option<Complex64> {
+    let i = Complex64::i();
+    let modified = matches!(kind, BesselKind::I | BesselKind::K);
+    let u = if modified { 1.0 / z } else { i / z };
+    let (plus, minus) = hankel_sums(u, nu)?;
+    let root = (FRAC_2_PI / z).sqrt();
+    let h1 = || root * hankel_phase(z, nu, 1.0, shift) * plus;
+    let h2 = || root * hankel_phase(z, nu, -1.0, shift) * minus;
+    Some(match kind {
+        BesselKind::J => 0.5 * (h1() + h2()),
+        BesselKind::Y => -0.5 * i * (h1() - h2()),
+        BesselKind::H1 => h1(),
+        BesselKind::H2 => h2(),
+        BesselKind::K => 0.5 * PI * root * (shift - z).exp() * plus,
+        BesselKind::I => {
+            // DLMF 10.40.5 with the upper sign for Im z >= 0
+            let (sin, cos) = (PI * (nu % 2.0)).sin_cos();
+            let side = if z.im >= 0.0 {
+                i * Complex64::new(cos, sin)
+            } else {
+                -i * Complex64::new(cos, -sin)
+            };
+            0.5 * root * ((z + shift).exp() * minus + side * (shift - z).exp() * plus)
+        }
+    })
+}
+
+/// F_ν(z) by a refined sum, or `None` if neither region applies
+fn value(kind: BesselKind, z: Complex64, nu: f64, scaling: Scaling) -> Option<Complex64> {
+    // On the cut the side depends on the sign of zero, which `bessel` handles
+    let on_cut = z.im == 0.0 && z.re < 0.0;
+    if !nu.is_finite() || nu < 0.0 || on_cut {
+        return None;
+    }
+    let shift = if scaling == Scaling::Scaled {
+        kind.log_scale(z)
+    } else {
+        Complex64::new(0.0, 0.0)
+    };
+    let r = z.norm();
+    let value = if r <= SERIES_RADIUS && r > 0.0 && matches!(kind, BesselKind::J | BesselKind::I) {
+        let sign = if kind == BesselKind::I { 1.0 } else { -1.0 };
+        series(z, nu, sign)? * shift.exp()
+    } else if z.re > 0.0 && r >= MIN_ASYMPTOTIC.max(nu * nu) {
+        asymptotic(kind, z, nu, shift)?
+    } else {
+        return None;
+    };
+    (value.re.is_finite() && value.im.is_finite()).then_some(value)
+}
+
+/// Evaluate `kind` of order `nu` at every point of `zs` to about 1e-12
+///
+/// Trades the last digits of [`bessel_slice`](crate::bessel_slice) for
+/// shorter `f64` sums; see the [module documentation](self). The first point
+/// that fails is reported as the error.
+///
+/// # Parameters
+/// * `kind` - Kind of Bessel function
+/// * `zs` - Complex arguments
+/// * `nu` - Order (real number)
+/// * `scaling` - Scaling option, with the factor of [`bessel`] for `kind`
+pub fn bessel_slice_hybrid(
+    kind: BesselKind,
+    zs: &[Complex64],
+    nu: f64,
+    scaling: Scaling,
+) -> Result<Vec<Complex64>, BesselError> {
+    zs.iter()
+        .map(|&z| match value(kind, z, nu, scaling) {
+            Some(value) => Ok(value),
+            None => bessel(kind, nu, z, scaling),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hybrid_accuracy() {
+        let zs: Vec<Complex64> = (0..240)
+            .map(|k| Complex64::from_polar(0.05 + 0.2 * (k as f64).powf(1.4), 0.29 * k as f64))
+            .collect();
+        let mut refined = 0;
+        for kind in BesselKind::ALL {
+            for &nu in &[0.0, 0.5, 1.0, 2.7, 4.2] {
+                for scaling in [Scaling::Unscaled, Scaling::Scaled] {
+                    let values = bessel_slice_hybrid(kind, &zs, nu, scaling).unwrap();
+                    for (&z, hybrid) in zs.iter().zip(&values) {
+                        refined += value(kind, z, nu, scaling).is_some() as usize;
+                        let exact = bessel(kind, nu, z, scaling).unwrap();
+                        let envelope = match kind {
+                            BesselKind::I | BesselKind::K => exact.norm(),
+                            _ => {
+                                // Unscaled, the oscillating kinds grow like e^{|Im z|}
+                                let growth = match scaling {
+                                    Scaling::Scaled => 1.0,
+                                    Scaling::Unscaled => (-kind.log_scale(z).re).exp(),
+                                };
+                                exact.norm().max(growth / z.norm().max(1.0).sqrt())
+                            }
+                        };
+                        let error = (hybrid - exact).norm() / envelope;
+                        assert!(error < 1e-12, "{:?}_{}({}) {:e}", kind, nu, z, error);
+                    }
+                }
+            }
+        }
+        assert!(refined > 1000, "{}", refined);
+    }
+
+    #[test]
+    fn test_fallback() {
+        // Negative orders, the origin and the cut go through `bessel` unchanged
+        let z = Complex64::new(1.0, 0.5);
+        let values = bessel_slice_hybrid(BesselKind::Y, &[z], -0.7, Scaling::Unscaled).unwrap();
+        assert_eq!(
+            values[0],
+            bessel(BesselKind::Y, -0.7, z, Scaling::Unscaled).unwrap()
+        );
+        let zs = [z, Complex64::new(0.0, 0.0)];
+        let values = bessel_slice_hybrid(BesselKind::K, &zs, 0.0, Scaling::Unscaled).unwrap();
+        assert_eq!(values[1], Complex64::new(f64::INFINITY, 0.0));
+        // Either side of the cut, as selected by the sign of zero
+        for z in [Complex64::new(-1.0, 0.0), Complex64::new(-1.0, -0.0)] {
+            for kind in [BesselKind::J, BesselKind::I] {
+                let values = bessel_slice_hybrid(kind, &[z], 0.3, Scaling::Unscaled).unwrap();
+                assert_eq!(values[0], bessel(kind, 0.3, z, Scaling::Unscaled).unwrap());
+            }
+        }
+        let zs = [z, Complex64::new(f64::NAN, 0.0)];
+        assert!(bessel_slice_hybrid(BesselKind::K, &zs, 0.0, Scaling::Unscaled).is_err());
+    }
+}
@@ -0,0 +1,254 @@
+//! No-allocation sequence API writing into caller-provided buffers
+//!
+//! The Amos routines return real and imaginary parts in separate arrays. Here
+//! the output slice itself is used as that split storage (real parts in the
+//! first half, imaginary parts in the second) and then interleaved in place,
+//! so no temporary vectors are needed. Y additionally needs two work arrays,
+//! so it is evaluated in fixed-size chunks on the stack.
+//!
+//! Negative orders and arguments below the branch cut (Im z = -0.0) go through
+//! the reflection formulas, which do allocate.
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{bessel_j_into, Scaling};
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let mut out = [Complex64::new(0.0, 0.0); 8];
+//!     for k in 0..100 {
+//!         let z = Complex64::new(0.1 * k as f64, 1.0);
+//!         let info = bessel_j_into(z, 0.0, Scaling::Unscaled, &mut out)?;
+//!         assert_eq!(info.underflow_count, 0);
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use crate::backend::{ActiveBackend, Backend};
+use crate::{
+    check_amos_len, check_backend_range, clear_real_axis, validate_inputs, BesselError, BesselKind,
+    Scaling,
+};
+use crate::{direct, reflection};
+use num_complex::Complex64;
+
+/// Chunk length for evaluations that need extra work arrays
+const STACK_CHUNK: usize = 32;
+
+/// Information about a sequence written into a caller-provided buffer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SequenceInfo {
+    /// Number of function values that experienced underflow
+    pub underflow_count: i32,
+}
+
+/// View a complex slice as its interleaved (re, im) f64 storage
+pub(crate) fn as_f64_slice(values: &mut [Complex64]) -> &mut [f64] {
+    // Complex<f64> is #[repr(C)] with fields re, im
+    unsafe { std::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut f64, values.len() * 2) }
+}
+
+/// Turn [r0, ..., r(n-1), i0, ..., i(n-1)] into [r0, i0, r1, i1, ...] in place
+///
+/// Divide and conquer with rotations: O(n log n) time, no extra memory.
+pub(crate) fn interleave_in_place(data: &mut [f64]) {
+    let n = data.len() / 2;
+    if n <= 1 {
+        return;
+    }
+    let m = n / 2;
+    // [R1 R2 I1 I2] -> [R1 I1 R2 I2] with |R1| = |I1| = m
+    data[m..n + m].rotate_left(n - m);
+    let (first, second) = data.split_at_mut(2 * m);
+    interleave_in_place(first);
+    interleave_in_place(second);
+}
+
+pub(crate) fn error_for(
+    kind: BesselKind,
+    ierr: i32,
+    z: Complex64,
+    nu: f64,
+    kode: i32,
+    n: usize,
+) -> BesselError {
+    let routine = match kind {
+        BesselKind::J => "zbesj",
+        BesselKind::Y => "zbesy",
+        BesselKind::I => "zbesi",
+        BesselKind::K => "zbesk",
+        BesselKind::H1 | BesselKind::H2 => "zbesh",
+    };
+    BesselError::amos(routine, ierr, z, Some(nu), kode, n)
+}
+
+/// Calculate a Bessel function sequence of the given kind into `out`
+///
+/// `out[k]` receives the function at order ν+k, for k = 0, ..., out.len()-1.
+///
+/// # Parameters
+/// * `kind` - Kind of Bessel function
+/// * `z` - Complex argument
+/// * `nu` - Order (real number)
+/// * `scaling` - Scaling option
+/// * `out` - Output buffer; its length is the number of orders
+pub fn bessel_into(
+    kind: BesselKind,
+    z: Complex64,
+    nu: f64,
+    scaling: Scaling,
+    out: &mut [Complex64],
+) -> Result<SequenceInfo, BesselError> {
+    let n = out.len();
+    if n == 0 {
+        return Err(BesselError::InvalidParameter(
+            "output slice must not be empty".to_string(),
+        ));
+    }
+    check_amos_len(n)?;
+    let kode = scaling.kode();
+    validate_inputs(z, nu, kode)?;
+
+    if nu < 0.0 || reflection::below_cut(z) {
+        let result = kind.eval_impl(z, nu, kode, n, false)?;
+        out.copy_from_slice(&result.values);
+        return Ok(SequenceInfo {
+            underflow_count: result.underflow_count,
+        });
+    }
+
+    if direct::fill(kind, z, nu, kode, out).is_some() {
+        return Ok(SequenceInfo { underflow_count: 0 });
+    }
+    check_backend_range(z, nu, n)?;
+
+    if kind == BesselKind::Y {
+        let mut underflow_count = 0;
+        let mut cyr = [0.0; STACK_CHUNK];
+        let mut cyi = [0.0; STACK_CHUNK];
+        for (c, chunk) in out.chunks_mut(STACK_CHUNK).enumerate() {
+            let len = chunk.len();
+            let order = nu + (c * STACK_CHUNK) as f64;
+            let (ierr, nz) =
+                ActiveBackend::bessel(kind, z, order, kode, &mut cyr[..len], &mut cyi[..len]);
+            if ierr != 0 {
+                return Err(error_for(kind, ierr, z, order, kode, len));
+            }
+            clear_real_axis(kind, z, &mut cyi[..len]);
+            underflow_count += nz;
+            for (k, value) in chunk.iter_mut().enumerate() {
+                *value = Complex64::new(cyr[k], cyi[k]);
+            }
+        }
+        return Ok(SequenceInfo { underflow_count });
+    }
+
+    let data = as_f64_slice(out);
+    let (cyr, cyi) = data.split_at_mut(n);
+    let (ierr, nz) = ActiveBackend::bessel(kind, z, nu, kode, cyr, cyi);
+    if ierr != 0 {
+        return Err(error_for(kind, ierr, z, nu, kode, n));
+    }
+    clear_real_axis(kind, z, cyi);
+    interleave_in_place(data);
+
+    Ok(SequenceInfo {
+        underflow_count: nz,
+    })
+}
+
+/// Calculate J_ν(z), ..., J_{ν+n-1}(z) into `out` without allocating
+///
+/// See [`bessel_into`].
+pub fn bessel_j_into(
+    z: Complex64,
+    nu: f64,
+    scaling: Scaling,
+    out: &mut [Complex64],
+) -> Result<SequenceInfo, BesselError> {
+    bessel_into(BesselKind::J, z, nu, scaling, out)
+}
+
+/// Calculate Y_ν(z), ..., Y_{ν+n-1}(z) into `out` without allocating
+///
+/// See [`bessel_into`].
+pub fn bessel_y_into(
+    z: Complex64,
+    nu: f64,
+    scaling: Scaling,
+    out: &mut [Complex64],
+) -> Result<SequenceInfo, BesselError> {
+    bessel_into(BesselKind::Y, z, nu, scaling, out)
+}
+
+/// Calculate I_ν(z), ..., I_{ν+n-1}(z) into `out` without allocating
+///
+/// See [`bessel_into`].
+pub fn bessel_i_into(
+    z: Complex64,
+    nu: f64,
+    scaling: Scaling,
+    out: &mut [Complex64],
+) -> Result<SequenceInfo, BesselError> {
+    bessel_into(BesselKind::I, z, nu, scaling, out)
+}
+
+/// Calculate K_ν(z), ..., K_{ν+n-1}(z) into `out` without allocating
+///
+/// See [`bessel_into`].
+pub fn bessel_k_into(
+    z: Complex64,
+    nu: f64,
+    scaling: Scaling,
+    out: &mut [Complex64],
+) -> Result<SequenceInfo, BesselError> {
+    bessel_into(BesselKind::K, z, nu, scaling, out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interleave_in_place() {
+        for n in 0..40 {
+            let mut data: Vec<f64> = (0..n)
+                .map(|k| k as f64)
+                .chain((0..n).map(|k| -(k as f64)))
+                .collect();
+            interleave_in_place(&mut data);
+            for k in 0..n {
+                assert_eq!(data[2 * k], k as f64);
+                assert_eq!(data[2 * k + 1], -(k as f64));
+            }
+        }
+    }
+
+    #[test]
+    fn test_into_matches_allocating_api() {
+        let z = Complex64::new(3.0, 2.0);
+        for kind in BesselKind::ALL {
+            // Longer than one stack chunk to exercise the chunked Y path
+            let mut out = vec![Complex64::new(0.0, 0.0); 40];
+            bessel_into(kind, z, 0.25, Scaling::Scaled, &mut out).unwrap();
+            let expected = kind.eval_impl(z, 0.25, 2, 40, false).unwrap();
+            for (k, (a, e)) in out.iter().zip(&expected.values).enumerate() {
+                let diff = (a - e).norm() / e.norm();
+                assert!(
+                    diff < 1e-13,
+                    "{:?} order offset {}: diff = {}",
+                    kind,
+                    k,
+                    diff
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_into_empty_slice() {
+        let z = Complex64::new(1.0, 0.0);
+        assert!(bessel_k_into(z, 0.0, Scaling::Unscaled, &mut []).is_err());
+    }
+}
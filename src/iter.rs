@@ -0,0 +1,213 @@
+//! Lazy iteration over a sequence of orders
+//!
+//! The iterators yield f(ν), f(ν+1), f(ν+2), ... on demand, so series
+//! summation code can stop at convergence without choosing the sequence
+//! length up front.
+//!
+//! For Y, K and the Hankel functions (with ν >= 0) the first two values come
+//! from one FFI call and the rest follow from the three-term recurrence,
+//! which is stable in the direction of increasing order for these functions.
+//! J and I are minimal solutions of that recurrence, so forward recurrence
+//! would lose accuracy; they are fetched in blocks of growing length instead.
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{bessel_j_iter, Scaling};
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let z = Complex64::new(1.0, 0.5);
+//!
+//!     // Sum J_n(z) until the terms become negligible
+//!     let mut sum = Complex64::new(0.0, 0.0);
+//!     for value in bessel_j_iter(z, 0.0, Scaling::Unscaled) {
+//!         let value = value?;
+//!         sum += value;
+//!         if value.norm() < 1e-17 {
+//!             break;
+//!         }
+//!     }
+//!     println!("sum = {}", sum);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{BesselError, BesselKind, Scaling};
+use num_complex::Complex64;
+
+const INITIAL_BLOCK: usize = 4;
+const MAX_BLOCK: usize = 64;
+
+/// Iterator over f(ν), f(ν+1), f(ν+2), ... for one kind of Bessel function
+///
+/// Created by [`bessel_iter`] or the kind-specific constructors. Each item is
+/// a `Result`; after the first error the iterator is exhausted.
+#[derive(Debug, Clone)]
+pub struct BesselIter {
+    kind: BesselKind,
+    z: Complex64,
+    kode: i32,
+    /// Order of the next value to fetch from the FFI
+    next_order: f64,
+    buffer: Vec<Complex64>,
+    pos: usize,
+    block: usize,
+    /// The last two values and the order of the latter (recurrence mode)
+    recurrence: Option<(Complex64, Complex64, f64)>,
+    done: bool,
+}
+
+impl BesselIter {
+    fn uses_recurrence(&self) -> bool {
+        matches!(
+            self.kind,
+            BesselKind::Y | BesselKind::K | BesselKind::H1 | BesselKind::H2
+        ) && self.next_order >= 0.0
+    }
+
+    fn fetch(&mut self, n: usize) -> Result<(), BesselError> {
+        let result = self
+            .kind
+            .eval_impl(self.z, self.next_order, self.kode, n, false)?;
+        self.next_order += n as f64;
+        self.buffer = result.values;
+        self.pos = 0;
+        Ok(())
+    }
+}
+
+impl Iterator for BesselIter {
+    type Item = Result<Complex64, BesselError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        if let Some((prev, curr, mu)) = self.recurrence {
+            // C_{μ+1} = (2μ/z) C_μ ∓ C_{μ-1}, with + for K
+            let factor = 2.0 * mu / self.z;
+            let next = match self.kind {
+                BesselKind::K => factor * curr + prev,
+                _ => factor * curr - prev,
+            };
+            self.recurrence = Some((curr, next, mu + 1.0));
+            return Some(Ok(next));
+        }
+
+        if self.pos == self.buffer.len() {
+            let n = if self.uses_recurrence() {
+                2
+            } else {
+                self.block
+            };
+            if let Err(err) = self.fetch(n) {
+                self.done = true;
+                return Some(Err(err));
+            }
+            self.block = (self.block * 2).min(MAX_BLOCK);
+        }
+
+        let value = self.buffer[self.pos];
+        self.pos += 1;
+
+        if self.pos == 2 && self.buffer.len() == 2 && self.uses_recurrence() {
+            // Both seeds have been yielded; continue by recurrence
+            self.recurrence = Some((self.buffer[0], self.buffer[1], self.next_order - 1.0));
+        }
+
+        Some(Ok(value))
+    }
+}
+
+/// Create a lazy iterator over f(ν), f(ν+1), ... for the given kind
+///
+/// # Parameters
+/// * `kind` - Kind of Bessel function
+/// * `z` - Complex argument
+/// * `nu` - Starting order (real number)
+/// * `scaling` - Scaling option
+pub fn bessel_iter(kind: BesselKind, z: Complex64, nu: f64, scaling: Scaling) -> BesselIter {
+    BesselIter {
+        kind,
+        z,
+        kode: scaling.kode(),
+        next_order: nu,
+        buffer: Vec::new(),
+        pos: 0,
+        block: INITIAL_BLOCK,
+        recurrence: None,
+        done: false,
+    }
+}
+
+/// Create a lazy iterator over J_ν(z), J_{ν+1}(z), ...
+pub fn bessel_j_iter(z: Complex64, nu: f64, scaling: Scaling) -> BesselIter {
+    bessel_iter(BesselKind::J, z, nu, scaling)
+}
+
+/// Create a lazy iterator over Y_ν(z), Y_{ν+1}(z), ...
+pub fn bessel_y_iter(z: Complex64, nu: f64, scaling: Scaling) -> BesselIter {
+    bessel_iter(BesselKind::Y, z, nu, scaling)
+}
+
+/// Create a lazy iterator over I_ν(z), I_{ν+1}(z), ...
+pub fn bessel_i_iter(z: Complex64, nu: f64, scaling: Scaling) -> BesselIter {
+    bessel_iter(BesselKind::I, z, nu, scaling)
+}
+
+/// Create a lazy iterator over K_ν(z), K_{ν+1}(z), ...
+pub fn bessel_k_iter(z: Complex64, nu: f64, scaling: Scaling) -> BesselIter {
+    bessel_iter(BesselKind::K, z, nu, scaling)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bessel_j, bessel_k, bessel_y};
+
+    fn assert_close(actual: &[Complex64], expected: &[Complex64], tol: f64) {
+        assert_eq!(actual.len(), expected.len());
+        for (k, (a, e)) in actual.iter().zip(expected).enumerate() {
+            let diff = (a - e).norm() / e.norm();
+            assert!(diff < tol, "order offset {}: diff = {}", k, diff);
+        }
+    }
+
+    #[test]
+    fn test_block_mode_matches_sequence() {
+        let z = Complex64::new(4.0, 1.0);
+        let values: Vec<_> = bessel_j_iter(z, 0.5, Scaling::Unscaled)
+            .take(30)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let expected = bessel_j(z, 0.5, 1, 30).unwrap().values;
+        assert_close(&values, &expected, 1e-13);
+    }
+
+    #[test]
+    fn test_recurrence_mode_matches_sequence() {
+        let z = Complex64::new(4.0, 1.0);
+
+        let values: Vec<_> = bessel_y_iter(z, 0.0, Scaling::Unscaled)
+            .take(20)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let expected = bessel_y(z, 0.0, 1, 20).unwrap().values;
+        assert_close(&values, &expected, 1e-12);
+
+        let values: Vec<_> = bessel_k_iter(z, 0.25, Scaling::Scaled)
+            .take(20)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let expected = bessel_k(z, 0.25, 2, 20).unwrap().values;
+        assert_close(&values, &expected, 1e-12);
+    }
+
+    #[test]
+    fn test_error_ends_iteration() {
+        let mut iter = bessel_k_iter(Complex64::new(f64::NAN, 0.0), 0.0, Scaling::Unscaled);
+        assert!(matches!(iter.next(), Some(Err(_))));
+        assert!(iter.next().is_none());
+    }
+}
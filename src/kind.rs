@@ -0,0 +1,210 @@
+//! Single dispatch entry point over the Bessel function kinds
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{bessel, BesselKind, Scaling};
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let z = Complex64::new(2.0, 1.0);
+//!
+//!     for kind in BesselKind::ALL {
+//!         let value = bessel(kind, 0.5, z, Scaling::Unscaled)?;
+//!         println!("{:?}_0.5({}) = {}", kind, z, value);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use crate::order::{negative_integer_sign, Order};
+use crate::{
+    bessel_h_impl, bessel_i_impl, bessel_j_impl, bessel_k_impl, bessel_y_impl, single_value,
+    BesselError, BesselResult, Scaling,
+};
+use num_complex::Complex64;
+
+/// Kind of Bessel function
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub enum BesselKind {
+    /// Bessel function of the first kind J_ν(z)
+    J,
+    /// Bessel function of the second kind Y_ν(z)
+    Y,
+    /// Modified Bessel function of the first kind I_ν(z)
+    I,
+    /// Modified Bessel function of the second kind K_ν(z)
+    K,
+    /// Hankel function of the first kind H^(1)_ν(z)
+    H1,
+    /// Hankel function of the second kind H^(2)_ν(z)
+    H2,
+}
+
+impl BesselKind {
+    /// All kinds, in declaration order
+    pub const ALL: [BesselKind; 6] = [
+        BesselKind::J,
+        BesselKind::Y,
+        BesselKind::I,
+        BesselKind::K,
+        BesselKind::H1,
+        BesselKind::H2,
+    ];
+
+    /// Logarithm of the kode=2 scaling factor, so that scaled = value * exp(result)
+    pub(crate) fn log_scale(self, z: Complex64) -> Complex64 {
+        match self {
+            BesselKind::J | BesselKind::Y => Complex64::new(-z.im.abs(), 0.0),
+            BesselKind::I => Complex64::new(-z.re.abs(), 0.0),
+            BesselKind::K => z,
+            BesselKind::H1 => Complex64::new(z.im, -z.re),
+            BesselKind::H2 => Complex64::new(-z.im, z.re),
+        }
+    }
+
+    /// Evaluate the sequence of orders ν, ν+1, ..., ν+n-1 for this kind
+    pub(crate) fn eval_impl(
+        self,
+        z: Complex64,
+        nu: f64,
+        kode: i32,
+        n: usize,
+        allow_accuracy_loss: bool,
+    ) -> Result<BesselResult, BesselError> {
+        match self {
+            BesselKind::J => bessel_j_impl(z, nu, kode, n, allow_accuracy_loss),
+            BesselKind::Y => bessel_y_impl(z, nu, kode, n, allow_accuracy_loss),
+            BesselKind::I => bessel_i_impl(z, nu, kode, n, allow_accuracy_loss),
+            BesselKind::K => bessel_k_impl(z, nu, kode, n, allow_accuracy_loss),
+            BesselKind::H1 => bessel_h_impl(z, nu, kode, 1, n, allow_accuracy_loss),
+            BesselKind::H2 => bessel_h_impl(z, nu, kode, 2, n, allow_accuracy_loss),
+        }
+    }
+}
+
+/// Calculate a Bessel function of the given kind (single value)
+///
+/// # Parameters
+/// * `kind` - Kind of Bessel function
+/// * `nu` - Order (real number)
+/// * `z` - Complex argument
+/// * `scaling` - Scaling option; the factor depends on the kind:
+///   exp(-abs(Im(z))) for J and Y, exp(-abs(Re(z))) for I, exp(z) for K,
+///   exp(-iz) for H1 and exp(iz) for H2
+///
+/// # Returns
+/// Complex value of the function
+///
+/// Integer and half-integer orders are routed to cheaper special paths; see
+/// [`Order`].
+pub fn bessel(
+    kind: BesselKind,
+    nu: impl Into<Order>,
+    z: Complex64,
+    scaling: Scaling,
+) -> Result<Complex64, BesselError> {
+    let order = nu.into();
+    let nu = order.value();
+    if order.is_integer() && nu < 0.0 {
+        let value = single_value(kind, z, -nu, scaling.kode())?;
+        return Ok(value * negative_integer_sign(kind, -nu));
+    }
+    single_value(kind, z, nu, scaling.kode())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bessel_h, I, J, K, Y};
+
+    #[test]
+    fn test_dispatch_matches_simple_api() {
+        let z = Complex64::new(10.0, 20.0);
+        let nu = 1.0;
+
+        assert_eq!(
+            bessel(BesselKind::J, nu, z, Scaling::Unscaled).unwrap(),
+            J(nu, z).unwrap()
+        );
+        assert_eq!(
+            bessel(BesselKind::Y, nu, z, Scaling::Unscaled).unwrap(),
+            Y(nu, z).unwrap()
+        );
+        assert_eq!(
+            bessel(BesselKind::I, nu, z, Scaling::Unscaled).unwrap(),
+            I(nu, z).unwrap()
+        );
+        assert_eq!(
+            bessel(BesselKind::K, nu, z, Scaling::Unscaled).unwrap(),
+            K(nu, z).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_hankel_combination() {
+        // H1 = J + iY, H2 = J - iY
+        let z = Complex64::new(3.0, 0.5);
+        let nu = 0.5;
+        let i = Complex64::new(0.0, 1.0);
+
+        let j = J(nu, z).unwrap();
+        let y = Y(nu, z).unwrap();
+        let h1 = bessel(BesselKind::H1, nu, z, Scaling::Unscaled).unwrap();
+        let h2 = bessel(BesselKind::H2, nu, z, Scaling::Unscaled).unwrap();
+
+        assert!((h1 - (j + i * y)).norm() < 1e-14);
+        assert!((h2 - (j - i * y)).norm() < 1e-14);
+    }
+
+    #[test]
+    fn test_hankel_scaling_consistency() {
+        let z = Complex64::new(20.0, -30.0);
+        let nu = 2.0;
+        let i = Complex64::new(0.0, 1.0);
+
+        let h1 = bessel_h(z, nu, 1, 1, 1).unwrap().values[0];
+        let h1_scaled = bessel(BesselKind::H1, nu, z, Scaling::Scaled).unwrap();
+
+        // For H1 the scaling factor is exp(-iz)
+        let expected = h1 * (-i * z).exp();
+        let diff = (h1_scaled - expected).norm() / expected.norm();
+        assert!(
+            diff < 1e-13,
+            "H1 scaling consistency failed: diff = {}",
+            diff
+        );
+    }
+
+    #[test]
+    fn test_special_order_paths_match_general_path() {
+        let z = Complex64::new(2.5, 1.5);
+        for kind in BesselKind::ALL {
+            for nu in [0.5, -0.5, -3.0] {
+                let routed = bessel(kind, nu, z, Scaling::Unscaled).unwrap();
+                let general = kind.eval_impl(z, nu, 1, 1, false).unwrap().values[0];
+                let diff = (routed - general).norm() / general.norm();
+                assert!(diff < 1e-13, "{:?}_{}: diff = {}", kind, nu, diff);
+            }
+        }
+        // ν = 1/2 comes from the closed forms, and ν = -1/2 from them by the
+        // exact reflection
+        for kind in BesselKind::ALL {
+            let closed = crate::half_integer::value(kind, z, 0.5, 1).unwrap();
+            assert_eq!(bessel(kind, 0.5, z, Scaling::Unscaled).unwrap(), closed);
+        }
+        let y = crate::half_integer::value(BesselKind::Y, z, 0.5, 1).unwrap();
+        assert_eq!(
+            bessel(BesselKind::J, -0.5, z, Scaling::Unscaled).unwrap(),
+            -y
+        );
+        assert_eq!(
+            bessel(BesselKind::K, -2, z, Scaling::Scaled).unwrap(),
+            bessel(BesselKind::K, 2, z, Scaling::Scaled).unwrap()
+        );
+    }
+}
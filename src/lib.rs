@@ -1,4 +1,5 @@
 #![deprecated(since = "0.1.3", note = "This crate is no longer maintained. Use the `complex-bessel` crate instead.")]
+#![cfg_attr(feature = "f128", feature(f128))]
 
 //! # zbessel-rs
 //!
@@ -44,6 +45,9 @@
 //! }
 //! ```
 //!
+//! The single-value functions are generic over [`BesselFloat`], which is
+//! implemented for `f32` and `f64`.
+//!
 //! ## Advanced Usage
 //!
 //! For multiple value calculations or scaling:
@@ -67,46 +71,636 @@
 //!     
 //!     // Airy function derivative (id=1)
 //!     let ai_prime = airy_ai(z, 1, 1)?;
-//!     println!("Ai'({}) = {}", z, ai_prime);
+//!     println!("Ai'({}) = {}", z, ai_prime.value);
 //!     
 //!     Ok(())
 //! }
+//! ```
+//!
+//! ## Non-finite Inputs
+//!
+//! A NaN or infinite argument or order is never passed to the Amos routines.
+//! Every function returning `Result` rejects it with
+//! [`BesselError::InvalidParameter`] naming the offending input, whatever
+//! the kind, scaling or sequence length; the `_or_nan` functions and the
+//! unchecked [`fast`] and [`fast32`] functions return NaN + iNaN instead.
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{fast, real, BesselError, J};
+//!
+//! let z = Complex64::new(1.0, f64::NAN);
+//! assert!(matches!(J(0.0, z), Err(BesselError::InvalidParameter(_))));
+//! assert!(matches!(real::Y(0.0, f64::NEG_INFINITY), Err(BesselError::InvalidParameter(_))));
+//! assert!(fast::J_unchecked(f64::INFINITY, Complex64::new(1.0, 0.0)).re.is_nan());
+//! ```
+//!
+//! ## Thread Safety
+//!
+//! Every function is reentrant and may be called from any number of threads
+//! at once, including from rayon pools. Neither the Amos translation nor the
+//! Rust code keeps mutable global state: the only statics in the C++ sources
+//! are `static const` values of the floating-point radix, initialized once
+//! under the thread-safe initialization of C++11, and each call works on its
+//! own stack and output buffers. The `self-check` feature's violation
+//! counter is atomic. All public types are `Send` and `Sync`; those that
+//! reuse storage between calls, [`CachedBessel`] and [`Workspace`], take
+//! `&mut self`, so give each thread its own.
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::J;
+//!
+//! let zs: Vec<Complex64> = (1..=64).map(|k| Complex64::new(0.25 * k as f64, 0.5)).collect();
+//! std::thread::scope(|scope| {
+//!     for chunk in zs.chunks(16) {
+//!         scope.spawn(move || chunk.iter().map(|&z| J(0.0, z).unwrap()).count());
+//!     }
+//! });
+//! ```
 
-use num_complex::Complex64;
-use std::os::raw::{c_double, c_int};
-
-// Include the generated bindings
-include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+use num_complex::{Complex, Complex64};
+
+#[cfg(feature = "capi")]
+pub mod capi;
+pub mod compat;
+pub mod complex32;
+pub mod continued_fraction;
+#[cfg(feature = "dual")]
+pub mod dual;
+pub mod fast;
+pub mod fast32;
+#[cfg(feature = "interval")]
+pub mod interval;
+pub mod prelude;
+#[cfg(feature = "f128")]
+pub mod quad;
+pub mod real;
+pub mod stats_support;
+#[cfg(feature = "amos")]
+pub mod sys;
+#[cfg(feature = "uom")]
+pub mod units;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "rkyv")]
+mod archive;
+mod array;
+#[cfg(feature = "arrow")]
+mod arrow;
+mod asymptotic;
+mod backend;
+mod builder;
+mod cache;
+#[cfg(feature = "candle")]
+mod candle;
+mod combined;
+mod condition;
+mod continuation;
+mod control;
+#[cfg(feature = "plotters")]
+mod curve;
+#[cfg(feature = "polars")]
+mod dataframe;
+mod derivative;
+mod direct;
+mod evaluator;
+mod export;
+mod ext;
+mod extended;
+mod feasibility;
+mod fft;
+mod float;
+mod function;
+mod gamma;
+#[cfg(feature = "gpu")]
+mod gpu;
+mod grid;
+mod half_integer;
+mod hybrid;
+mod into_slice;
+mod iter;
+mod kind;
+mod machine;
+#[cfg(feature = "nalgebra")]
+mod matrix;
+mod miller;
+mod modified;
+mod normalized;
+#[cfg(feature = "tokio")]
+mod offload;
+mod options;
+mod order;
+mod orders;
+mod origin;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "plot")]
+mod plot;
+mod rational;
+mod recurrence;
+mod reflection;
+#[cfg(feature = "self-check")]
+mod self_check;
+mod series;
+mod slice;
+mod stream;
+mod table;
+mod uniform;
+mod workspace;
+
+#[cfg(feature = "rkyv")]
+pub use archive::{read_grid_archive, write_grid_archive, ArchivedGridArchive, GridArchive};
+pub use array::{bessel_array, bessel_i_array, bessel_j_array, bessel_k_array, bessel_y_array};
+#[cfg(feature = "arrow")]
+pub use arrow::{bessel_arrow, bessel_arrow_real};
+#[cfg(feature = "amos")]
+pub use backend::Amos;
+pub use backend::Backend;
+#[cfg(feature = "backend-rust")]
+pub use backend::RustBackend;
+#[cfg(feature = "tracing")]
+pub use backend::Traced;
+pub use builder::Bessel;
+pub use cache::CachedBessel;
+#[cfg(feature = "candle")]
+pub use candle::bessel_tensor;
+pub use combined::{cyl_bessel_all, mod_bessel_all, CylinderBessel, ModifiedBessel};
+pub use condition::{condition_number, ConditionNumber};
+pub use continuation::bessel_continued;
+pub use control::{
+    bessel_grid_controlled, bessel_slice_controlled, BatchControl, CancelToken, Partial, Progress,
+};
+#[cfg(feature = "plotters")]
+pub use curve::{draw_curves, Curve, POINTS_PER_PERIOD};
+#[cfg(feature = "polars")]
+pub use dataframe::{BesselExpr, BesselNamespace};
+pub use derivative::{bessel_with_derivative, h1_h1p, h2_h2p, i_ip, j_jp, k_kp, y_yp};
+pub use evaluator::Evaluator;
+pub use export::{write_grid_csv, write_grid_npy, write_grid_npz, ExportLayout};
+pub use ext::BesselExt;
+pub use extended::{
+    bessel_both_scalings, bessel_extended, BothScalings, H1_extended, H2_extended, I_extended,
+    J_extended, K_extended, ScaledComplex, Y_extended,
+};
+pub use feasibility::{can_evaluate, Feasibility};
+pub use fft::all_jn_fft;
+pub use float::BesselFloat;
+pub use function::{BesselFunction, H1nu, H2nu, Inu, Jnu, Knu, Ynu};
+#[cfg(feature = "gpu")]
+pub use gpu::{GpuBatch, GpuFunction};
+pub use grid::{bessel_grid, bessel_j_grid, BesselGrid};
+pub use hybrid::bessel_slice_hybrid;
+pub use into_slice::{
+    bessel_i_into, bessel_into, bessel_j_into, bessel_k_into, bessel_y_into, SequenceInfo,
+};
+pub use iter::{
+    bessel_i_iter, bessel_iter, bessel_j_iter, bessel_k_iter, bessel_y_iter, BesselIter,
+};
+pub use kind::{bessel, BesselKind};
+pub use machine::{machine_parameters, MachineParameters, SafeRange};
+#[cfg(feature = "nalgebra")]
+pub use matrix::{
+    bessel_i_matrix, bessel_j_matrix, bessel_k_matrix, bessel_matrix, bessel_y_matrix,
+};
+pub use miller::{miller_backward, MillerNormalization};
+pub use normalized::{bessel_normalized, Normalization, NormalizedSequence};
+#[cfg(feature = "tokio")]
+pub use offload::{spawn_bessel_grid, spawn_bessel_slice};
+pub use options::{
+    bessel_h_opt, bessel_i_opt, bessel_j_opt, bessel_k_opt, bessel_y_opt, BesselOptions, CutSide,
+};
+pub use order::Order;
+pub use orders::{
+    bessel_i_orders, bessel_j_orders, bessel_k_orders, bessel_orders, bessel_span, bessel_y_orders,
+};
+#[cfg(feature = "rayon")]
+pub use parallel::{par_bessel_j_slice, par_bessel_slice, par_grid};
+#[cfg(feature = "plot")]
+pub use plot::{Plot, PlotStyle};
+#[cfg(feature = "self-check")]
+pub use self_check::self_check_violations;
+pub use slice::{bessel_j_slice, bessel_slice};
+pub use stream::{bessel_for_each_chunk, BesselStream, Chunk};
+pub use table::TableEvaluator;
+pub use workspace::{
+    bessel_h_with_workspace, bessel_i_with_workspace, bessel_j_with_workspace,
+    bessel_k_with_workspace, bessel_y_with_workspace, Workspace,
+};
+
+use backend::ActiveBackend;
+use float::{from_complex64, to_complex64};
+
+// The public types can be shared and sent between threads; see "Thread Safety"
+const _: () = {
+    const fn send_sync<T: Send + Sync>() {}
+    send_sync::<BesselResult>();
+    send_sync::<AiryResult>();
+    send_sync::<BesselError>();
+    send_sync::<BesselGrid>();
+    send_sync::<BesselOptions>();
+    send_sync::<Bessel>();
+    send_sync::<CachedBessel>();
+    send_sync::<Evaluator>();
+    send_sync::<TableEvaluator>();
+    send_sync::<Workspace>();
+    send_sync::<BesselIter>();
+    send_sync::<CancelToken>();
+};
 
 /// Structure representing the result of complex Bessel function calculations
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BesselResult {
     /// Calculated function values
     pub values: Vec<Complex64>,
     /// Number of function values that experienced underflow
     pub underflow_count: i32,
+    /// Number of function values returned as infinities after an overflow
+    ///
+    /// Always 0 unless [`BesselOptions::overflow_to_infinity`] is set; without
+    /// it an overflow is an error.
+    pub overflow_count: i32,
+    /// Estimated number of decimal digits lost to the size of |z| and the
+    /// orders by the path that computed the values: [`lost_digits`] for the
+    /// backend, 0 for the crate's own paths, which reduce the argument
+    /// exactly, except log10 ν for the uniform expansions of orders beyond
+    /// sqrt(0.5·`i32::MAX`)
+    pub lost_digits: f64,
+}
+
+/// Estimate the decimal digits the backend loses at argument `z` and orders
+/// ν, ..., ν+n-1
+///
+/// This bounds the conditioning of the backend's evaluation, not of the
+/// result: [`BesselResult::lost_digits`] reports the loss of the path that
+/// was actually taken, which for the crate's own paths is usually 0.
+///
+/// The Amos routines reduce the argument and order with an absolute error of
+/// about one ulp of the larger of |z| and the largest |order|, so about log10
+/// of that magnitude is lost from the 15.7 digits of an `f64`. Amos reports
+/// IERR=3 above sqrt(0.5·`i32::MAX`) ≈ 3.3e4 and evaluates nothing beyond
+/// 0.5·`i32::MAX` ≈ 1.1e9 (see [`BesselError::OutOfValidatedRange`]); callers
+/// that need more can switch to the `quad` module when the estimate is too
+/// large.
+pub fn lost_digits(z: Complex64, nu: f64, n: usize) -> f64 {
+    let fnu = nu.abs().max((nu + n.saturating_sub(1) as f64).abs());
+    z.norm().max(fnu).max(1.0).log10()
+}
+
+impl BesselResult {
+    /// Number of calculated function values
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Whether the result holds no values
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl std::ops::Deref for BesselResult {
+    type Target = [Complex64];
+
+    fn deref(&self) -> &[Complex64] {
+        &self.values
+    }
+}
+
+impl std::ops::Index<usize> for BesselResult {
+    type Output = Complex64;
+
+    fn index(&self, index: usize) -> &Complex64 {
+        &self.values[index]
+    }
+}
+
+impl IntoIterator for BesselResult {
+    type Item = Complex64;
+    type IntoIter = std::vec::IntoIter<Complex64>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a BesselResult {
+    type Item = &'a Complex64;
+    type IntoIter = std::slice::Iter<'a, Complex64>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.iter()
+    }
+}
+
+/// Structure representing the result of a complex Airy function Ai(z) calculation
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AiryResult {
+    /// Calculated function value
+    pub value: Complex64,
+    /// Underflow indicator (0: normal return, 1: the value was set to zero due to underflow)
+    pub underflow_count: i32,
+}
+
+/// Scaling option passed to the underlying routines as `kode`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub enum Scaling {
+    /// No scaling (kode=1)
+    #[default]
+    Unscaled,
+    /// Exponential scaling (kode=2); the factor depends on the function
+    Scaled,
+}
+
+impl Scaling {
+    /// The `kode` value understood by the Amos routines
+    pub fn kode(self) -> i32 {
+        match self {
+            Scaling::Unscaled => 1,
+            Scaling::Scaled => 2,
+        }
+    }
 }
 
 /// Error types
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BesselError {
     /// Invalid input parameters
     InvalidParameter(String),
-    /// Computation error
-    ComputationError(String),
+    /// Computation error reported by an Amos routine
+    ComputationError {
+        /// Name of the routine, e.g. `"zbesj"`
+        routine: String,
+        /// Raw IERR code
+        ierr: i32,
+        /// Argument passed to the routine
+        z: Complex64,
+        /// Starting order passed to the routine (`None` for the Airy functions)
+        nu: Option<f64>,
+        /// Scaling option passed to the routine
+        kode: i32,
+        /// Number of requested values (1 for the Airy functions)
+        n: usize,
+    },
+    /// An argument or order beyond the range the evaluation is validated for,
+    /// detected before calling the backend
+    ///
+    /// Orders are limited to [`MAX_ORDER`] everywhere. Evaluations that reach
+    /// the backend are also limited to its range, |z| and ν+n-1 up to
+    /// 0.5·`i32::MAX` for Amos, where it would report IERR=4.
+    OutOfValidatedRange {
+        /// The quantity out of range: `"|z|"`, `"|nu|"`, `"nu"` or `"nu+n-1"`
+        quantity: String,
+        /// Its value
+        value: f64,
+        /// The largest value accepted
+        limit: f64,
+    },
+}
+
+impl BesselError {
+    /// Error for a nonzero IERR returned by `routine` for the given inputs
+    pub(crate) fn amos(
+        routine: &str,
+        ierr: i32,
+        z: Complex64,
+        nu: Option<f64>,
+        kode: i32,
+        n: usize,
+    ) -> Self {
+        BesselError::ComputationError {
+            routine: routine.to_string(),
+            ierr,
+            z,
+            nu,
+            kode,
+            n,
+        }
+    }
+
+    /// Error for `quantity` exceeding `limit`
+    pub(crate) fn out_of_range(quantity: &str, value: f64, limit: f64) -> Self {
+        BesselError::OutOfValidatedRange {
+            quantity: quantity.to_string(),
+            value,
+            limit,
+        }
+    }
+
+    /// Raw Amos IERR code, if the error came from an Amos routine
+    pub fn ierr(&self) -> Option<i32> {
+        match self {
+            BesselError::ComputationError { ierr, .. } => Some(*ierr),
+            BesselError::InvalidParameter(_) | BesselError::OutOfValidatedRange { .. } => None,
+        }
+    }
+
+    /// Whether the inputs were rejected, by validation or by Amos (IERR=1)
+    pub fn is_invalid_input(&self) -> bool {
+        matches!(self, BesselError::InvalidParameter(_)) || self.ierr() == Some(1)
+    }
+
+    /// Whether the result would overflow (IERR=2); a scaled evaluation may succeed
+    pub fn is_overflow(&self) -> bool {
+        self.ierr() == Some(2)
+    }
+
+    /// Whether precision was lost because of a large |z| or ν (IERR=3 or 4),
+    /// or would have been beyond the validated range
+    pub fn is_accuracy_loss(&self) -> bool {
+        matches!(self.ierr(), Some(3 | 4)) || self.is_out_of_validated_range()
+    }
+
+    /// Whether an argument or order was beyond the validated range
+    pub fn is_out_of_validated_range(&self) -> bool {
+        matches!(self, BesselError::OutOfValidatedRange { .. })
+    }
+
+    /// Whether the algorithm did not terminate (IERR=5)
+    pub fn is_no_convergence(&self) -> bool {
+        self.ierr() == Some(5)
+    }
 }
 
 impl std::fmt::Display for BesselError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             BesselError::InvalidParameter(msg) => write!(f, "Invalid parameter: {}", msg),
-            BesselError::ComputationError(msg) => write!(f, "Computation error: {}", msg),
+            BesselError::ComputationError {
+                routine,
+                ierr,
+                z,
+                nu,
+                kode,
+                n,
+            } => {
+                write!(
+                    f,
+                    "Computation error: {} error code: {} (z = {}",
+                    routine, ierr, z
+                )?;
+                if let Some(nu) = nu {
+                    write!(f, ", nu = {}, kode = {}, n = {})", nu, kode, n)
+                } else {
+                    write!(f, ", kode = {})", kode)
+                }
+            }
+            BesselError::OutOfValidatedRange {
+                quantity,
+                value,
+                limit,
+            } => write!(
+                f,
+                "Out of validated range: {} = {:e} exceeds {:e}",
+                quantity, value, limit
+            ),
         }
     }
 }
 
 impl std::error::Error for BesselError {}
 
+/// Reject a scaling code other than 1 or 2
+fn validate_kode(kode: i32) -> Result<(), BesselError> {
+    if kode != 1 && kode != 2 {
+        return Err(BesselError::InvalidParameter(format!(
+            "kode must be 1 or 2, got {}",
+            kode
+        )));
+    }
+    Ok(())
+}
+
+/// Reject a non-finite argument z
+pub(crate) fn validate_argument(z: Complex64) -> Result<(), BesselError> {
+    if !z.re.is_finite() || !z.im.is_finite() {
+        return Err(BesselError::InvalidParameter(format!(
+            "z must be finite, got {}",
+            z
+        )));
+    }
+    Ok(())
+}
+
+/// Validate the inputs shared by the Bessel sequence routines
+pub(crate) fn validate_inputs(z: Complex64, nu: f64, kode: i32) -> Result<(), BesselError> {
+    validate_kode(kode)?;
+    validate_argument(z)?;
+    if !nu.is_finite() {
+        return Err(BesselError::InvalidParameter(format!(
+            "nu must be finite, got {}",
+            nu
+        )));
+    }
+    if nu.abs() > MAX_ORDER {
+        return Err(BesselError::out_of_range("|nu|", nu.abs(), MAX_ORDER));
+    }
+    Ok(())
+}
+
+/// Largest |ν| accepted
+///
+/// Beyond the orders Amos evaluates, the uniform asymptotic expansions take
+/// over with a relative error of about ν·ε, leaving six significant digits at
+/// this limit. Larger orders give [`BesselError::OutOfValidatedRange`].
+pub const MAX_ORDER: f64 = 1e-6 / f64::EPSILON;
+
+/// Reject an argument or orders ν, ..., ν+n-1 outside the range of the
+/// backend before calling it, instead of passing on its IERR=4
+pub(crate) fn check_backend_range(z: Complex64, nu: f64, n: usize) -> Result<(), BesselError> {
+    let limit = ActiveBackend::RANGE_LIMIT;
+    let top = nu + n.saturating_sub(1) as f64;
+    if z.norm() > limit {
+        return Err(BesselError::out_of_range("|z|", z.norm(), limit));
+    }
+    if top > limit {
+        let quantity = if n > 1 { "nu+n-1" } else { "nu" };
+        return Err(BesselError::out_of_range(quantity, top, limit));
+    }
+    Ok(())
+}
+
+/// Default largest sequence length accepted by the positional API
+///
+/// Each value needs 16 bytes plus working storage, so this keeps an accidental
+/// huge `n` from attempting a multi-gigabyte allocation. Use the `_opt`
+/// functions with a larger [`BesselOptions::max_n`] for longer sequences.
+pub const DEFAULT_MAX_N: usize = 1 << 20;
+
+/// Reject a sequence length above `max_n`
+pub(crate) fn check_max_n(n: usize, max_n: usize) -> Result<(), BesselError> {
+    if n > max_n {
+        return Err(BesselError::InvalidParameter(format!(
+            "n ({}) exceeds max_n ({}); use the `_opt` functions with a larger `max_n` for longer sequences",
+            n, max_n
+        )));
+    }
+    Ok(())
+}
+
+/// Reject a sequence length the Amos `int` interface cannot express
+pub(crate) fn check_amos_len(n: usize) -> Result<(), BesselError> {
+    if i32::try_from(n).is_err() {
+        return Err(BesselError::InvalidParameter(format!(
+            "n ({}) exceeds the Amos limit of {}",
+            n,
+            i32::MAX
+        )));
+    }
+    Ok(())
+}
+
+/// Validate the inputs of the Airy routines
+fn validate_airy_inputs(z: Complex64, id: i32, kode: i32) -> Result<(), BesselError> {
+    if id != 0 && id != 1 {
+        return Err(BesselError::InvalidParameter(format!(
+            "id must be 0 or 1, got {}",
+            id
+        )));
+    }
+    validate_kode(kode)?;
+    validate_argument(z)
+}
+
+/// Evaluate orders ν, ..., ν+n-1 straight into a `Vec<Complex64>`
+///
+/// The vector's storage is used as the split real/imaginary output arrays of
+/// the backend and then interleaved in place, so the values are written once
+/// with no temporary arrays. Returns IERR, the underflow count and the values.
+fn backend_sequence(
+    kind: BesselKind,
+    z: Complex64,
+    nu: f64,
+    kode: i32,
+    n: usize,
+) -> (i32, i32, Vec<Complex64>) {
+    let mut values = vec![Complex64::new(0.0, 0.0); n];
+    let data = into_slice::as_f64_slice(&mut values);
+    let (cyr, cyi) = data.split_at_mut(n);
+    let (ierr, nz) = ActiveBackend::bessel(kind, z, nu, kode, cyr, cyi);
+    clear_real_axis(kind, z, cyi);
+    into_slice::interleave_in_place(data);
+    (ierr, nz, values)
+}
+
+/// Zero the imaginary parts the backend leaves from rounding where the values
+/// are real: J, Y, I and K on the positive real axis, whose scaling factors
+/// are real as well
+pub(crate) fn clear_real_axis(kind: BesselKind, z: Complex64, cyi: &mut [f64]) {
+    if z.im == 0.0 && z.re > 0.0 && !matches!(kind, BesselKind::H1 | BesselKind::H2) {
+        cyi.fill(0.0);
+    }
+}
+
 /// Calculate complex Bessel function J_ν(z)
 ///
 /// # Parameters
@@ -115,45 +709,68 @@ impl std::error::Error for BesselError {}
 /// * `kode` - Scaling option (1: no scaling, 2: exp(-abs(Im(z))) scaling)
 /// * `n` - Number of function values to calculate
 pub fn bessel_j(z: Complex64, nu: f64, kode: i32, n: usize) -> Result<BesselResult, BesselError> {
+    check_max_n(n, DEFAULT_MAX_N)?;
+    bessel_j_impl(z, nu, kode, n, false)
+}
+
+/// Shared implementation of [`bessel_j`]; IERR=3 (loss of significance) is
+/// accepted as a successful return when `allow_accuracy_loss` is set.
+pub(crate) fn bessel_j_impl(
+    z: Complex64,
+    nu: f64,
+    kode: i32,
+    n: usize,
+    allow_accuracy_loss: bool,
+) -> Result<BesselResult, BesselError> {
     if n == 0 {
         return Err(BesselError::InvalidParameter(
             "n must be greater than 0".to_string(),
         ));
     }
+    validate_inputs(z, nu, kode)?;
+    check_amos_len(n)?;
+    #[cfg(feature = "self-check")]
+    if let Some(result) = self_check::checked(BesselKind::J, z, nu, kode, n, allow_accuracy_loss) {
+        return result;
+    }
+    if let Some(result) = origin::sequence(BesselKind::J, z, nu, n) {
+        return Ok(result);
+    }
+    if let Some(result) =
+        reflection::below_cut_sequence(BesselKind::J, z, nu, kode, n, allow_accuracy_loss)
+    {
+        return result;
+    }
+    if nu < 0.0 {
+        return reflection::negative_order_sequence(
+            BesselKind::J,
+            z,
+            nu,
+            kode,
+            n,
+            allow_accuracy_loss,
+        );
+    }
 
-    let mut cyr = vec![0.0; n];
-    let mut cyi = vec![0.0; n];
-    let mut nz = 0i32;
-
-    let result = unsafe {
-        zbesj(
-            z.re as c_double,
-            z.im as c_double,
-            nu as c_double,
-            kode as c_int,
-            n as c_int,
-            cyr.as_mut_ptr(),
-            cyi.as_mut_ptr(),
-            &mut nz,
-        )
-    };
-
-    if result != 0 {
-        return Err(BesselError::ComputationError(format!(
-            "zbesj error code: {}",
-            result
-        )));
+    if let Some(result) = recurrence::integer_sequence(BesselKind::J, z, nu, kode, n) {
+        return Ok(result);
+    }
+    if let Some(result) = direct::sequence(BesselKind::J, z, nu, kode, n) {
+        return Ok(result);
     }
 
-    let values = cyr
-        .into_iter()
-        .zip(cyi.into_iter())
-        .map(|(r, i)| Complex64::new(r, i))
-        .collect();
+    check_backend_range(z, nu, n)?;
+    let (result, nz, values) = backend_sequence(BesselKind::J, z, nu, kode, n);
+
+    if result != 0 && !(allow_accuracy_loss && result == 3) {
+        return Err(BesselError::amos("zbesj", result, z, Some(nu), kode, n));
+    }
 
     Ok(BesselResult {
         values,
         underflow_count: nz,
+        overflow_count: 0,
+        lost_digits: lost_digits(z, nu, n),
     })
 }
 
@@ -165,49 +782,68 @@ pub fn bessel_j(z: Complex64, nu: f64, kode: i32, n: usize) -> Result<BesselResu
 /// * `kode` - Scaling option (1: no scaling, 2: exp(-abs(Im(z))) scaling)
 /// * `n` - Number of function values to calculate
 pub fn bessel_y(z: Complex64, nu: f64, kode: i32, n: usize) -> Result<BesselResult, BesselError> {
+    check_max_n(n, DEFAULT_MAX_N)?;
+    bessel_y_impl(z, nu, kode, n, false)
+}
+
+/// Shared implementation of [`bessel_y`]; IERR=3 (loss of significance) is
+/// accepted as a successful return when `allow_accuracy_loss` is set.
+pub(crate) fn bessel_y_impl(
+    z: Complex64,
+    nu: f64,
+    kode: i32,
+    n: usize,
+    allow_accuracy_loss: bool,
+) -> Result<BesselResult, BesselError> {
     if n == 0 {
         return Err(BesselError::InvalidParameter(
             "n must be greater than 0".to_string(),
         ));
     }
+    validate_inputs(z, nu, kode)?;
+    check_amos_len(n)?;
+    #[cfg(feature = "self-check")]
+    if let Some(result) = self_check::checked(BesselKind::Y, z, nu, kode, n, allow_accuracy_loss) {
+        return result;
+    }
+    if let Some(result) = origin::sequence(BesselKind::Y, z, nu, n) {
+        return Ok(result);
+    }
+    if let Some(result) =
+        reflection::below_cut_sequence(BesselKind::Y, z, nu, kode, n, allow_accuracy_loss)
+    {
+        return result;
+    }
+    if nu < 0.0 {
+        return reflection::negative_order_sequence(
+            BesselKind::Y,
+            z,
+            nu,
+            kode,
+            n,
+            allow_accuracy_loss,
+        );
+    }
 
-    let mut cyr = vec![0.0; n];
-    let mut cyi = vec![0.0; n];
-    let mut cwrkr = vec![0.0; n];
-    let mut cwrki = vec![0.0; n];
-    let mut nz = 0i32;
-
-    let result = unsafe {
-        zbesy(
-            z.re as c_double,
-            z.im as c_double,
-            nu as c_double,
-            kode as c_int,
-            n as c_int,
-            cyr.as_mut_ptr(),
-            cyi.as_mut_ptr(),
-            &mut nz,
-            cwrkr.as_mut_ptr(),
-            cwrki.as_mut_ptr(),
-        )
-    };
-
-    if result != 0 {
-        return Err(BesselError::ComputationError(format!(
-            "zbesy error code: {}",
-            result
-        )));
+    if let Some(result) = recurrence::integer_sequence(BesselKind::Y, z, nu, kode, n) {
+        return Ok(result);
     }
+    if let Some(result) = direct::sequence(BesselKind::Y, z, nu, kode, n) {
+        return Ok(result);
+    }
+
+    check_backend_range(z, nu, n)?;
+    let (result, nz, values) = backend_sequence(BesselKind::Y, z, nu, kode, n);
 
-    let values = cyr
-        .into_iter()
-        .zip(cyi.into_iter())
-        .map(|(r, i)| Complex64::new(r, i))
-        .collect();
+    if result != 0 && !(allow_accuracy_loss && result == 3) {
+        return Err(BesselError::amos("zbesy", result, z, Some(nu), kode, n));
+    }
 
     Ok(BesselResult {
         values,
         underflow_count: nz,
+        overflow_count: 0,
+        lost_digits: lost_digits(z, nu, n),
     })
 }
 
@@ -219,45 +855,68 @@ pub fn bessel_y(z: Complex64, nu: f64, kode: i32, n: usize) -> Result<BesselResu
 /// * `kode` - Scaling option (1: no scaling, 2: exp(-abs(Re(z))) scaling)
 /// * `n` - Number of function values to calculate
 pub fn bessel_i(z: Complex64, nu: f64, kode: i32, n: usize) -> Result<BesselResult, BesselError> {
+    check_max_n(n, DEFAULT_MAX_N)?;
+    bessel_i_impl(z, nu, kode, n, false)
+}
+
+/// Shared implementation of [`bessel_i`]; IERR=3 (loss of significance) is
+/// accepted as a successful return when `allow_accuracy_loss` is set.
+pub(crate) fn bessel_i_impl(
+    z: Complex64,
+    nu: f64,
+    kode: i32,
+    n: usize,
+    allow_accuracy_loss: bool,
+) -> Result<BesselResult, BesselError> {
     if n == 0 {
         return Err(BesselError::InvalidParameter(
             "n must be greater than 0".to_string(),
         ));
     }
+    validate_inputs(z, nu, kode)?;
+    check_amos_len(n)?;
+    #[cfg(feature = "self-check")]
+    if let Some(result) = self_check::checked(BesselKind::I, z, nu, kode, n, allow_accuracy_loss) {
+        return result;
+    }
+    if let Some(result) = origin::sequence(BesselKind::I, z, nu, n) {
+        return Ok(result);
+    }
+    if let Some(result) =
+        reflection::below_cut_sequence(BesselKind::I, z, nu, kode, n, allow_accuracy_loss)
+    {
+        return result;
+    }
+    if nu < 0.0 {
+        return reflection::negative_order_sequence(
+            BesselKind::I,
+            z,
+            nu,
+            kode,
+            n,
+            allow_accuracy_loss,
+        );
+    }
 
-    let mut cyr = vec![0.0; n];
-    let mut cyi = vec![0.0; n];
-    let mut nz = 0i32;
-
-    let result = unsafe {
-        zbesi(
-            z.re as c_double,
-            z.im as c_double,
-            nu as c_double,
-            kode as c_int,
-            n as c_int,
-            cyr.as_mut_ptr(),
-            cyi.as_mut_ptr(),
-            &mut nz,
-        )
-    };
-
-    if result != 0 {
-        return Err(BesselError::ComputationError(format!(
-            "zbesi error code: {}",
-            result
-        )));
+    if let Some(result) = recurrence::integer_sequence(BesselKind::I, z, nu, kode, n) {
+        return Ok(result);
+    }
+    if let Some(result) = direct::sequence(BesselKind::I, z, nu, kode, n) {
+        return Ok(result);
     }
 
-    let values = cyr
-        .into_iter()
-        .zip(cyi.into_iter())
-        .map(|(r, i)| Complex64::new(r, i))
-        .collect();
+    check_backend_range(z, nu, n)?;
+    let (result, nz, values) = backend_sequence(BesselKind::I, z, nu, kode, n);
+
+    if result != 0 && !(allow_accuracy_loss && result == 3) {
+        return Err(BesselError::amos("zbesi", result, z, Some(nu), kode, n));
+    }
 
     Ok(BesselResult {
         values,
         underflow_count: nz,
+        overflow_count: 0,
+        lost_digits: lost_digits(z, nu, n),
     })
 }
 
@@ -269,45 +928,149 @@ pub fn bessel_i(z: Complex64, nu: f64, kode: i32, n: usize) -> Result<BesselResu
 /// * `kode` - Scaling option (1: no scaling, 2: exp(z) scaling)
 /// * `n` - Number of function values to calculate
 pub fn bessel_k(z: Complex64, nu: f64, kode: i32, n: usize) -> Result<BesselResult, BesselError> {
+    check_max_n(n, DEFAULT_MAX_N)?;
+    bessel_k_impl(z, nu, kode, n, false)
+}
+
+/// Shared implementation of [`bessel_k`]; IERR=3 (loss of significance) is
+/// accepted as a successful return when `allow_accuracy_loss` is set.
+pub(crate) fn bessel_k_impl(
+    z: Complex64,
+    nu: f64,
+    kode: i32,
+    n: usize,
+    allow_accuracy_loss: bool,
+) -> Result<BesselResult, BesselError> {
     if n == 0 {
         return Err(BesselError::InvalidParameter(
             "n must be greater than 0".to_string(),
         ));
     }
+    validate_inputs(z, nu, kode)?;
+    check_amos_len(n)?;
+    #[cfg(feature = "self-check")]
+    if let Some(result) = self_check::checked(BesselKind::K, z, nu, kode, n, allow_accuracy_loss) {
+        return result;
+    }
+    if let Some(result) = origin::sequence(BesselKind::K, z, nu, n) {
+        return Ok(result);
+    }
+    if let Some(result) =
+        reflection::below_cut_sequence(BesselKind::K, z, nu, kode, n, allow_accuracy_loss)
+    {
+        return result;
+    }
+    if nu < 0.0 {
+        return reflection::negative_order_sequence(
+            BesselKind::K,
+            z,
+            nu,
+            kode,
+            n,
+            allow_accuracy_loss,
+        );
+    }
 
-    let mut cyr = vec![0.0; n];
-    let mut cyi = vec![0.0; n];
-    let mut nz = 0i32;
-
-    let result = unsafe {
-        zbesk(
-            z.re as c_double,
-            z.im as c_double,
-            nu as c_double,
-            kode as c_int,
-            n as c_int,
-            cyr.as_mut_ptr(),
-            cyi.as_mut_ptr(),
-            &mut nz,
-        )
-    };
+    if let Some(result) = recurrence::integer_sequence(BesselKind::K, z, nu, kode, n) {
+        return Ok(result);
+    }
+    if let Some(result) = direct::sequence(BesselKind::K, z, nu, kode, n) {
+        return Ok(result);
+    }
 
-    if result != 0 {
-        return Err(BesselError::ComputationError(format!(
-            "zbesk error code: {}",
-            result
+    check_backend_range(z, nu, n)?;
+    let (result, nz, values) = backend_sequence(BesselKind::K, z, nu, kode, n);
+
+    if result != 0 && !(allow_accuracy_loss && result == 3) {
+        return Err(BesselError::amos("zbesk", result, z, Some(nu), kode, n));
+    }
+
+    Ok(BesselResult {
+        values,
+        underflow_count: nz,
+        overflow_count: 0,
+        lost_digits: lost_digits(z, nu, n),
+    })
+}
+
+/// Calculate complex Hankel function H^(m)_ν(z)
+///
+/// # Parameters
+/// * `z` - Complex argument (nonzero)
+/// * `nu` - Order (real number)
+/// * `kode` - Scaling option (1: no scaling, 2: exp(-(3-2m)*i*z) scaling)
+/// * `m` - Kind of Hankel function (1: H^(1), 2: H^(2))
+/// * `n` - Number of function values to calculate
+pub fn bessel_h(
+    z: Complex64,
+    nu: f64,
+    kode: i32,
+    m: i32,
+    n: usize,
+) -> Result<BesselResult, BesselError> {
+    check_max_n(n, DEFAULT_MAX_N)?;
+    bessel_h_impl(z, nu, kode, m, n, false)
+}
+
+/// Shared implementation of [`bessel_h`]; IERR=3 (loss of significance) is
+/// accepted as a successful return when `allow_accuracy_loss` is set.
+pub(crate) fn bessel_h_impl(
+    z: Complex64,
+    nu: f64,
+    kode: i32,
+    m: i32,
+    n: usize,
+    allow_accuracy_loss: bool,
+) -> Result<BesselResult, BesselError> {
+    if n == 0 {
+        return Err(BesselError::InvalidParameter(
+            "n must be greater than 0".to_string(),
+        ));
+    }
+    validate_inputs(z, nu, kode)?;
+    check_amos_len(n)?;
+    if m != 1 && m != 2 {
+        return Err(BesselError::InvalidParameter(format!(
+            "m must be 1 or 2, got {}",
+            m
         )));
     }
+    let kind = if m == 1 {
+        BesselKind::H1
+    } else {
+        BesselKind::H2
+    };
+    #[cfg(feature = "self-check")]
+    if let Some(result) = self_check::checked(kind, z, nu, kode, n, allow_accuracy_loss) {
+        return result;
+    }
+    if let Some(result) = origin::sequence(kind, z, nu, n) {
+        return Ok(result);
+    }
+    if let Some(result) = reflection::below_cut_sequence(kind, z, nu, kode, n, allow_accuracy_loss)
+    {
+        return result;
+    }
+    if nu < 0.0 {
+        return reflection::negative_order_sequence(kind, z, nu, kode, n, allow_accuracy_loss);
+    }
 
-    let values = cyr
-        .into_iter()
-        .zip(cyi.into_iter())
-        .map(|(r, i)| Complex64::new(r, i))
-        .collect();
+    if let Some(result) = direct::sequence(kind, z, nu, kode, n) {
+        return Ok(result);
+    }
+
+    check_backend_range(z, nu, n)?;
+    let (result, nz, values) = backend_sequence(kind, z, nu, kode, n);
+
+    if result != 0 && !(allow_accuracy_loss && result == 3) {
+        return Err(BesselError::amos("zbesh", result, z, Some(nu), kode, n));
+    }
 
     Ok(BesselResult {
         values,
         underflow_count: nz,
+        overflow_count: 0,
+        lost_digits: lost_digits(z, nu, n),
     })
 }
 
@@ -317,31 +1080,22 @@ pub fn bessel_k(z: Complex64, nu: f64, kode: i32, n: usize) -> Result<BesselResu
 /// * `z` - Complex argument
 /// * `id` - Differentiation option (0: Ai(z), 1: Ai'(z))
 /// * `kode` - Scaling option (1: no scaling, 2: exp(zeta) scaling where zeta=(2/3)*z^(3/2))
-pub fn airy_ai(z: Complex64, id: i32, kode: i32) -> Result<Complex64, BesselError> {
-    let mut air = 0.0;
-    let mut aii = 0.0;
-    let mut nz = 0i32;
-
-    let result = unsafe {
-        zairy(
-            z.re as c_double,
-            z.im as c_double,
-            id as c_int,
-            kode as c_int,
-            &mut air,
-            &mut aii,
-            &mut nz,
-        )
-    };
+///
+/// Ai(z) and Ai'(z) decay exponentially for large |z| with |arg(z)| < π/3, so the
+/// value may underflow to zero; this is reported in `underflow_count`.
+pub fn airy_ai(z: Complex64, id: i32, kode: i32) -> Result<AiryResult, BesselError> {
+    validate_airy_inputs(z, id, kode)?;
+
+    let (result, value, nz) = ActiveBackend::airy_ai(z, id, kode);
 
     if result != 0 {
-        return Err(BesselError::ComputationError(format!(
-            "zairy error code: {}",
-            result
-        )));
+        return Err(BesselError::amos("zairy", result, z, None, kode, 1));
     }
 
-    Ok(Complex64::new(air, aii))
+    Ok(AiryResult {
+        value,
+        underflow_count: nz,
+    })
 }
 
 /// Calculate complex Airy function Bi(z)
@@ -351,34 +1105,55 @@ pub fn airy_ai(z: Complex64, id: i32, kode: i32) -> Result<Complex64, BesselErro
 /// * `id` - Differentiation option (0: Bi(z), 1: Bi'(z))
 /// * `kode` - Scaling option (1: no scaling, 2: exp(-abs(Re(zeta))) scaling where zeta=(2/3)*z^(3/2))
 pub fn airy_bi(z: Complex64, id: i32, kode: i32) -> Result<Complex64, BesselError> {
-    let mut bir = 0.0;
-    let mut bii = 0.0;
-
-    let result = unsafe {
-        zbiry(
-            z.re as c_double,
-            z.im as c_double,
-            id as c_int,
-            kode as c_int,
-            &mut bir,
-            &mut bii,
-        )
-    };
+    validate_airy_inputs(z, id, kode)?;
+
+    let (result, value) = ActiveBackend::airy_bi(z, id, kode);
 
     if result != 0 {
-        return Err(BesselError::ComputationError(format!(
-            "zbiry error code: {}",
-            result
-        )));
+        return Err(BesselError::amos("zbiry", result, z, None, kode, 1));
     }
 
-    Ok(Complex64::new(bir, bii))
+    Ok(value)
 }
 
 // ========================================
 // Simple single-value calculation functions
 // ========================================
 
+/// Evaluate a single value without heap allocation
+///
+/// Same validation and errors as the sequence functions with n = 1, but the
+/// output lives on the stack. Negative orders and arguments below the branch
+/// cut still go through the reflection formulas; half-integer orders, small
+/// and large arguments are computed in Rust without a backend call.
+pub(crate) fn single_value(
+    kind: BesselKind,
+    z: Complex64,
+    nu: f64,
+    kode: i32,
+) -> Result<Complex64, BesselError> {
+    validate_inputs(z, nu, kode)?;
+    #[cfg(feature = "self-check")]
+    if let Some(result) = self_check::checked(kind, z, nu, kode, 1, false) {
+        return result.map(|result| result.values[0]);
+    }
+    if nu < 0.0 || reflection::below_cut(z) {
+        let result = kind.eval_impl(z, nu, kode, 1, false)?;
+        return Ok(result.values[0]);
+    }
+    if let Some(value) = direct::value(kind, z, nu, kode) {
+        return Ok(value);
+    }
+    check_backend_range(z, nu, 1)?;
+    let (mut re, mut im) = ([0.0], [0.0]);
+    let (ierr, _) = ActiveBackend::bessel(kind, z, nu, kode, &mut re, &mut im);
+    if ierr != 0 {
+        return Err(into_slice::error_for(kind, ierr, z, nu, kode, 1));
+    }
+    clear_real_axis(kind, z, &mut im);
+    Ok(Complex64::new(re[0], im[0]))
+}
+
 /// Calculate Bessel function J_ν(z) (single value, no scaling)
 ///
 /// # Parameters
@@ -388,9 +1163,9 @@ pub fn airy_bi(z: Complex64, id: i32, kode: i32) -> Result<Complex64, BesselErro
 /// # Returns
 /// Complex value of J_ν(z)
 #[allow(non_snake_case)]
-pub fn J(nu: f64, z: Complex64) -> Result<Complex64, BesselError> {
-    let result = bessel_j(z, nu, 1, 1)?;
-    Ok(result.values[0])
+pub fn J<T: BesselFloat>(nu: T, z: Complex<T>) -> Result<Complex<T>, BesselError> {
+    let value = single_value(BesselKind::J, to_complex64(z), nu.into_f64(), 1)?;
+    Ok(from_complex64(value))
 }
 
 /// Calculate Bessel function Y_ν(z) (single value, no scaling)
@@ -402,9 +1177,9 @@ pub fn J(nu: f64, z: Complex64) -> Result<Complex64, BesselError> {
 /// # Returns
 /// Complex value of Y_ν(z)
 #[allow(non_snake_case)]
-pub fn Y(nu: f64, z: Complex64) -> Result<Complex64, BesselError> {
-    let result = bessel_y(z, nu, 1, 1)?;
-    Ok(result.values[0])
+pub fn Y<T: BesselFloat>(nu: T, z: Complex<T>) -> Result<Complex<T>, BesselError> {
+    let value = single_value(BesselKind::Y, to_complex64(z), nu.into_f64(), 1)?;
+    Ok(from_complex64(value))
 }
 
 /// Calculate modified Bessel function I_ν(z) (single value, no scaling)
@@ -416,9 +1191,9 @@ pub fn Y(nu: f64, z: Complex64) -> Result<Complex64, BesselError> {
 /// # Returns
 /// Complex value of I_ν(z)
 #[allow(non_snake_case)]
-pub fn I(nu: f64, z: Complex64) -> Result<Complex64, BesselError> {
-    let result = bessel_i(z, nu, 1, 1)?;
-    Ok(result.values[0])
+pub fn I<T: BesselFloat>(nu: T, z: Complex<T>) -> Result<Complex<T>, BesselError> {
+    let value = single_value(BesselKind::I, to_complex64(z), nu.into_f64(), 1)?;
+    Ok(from_complex64(value))
 }
 
 /// Calculate modified Bessel function K_ν(z) (single value, no scaling)
@@ -430,9 +1205,9 @@ pub fn I(nu: f64, z: Complex64) -> Result<Complex64, BesselError> {
 /// # Returns
 /// Complex value of K_ν(z)
 #[allow(non_snake_case)]
-pub fn K(nu: f64, z: Complex64) -> Result<Complex64, BesselError> {
-    let result = bessel_k(z, nu, 1, 1)?;
-    Ok(result.values[0])
+pub fn K<T: BesselFloat>(nu: T, z: Complex<T>) -> Result<Complex<T>, BesselError> {
+    let value = single_value(BesselKind::K, to_complex64(z), nu.into_f64(), 1)?;
+    Ok(from_complex64(value))
 }
 
 /// Calculate Airy function Ai(z) (no scaling)
@@ -443,8 +1218,8 @@ pub fn K(nu: f64, z: Complex64) -> Result<Complex64, BesselError> {
 /// # Returns
 /// Complex value of Ai(z)
 #[allow(non_snake_case)]
-pub fn Ai(z: Complex64) -> Result<Complex64, BesselError> {
-    airy_ai(z, 0, 1)
+pub fn Ai<T: BesselFloat>(z: Complex<T>) -> Result<Complex<T>, BesselError> {
+    Ok(from_complex64(airy_ai(to_complex64(z), 0, 1)?.value))
 }
 
 /// Calculate Airy function Bi(z) (no scaling)
@@ -455,8 +1230,8 @@ pub fn Ai(z: Complex64) -> Result<Complex64, BesselError> {
 /// # Returns
 /// Complex value of Bi(z)
 #[allow(non_snake_case)]
-pub fn Bi(z: Complex64) -> Result<Complex64, BesselError> {
-    airy_bi(z, 0, 1)
+pub fn Bi<T: BesselFloat>(z: Complex<T>) -> Result<Complex<T>, BesselError> {
+    airy_bi(to_complex64(z), 0, 1).map(from_complex64)
 }
 
 // ========================================
@@ -472,9 +1247,9 @@ pub fn Bi(z: Complex64) -> Result<Complex64, BesselError> {
 /// # Returns
 /// Complex value of J_ν(z) with exp(-abs(Im(z))) scaling
 #[allow(non_snake_case)]
-pub fn J_scaled(nu: f64, z: Complex64) -> Result<Complex64, BesselError> {
-    let result = bessel_j(z, nu, 2, 1)?;
-    Ok(result.values[0])
+pub fn J_scaled<T: BesselFloat>(nu: T, z: Complex<T>) -> Result<Complex<T>, BesselError> {
+    let value = single_value(BesselKind::J, to_complex64(z), nu.into_f64(), 2)?;
+    Ok(from_complex64(value))
 }
 
 /// Calculate Bessel function Y_ν(z) with scaling (single value)
@@ -486,9 +1261,9 @@ pub fn J_scaled(nu: f64, z: Complex64) -> Result<Complex64, BesselError> {
 /// # Returns
 /// Complex value of Y_ν(z) with exp(-abs(Im(z))) scaling
 #[allow(non_snake_case)]
-pub fn Y_scaled(nu: f64, z: Complex64) -> Result<Complex64, BesselError> {
-    let result = bessel_y(z, nu, 2, 1)?;
-    Ok(result.values[0])
+pub fn Y_scaled<T: BesselFloat>(nu: T, z: Complex<T>) -> Result<Complex<T>, BesselError> {
+    let value = single_value(BesselKind::Y, to_complex64(z), nu.into_f64(), 2)?;
+    Ok(from_complex64(value))
 }
 
 /// Calculate modified Bessel function I_ν(z) with scaling (single value)
@@ -500,9 +1275,9 @@ pub fn Y_scaled(nu: f64, z: Complex64) -> Result<Complex64, BesselError> {
 /// # Returns
 /// Complex value of I_ν(z) with exp(-abs(Re(z))) scaling
 #[allow(non_snake_case)]
-pub fn I_scaled(nu: f64, z: Complex64) -> Result<Complex64, BesselError> {
-    let result = bessel_i(z, nu, 2, 1)?;
-    Ok(result.values[0])
+pub fn I_scaled<T: BesselFloat>(nu: T, z: Complex<T>) -> Result<Complex<T>, BesselError> {
+    let value = single_value(BesselKind::I, to_complex64(z), nu.into_f64(), 2)?;
+    Ok(from_complex64(value))
 }
 
 /// Calculate modified Bessel function K_ν(z) with scaling (single value)
@@ -514,9 +1289,9 @@ pub fn I_scaled(nu: f64, z: Complex64) -> Result<Complex64, BesselError> {
 /// # Returns
 /// Complex value of K_ν(z) with exp(z) scaling
 #[allow(non_snake_case)]
-pub fn K_scaled(nu: f64, z: Complex64) -> Result<Complex64, BesselError> {
-    let result = bessel_k(z, nu, 2, 1)?;
-    Ok(result.values[0])
+pub fn K_scaled<T: BesselFloat>(nu: T, z: Complex<T>) -> Result<Complex<T>, BesselError> {
+    let value = single_value(BesselKind::K, to_complex64(z), nu.into_f64(), 2)?;
+    Ok(from_complex64(value))
 }
 
 /// Calculate Airy function Ai(z) with scaling
@@ -527,8 +1302,8 @@ pub fn K_scaled(nu: f64, z: Complex64) -> Result<Complex64, BesselError> {
 /// # Returns
 /// Complex value of Ai(z) with exp(zeta) scaling where zeta=(2/3)*z^(3/2)
 #[allow(non_snake_case)]
-pub fn Ai_scaled(z: Complex64) -> Result<Complex64, BesselError> {
-    airy_ai(z, 0, 2)
+pub fn Ai_scaled<T: BesselFloat>(z: Complex<T>) -> Result<Complex<T>, BesselError> {
+    Ok(from_complex64(airy_ai(to_complex64(z), 0, 2)?.value))
 }
 
 /// Calculate Airy function Bi(z) with scaling
@@ -539,14 +1314,350 @@ pub fn Ai_scaled(z: Complex64) -> Result<Complex64, BesselError> {
 /// # Returns
 /// Complex value of Bi(z) with exp(-abs(Re(zeta))) scaling where zeta=(2/3)*z^(3/2)
 #[allow(non_snake_case)]
-pub fn Bi_scaled(z: Complex64) -> Result<Complex64, BesselError> {
-    airy_bi(z, 0, 2)
+pub fn Bi_scaled<T: BesselFloat>(z: Complex<T>) -> Result<Complex<T>, BesselError> {
+    airy_bi(to_complex64(z), 0, 2).map(from_complex64)
+}
+
+// ========================================
+// Integer-order single-value calculation functions
+// ========================================
+
+/// Sign (-1)^n for an integer order
+fn integer_order_sign(n: i32) -> f64 {
+    if n % 2 == 0 {
+        1.0
+    } else {
+        -1.0
+    }
+}
+
+/// Calculate Bessel function J_n(z) for integer order n (single value, no scaling)
+///
+/// Negative orders use J_{-n}(z) = (-1)^n J_n(z).
+///
+/// # Parameters
+/// * `n` - Order (integer, may be negative)
+/// * `z` - Complex argument
+///
+/// # Returns
+/// Complex value of J_n(z)
+#[allow(non_snake_case)]
+pub fn Jn<T: BesselFloat>(n: i32, z: Complex<T>) -> Result<Complex<T>, BesselError> {
+    let value = J(n.unsigned_abs() as f64, to_complex64(z))?;
+    Ok(from_complex64(if n < 0 {
+        value * integer_order_sign(n)
+    } else {
+        value
+    }))
+}
+
+/// Calculate Bessel function Y_n(z) for integer order n (single value, no scaling)
+///
+/// Negative orders use Y_{-n}(z) = (-1)^n Y_n(z).
+///
+/// # Parameters
+/// * `n` - Order (integer, may be negative)
+/// * `z` - Complex argument
+///
+/// # Returns
+/// Complex value of Y_n(z)
+#[allow(non_snake_case)]
+pub fn Yn<T: BesselFloat>(n: i32, z: Complex<T>) -> Result<Complex<T>, BesselError> {
+    let value = Y(n.unsigned_abs() as f64, to_complex64(z))?;
+    Ok(from_complex64(if n < 0 {
+        value * integer_order_sign(n)
+    } else {
+        value
+    }))
+}
+
+/// Calculate modified Bessel function I_n(z) for integer order n (single value, no scaling)
+///
+/// Negative orders use I_{-n}(z) = I_n(z).
+///
+/// # Parameters
+/// * `n` - Order (integer, may be negative)
+/// * `z` - Complex argument
+///
+/// # Returns
+/// Complex value of I_n(z)
+#[allow(non_snake_case)]
+pub fn In<T: BesselFloat>(n: i32, z: Complex<T>) -> Result<Complex<T>, BesselError> {
+    I(T::round_from_f64(n.unsigned_abs() as f64), z)
+}
+
+/// Calculate modified Bessel function K_n(z) for integer order n (single value, no scaling)
+///
+/// Negative orders use K_{-n}(z) = K_n(z).
+///
+/// # Parameters
+/// * `n` - Order (integer, may be negative)
+/// * `z` - Complex argument
+///
+/// # Returns
+/// Complex value of K_n(z)
+#[allow(non_snake_case)]
+pub fn Kn<T: BesselFloat>(n: i32, z: Complex<T>) -> Result<Complex<T>, BesselError> {
+    K(T::round_from_f64(n.unsigned_abs() as f64), z)
+}
+
+// ========================================
+// NaN-propagating single-value calculation functions
+// ========================================
+
+/// NaN + iNaN in the requested precision
+fn complex_nan<T: BesselFloat>() -> Complex<T> {
+    Complex::new(T::nan(), T::nan())
+}
+
+/// Calculate Bessel function J_ν(z), returning NaN + iNaN on any failure
+///
+/// Same as [`J`] but without a `Result`, for array pipelines that
+/// detect errors downstream.
+#[allow(non_snake_case)]
+pub fn J_or_nan<T: BesselFloat>(nu: T, z: Complex<T>) -> Complex<T> {
+    J(nu, z).unwrap_or_else(|_| complex_nan())
+}
+
+/// Calculate Bessel function Y_ν(z), returning NaN + iNaN on any failure
+///
+/// Same as [`Y`] but without a `Result`, for array pipelines that
+/// detect errors downstream.
+#[allow(non_snake_case)]
+pub fn Y_or_nan<T: BesselFloat>(nu: T, z: Complex<T>) -> Complex<T> {
+    Y(nu, z).unwrap_or_else(|_| complex_nan())
+}
+
+/// Calculate modified Bessel function I_ν(z), returning NaN + iNaN on any failure
+///
+/// Same as [`I`] but without a `Result`, for array pipelines that
+/// detect errors downstream.
+#[allow(non_snake_case)]
+pub fn I_or_nan<T: BesselFloat>(nu: T, z: Complex<T>) -> Complex<T> {
+    I(nu, z).unwrap_or_else(|_| complex_nan())
+}
+
+/// Calculate modified Bessel function K_ν(z), returning NaN + iNaN on any failure
+///
+/// Same as [`K`] but without a `Result`, for array pipelines that
+/// detect errors downstream.
+#[allow(non_snake_case)]
+pub fn K_or_nan<T: BesselFloat>(nu: T, z: Complex<T>) -> Complex<T> {
+    K(nu, z).unwrap_or_else(|_| complex_nan())
+}
+
+/// Calculate Airy function Ai(z), returning NaN + iNaN on any failure
+///
+/// Same as [`Ai`] but without a `Result`, for array pipelines that
+/// detect errors downstream.
+#[allow(non_snake_case)]
+pub fn Ai_or_nan<T: BesselFloat>(z: Complex<T>) -> Complex<T> {
+    Ai(z).unwrap_or_else(|_| complex_nan())
+}
+
+/// Calculate Airy function Bi(z), returning NaN + iNaN on any failure
+///
+/// Same as [`Bi`] but without a `Result`, for array pipelines that
+/// detect errors downstream.
+#[allow(non_snake_case)]
+pub fn Bi_or_nan<T: BesselFloat>(z: Complex<T>) -> Complex<T> {
+    Bi(z).unwrap_or_else(|_| complex_nan())
+}
+
+/// Calculate scaled Bessel function J_ν(z), returning NaN + iNaN on any failure
+///
+/// See [`J_scaled`] for the scaling factor.
+#[allow(non_snake_case)]
+pub fn J_scaled_or_nan<T: BesselFloat>(nu: T, z: Complex<T>) -> Complex<T> {
+    J_scaled(nu, z).unwrap_or_else(|_| complex_nan())
+}
+
+/// Calculate scaled Bessel function Y_ν(z), returning NaN + iNaN on any failure
+///
+/// See [`Y_scaled`] for the scaling factor.
+#[allow(non_snake_case)]
+pub fn Y_scaled_or_nan<T: BesselFloat>(nu: T, z: Complex<T>) -> Complex<T> {
+    Y_scaled(nu, z).unwrap_or_else(|_| complex_nan())
+}
+
+/// Calculate scaled modified Bessel function I_ν(z), returning NaN + iNaN on any failure
+///
+/// See [`I_scaled`] for the scaling factor.
+#[allow(non_snake_case)]
+pub fn I_scaled_or_nan<T: BesselFloat>(nu: T, z: Complex<T>) -> Complex<T> {
+    I_scaled(nu, z).unwrap_or_else(|_| complex_nan())
+}
+
+/// Calculate scaled modified Bessel function K_ν(z), returning NaN + iNaN on any failure
+///
+/// See [`K_scaled`] for the scaling factor.
+#[allow(non_snake_case)]
+pub fn K_scaled_or_nan<T: BesselFloat>(nu: T, z: Complex<T>) -> Complex<T> {
+    K_scaled(nu, z).unwrap_or_else(|_| complex_nan())
+}
+
+/// Calculate scaled Airy function Ai(z), returning NaN + iNaN on any failure
+///
+/// See [`Ai_scaled`] for the scaling factor.
+#[allow(non_snake_case)]
+pub fn Ai_scaled_or_nan<T: BesselFloat>(z: Complex<T>) -> Complex<T> {
+    Ai_scaled(z).unwrap_or_else(|_| complex_nan())
+}
+
+/// Calculate scaled Airy function Bi(z), returning NaN + iNaN on any failure
+///
+/// See [`Bi_scaled`] for the scaling factor.
+#[allow(non_snake_case)]
+pub fn Bi_scaled_or_nan<T: BesselFloat>(z: Complex<T>) -> Complex<T> {
+    Bi_scaled(z).unwrap_or_else(|_| complex_nan())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_or_nan_variants() {
+        let z = Complex64::new(1.5, 0.5);
+        assert_eq!(J_or_nan(0.5, z), J(0.5, z).unwrap());
+        assert_eq!(Bi_scaled_or_nan(z), Bi_scaled(z).unwrap());
+
+        // A NaN argument is an input error
+        let k = K_or_nan(0.0, Complex64::new(f64::NAN, 0.0));
+        assert!(k.re.is_nan() && k.im.is_nan());
+
+        // I_0(1000) overflows, in f32 as well
+        let i = I_or_nan(0.0f32, Complex::new(1000.0f32, 0.0));
+        assert!(i.re.is_nan() && i.im.is_nan());
+    }
+
+    #[test]
+    fn test_invalid_codes_and_non_finite_inputs() {
+        let z = Complex64::new(1.0, 1.0);
+        let message = |e: BesselError| match e {
+            BesselError::InvalidParameter(msg) => msg,
+            other => panic!("expected InvalidParameter, got {:?}", other),
+        };
+
+        assert_eq!(
+            message(bessel_j(z, 0.0, 3, 1).unwrap_err()),
+            "kode must be 1 or 2, got 3"
+        );
+        assert_eq!(
+            message(bessel_h(z, 0.0, 1, 0, 1).unwrap_err()),
+            "m must be 1 or 2, got 0"
+        );
+        assert_eq!(
+            message(airy_ai(z, 2, 1).unwrap_err()),
+            "id must be 0 or 1, got 2"
+        );
+        assert_eq!(
+            message(airy_bi(z, 0, 0).unwrap_err()),
+            "kode must be 1 or 2, got 0"
+        );
+        assert_eq!(
+            message(bessel_k(z, f64::NAN, 1, 1).unwrap_err()),
+            "nu must be finite, got NaN"
+        );
+        assert!(
+            message(bessel_y(Complex64::new(1.0, f64::INFINITY), 0.0, 1, 1).unwrap_err())
+                .starts_with("z must be finite")
+        );
+        assert!(
+            message(bessel_i(Complex64::new(f64::NAN, 0.0), -0.5, 1, 2).unwrap_err())
+                .starts_with("z must be finite")
+        );
+    }
+
+    #[test]
+    fn test_real_axis_values_are_real() {
+        // Amos leaves imaginary parts of order 1e-18 in J_8(4)
+        let z = Complex64::new(4.0, 0.0);
+        let mut out = [Complex64::new(1.0, 1.0); 3];
+        bessel_j_into(z, 8.0, Scaling::Unscaled, &mut out).unwrap();
+        let mut ws = Workspace::new();
+        let values = bessel_y_with_workspace(z, 7.5, 1, 3, &mut ws)
+            .unwrap()
+            .to_vec();
+        for value in out.iter().chain(&values) {
+            assert_eq!(value.im, 0.0);
+        }
+        assert_eq!(fast::J_unchecked(8.0, z).im, 0.0);
+        assert_eq!(K_scaled(8.3, z).unwrap().im, 0.0);
+        // As do the uniform expansions that take over at huge orders
+        assert_eq!(J(1e5, Complex64::new(2e5, 0.0)).unwrap().im, 0.0);
+        // The Hankel functions are complex there
+        assert!(bessel_h(z, 8.0, 1, 1, 1).unwrap().values[0].im != 0.0);
+    }
+
+    #[test]
+    fn test_error_introspection() {
+        let overflow = bessel_i(Complex64::new(1000.0, 0.0), 0.0, 1, 1).unwrap_err();
+        assert_eq!(overflow.ierr(), Some(2));
+        assert!(overflow.is_overflow() && !overflow.is_accuracy_loss());
+        assert_eq!(
+            overflow.to_string(),
+            "Computation error: zbesi error code: 2 (z = 1000+0i, nu = 0, kode = 1, n = 1)"
+        );
+        assert!(bessel_i(Complex64::new(1000.0, 0.0), 0.0, 2, 1).is_ok());
+
+        assert_eq!(
+            overflow,
+            BesselError::ComputationError {
+                routine: "zbesi".to_string(),
+                ierr: 2,
+                z: Complex64::new(1000.0, 0.0),
+                nu: Some(0.0),
+                kode: 1,
+                n: 1,
+            }
+        );
+
+        let rejected = bessel_j(Complex64::new(1.0, 0.0), 0.0, 3, 1).unwrap_err();
+        assert_eq!(rejected.ierr(), None);
+        assert!(rejected.is_invalid_input());
+
+        let beyond = J(2.0 * MAX_ORDER, Complex64::new(1.0, 0.0)).unwrap_err();
+        assert!(beyond.is_out_of_validated_range() && beyond.is_accuracy_loss());
+        assert_eq!(beyond.ierr(), None);
+    }
+
+    #[test]
+    fn test_single_value_matches_sequence() {
+        let z = Complex64::new(0.7, -1.2);
+        for kind in BesselKind::ALL {
+            for &nu in &[0.0, 2.5, -1.25] {
+                for kode in 1..=2 {
+                    let expected = kind.eval_impl(z, nu, kode, 1, false).unwrap().values[0];
+                    assert_eq!(single_value(kind, z, nu, kode).unwrap(), expected);
+                }
+            }
+        }
+        let zero = Complex64::new(0.0, 0.0);
+        assert_eq!(
+            single_value(BesselKind::K, zero, 0.0, 1).unwrap(),
+            bessel_k(zero, 0.0, 1, 1).unwrap().values[0]
+        );
+    }
+
+    #[test]
+    fn test_sequence_length_guard() {
+        let z = Complex64::new(1.0, 1.0);
+        assert!(matches!(
+            bessel_j(z, 0.0, 1, usize::MAX),
+            Err(BesselError::InvalidParameter(_))
+        ));
+        assert!(bessel_k(z, 0.0, 1, DEFAULT_MAX_N + 1).is_err());
+
+        let options = BesselOptions {
+            max_n: usize::MAX,
+            n: i32::MAX as usize + 1,
+            ..Default::default()
+        };
+        let message = bessel_y_opt(z, 0.0, &options).unwrap_err().to_string();
+        assert!(message.contains("Amos limit"), "{}", message);
+    }
+
     // Simple function tests
     #[test]
     fn test_simple_j() {
@@ -624,102 +1735,6 @@ mod tests {
         assert!(diff < 1e-10, "Bi test failed: diff = {}", diff);
     }
 
-    #[test]
-    fn test_j_scaling_consistency() {
-        let z = Complex64::new(-100.0, 200.0);
-        let nu = 1.0;
-
-        // Calculate using regular J function
-        let j_regular = J(nu, z).unwrap();
-
-        // Calculate using J_scaled function
-        let j_scaled = J_scaled(nu, z).unwrap();
-
-        // For J functions, the scaling factor is exp(-abs(Im(z)))
-        let scale_factor = (-z.im.abs()).exp();
-        let j_regular_scaled = j_regular * scale_factor;
-
-        // Check if J_scaled result matches J result multiplied by scale factor
-        let diff = (j_scaled - j_regular_scaled).norm();
-        assert!(
-            diff < 1e-10,
-            "J scaling consistency failed: diff = {}",
-            diff
-        );
-    }
-
-    #[test]
-    fn test_y_scaling_consistency() {
-        let z = Complex64::new(200.0, -150.0);
-        let nu = 1.0;
-
-        // Calculate using regular Y function
-        let y_regular = Y(nu, z).unwrap();
-
-        // Calculate using Y_scaled function
-        let y_scaled = Y_scaled(nu, z).unwrap();
-
-        // For Y functions, the scaling factor is exp(-abs(Im(z)))
-        let scale_factor = (-z.im.abs()).exp();
-        let y_regular_scaled = y_regular * scale_factor;
-
-        // Check if Y_scaled result matches Y result multiplied by scale factor
-        let diff = (y_scaled - y_regular_scaled).norm();
-        assert!(
-            diff < 1e-10,
-            "Y scaling consistency failed: diff = {}",
-            diff
-        );
-    }
-
-    #[test]
-    fn test_i_scaling_consistency() {
-        let z = Complex64::new(200.0, -100.0);
-        let nu = 1.0;
-
-        // Calculate using regular I function
-        let i_regular = I(nu, z).unwrap();
-
-        // Calculate using I_scaled function
-        let i_scaled = I_scaled(nu, z).unwrap();
-
-        // For I functions, the scaling factor is exp(-abs(Re(z)))
-        let scale_factor = (-z.re.abs()).exp();
-        let i_regular_scaled = i_regular * scale_factor;
-
-        // Check if I_scaled result matches I result multiplied by scale factor
-        let diff = (i_scaled - i_regular_scaled).norm();
-        assert!(
-            diff < 1e-10,
-            "I scaling consistency failed: diff = {}",
-            diff
-        );
-    }
-
-    #[test]
-    fn test_k_scaling_consistency() {
-        let z = Complex64::new(100.0, -50.0);
-        let nu = 1.0;
-
-        // Calculate using regular K function
-        let k_regular = K(nu, z).unwrap();
-
-        // Calculate using K_scaled function
-        let k_scaled = K_scaled(nu, z).unwrap();
-
-        // For K functions, the scaling factor is exp(z)
-        let scale_factor = z.exp();
-        let k_regular_scaled = k_regular * scale_factor;
-
-        // Check if K_scaled result matches K result multiplied by scale factor
-        let diff = (k_scaled - k_regular_scaled).norm();
-        assert!(
-            diff < 1e-10,
-            "K scaling consistency failed: diff = {}",
-            diff
-        );
-    }
-
     #[test]
     fn test_ai_scaling_consistency() {
         let z = Complex64::new(100.0, -50.0);
@@ -769,4 +1784,90 @@ mod tests {
             diff
         );
     }
+
+    #[test]
+    fn test_airy_ai_underflow_count() {
+        // Ai(z) is far below the smallest f64 for large positive real z
+        let z = Complex64::new(1000.0, 0.0);
+        let result = airy_ai(z, 0, 1).unwrap();
+        assert_eq!(result.underflow_count, 1);
+        assert_eq!(result.value, Complex64::new(0.0, 0.0));
+
+        // The scaled value is representable and does not underflow
+        let scaled = airy_ai(z, 0, 2).unwrap();
+        assert_eq!(scaled.underflow_count, 0);
+        assert!(scaled.value.norm() > 0.0);
+    }
+
+    #[test]
+    fn test_integer_order_symmetry() {
+        let z = Complex64::new(2.0, -1.0);
+
+        assert_eq!(Jn(3, z).unwrap(), J(3.0, z).unwrap());
+        assert_eq!(Jn(-3, z).unwrap(), -J(3.0, z).unwrap());
+        assert_eq!(Jn(-4, z).unwrap(), J(4.0, z).unwrap());
+        assert_eq!(Yn(-3, z).unwrap(), -Y(3.0, z).unwrap());
+        assert_eq!(In(-5, z).unwrap(), I(5.0, z).unwrap());
+        assert_eq!(Kn(-2, z).unwrap(), K(2.0, z).unwrap());
+    }
+
+    #[test]
+    fn test_bessel_result_as_slice() {
+        let z = Complex64::new(2.0, 1.0);
+        let result = bessel_j(z, 0.0, 1, 3).unwrap();
+
+        assert_eq!(result.len(), 3);
+        assert!(!result.is_empty());
+        assert_eq!(result[1], result.values[1]);
+        assert_eq!(result.first(), result.values.first());
+
+        let borrowed: Vec<Complex64> = (&result).into_iter().copied().collect();
+        let owned: Vec<Complex64> = result.clone().into_iter().collect();
+        assert_eq!(borrowed, owned);
+        assert_eq!(owned, result.values);
+    }
+
+    #[test]
+    fn test_lost_digits() {
+        let small = bessel_j(Complex64::new(0.6, 0.8), 0.0, 1, 2).unwrap();
+        assert_eq!(small.lost_digits, 0.0);
+        // Governed by the largest order of the sequence, or by |z|
+        let orders = bessel_i(Complex64::new(0.5, 0.0), 998.0, 2, 3).unwrap();
+        assert!((orders.lost_digits - 3.0).abs() < 1e-12);
+        let negative = bessel_y(Complex64::new(6.0, 8.0), -10.3, 1, 2).unwrap();
+        assert!((negative.lost_digits - 10.3f64.log10()).abs() < 1e-12);
+        // Amos reports IERR=3 from sqrt(0.5·i32::MAX) ≈ 3.3e4, where about 4.5
+        // digits are lost; off the real axis the left half-plane still goes
+        // through Amos
+        let threshold = (0.5 * i32::MAX as f64).sqrt();
+        let below = bessel_j(Complex64::new(-0.99 * threshold, 1.0), 0.0, 1, 1).unwrap();
+        assert!((below.lost_digits - 4.5).abs() < 0.1);
+        assert!(bessel_j(Complex64::new(-1.01 * threshold, 1.0), 0.0, 1, 1).is_err());
+        // The Hankel expansion reduces the phase exactly, and the uniform
+        // expansions lose log10 ν
+        let hankel = bessel_j(Complex64::new(1e8, 0.0), 0.0, 1, 1).unwrap();
+        assert_eq!(hankel.lost_digits, 0.0);
+        let uniform = bessel_j(Complex64::new(2e5, 1.0), 1e5, 2, 1).unwrap();
+        assert!((uniform.lost_digits - 5.0).abs() < 1e-12);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let z = Complex64::new(2.0, 1.0);
+        let result = bessel_k(z, 0.5, 2, 3).unwrap();
+        let json = serde_json::to_string(&result).unwrap();
+        let decoded: BesselResult = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.values, result.values);
+        assert_eq!(decoded.underflow_count, result.underflow_count);
+
+        let kind: BesselKind =
+            serde_json::from_str(&serde_json::to_string(&BesselKind::H2).unwrap()).unwrap();
+        assert_eq!(kind, BesselKind::H2);
+
+        let error = bessel_k(z, 0.0, 1, 0).unwrap_err();
+        let decoded: BesselError =
+            serde_json::from_str(&serde_json::to_string(&error).unwrap()).unwrap();
+        assert_eq!(decoded.to_string(), error.to_string());
+    }
 }
@@ -0,0 +1,199 @@
+//! Machine constants and evaluation limits
+//!
+//! [`machine_parameters`] reports the constants the Amos routines derive from
+//! the `f64` format, together with the limits of the active backend, and
+//! turns them into the largest |z| and ν at which each function evaluates at
+//! full precision. Adaptive callers can plan with them, switching to scaled
+//! values or to the `quad` module in advance, instead of probing for errors:
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{bessel, machine_parameters, BesselKind, Scaling};
+//!
+//! fn main() -> Result<(), zbessel_rs::BesselError> {
+//!     let machine = machine_parameters();
+//!     let z = Complex64::new(0.0, 800.0);
+//!     let scaling = if z.norm() <= machine.bessel_range(Scaling::Unscaled).argument {
+//!         Scaling::Unscaled
+//!     } else {
+//!         Scaling::Scaled
+//!     };
+//!     assert_eq!(scaling, Scaling::Scaled);
+//!     println!("{}", bessel(BesselKind::J, 0.0, z, scaling)?);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::asymptotic::argument_limit;
+use crate::backend::{ActiveBackend, Backend};
+use crate::{Scaling, MAX_ORDER};
+
+/// Constants and limits the evaluation assumes
+///
+/// The exponent thresholds and switch-over points are those of the Amos
+/// routines. The Rust backend only overflows past `f64::MAX`, so planning with
+/// them is safe for either backend.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MachineParameters {
+    /// Name of the active backend, as in [`Backend::NAME`]
+    pub backend: &'static str,
+    /// Relative tolerance the series and recurrences are carried to (Amos
+    /// TOL, the unit roundoff of `f64`)
+    pub tolerance: f64,
+    /// Largest |ln| of a value before it overflows or underflows (ELIM,
+    /// about 700.9); unscaled results past it give IERR=2 or count as
+    /// underflow
+    pub exponent_limit: f64,
+    /// |ln| of a value above which intermediate results are rescaled to stay
+    /// in range (ALIM, about 664.9)
+    pub rescale_exponent: f64,
+    /// e^ELIM, the magnitude above which a value overflows
+    pub overflow_threshold: f64,
+    /// e^-ELIM, the magnitude below which a value underflows to zero
+    pub underflow_threshold: f64,
+    /// |z| above which the Hankel expansion for large arguments is used (RL)
+    pub asymptotic_argument: f64,
+    /// ν above which the uniform expansions for large orders are used (FNUL)
+    pub asymptotic_order: f64,
+    /// |z| and ν above which the backend reports reduced precision (IERR=3)
+    pub precision_limit: f64,
+    /// |z| and ν+n-1 above which the backend evaluates nothing; see
+    /// [`BesselError::OutOfValidatedRange`](crate::BesselError::OutOfValidatedRange)
+    pub range_limit: f64,
+    /// Largest |ν| accepted, [`MAX_ORDER`]
+    pub max_order: f64,
+}
+
+/// Largest |z| and ν at which a function evaluates at full precision
+///
+/// Within it neither the exponential factor of the function overflows,
+/// whatever the phase of z, nor does the backend report reduced precision.
+/// Overflow of the function itself is not covered: Y_ν(z), K_ν(z) and the
+/// Hankel functions still overflow for |z| far below ν.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SafeRange {
+    /// Largest |z|
+    pub argument: f64,
+    /// Largest ν
+    pub order: f64,
+}
+
+/// Report the machine constants and limits of the active backend
+///
+/// # Returns
+/// The constants as computed by the Amos routines for `f64`
+pub fn machine_parameters() -> MachineParameters {
+    // As in the Amos routines: the smaller exponent range of the format less
+    // a margin of three decimal digits, and the digits of the significand
+    let log10_radix = 2f64.log10();
+    let exponent_range = f64::MIN_EXP.abs().min(f64::MAX_EXP) as f64;
+    let exponent_limit = (exponent_range * log10_radix - 3.0) * 2.303;
+    let digits = log10_radix * (f64::MANTISSA_DIGITS - 1) as f64;
+    let rescale_exponent = exponent_limit + (-2.303 * digits).max(-41.45);
+    let digits = digits.min(18.0);
+    MachineParameters {
+        backend: ActiveBackend::NAME,
+        tolerance: f64::EPSILON.max(1e-18),
+        exponent_limit,
+        rescale_exponent,
+        overflow_threshold: exponent_limit.exp(),
+        underflow_threshold: (-exponent_limit).exp(),
+        asymptotic_argument: 1.2 * digits + 3.0,
+        asymptotic_order: 6.0 * (digits - 3.0) + 10.0,
+        precision_limit: argument_limit().min(ActiveBackend::RANGE_LIMIT),
+        range_limit: ActiveBackend::RANGE_LIMIT,
+        max_order: MAX_ORDER,
+    }
+}
+
+impl MachineParameters {
+    /// Largest |z| and ν at which the Bessel functions evaluate at full
+    /// precision
+    ///
+    /// # Parameters
+    /// * `scaling` - Scaling option
+    ///
+    /// # Returns
+    /// Unscaled, |z| is limited by the exponential growth of the functions,
+    /// e^|Im z| for J and Y, e^|Re z| for I and K and e^∓Im z for the Hankel
+    /// functions, so that every kind and phase is safe up to ALIM. Scaled, the
+    /// factor is removed and only the precision limit remains.
+    pub fn bessel_range(&self, scaling: Scaling) -> SafeRange {
+        let argument = match scaling {
+            Scaling::Unscaled => self.rescale_exponent,
+            Scaling::Scaled => self.precision_limit,
+        };
+        SafeRange {
+            argument,
+            order: self.precision_limit,
+        }
+    }
+
+    /// Largest |z| at which the Airy functions evaluate at full precision
+    ///
+    /// # Parameters
+    /// * `scaling` - Scaling option
+    ///
+    /// # Returns
+    /// Unscaled, the Airy functions grow like e^|ζ| with ζ = (2/3) z^{3/2},
+    /// which stays below e^ALIM up to |z| = (3 ALIM / 2)^{2/3} ≈ 100. Scaled,
+    /// zairy reports reduced precision above (0.5·`i32::MAX`)^{1/3} ≈ 1024.
+    pub fn airy_range(&self, scaling: Scaling) -> f64 {
+        match scaling {
+            Scaling::Unscaled => (1.5 * self.rescale_exponent).powf(2.0 / 3.0),
+            Scaling::Scaled => (0.5 * i32::MAX as f64).cbrt(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{airy_ai, airy_bi, bessel, BesselKind};
+    use num_complex::Complex64;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_amos_constants() {
+        let machine = machine_parameters();
+        assert_eq!(machine.tolerance, f64::EPSILON);
+        assert!((machine.exponent_limit - 700.92).abs() < 0.01);
+        assert!((machine.rescale_exponent - 664.87).abs() < 0.01);
+        assert!((machine.asymptotic_argument - 21.78).abs() < 0.01);
+        assert!((machine.asymptotic_order - 85.92).abs() < 0.01);
+        assert!(machine.overflow_threshold.is_finite() && machine.underflow_threshold > 0.0);
+        assert!(machine.precision_limit <= machine.range_limit);
+        assert_eq!(machine.max_order, MAX_ORDER);
+    }
+
+    #[test]
+    fn test_safe_ranges_evaluate() {
+        let machine = machine_parameters();
+        let phases = (0..16).map(|k| Complex64::from_polar(1.0, PI * (k as f64 / 8.0 - 1.0) + 0.1));
+        for unit in phases {
+            for scaling in [Scaling::Unscaled, Scaling::Scaled] {
+                let range = machine.bessel_range(scaling);
+                let z = 0.999 * range.argument * unit;
+                for kind in BesselKind::ALL {
+                    for nu in [0.0, 1.5, 10.0] {
+                        assert!(
+                            bessel(kind, nu, z, scaling).is_ok(),
+                            "{:?}_{}({})",
+                            kind,
+                            nu,
+                            z
+                        );
+                    }
+                }
+            }
+            for (scaling, kode) in [(Scaling::Unscaled, 1), (Scaling::Scaled, 2)] {
+                let z = 0.999 * machine.airy_range(scaling) * unit;
+                assert!(
+                    airy_ai(z, 0, kode).is_ok() && airy_bi(z, 0, kode).is_ok(),
+                    "{}",
+                    z
+                );
+            }
+        }
+    }
+}
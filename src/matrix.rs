@@ -0,0 +1,175 @@
+//! Element-wise evaluation over nalgebra matrices
+//!
+//! [`bessel_matrix`] applies one function of one order to every entry of a
+//! nalgebra matrix or vector, dynamically or statically sized, and returns an
+//! owned matrix of the same shape. The entries are evaluated in column-major
+//! order with [`bessel_slice`](crate::bessel_slice), so the batched kernels
+//! apply.
+//!
+//! ```rust
+//! use nalgebra::{DMatrix, Matrix2};
+//! use num_complex::Complex64;
+//! use zbessel_rs::{bessel_j_matrix, Scaling};
+//!
+//! fn main() -> Result<(), zbessel_rs::BesselError> {
+//!     let z = DMatrix::from_fn(3, 4, |i, j| Complex64::new(i as f64 + 0.5, j as f64));
+//!     let values = bessel_j_matrix(0.0, &z, Scaling::Unscaled)?;
+//!     assert_eq!(values.shape(), (3, 4));
+//!
+//!     let small = Matrix2::new(
+//!         Complex64::new(1.0, 0.0), Complex64::new(2.0, 0.0),
+//!         Complex64::new(3.0, 0.0), Complex64::new(4.0, 0.0),
+//!     );
+//!     let values: Matrix2<Complex64> = bessel_j_matrix(1.0, &small, Scaling::Unscaled)?;
+//!     println!("{}", values);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{bessel_slice, BesselError, BesselKind, Scaling};
+use nalgebra::allocator::Allocator;
+use nalgebra::{DefaultAllocator, Dim, Matrix, OMatrix, RawStorage};
+use num_complex::Complex64;
+
+/// Calculate a Bessel function of the given kind at every entry of `z`
+///
+/// # Parameters
+/// * `kind` - Kind of Bessel function
+/// * `nu` - Order (real number)
+/// * `z` - Matrix or vector of complex arguments
+/// * `scaling` - Scaling option, with the factor of [`bessel`](crate::bessel) for `kind`
+///
+/// # Returns
+/// An owned matrix of the shape of `z`; the first entry that fails is
+/// reported as the error
+pub fn bessel_matrix<R, C, S>(
+    kind: BesselKind,
+    nu: f64,
+    z: &Matrix<Complex64, R, C, S>,
+    scaling: Scaling,
+) -> Result<OMatrix<Complex64, R, C>, BesselError>
+where
+    R: Dim,
+    C: Dim,
+    S: RawStorage<Complex64, R, C>,
+    DefaultAllocator: Allocator<R, C>,
+{
+    let zs: Vec<Complex64> = z.iter().copied().collect();
+    let values = bessel_slice(kind, &zs, nu, scaling)?;
+    let (rows, cols) = z.shape_generic();
+    Ok(OMatrix::from_iterator_generic(rows, cols, values))
+}
+
+/// Calculate J_ν at every entry of `z`
+///
+/// See [`bessel_matrix`].
+pub fn bessel_j_matrix<R, C, S>(
+    nu: f64,
+    z: &Matrix<Complex64, R, C, S>,
+    scaling: Scaling,
+) -> Result<OMatrix<Complex64, R, C>, BesselError>
+where
+    R: Dim,
+    C: Dim,
+    S: RawStorage<Complex64, R, C>,
+    DefaultAllocator: Allocator<R, C>,
+{
+    bessel_matrix(BesselKind::J, nu, z, scaling)
+}
+
+/// Calculate Y_ν at every entry of `z`
+///
+/// See [`bessel_matrix`].
+pub fn bessel_y_matrix<R, C, S>(
+    nu: f64,
+    z: &Matrix<Complex64, R, C, S>,
+    scaling: Scaling,
+) -> Result<OMatrix<Complex64, R, C>, BesselError>
+where
+    R: Dim,
+    C: Dim,
+    S: RawStorage<Complex64, R, C>,
+    DefaultAllocator: Allocator<R, C>,
+{
+    bessel_matrix(BesselKind::Y, nu, z, scaling)
+}
+
+/// Calculate I_ν at every entry of `z`
+///
+/// See [`bessel_matrix`].
+pub fn bessel_i_matrix<R, C, S>(
+    nu: f64,
+    z: &Matrix<Complex64, R, C, S>,
+    scaling: Scaling,
+) -> Result<OMatrix<Complex64, R, C>, BesselError>
+where
+    R: Dim,
+    C: Dim,
+    S: RawStorage<Complex64, R, C>,
+    DefaultAllocator: Allocator<R, C>,
+{
+    bessel_matrix(BesselKind::I, nu, z, scaling)
+}
+
+/// Calculate K_ν at every entry of `z`
+///
+/// See [`bessel_matrix`].
+pub fn bessel_k_matrix<R, C, S>(
+    nu: f64,
+    z: &Matrix<Complex64, R, C, S>,
+    scaling: Scaling,
+) -> Result<OMatrix<Complex64, R, C>, BesselError>
+where
+    R: Dim,
+    C: Dim,
+    S: RawStorage<Complex64, R, C>,
+    DefaultAllocator: Allocator<R, C>,
+{
+    bessel_matrix(BesselKind::K, nu, z, scaling)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bessel;
+    use nalgebra::{DMatrix, DVector, SMatrix};
+
+    fn close(a: Complex64, b: Complex64) -> bool {
+        (a - b).norm() <= 1e-12 * b.norm()
+    }
+
+    #[test]
+    fn test_shapes_and_values() {
+        let z = DMatrix::from_fn(4, 3, |i, j| {
+            Complex64::new(0.7 * i as f64 + 0.1, j as f64 - 1.0)
+        });
+        let values = bessel_k_matrix(0.5, &z, Scaling::Scaled).unwrap();
+        assert_eq!(values.shape(), (4, 3));
+        for (i, j) in [(0, 0), (3, 1), (2, 2)] {
+            let expected = bessel(BesselKind::K, 0.5, z[(i, j)], Scaling::Scaled).unwrap();
+            assert!(close(values[(i, j)], expected));
+        }
+
+        let v = DVector::from_fn(5, |i, _| Complex64::new(i as f64, 0.5));
+        assert_eq!(
+            bessel_j_matrix(1.0, &v, Scaling::Unscaled).unwrap().len(),
+            5
+        );
+
+        let s =
+            SMatrix::<Complex64, 2, 3>::from_fn(|i, j| Complex64::new(1.0 + i as f64, j as f64));
+        let values: SMatrix<Complex64, 2, 3> = bessel_y_matrix(2.0, &s, Scaling::Unscaled).unwrap();
+        let expected = bessel(BesselKind::Y, 2.0, s[(1, 2)], Scaling::Unscaled).unwrap();
+        assert!(close(values[(1, 2)], expected));
+
+        // Views keep the shape of the view
+        let column = bessel_i_matrix(0.0, &z.column(1), Scaling::Unscaled).unwrap();
+        assert_eq!(column.shape(), (4, 1));
+    }
+
+    #[test]
+    fn test_matrix_errors() {
+        let z = DMatrix::from_element(2, 2, Complex64::new(f64::NAN, 0.0));
+        assert!(bessel_k_matrix(0.0, &z, Scaling::Unscaled).is_err());
+    }
+}
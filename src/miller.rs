@@ -0,0 +1,224 @@
+//! Miller's algorithm for three-term recurrences
+//!
+//! A recurrence F_{k-1} = a_k F_k + b_k F_{k+1} usually has one solution
+//! that decays with k (the minimal solution, such as J or I against the
+//! order) and one that grows. Running it backward from a start index far
+//! beyond the wanted range with the seeds F_{start+1} = 0, F_start = 1
+//! converges to a multiple of the minimal solution; [`miller_backward`]
+//! fixes the multiple with a [`MillerNormalization`], rescaling on the way so
+//! the unnormalized values cannot overflow. The integer-order sequences of
+//! this crate use it with the anchor at the largest value.
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{miller_backward, MillerNormalization};
+//!
+//! fn main() -> Result<(), zbessel_rs::BesselError> {
+//!     // J_0(x), ..., J_9(x) normalized by J_0 + 2 (J_2 + J_4 + ...) = 1
+//!     let x = 3.0;
+//!     let values = miller_backward(
+//!         10,
+//!         60,
+//!         |k| (Complex64::new(2.0 * k as f64 / x, 0.0), Complex64::new(-1.0, 0.0)),
+//!         MillerNormalization::Sum {
+//!             weight: Box::new(|k| match k {
+//!                 0 => Complex64::new(1.0, 0.0),
+//!                 k if k % 2 == 0 => Complex64::new(2.0, 0.0),
+//!                 _ => Complex64::new(0.0, 0.0),
+//!             }),
+//!             total: Complex64::new(1.0, 0.0),
+//!         },
+//!     )?;
+//!     assert!((values[0].re - zbessel_rs::real::J(0.0, x)?).abs() < 1e-15);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::BesselError;
+use num_complex::Complex64;
+use std::fmt;
+
+/// Magnitude at which the unnormalized values are rescaled
+const RESCALE: f64 = 1e250;
+
+/// How [`miller_backward`] scales the recurred values
+pub enum MillerNormalization<'a> {
+    /// Scale so that F_index equals `value` (`index` below the number of
+    /// values returned)
+    Anchor {
+        /// Index of the known value
+        index: usize,
+        /// The known value F_index
+        value: Complex64,
+    },
+    /// Scale so that F_k equals the returned value at the index k of the
+    /// largest returned value, where it is least affected by rounding
+    Peak(Box<dyn FnOnce(usize) -> Result<Complex64, BesselError> + 'a>),
+    /// Scale so that Σ_k weight(k) F_k over 0 <= k <= start equals `total`
+    Sum {
+        /// Weight of F_k in the sum
+        weight: Box<dyn Fn(usize) -> Complex64 + 'a>,
+        /// The known value of the sum
+        total: Complex64,
+    },
+}
+
+impl fmt::Debug for MillerNormalization<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MillerNormalization::Anchor { index, value } => f
+                .debug_struct("Anchor")
+                .field("index", index)
+                .field("value", value)
+                .finish(),
+            MillerNormalization::Peak(_) => f.write_str("Peak"),
+            MillerNormalization::Sum { total, .. } => {
+                f.debug_struct("Sum").field("total", total).finish()
+            }
+        }
+    }
+}
+
+fn invalid(message: impl Into<String>) -> BesselError {
+    BesselError::InvalidParameter(message.into())
+}
+
+/// Compute the minimal solution F_0, ..., F_{n-1} of
+/// F_{k-1} = a_k F_k + b_k F_{k+1} by backward recurrence
+///
+/// # Parameters
+/// * `n` - Number of values returned
+/// * `start` - Index at which the recurrence starts (at least `n - 1`); it
+///   must lie well beyond both `n` and the point where the minimal solution
+///   starts to decay
+/// * `coefficients` - (a_k, b_k) for k = start, ..., 1
+/// * `normalization` - How the values are scaled
+pub fn miller_backward(
+    n: usize,
+    start: usize,
+    mut coefficients: impl FnMut(usize) -> (Complex64, Complex64),
+    normalization: MillerNormalization<'_>,
+) -> Result<Vec<Complex64>, BesselError> {
+    if n == 0 {
+        return Err(invalid("n must be greater than 0"));
+    }
+    if start + 1 < n {
+        return Err(invalid(format!(
+            "start must be at least n - 1 = {}, got {}",
+            n - 1,
+            start
+        )));
+    }
+    if let MillerNormalization::Anchor { index, .. } = normalization {
+        if index >= n {
+            return Err(invalid(format!(
+                "anchor index must be less than n = {}, got {}",
+                n, index
+            )));
+        }
+    }
+
+    let weight = match &normalization {
+        MillerNormalization::Sum { weight, .. } => Some(weight),
+        _ => None,
+    };
+    let zero = Complex64::new(0.0, 0.0);
+    let mut values = vec![zero; n];
+    let mut above = zero;
+    let mut current = Complex64::new(1.0, 0.0);
+    let mut sum = zero;
+    for k in (1..=start).rev() {
+        if k < n {
+            values[k] = current;
+        }
+        if let Some(weight) = weight {
+            sum += weight(k) * current;
+        }
+        let (a, b) = coefficients(k);
+        let below = a * current + b * above;
+        above = current;
+        current = below;
+        if current.norm() > RESCALE {
+            above /= RESCALE;
+            current /= RESCALE;
+            sum /= RESCALE;
+            for value in &mut values {
+                *value /= RESCALE;
+            }
+        }
+    }
+    values[0] = current;
+
+    let (reference, target) = match normalization {
+        MillerNormalization::Anchor { index, value } => (values[index], value),
+        MillerNormalization::Peak(known) => {
+            let (index, peak) = values
+                .iter()
+                .enumerate()
+                .max_by(|a, b| a.1.norm().total_cmp(&b.1.norm()))
+                .map(|(index, peak)| (index, *peak))
+                .unwrap_or((0, zero));
+            (peak, known(index)?)
+        }
+        MillerNormalization::Sum { weight, total } => (sum + weight(0) * current, total),
+    };
+    let magnitude = reference.norm();
+    if magnitude == 0.0 || !magnitude.is_finite() {
+        return Err(invalid(
+            "the recurrence vanished at the normalization point",
+        ));
+    }
+    // Divide by the magnitude first; complex division squares the divisor
+    let scale = target / (reference / magnitude) / magnitude;
+    for value in &mut values {
+        *value *= scale;
+    }
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bessel_i, bessel_j};
+
+    #[test]
+    fn test_sum_and_anchor_normalizations() {
+        // e^z = I_0 + 2 Σ I_k
+        let z = Complex64::new(4.0, -2.5);
+        let n = 30;
+        let recurrence = |k: usize| (2.0 * k as f64 / z, Complex64::new(1.0, 0.0));
+        let sum = MillerNormalization::Sum {
+            weight: Box::new(|k| Complex64::new(if k == 0 { 1.0 } else { 2.0 }, 0.0)),
+            total: z.exp(),
+        };
+        let values = miller_backward(n, 80, recurrence, sum).unwrap();
+        let expected = bessel_i(z, 0.0, 1, n).unwrap();
+        for (value, exact) in values.iter().zip(&expected.values) {
+            assert!((value - exact).norm() <= 1e-13 * exact.norm());
+        }
+
+        let z = Complex64::new(25.0, 1.0);
+        let expected = bessel_j(z, 0.0, 1, n).unwrap();
+        let anchor = MillerNormalization::Anchor {
+            index: 7,
+            value: expected.values[7],
+        };
+        let recurrence = |k: usize| (2.0 * k as f64 / z, Complex64::new(-1.0, 0.0));
+        let values = miller_backward(n, 120, recurrence, anchor).unwrap();
+        for (value, exact) in values.iter().zip(&expected.values) {
+            assert!((value - exact).norm() <= 1e-12 * exact.norm().max(0.05));
+        }
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        let recurrence = |_| (Complex64::new(1.0, 0.0), Complex64::new(1.0, 0.0));
+        let anchor = || MillerNormalization::Anchor {
+            index: 5,
+            value: Complex64::new(1.0, 0.0),
+        };
+        assert!(miller_backward(0, 10, recurrence, anchor()).is_err());
+        assert!(miller_backward(10, 5, recurrence, anchor()).is_err());
+        assert!(miller_backward(3, 10, recurrence, anchor()).is_err());
+    }
+}
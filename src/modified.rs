@@ -0,0 +1,215 @@
+//! I and K of orders 0 and 1 on the real axis
+//!
+//! * I_0, I_1 for |x| <= 20: the power series, whose terms are all positive
+//! * I_0, I_1 for |x| > 20: the Hankel expansion e^x / sqrt(2πx) Σ (-1)^k
+//!   a_k(ν) / x^k, where the e^{-x} part is below rounding
+//! * K_0, K_1 for 0 < x <= 1: the logarithmic series (DLMF 10.31.1, 10.31.2)
+//! * K_0, K_1 for x > 1: Steed's continued fraction (as in Numerical Recipes'
+//!   `bessik`), which yields both orders together
+//!
+//! The exponentially scaled forms are computed directly where the method
+//! produces them, so they stay finite far beyond the overflow of the
+//! unscaled functions. Values that overflow or underflow are declined and
+//! left to the backend, which reports them.
+
+use crate::asymptotic::argument_limit;
+use crate::{direct, BesselKind};
+use num_complex::Complex64;
+use std::f64::consts::{FRAC_PI_2, PI};
+
+/// Euler's constant γ
+const EULER: f64 = 0.577_215_664_901_532_9;
+
+/// Boundary between the power series and the Hankel expansion for I
+const I_SPLIT: f64 = 20.0;
+
+/// Boundary between the logarithmic series and the continued fraction for K
+const K_SPLIT: f64 = 1.0;
+
+/// Most terms of any series or continued fraction
+const MAX_TERMS: usize = 500;
+
+/// (I_0(x), I_1(x)) for 0 <= x <= 20 by the power series
+fn i_series(x: f64) -> (f64, f64) {
+    let q = 0.25 * x * x;
+    let (mut t0, mut t1) = (1.0, 0.5 * x);
+    let (mut i0, mut i1) = (t0, t1);
+    for k in 1..MAX_TERMS {
+        let k = k as f64;
+        t0 *= q / (k * k);
+        t1 *= q / (k * (k + 1.0));
+        i0 += t0;
+        i1 += t1;
+        if t0 <= f64::EPSILON * i0 && t1 <= f64::EPSILON * i1 {
+            break;
+        }
+    }
+    (i0, i1)
+}
+
+/// e^{-x} I_ν(x) for x > 20 and ν = 0 or 1 by the Hankel expansion
+fn i_scaled_asymptotic(x: f64, nu: f64) -> f64 {
+    let mu = 4.0 * nu * nu;
+    let mut term = 1.0_f64;
+    let mut sum = term;
+    for k in 1..MAX_TERMS {
+        let odd = (2 * k - 1) as f64;
+        let next = -term * (mu - odd * odd) / (8.0 * k as f64 * x);
+        if next.abs() >= term.abs() {
+            break;
+        }
+        term = next;
+        sum += term;
+        if term.abs() <= f64::EPSILON * sum {
+            break;
+        }
+    }
+    sum / (2.0 * PI * x).sqrt()
+}
+
+/// (K_0(x), K_1(x)) for 0 < x <= 1 by the logarithmic series
+fn k_series(x: f64) -> (f64, f64) {
+    let (i0, i1) = i_series(x);
+    let log = (0.5 * x).ln();
+    let q = 0.25 * x * x;
+
+    // Σ H_k q^k / (k!)² and Σ (ψ(k+1) + ψ(k+2)) q^k / (k! (k+1)!)
+    let (mut t0, mut t1) = (1.0, 1.0);
+    let mut harmonic = 0.0;
+    let (mut s0, mut s1) = (0.0, 1.0 - 2.0 * EULER);
+    for k in 1..MAX_TERMS {
+        let kf = k as f64;
+        t0 *= q / (kf * kf);
+        t1 *= q / (kf * (kf + 1.0));
+        harmonic += 1.0 / kf;
+        let d0 = t0 * harmonic;
+        let d1 = t1 * (2.0 * harmonic + 1.0 / (kf + 1.0) - 2.0 * EULER);
+        s0 += d0;
+        s1 += d1;
+        if d0.abs() <= f64::EPSILON * s0.abs() && d1.abs() <= f64::EPSILON * s1.abs() {
+            break;
+        }
+    }
+    let k0 = -(log + EULER) * i0 + s0;
+    let k1 = 1.0 / x + log * i1 - 0.25 * x * s1;
+    (k0, k1)
+}
+
+/// (e^x K_0(x), e^x K_1(x)) for x > 1 by Steed's continued fraction
+fn k_scaled_fraction(x: f64) -> (f64, f64) {
+    let mut b = 2.0 * (1.0 + x);
+    let mut d = 1.0 / b;
+    let mut delta = d;
+    let mut h = d;
+    let (mut q1, mut q2) = (0.0, 1.0);
+    let a1 = 0.25;
+    let (mut q, mut c, mut a) = (a1, a1, -a1);
+    let mut s = 1.0 + q * delta;
+    for i in 2..MAX_TERMS {
+        let i = i as f64;
+        a -= 2.0 * (i - 1.0);
+        c = -a * c / i;
+        let next = (q1 - b * q2) / a;
+        q1 = q2;
+        q2 = next;
+        q += c * next;
+        b += 2.0;
+        d = 1.0 / (b + a * d);
+        delta *= b * d - 1.0;
+        h += delta;
+        let ds = q * delta;
+        s += ds;
+        if ds.abs() <= f64::EPSILON * s.abs() {
+            break;
+        }
+    }
+    let k0 = (FRAC_PI_2 / x).sqrt() / s;
+    let k1 = k0 * (x + 0.5 - a1 * h) / x;
+    (k0, k1)
+}
+
+/// I_ν(x) for ν = 0 or 1, times e^{-|x|} if `scaled`
+pub(crate) fn bessel_i(nu: f64, x: f64, scaled: bool) -> f64 {
+    let (ax, sign) = (x.abs(), if nu == 1.0 { x.signum() } else { 1.0 });
+    let value = if ax <= I_SPLIT {
+        let (i0, i1) = i_series(ax);
+        let value = if nu == 1.0 { i1 } else { i0 };
+        if scaled {
+            value * (-ax).exp()
+        } else {
+            value
+        }
+    } else {
+        let value = i_scaled_asymptotic(ax, nu);
+        if scaled {
+            value
+        } else {
+            value * ax.exp()
+        }
+    };
+    sign * value
+}
+
+/// K_ν(x) for ν = 0 or 1 and x > 0, times e^x if `scaled`
+pub(crate) fn bessel_k(nu: f64, x: f64, scaled: bool) -> f64 {
+    let (k0, k1, factor) = if x <= K_SPLIT {
+        let (k0, k1) = k_series(x);
+        (k0, k1, if scaled { x.exp() } else { 1.0 })
+    } else {
+        let (k0, k1) = k_scaled_fraction(x);
+        (k0, k1, if scaled { 1.0 } else { (-x).exp() })
+    };
+    factor * if nu == 1.0 { k1 } else { k0 }
+}
+
+/// F_ν(z) for I and K of order 0 or 1 at real z, or `None` if the kernels do
+/// not apply or the value overflows or underflows
+pub(crate) fn value(kind: BesselKind, z: Complex64, nu: f64, kode: i32) -> Option<Complex64> {
+    let in_range = z.re.abs() <= argument_limit();
+    if z.im != 0.0 || !in_range || (nu != 0.0 && nu != 1.0) {
+        return None;
+    }
+    let (x, scaled) = (z.re, kode == 2);
+    let value = match kind {
+        BesselKind::I => bessel_i(nu, x, scaled),
+        BesselKind::K if x > 0.0 => bessel_k(nu, x, scaled),
+        _ => return None,
+    };
+    if x == 0.0 {
+        return Some(Complex64::new(value, 0.0));
+    }
+    direct::accept(Complex64::new(value, 0.0), value.abs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::direct::check_against_backend;
+
+    #[test]
+    fn test_modified_matches_backend() {
+        let mut xs: Vec<f64> = (1..300).map(|k| 0.0913 * k as f64).collect();
+        xs.extend([
+            1e-9, 0.999, 1.001, 19.99, 20.01, 45.0, 300.0, 650.0, 800.0, 1e4,
+        ]);
+        xs.extend(xs.clone().iter().map(|x| -x));
+        xs.push(0.0);
+        let zs: Vec<Complex64> = xs.iter().map(|&x| Complex64::new(x, 0.0)).collect();
+        let kinds = [BesselKind::I, BesselKind::K];
+        // The backends lose a few more digits in e^x for large x
+        let used = check_against_backend(value, &kinds, &zs, &[0.0, 1.0], 5e-14, |_, exact| {
+            exact.norm().max(f64::MIN_POSITIVE)
+        });
+        assert!(used > 2000);
+    }
+
+    #[test]
+    fn test_modified_declines() {
+        // Overflow and underflow are left to the backend
+        let z = Complex64::new(800.0, 0.0);
+        assert!(value(BesselKind::I, z, 0.0, 1).is_none());
+        assert!(value(BesselKind::K, z, 1.0, 1).is_none());
+        assert!(value(BesselKind::K, -z, 0.0, 2).is_none());
+        assert!(value(BesselKind::I, Complex64::new(3.0, 1e-300), 0.0, 1).is_none());
+    }
+}
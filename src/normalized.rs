@@ -0,0 +1,201 @@
+//! Sequences returned relative to one of their elements
+//!
+//! [`bessel_normalized`] returns F_ν(z), ..., F_{ν+n-1}(z) divided by the
+//! element chosen by a [`Normalization`], together with that element as a
+//! [`ScaledComplex`] constant. The sequence is evaluated scaled (kode=2), so
+//! the exponential growth in |z| only enters the constant; for Y, K, H1 and
+//! H2, whose magnitude grows with the order, a sequence that would overflow
+//! is continued by forward recurrence with a running power-of-two exponent.
+//! Either way the normalized values are plain `Complex64` numbers of moderate
+//! size, which keeps downstream recurrences and products well conditioned.
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{bessel_normalized, BesselKind, Normalization};
+//!
+//! fn main() -> Result<(), zbessel_rs::BesselError> {
+//!     // Y_0(1), ..., Y_299(1): the last elements are far beyond f64
+//!     let z = Complex64::new(1.0, 0.0);
+//!     let sequence = bessel_normalized(BesselKind::Y, z, 0.0, 300, Normalization::Largest)?;
+//!     assert_eq!(sequence.values[299], Complex64::new(1.0, 0.0));
+//!     let log10 = sequence.constant.ln().re / std::f64::consts::LN_10;
+//!     println!("|Y_299(1)| = 10^{:.2}", log10);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{BesselError, BesselKind, ScaledComplex};
+use num_complex::Complex64;
+
+/// Magnitude at which the forward recurrence moves a power of two into the exponent
+const RESCALE_EXP2: i32 = 600;
+
+/// Which element of a sequence becomes 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Normalization {
+    /// The first element, F_ν(z)
+    #[default]
+    First,
+    /// The element of largest magnitude, so that no normalized value exceeds 1
+    Largest,
+}
+
+/// A sequence F_{ν+k}(z) = `constant` · `values[k]`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NormalizedSequence {
+    /// Values relative to the normalizing element, which is exactly 1
+    pub values: Vec<Complex64>,
+    /// The normalizing element itself
+    pub constant: ScaledComplex,
+    /// Index of the normalizing element
+    pub index: usize,
+    /// Number of function values that experienced underflow
+    pub underflow_count: i32,
+}
+
+impl NormalizedSequence {
+    /// The unnormalized element F_{ν+k}(z)
+    pub fn value(&self, k: usize) -> ScaledComplex {
+        let constant = self.constant;
+        ScaledComplex::new(constant.mantissa * self.values[k], constant.exp2)
+    }
+}
+
+/// Scaled orders ν, ..., ν+n-1 of Y, K, H1 or H2 by forward recurrence, each
+/// as mantissa · 2^exponent
+fn forward(
+    kind: BesselKind,
+    z: Complex64,
+    nu: f64,
+    n: usize,
+) -> Result<Vec<ScaledComplex>, BesselError> {
+    let seeds = kind.eval_impl(z, nu, 2, 2, false)?.values;
+    // F_{k+1} = (2(ν+k)/z) F_k + s F_{k-1}
+    let s = if kind == BesselKind::K { 1.0 } else { -1.0 };
+    let two_over_z = 2.0 / z;
+    let (mut previous, mut current) = (seeds[0], seeds[1]);
+    let mut exp2 = 0;
+    let mut values = vec![ScaledComplex::from(previous), ScaledComplex::from(current)];
+    for k in 1..n - 1 {
+        let next = two_over_z * (nu + k as f64) * current + s * previous;
+        previous = current;
+        current = next;
+        if current.norm() > 2f64.powi(RESCALE_EXP2) {
+            previous /= 2f64.powi(RESCALE_EXP2);
+            current /= 2f64.powi(RESCALE_EXP2);
+            exp2 += RESCALE_EXP2;
+        }
+        values.push(ScaledComplex::new(current, exp2));
+    }
+    Ok(values)
+}
+
+/// Calculate orders ν, ν+1, ..., ν+n-1 relative to one of them
+///
+/// With [`Normalization::First`], elements more than about 1e308 times the
+/// first saturate to infinity; [`Normalization::Largest`] keeps every
+/// normalized value at most 1 in magnitude, with the elements far below the
+/// largest rounded to zero.
+///
+/// # Parameters
+/// * `kind` - Kind of Bessel function
+/// * `z` - Complex argument
+/// * `nu` - Starting order (real number)
+/// * `n` - Number of orders
+/// * `normalization` - Which element becomes 1
+pub fn bessel_normalized(
+    kind: BesselKind,
+    z: Complex64,
+    nu: f64,
+    n: usize,
+    normalization: Normalization,
+) -> Result<NormalizedSequence, BesselError> {
+    // The recurrence loses accuracy for K in the left half-plane
+    let growing = matches!(kind, BesselKind::Y | BesselKind::H1 | BesselKind::H2)
+        || (kind == BesselKind::K && z.re >= 0.0);
+    let (entries, underflow_count) = match kind.eval_impl(z, nu, 2, n, false) {
+        Ok(result) => {
+            let entries = result.values.into_iter().map(ScaledComplex::from).collect();
+            (entries, result.underflow_count)
+        }
+        Err(error) if error.is_overflow() && growing && n > 2 => (forward(kind, z, nu, n)?, 0),
+        Err(error) => return Err(error),
+    };
+
+    let index = match normalization {
+        Normalization::First => 0,
+        Normalization::Largest => entries
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.ln().re.total_cmp(&b.1.ln().re))
+            .map_or(0, |(index, _)| index),
+    };
+    let reference = entries[index];
+    if reference.mantissa.norm() == 0.0 {
+        return Err(BesselError::InvalidParameter(format!(
+            "the sequence vanishes at the normalization index {}",
+            index
+        )));
+    }
+    let values = entries
+        .iter()
+        .map(|entry| {
+            let ratio = entry.mantissa / reference.mantissa;
+            ScaledComplex::new(ratio, entry.exp2 - reference.exp2).to_complex64()
+        })
+        .collect();
+    // Undo the kode=2 scaling, which is the same for every order
+    let unscaled = ScaledComplex::from_log_factor(reference.mantissa, -kind.log_scale(z));
+    Ok(NormalizedSequence {
+        values,
+        constant: ScaledComplex::new(unscaled.mantissa, unscaled.exp2 + reference.exp2),
+        index,
+        underflow_count,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bessel, Scaling};
+
+    #[test]
+    fn test_normalized_matches_sequence() {
+        let z = Complex64::new(3.0, -1.5);
+        for kind in BesselKind::ALL {
+            for normalization in [Normalization::First, Normalization::Largest] {
+                let sequence = bessel_normalized(kind, z, 0.25, 12, normalization).unwrap();
+                assert_eq!(sequence.values[sequence.index], Complex64::new(1.0, 0.0));
+                for k in 0..12 {
+                    let exact = bessel(kind, 0.25 + k as f64, z, Scaling::Unscaled).unwrap();
+                    let value = sequence.value(k).to_complex64();
+                    let error = (value - exact).norm() / exact.norm();
+                    assert!(error < 1e-13, "{:?} k={} {:e}", kind, k, error);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_overflowing_sequences() {
+        // I_0(1000) ≈ e^995.6 overflows only through the constant
+        let z = Complex64::new(1000.0, 0.0);
+        let sequence = bessel_normalized(BesselKind::I, z, 0.0, 3, Normalization::First).unwrap();
+        assert!(!sequence.constant.is_in_f64_range());
+        assert!((sequence.constant.ln().re - 995.627_308_889_869_5).abs() < 1e-12);
+        assert!((sequence.values[1].re - 0.999_499_874_874_804_3).abs() < 1e-15);
+
+        // Y_200(2) ≈ -1.26e372 overflows through the order
+        let z = Complex64::new(2.0, 0.0);
+        assert!(crate::bessel_y(z, 0.0, 1, 201).is_err());
+        let sequence =
+            bessel_normalized(BesselKind::Y, z, 0.0, 201, Normalization::Largest).unwrap();
+        assert_eq!(sequence.index, 200);
+        assert!(sequence.values.iter().all(|v| v.norm() <= 1.0));
+        let constant = sequence.constant;
+        assert!(constant.mantissa.re < 0.0);
+        assert!((constant.ln().re - 856.793_965_129_405_7).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,115 @@
+//! Batch evaluation on the tokio blocking pool (feature `tokio`)
+//!
+//! Large grids and slices take long enough to stall an async executor. The
+//! `spawn_*` helpers move the inputs onto tokio's blocking thread pool and
+//! return a future for the result. The work starts as soon as the helper is
+//! called, so they must be called from within a tokio runtime.
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{spawn_bessel_grid, BesselKind, Scaling};
+//!
+//! fn main() -> Result<(), zbessel_rs::BesselError> {
+//!     let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+//!     runtime.block_on(async {
+//!         let nus: Vec<f64> = (0..50).map(f64::from).collect();
+//!         let zs: Vec<Complex64> = (1..=1000).map(|k| Complex64::new(0.05 * k as f64, 0.0)).collect();
+//!         let grid = spawn_bessel_grid(BesselKind::J, nus, zs, Scaling::Unscaled).await?;
+//!         assert_eq!(grid.dims(), (50, 1000));
+//!         Ok(())
+//!     })
+//! }
+//! ```
+
+use crate::{bessel_grid, bessel_slice, BesselError, BesselGrid, BesselKind, Scaling};
+use num_complex::Complex64;
+use std::future::Future;
+use tokio::task::JoinHandle;
+
+/// Wait for a blocking task, re-raising its panic if it had one
+async fn join<T>(handle: JoinHandle<Result<T, BesselError>>) -> Result<T, BesselError> {
+    match handle.await {
+        Ok(result) => result,
+        Err(error) if error.is_panic() => std::panic::resume_unwind(error.into_panic()),
+        Err(_) => Err(BesselError::InvalidParameter(
+            "evaluation task was cancelled".to_string(),
+        )),
+    }
+}
+
+/// Run [`bessel_grid`] on the blocking pool
+///
+/// # Parameters
+/// * `kind` - Kind of Bessel function
+/// * `nus` - Orders (any real numbers, in any order)
+/// * `zs` - Complex arguments
+/// * `scaling` - Scaling option
+///
+/// # Panics
+/// If called outside a tokio runtime.
+pub fn spawn_bessel_grid(
+    kind: BesselKind,
+    nus: Vec<f64>,
+    zs: Vec<Complex64>,
+    scaling: Scaling,
+) -> impl Future<Output = Result<BesselGrid, BesselError>> {
+    join(tokio::task::spawn_blocking(move || {
+        bessel_grid(kind, &nus, &zs, scaling)
+    }))
+}
+
+/// Run [`bessel_slice`] on the blocking pool
+///
+/// # Parameters
+/// * `kind` - Kind of Bessel function
+/// * `zs` - Complex arguments
+/// * `nu` - Order (real number)
+/// * `scaling` - Scaling option
+///
+/// # Panics
+/// If called outside a tokio runtime.
+pub fn spawn_bessel_slice(
+    kind: BesselKind,
+    zs: Vec<Complex64>,
+    nu: f64,
+    scaling: Scaling,
+) -> impl Future<Output = Result<Vec<Complex64>, BesselError>> {
+    join(tokio::task::spawn_blocking(move || {
+        bessel_slice(kind, &zs, nu, scaling)
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offloaded_results_match() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let zs: Vec<Complex64> = (0..500)
+            .map(|k| Complex64::new(0.03 * k as f64 - 5.0, 0.7))
+            .collect();
+        runtime.block_on(async {
+            // Both tasks run while neither is awaited
+            let slice = spawn_bessel_slice(BesselKind::Y, zs.clone(), 0.5, Scaling::Scaled);
+            let grid =
+                spawn_bessel_grid(BesselKind::I, vec![0.0, 1.0], zs.clone(), Scaling::Unscaled);
+            assert_eq!(
+                slice.await.unwrap(),
+                bessel_slice(BesselKind::Y, &zs, 0.5, Scaling::Scaled).unwrap()
+            );
+            assert_eq!(
+                grid.await.unwrap(),
+                bessel_grid(BesselKind::I, &[0.0, 1.0], &zs, Scaling::Unscaled).unwrap()
+            );
+            let bad = vec![Complex64::new(f64::NAN, 0.0)];
+            assert!(
+                spawn_bessel_slice(BesselKind::K, bad, 0.0, Scaling::Unscaled)
+                    .await
+                    .is_err()
+            );
+        });
+    }
+}
@@ -0,0 +1,451 @@
+//! Options-struct entry points
+//!
+//! These functions take a [`BesselOptions`] instead of positional `kode`/`n`
+//! arguments, so new options can be added without changing their signatures.
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{bessel_j_opt, BesselOptions, Scaling};
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let z = Complex64::new(2.0, 1.0);
+//!
+//!     let mut options = BesselOptions::default();
+//!     options.scaling = Scaling::Scaled;
+//!     options.n = 4;
+//!     let result = bessel_j_opt(z, 0.0, &options)?;
+//!     assert_eq!(result.values.len(), 4);
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! On the branch cut along the negative real axis the sign of a zero
+//! imaginary part selects the side by default. [`BesselOptions::cut_side`]
+//! fixes the side instead, so that a contour running just below the axis sees
+//! continuous values whatever the sign of zero it computes:
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{bessel_y_opt, BesselOptions, CutSide};
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let mut options = BesselOptions::default();
+//!     options.cut_side = CutSide::Below;
+//!     let on_cut = bessel_y_opt(Complex64::new(-2.0, 0.0), 1.0, &options)?;
+//!     let near = bessel_y_opt(Complex64::new(-2.0, -1e-12), 1.0, &options)?;
+//!     assert!((on_cut.values[0] - near.values[0]).norm() < 1e-10);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{
+    check_max_n, lost_digits, BesselError, BesselKind, BesselResult, Scaling, DEFAULT_MAX_N,
+};
+use num_complex::Complex64;
+
+/// Side of the branch cut on the negative real axis that values follow
+///
+/// Applies to Re z < 0 with Im z = +0.0 or -0.0; elsewhere the functions are
+/// continuous and the option has no effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CutSide {
+    /// The limit from Im z > 0 for either sign of zero, as the Amos routines return
+    Above,
+    /// The limit from Im z < 0 for either sign of zero
+    Below,
+    /// The limit from above for Im z = +0.0 and from below for Im z = -0.0,
+    /// as the positional API and SciPy return
+    #[default]
+    SignOfZero,
+}
+
+impl CutSide {
+    /// `z` with the sign of a zero imaginary part set to select this side
+    fn place(self, z: Complex64) -> Complex64 {
+        match self {
+            _ if z.im != 0.0 => z,
+            CutSide::Above => Complex64::new(z.re, 0.0),
+            CutSide::Below => Complex64::new(z.re, -0.0),
+            CutSide::SignOfZero => z,
+        }
+    }
+}
+
+/// Options controlling a Bessel function evaluation
+///
+/// The struct is `#[non_exhaustive]`; start from [`BesselOptions::default()`]
+/// and set the fields that differ.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub struct BesselOptions {
+    /// Scaling option (default: [`Scaling::Unscaled`])
+    pub scaling: Scaling,
+    /// Number of function values to calculate for orders ν, ν+1, ..., ν+n-1 (default: 1)
+    pub n: usize,
+    /// Return values even when Amos reports a loss of significance (IERR=3)
+    ///
+    /// In that case fewer than half of the machine digits may be correct.
+    /// Default: `false`, which reports IERR=3 as an error like the positional API.
+    pub allow_accuracy_loss: bool,
+    /// Largest accepted `n`; larger requests fail with `InvalidParameter`
+    /// (default: [`DEFAULT_MAX_N`]; values up to `i32::MAX`, the largest length
+    /// the Amos interface can express, are honoured)
+    pub max_n: usize,
+    /// Side of the branch cut followed on the negative real axis (default:
+    /// [`CutSide::SignOfZero`])
+    pub cut_side: CutSide,
+    /// Return infinities instead of an error when the values overflow (IERR=2)
+    ///
+    /// The direction of each infinity is taken from the scaled function,
+    /// whose scaling factor has a known phase; entries of the sequence that
+    /// stay in range are returned finite, to about 1e-13 relative accuracy.
+    /// [`BesselResult::overflow_count`] counts the infinite entries. When
+    /// the scaled function overflows too, the direction cannot be determined
+    /// and every value is (∞, NaN). Default: `false`.
+    pub overflow_to_infinity: bool,
+}
+
+impl Default for BesselOptions {
+    fn default() -> Self {
+        BesselOptions {
+            scaling: Scaling::Unscaled,
+            n: 1,
+            allow_accuracy_loss: false,
+            max_n: DEFAULT_MAX_N,
+            cut_side: CutSide::SignOfZero,
+            overflow_to_infinity: false,
+        }
+    }
+}
+
+/// Evaluate `kind` with the options, on the side of the cut they select
+fn eval_opt(
+    kind: BesselKind,
+    z: Complex64,
+    nu: f64,
+    options: &BesselOptions,
+) -> Result<BesselResult, BesselError> {
+    check_max_n(options.n, options.max_n)?;
+    let z = options.cut_side.place(z);
+    let eval = |kode| kind.eval_impl(z, nu, kode, options.n, options.allow_accuracy_loss);
+    match eval(options.scaling.kode()) {
+        Err(error) if error.is_overflow() && options.overflow_to_infinity => {
+            let scaled = match options.scaling {
+                Scaling::Unscaled => eval(Scaling::Scaled.kode()).ok(),
+                Scaling::Scaled => None,
+            };
+            Ok(overflowed(kind, z, nu, options.n, scaled))
+        }
+        result => result,
+    }
+}
+
+/// Values for a sequence that overflowed, from its scaled values if known
+fn overflowed(
+    kind: BesselKind,
+    z: Complex64,
+    nu: f64,
+    n: usize,
+    scaled: Option<BesselResult>,
+) -> BesselResult {
+    let Some(scaled) = scaled else {
+        return BesselResult {
+            values: vec![Complex64::new(f64::INFINITY, f64::NAN); n],
+            underflow_count: 0,
+            overflow_count: n as i32,
+            lost_digits: lost_digits(z, nu, n),
+        };
+    };
+    // value = scaled * exp(-log_scale), applied per component in log space so
+    // that only the components that are truly out of range become infinite
+    let log_scale = kind.log_scale(z);
+    let phase = Complex64::from_polar(1.0, -log_scale.im);
+    let component = |unit: f64, log_norm: f64| {
+        if unit == 0.0 {
+            0.0
+        } else {
+            unit.signum() * (unit.abs().ln() + log_norm).exp()
+        }
+    };
+    let values: Vec<Complex64> = scaled
+        .values
+        .iter()
+        .map(|&s| {
+            if s == Complex64::new(0.0, 0.0) {
+                return s;
+            }
+            let unit = s / s.norm() * phase;
+            let log_norm = s.norm().ln() - log_scale.re;
+            Complex64::new(component(unit.re, log_norm), component(unit.im, log_norm))
+        })
+        .collect();
+    let overflow_count = values.iter().filter(|v| !v.is_finite()).count() as i32;
+    // Undoing the scaling can also push components that were nonzero in the
+    // scaled result below the smallest subnormal
+    let underflowed =
+        |s: &Complex64, v: &Complex64| (s.re != 0.0 && v.re == 0.0) || (s.im != 0.0 && v.im == 0.0);
+    let underflow_count = scaled.underflow_count
+        + scaled
+            .values
+            .iter()
+            .zip(&values)
+            .filter(|(s, v)| underflowed(s, v))
+            .count() as i32;
+    BesselResult {
+        values,
+        underflow_count,
+        overflow_count,
+        lost_digits: scaled.lost_digits,
+    }
+}
+
+/// Calculate complex Bessel function J_ν(z) with the given options
+///
+/// # Parameters
+/// * `z` - Complex argument
+/// * `nu` - Order (real number)
+/// * `options` - Evaluation options (scaling factor: exp(-abs(Im(z))))
+pub fn bessel_j_opt(
+    z: Complex64,
+    nu: f64,
+    options: &BesselOptions,
+) -> Result<BesselResult, BesselError> {
+    eval_opt(BesselKind::J, z, nu, options)
+}
+
+/// Calculate complex Bessel function Y_ν(z) with the given options
+///
+/// # Parameters
+/// * `z` - Complex argument
+/// * `nu` - Order (real number)
+/// * `options` - Evaluation options (scaling factor: exp(-abs(Im(z))))
+pub fn bessel_y_opt(
+    z: Complex64,
+    nu: f64,
+    options: &BesselOptions,
+) -> Result<BesselResult, BesselError> {
+    eval_opt(BesselKind::Y, z, nu, options)
+}
+
+/// Calculate complex modified Bessel function I_ν(z) with the given options
+///
+/// # Parameters
+/// * `z` - Complex argument
+/// * `nu` - Order (real number)
+/// * `options` - Evaluation options (scaling factor: exp(-abs(Re(z))))
+pub fn bessel_i_opt(
+    z: Complex64,
+    nu: f64,
+    options: &BesselOptions,
+) -> Result<BesselResult, BesselError> {
+    eval_opt(BesselKind::I, z, nu, options)
+}
+
+/// Calculate complex modified Bessel function K_ν(z) with the given options
+///
+/// # Parameters
+/// * `z` - Complex argument
+/// * `nu` - Order (real number)
+/// * `options` - Evaluation options (scaling factor: exp(z))
+pub fn bessel_k_opt(
+    z: Complex64,
+    nu: f64,
+    options: &BesselOptions,
+) -> Result<BesselResult, BesselError> {
+    eval_opt(BesselKind::K, z, nu, options)
+}
+
+/// Calculate complex Hankel function H^(m)_ν(z) with the given options
+///
+/// # Parameters
+/// * `z` - Complex argument (nonzero)
+/// * `nu` - Order (real number)
+/// * `m` - Kind of Hankel function (1: H^(1), 2: H^(2))
+/// * `options` - Evaluation options (scaling factor: exp(-(3-2m)*i*z))
+pub fn bessel_h_opt(
+    z: Complex64,
+    nu: f64,
+    m: i32,
+    options: &BesselOptions,
+) -> Result<BesselResult, BesselError> {
+    let kind = match m {
+        1 => BesselKind::H1,
+        2 => BesselKind::H2,
+        _ => {
+            return Err(BesselError::InvalidParameter(format!(
+                "m must be 1 or 2, got {}",
+                m
+            )))
+        }
+    };
+    eval_opt(kind, z, nu, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bessel_h, bessel_j, bessel_k};
+
+    #[test]
+    fn test_default_options_match_positional_api() {
+        let z = Complex64::new(10.0, 20.0);
+        let result = bessel_j_opt(z, 1.0, &BesselOptions::default()).unwrap();
+        let direct = bessel_j(z, 1.0, 1, 1).unwrap();
+        assert_eq!(result.values, direct.values);
+
+        let options = BesselOptions {
+            scaling: Scaling::Scaled,
+            n: 3,
+            ..BesselOptions::default()
+        };
+        for m in 1..=2 {
+            let result = bessel_h_opt(z, 0.5, m, &options).unwrap();
+            assert_eq!(result.values, bessel_h(z, 0.5, 2, m, 3).unwrap().values);
+        }
+        assert!(matches!(
+            bessel_h_opt(z, 0.5, 3, &options),
+            Err(BesselError::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_scaled_sequence_options() {
+        let z = Complex64::new(100.0, -50.0);
+        let options = BesselOptions {
+            scaling: Scaling::Scaled,
+            n: 3,
+            ..Default::default()
+        };
+        let result = bessel_k_opt(z, 0.5, &options).unwrap();
+        let direct = bessel_k(z, 0.5, 2, 3).unwrap();
+        assert_eq!(result.values, direct.values);
+    }
+
+    #[test]
+    fn test_max_n_guard() {
+        let z = Complex64::new(1.0, 1.0);
+        let options = BesselOptions {
+            n: 10,
+            max_n: 5,
+            ..Default::default()
+        };
+        assert!(matches!(
+            bessel_i_opt(z, 0.0, &options),
+            Err(BesselError::InvalidParameter(_))
+        ));
+    }
+
+    // The Rust backend rejects such arguments outright (IERR=4)
+    #[cfg(feature = "amos")]
+    #[test]
+    fn test_allow_accuracy_loss() {
+        // |z| beyond sqrt(0.5·i32::MAX) triggers IERR=3 in zbesj; the right
+        // half-plane is served by the Hankel expansion instead
+        assert!(bessel_j(Complex64::new(1.0e8, 0.0), 0.0, 1, 1).is_ok());
+        let z = Complex64::new(-1.0e8, 0.0);
+        assert!(bessel_j(z, 0.0, 1, 1).is_err());
+
+        let options = BesselOptions {
+            allow_accuracy_loss: true,
+            ..Default::default()
+        };
+        let result = bessel_j_opt(z, 0.0, &options).unwrap();
+        assert!(result.values[0].norm() < 1e-3);
+    }
+
+    #[test]
+    fn test_cut_side() {
+        let above = Complex64::new(-3.0, 0.0);
+        let below = Complex64::new(-3.0, -0.0);
+        let near_below = Complex64::new(-3.0, -1e-13);
+        let reference = bessel_k_opt(near_below, 0.5, &BesselOptions::default()).unwrap();
+        let below_by_default = bessel_k_opt(below, 0.5, &BesselOptions::default()).unwrap();
+        assert_eq!(
+            below_by_default.values,
+            bessel_k(below, 0.5, 1, 1).unwrap().values
+        );
+        let mut options = BesselOptions {
+            n: 2,
+            cut_side: CutSide::Above,
+            ..Default::default()
+        };
+        let from_above = bessel_k_opt(below, 0.5, &options).unwrap();
+        assert_eq!(
+            from_above.values,
+            bessel_k_opt(above, 0.5, &options).unwrap().values
+        );
+
+        options.cut_side = CutSide::Below;
+        let result = bessel_k_opt(above, 0.5, &options).unwrap();
+        assert!((result.values[0] - reference.values[0]).norm() < 1e-10);
+        for (value, from_above) in result.values.iter().zip(&from_above.values) {
+            assert_eq!(*value, from_above.conj());
+        }
+
+        options.cut_side = CutSide::SignOfZero;
+        assert_eq!(
+            bessel_k_opt(below, 0.5, &options).unwrap().values,
+            result.values
+        );
+        assert_eq!(
+            bessel_k_opt(above, 0.5, &options).unwrap().values,
+            from_above.values
+        );
+        // Off the cut, or on the positive real axis, the option has no effect
+        let off = Complex64::new(2.0, -0.0);
+        let result = bessel_j_opt(off, 1.5, &options).unwrap();
+        options.cut_side = CutSide::Above;
+        assert_eq!(
+            result.values,
+            bessel_j_opt(off, 1.5, &options).unwrap().values
+        );
+    }
+
+    #[test]
+    fn test_overflow_to_infinity() {
+        let z = Complex64::new(800.0, 300.0);
+        let mut options = BesselOptions {
+            n: 2,
+            ..Default::default()
+        };
+        assert!(bessel_i_opt(z, 0.0, &options).unwrap_err().is_overflow());
+
+        options.overflow_to_infinity = true;
+        let result = bessel_i_opt(z, 0.0, &options).unwrap();
+        assert_eq!(result.overflow_count, 2);
+        // I_ν(z) ~ e^z / sqrt(2πz), so the direction is that of e^{300i} / sqrt(z)
+        let direction = Complex64::from_polar(1.0, 300.0) / z.sqrt();
+        for value in &result.values {
+            assert_eq!(value.re.signum(), direction.re.signum());
+            assert_eq!(value.im.signum(), direction.im.signum());
+            assert!(value.re.is_infinite() && value.im.is_infinite());
+        }
+
+        // On the real axis the imaginary part stays 0, and finite values
+        // are left alone
+        let result = bessel_i_opt(Complex64::new(750.0, 0.0), 0.0, &options).unwrap();
+        assert_eq!(result.values[0], Complex64::new(f64::INFINITY, 0.0));
+        let finite = bessel_i_opt(Complex64::new(2.0, 0.0), 0.0, &options).unwrap();
+        assert_eq!(finite.overflow_count, 0);
+    }
+
+    #[test]
+    fn test_overflow_recounts_underflows() {
+        // Unscaling K multiplies by e^{-z}, which takes the first value to
+        // zero; the second was already zero in the scaled result
+        let scaled = BesselResult {
+            values: vec![Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0)],
+            underflow_count: 1,
+            overflow_count: 0,
+            lost_digits: 0.0,
+        };
+        let z = Complex64::new(800.0, 0.0);
+        let result = overflowed(BesselKind::K, z, 0.0, 2, Some(scaled));
+        assert_eq!(result.values, vec![Complex64::new(0.0, 0.0); 2]);
+        assert_eq!(result.underflow_count, 2);
+        assert_eq!(result.overflow_count, 0);
+    }
+}
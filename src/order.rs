@@ -0,0 +1,110 @@
+//! Order type with classification for special-case routing
+//!
+//! [`bessel`](crate::bessel) accepts anything convertible into an [`Order`],
+//! so plain `f64` and `i32` orders keep working. Integer and half-integer
+//! orders are recognized and routed to cheaper paths: negative integer orders
+//! use the sign identities instead of the general reflection formulas, and
+//! half-integer orders, ±1/2 included, reach the spherical closed forms of
+//! the direct paths (-1/2 through the reflection formulas, which are exact
+//! there).
+//!
+//! ```rust
+//! use zbessel_rs::Order;
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let order = Order::new(-2.5)?;
+//!     assert!(order.is_half_integer() && !order.is_integer());
+//!     assert!(Order::new(f64::NAN).is_err());
+//!     assert!(Order::from(3).is_integer());
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{BesselError, BesselKind};
+
+/// Order ν of a Bessel function
+///
+/// [`Order::new`] rejects non-finite values up front; orders converted with
+/// `From` are checked when they are evaluated.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Order(f64);
+
+impl Order {
+    /// Create an order, rejecting NaN and infinities
+    pub fn new(nu: f64) -> Result<Self, BesselError> {
+        if !nu.is_finite() {
+            return Err(BesselError::InvalidParameter(format!(
+                "nu must be finite, got {}",
+                nu
+            )));
+        }
+        Ok(Order(nu))
+    }
+
+    /// The order as `f64`
+    pub fn value(self) -> f64 {
+        self.0
+    }
+
+    /// Whether ν is an integer
+    pub fn is_integer(self) -> bool {
+        self.0.is_finite() && self.0.fract() == 0.0
+    }
+
+    /// Whether ν is an odd multiple of 1/2 (the spherical Bessel orders)
+    pub fn is_half_integer(self) -> bool {
+        self.0.is_finite() && self.0.fract() != 0.0 && (self.0 - 0.5).fract() == 0.0
+    }
+}
+
+impl From<f64> for Order {
+    fn from(nu: f64) -> Self {
+        Order(nu)
+    }
+}
+
+impl From<i32> for Order {
+    fn from(n: i32) -> Self {
+        Order(n as f64)
+    }
+}
+
+impl From<Order> for f64 {
+    fn from(order: Order) -> Self {
+        order.0
+    }
+}
+
+/// Sign relating F_{-n} to F_n for integer n
+pub(crate) fn negative_integer_sign(kind: BesselKind, n: f64) -> f64 {
+    match kind {
+        // I_{-n} = I_n, K_{-n} = K_n
+        BesselKind::I | BesselKind::K => 1.0,
+        // J, Y and both Hankel functions pick up (-1)^n
+        _ => {
+            if n % 2.0 == 0.0 {
+                1.0
+            } else {
+                -1.0
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classification() {
+        assert!(Order::from(0.0).is_integer());
+        assert!(Order::from(-7).is_integer());
+        assert!(!Order::from(0.5).is_integer());
+        assert!(Order::from(0.5).is_half_integer());
+        assert!(Order::from(-1.5).is_half_integer());
+        assert!(!Order::from(1.0).is_half_integer());
+        assert!(!Order::from(f64::INFINITY).is_integer());
+        assert!(Order::new(f64::NEG_INFINITY).is_err());
+    }
+}
@@ -0,0 +1,269 @@
+//! Evaluation at an arbitrary list of orders
+//!
+//! The orders are sorted and split into runs ν, ν+1, ν+2, ... so that each
+//! run is evaluated with a single sequence call, then the values are returned
+//! in the order they were requested. [`bessel_span`] goes further and covers
+//! orders that differ by any integer with one sequence call, computing the
+//! orders in between by the recurrence inside the sequence routines.
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{bessel_k_orders, Scaling};
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let z = Complex64::new(2.0, 1.0);
+//!
+//!     // Two FFI calls: {0.5, 1.5, 2.5} and {1/3}
+//!     let result = bessel_k_orders(z, &[2.5, 1.0 / 3.0, 0.5, 1.5], Scaling::Unscaled)?;
+//!     assert_eq!(result.values.len(), 4);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{BesselError, BesselKind, BesselResult, Scaling, DEFAULT_MAX_N};
+use num_complex::Complex64;
+
+/// Calculate a Bessel function of the given kind at each of `orders`
+///
+/// # Parameters
+/// * `kind` - Kind of Bessel function
+/// * `z` - Complex argument
+/// * `orders` - Orders to evaluate (any real numbers, in any order, duplicates allowed)
+/// * `scaling` - Scaling option
+///
+/// # Returns
+/// `values[i]` holds the function at `orders[i]`; `underflow_count` is the
+/// total over all runs.
+pub fn bessel_orders(
+    kind: BesselKind,
+    z: Complex64,
+    orders: &[f64],
+    scaling: Scaling,
+) -> Result<BesselResult, BesselError> {
+    if orders.is_empty() {
+        return Err(BesselError::InvalidParameter(
+            "orders must not be empty".to_string(),
+        ));
+    }
+
+    let mut sorted: Vec<usize> = (0..orders.len()).collect();
+    sorted.sort_by(|&a, &b| orders[a].total_cmp(&orders[b]));
+
+    let mut values = vec![Complex64::new(0.0, 0.0); orders.len()];
+    let mut underflow_count = 0;
+    let mut lost_digits: f64 = 0.0;
+
+    let mut start = 0;
+    while start < sorted.len() {
+        // Extend the run while the next distinct order is exactly one larger
+        let mut end = start + 1;
+        let mut last = orders[sorted[start]];
+        let mut len = 1;
+        while end < sorted.len() {
+            let next = orders[sorted[end]];
+            if next == last {
+                end += 1;
+            } else if next == last + 1.0 {
+                last = next;
+                len += 1;
+                end += 1;
+            } else {
+                break;
+            }
+        }
+
+        let nu = orders[sorted[start]];
+        let run = kind.eval_impl(z, nu, scaling.kode(), len, false)?;
+        underflow_count += run.underflow_count;
+        lost_digits = lost_digits.max(run.lost_digits);
+
+        let mut k = 0;
+        for (pos, &idx) in sorted[start..end].iter().enumerate() {
+            if pos > 0 && orders[idx] != orders[sorted[start + pos - 1]] {
+                k += 1;
+            }
+            values[idx] = run.values[k];
+        }
+
+        start = end;
+    }
+
+    Ok(BesselResult {
+        values,
+        underflow_count,
+        overflow_count: 0,
+        lost_digits,
+    })
+}
+
+/// Calculate Bessel function J_ν(z) at each of `orders`
+///
+/// See [`bessel_orders`].
+pub fn bessel_j_orders(
+    z: Complex64,
+    orders: &[f64],
+    scaling: Scaling,
+) -> Result<BesselResult, BesselError> {
+    bessel_orders(BesselKind::J, z, orders, scaling)
+}
+
+/// Calculate Bessel function Y_ν(z) at each of `orders`
+///
+/// See [`bessel_orders`].
+pub fn bessel_y_orders(
+    z: Complex64,
+    orders: &[f64],
+    scaling: Scaling,
+) -> Result<BesselResult, BesselError> {
+    bessel_orders(BesselKind::Y, z, orders, scaling)
+}
+
+/// Calculate modified Bessel function I_ν(z) at each of `orders`
+///
+/// See [`bessel_orders`].
+pub fn bessel_i_orders(
+    z: Complex64,
+    orders: &[f64],
+    scaling: Scaling,
+) -> Result<BesselResult, BesselError> {
+    bessel_orders(BesselKind::I, z, orders, scaling)
+}
+
+/// Calculate modified Bessel function K_ν(z) at each of `orders`
+///
+/// See [`bessel_orders`].
+pub fn bessel_k_orders(
+    z: Complex64,
+    orders: &[f64],
+    scaling: Scaling,
+) -> Result<BesselResult, BesselError> {
+    bessel_orders(BesselKind::K, z, orders, scaling)
+}
+
+/// Largest gap between requested orders bridged by one sequence call
+const MAX_GAP: usize = 64;
+
+/// Calculate a Bessel function of the given kind at each of `orders` from
+/// as few sequence calls as possible
+///
+/// Orders that differ from the smallest one of a group by an integer, with
+/// gaps of at most 64 between neighbours, are read off a single sequence
+/// ν, ν+1, ..., so evaluating J at orders 0, 10, 20, ..., 1000 costs one
+/// call instead of 101. Scattered fractional orders fall back to one call
+/// each.
+///
+/// # Parameters
+/// * `kind` - Kind of Bessel function
+/// * `z` - Complex argument
+/// * `orders` - Orders to evaluate (any real numbers, in any order, duplicates allowed)
+/// * `scaling` - Scaling option
+///
+/// # Returns
+/// `values[i]` holds the function at `orders[i]`; `underflow_count` is the
+/// total over the computed sequences, including orders in the gaps.
+pub fn bessel_span(
+    kind: BesselKind,
+    z: Complex64,
+    orders: &[f64],
+    scaling: Scaling,
+) -> Result<BesselResult, BesselError> {
+    if orders.is_empty() {
+        return Err(BesselError::InvalidParameter(
+            "orders must not be empty".to_string(),
+        ));
+    }
+
+    let mut sorted: Vec<usize> = (0..orders.len()).collect();
+    sorted.sort_by(|&a, &b| orders[a].total_cmp(&orders[b]));
+
+    let mut values = vec![Complex64::new(0.0, 0.0); orders.len()];
+    let mut underflow_count = 0;
+    let mut lost_digits: f64 = 0.0;
+
+    // Each group is a start order and the (order index, offset) pairs it covers
+    let mut remaining = sorted;
+    while let Some(&first) = remaining.first() {
+        let start = orders[first];
+        let mut members = Vec::new();
+        let mut rest = Vec::new();
+        let mut last = 0;
+        for &idx in &remaining {
+            let offset = (orders[idx] - start).round();
+            let exact = start + offset == orders[idx];
+            let k = offset as usize;
+            if exact && k - last <= MAX_GAP && k < DEFAULT_MAX_N {
+                members.push((idx, k));
+                last = k;
+            } else {
+                rest.push(idx);
+            }
+        }
+
+        let run = kind.eval_impl(z, start, scaling.kode(), last + 1, false)?;
+        underflow_count += run.underflow_count;
+        lost_digits = lost_digits.max(run.lost_digits);
+        for (idx, k) in members {
+            values[idx] = run.values[k];
+        }
+        remaining = rest;
+    }
+
+    Ok(BesselResult {
+        values,
+        underflow_count,
+        overflow_count: 0,
+        lost_digits,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{J, K};
+
+    #[test]
+    fn test_orders_match_single_values() {
+        let z = Complex64::new(3.0, -1.0);
+        let orders = [2.5, 0.25, 1.5, -0.5, 0.5, 1.5, 7.0];
+
+        let result = bessel_k_orders(z, &orders, Scaling::Unscaled).unwrap();
+        for (nu, value) in orders.iter().zip(&result.values) {
+            let expected = K(*nu, z).unwrap();
+            let diff = (value - expected).norm() / expected.norm();
+            assert!(diff < 1e-14, "K_{} mismatch: diff = {}", nu, diff);
+        }
+
+        let result = bessel_j_orders(z, &orders, Scaling::Unscaled).unwrap();
+        for (nu, value) in orders.iter().zip(&result.values) {
+            let expected = J(*nu, z).unwrap();
+            let diff = (value - expected).norm() / expected.norm();
+            assert!(diff < 1e-13, "J_{} mismatch: diff = {}", nu, diff);
+        }
+    }
+
+    #[test]
+    fn test_span_bridges_gaps() {
+        let z = Complex64::new(200.0, 2.0);
+        let orders: Vec<f64> = (0..=30)
+            .rev()
+            .map(|k| 5.0 * k as f64)
+            .chain([0.3, 40.3, 1.0 / 3.0, 400.0, 1.0])
+            .collect();
+        for kind in [BesselKind::J, BesselKind::K] {
+            let result = bessel_span(kind, z, &orders, Scaling::Scaled).unwrap();
+            for (nu, value) in orders.iter().zip(&result.values) {
+                let expected = crate::bessel(kind, *nu, z, Scaling::Scaled).unwrap();
+                let diff = (value - expected).norm() / expected.norm();
+                assert!(diff < 1e-12, "{:?}_{}: diff = {:e}", kind, nu, diff);
+            }
+        }
+        assert!(bessel_span(BesselKind::J, z, &[], Scaling::Unscaled).is_err());
+    }
+
+    #[test]
+    fn test_empty_orders() {
+        let z = Complex64::new(1.0, 0.0);
+        assert!(bessel_i_orders(z, &[], Scaling::Unscaled).is_err());
+    }
+}
@@ -0,0 +1,112 @@
+//! Exact values at z = 0
+//!
+//! The Amos routines reject z = 0 (IERR 1) for Y, K and the Hankel functions,
+//! so a grid or slice that happens to contain the origin would fail as a
+//! whole. The values there are known exactly and are returned without a
+//! backend call:
+//!
+//! * J_ν(0) and I_ν(0) are 1 for ν = 0 and 0 for ν > 0 or a negative
+//!   integer ν; for other negative ν they diverge with the sign of
+//!   1/Γ(ν+1)
+//! * Y_ν(0) is -∞ for ν ≥ 0; for ν < 0 it has the sign of -cos(νπ), or is 0
+//!   when cos(νπ) = 0
+//! * K_ν(0) is +∞
+//! * H^(1)_ν(0) = J_ν(0) + iY_ν(0) and H^(2)_ν(0) = J_ν(0) - iY_ν(0)
+//!
+//! The divergent values are the limits as x → 0 along the positive real axis,
+//! where every function is real; near the origin off that axis the functions
+//! grow without bound in all directions. Every scaling factor is 1 at z = 0.
+
+use crate::{BesselKind, BesselResult};
+use num_complex::Complex64;
+
+/// Value of the given kind and order at z = 0
+pub(crate) fn value(kind: BesselKind, nu: f64) -> Complex64 {
+    let j = || {
+        if nu == 0.0 {
+            1.0
+        } else if nu > 0.0 || nu.fract() == 0.0 {
+            0.0
+        } else {
+            // 1/Γ(ν+1) is positive for ν > -1 and alternates in sign
+            // between the poles at the negative integers
+            let sign = if (-nu).ceil() % 2.0 == 1.0 { 1.0 } else { -1.0 };
+            sign * f64::INFINITY
+        }
+    };
+    let y = || {
+        if nu >= 0.0 {
+            f64::NEG_INFINITY
+        } else if (nu - 0.5).fract() == 0.0 {
+            // cos(νπ) = 0 exactly at half-odd-integer orders, where Y_ν = ±J_{-ν}
+            0.0
+        } else {
+            -(nu * std::f64::consts::PI).cos().signum() * f64::INFINITY
+        }
+    };
+    match kind {
+        BesselKind::J | BesselKind::I => Complex64::new(j(), 0.0),
+        BesselKind::Y => Complex64::new(y(), 0.0),
+        BesselKind::K => Complex64::new(f64::INFINITY, 0.0),
+        BesselKind::H1 => Complex64::new(j(), y()),
+        BesselKind::H2 => Complex64::new(j(), -y()),
+    }
+}
+
+/// Orders ν, ..., ν+n-1 at z = 0, or `None` if `z` is not 0
+pub(crate) fn sequence(kind: BesselKind, z: Complex64, nu: f64, n: usize) -> Option<BesselResult> {
+    if z != Complex64::new(0.0, 0.0) {
+        return None;
+    }
+    Some(BesselResult {
+        values: (0..n).map(|k| value(kind, nu + k as f64)).collect(),
+        underflow_count: 0,
+        overflow_count: 0,
+        lost_digits: 0.0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bessel, bessel_grid, bessel_y, Scaling};
+
+    #[test]
+    fn test_values() {
+        let inf = f64::INFINITY;
+        let z = Complex64::new(0.0, 0.0);
+        let cases = [
+            (BesselKind::J, 0.0, Complex64::new(1.0, 0.0)),
+            (BesselKind::J, 2.5, Complex64::new(0.0, 0.0)),
+            (BesselKind::J, -3.0, Complex64::new(0.0, 0.0)),
+            // J_{-1/2}(x) = √(2/πx) cos x, J_{-3/2}(x) = -√(2/πx) (cos x / x + sin x)
+            (BesselKind::J, -0.5, Complex64::new(inf, 0.0)),
+            (BesselKind::J, -1.5, Complex64::new(-inf, 0.0)),
+            (BesselKind::I, -2.5, Complex64::new(inf, 0.0)),
+            (BesselKind::Y, 1.0, Complex64::new(-inf, 0.0)),
+            // Y_{-1}(x) = -Y_1(x), Y_{-1/2}(x) = J_{1/2}(x)
+            (BesselKind::Y, -1.0, Complex64::new(inf, 0.0)),
+            (BesselKind::Y, -0.5, Complex64::new(0.0, 0.0)),
+            (BesselKind::K, -0.3, Complex64::new(inf, 0.0)),
+            (BesselKind::H1, 0.0, Complex64::new(1.0, -inf)),
+            (BesselKind::H2, 2.0, Complex64::new(0.0, inf)),
+        ];
+        for (kind, nu, expected) in cases {
+            for scaling in [Scaling::Unscaled, Scaling::Scaled] {
+                let value = bessel(kind, nu, z, scaling).unwrap();
+                assert_eq!(value, expected, "{:?}_{}(0)", kind, nu);
+            }
+        }
+        let y = bessel_y(z, -1.5, 1, 3).unwrap();
+        assert_eq!(y.values[1], Complex64::new(0.0, 0.0));
+        assert_eq!(y.values[2], Complex64::new(-inf, 0.0));
+    }
+
+    #[test]
+    fn test_grid_through_origin() {
+        let zs = [-1.0, 0.0, 1.0].map(|x| Complex64::new(x, 0.0));
+        let grid = bessel_grid(BesselKind::K, &[0.0, 1.0], &zs, Scaling::Scaled).unwrap();
+        assert_eq!(grid.get(1, 1), Complex64::new(f64::INFINITY, 0.0));
+        assert!(grid.get(0, 2).re.is_finite());
+    }
+}
@@ -0,0 +1,150 @@
+//! Parallel batch evaluation with rayon (feature `rayon`)
+//!
+//! The Amos kernels keep no global state, so independent arguments can be
+//! evaluated on different threads. Outputs are always in input order, and
+//! when several points fail the error of the first one (by index) is
+//! returned, so results do not depend on the thread schedule.
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{par_bessel_j_slice, par_grid, BesselKind, Scaling};
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let zs: Vec<Complex64> = (1..=10_000)
+//!         .map(|k| Complex64::new(0.01 * k as f64, 0.25))
+//!         .collect();
+//!     let j0 = par_bessel_j_slice(&zs, 0.0, Scaling::Unscaled)?;
+//!
+//!     // K_0, ..., K_4 at every point, one row per argument
+//!     let k = par_grid(BesselKind::K, &zs, 0.0, 5, Scaling::Scaled)?;
+//!     assert_eq!(k.len(), zs.len() * 5);
+//!     println!("{} {}", j0[9_999], k[5 * 9_999 + 4]);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{bessel, bessel_into, bessel_j_slice, BesselError, BesselKind, Scaling};
+use num_complex::Complex64;
+use rayon::prelude::*;
+
+/// Points per task for the lane-wise J kernel
+const PAR_CHUNK: usize = 1024;
+
+/// Evaluate `kind` of order `nu` at every point of `zs` in parallel
+///
+/// # Parameters
+/// * `kind` - Kind of Bessel function
+/// * `zs` - Complex arguments
+/// * `nu` - Order (real number)
+/// * `scaling` - Scaling option
+pub fn par_bessel_slice(
+    kind: BesselKind,
+    zs: &[Complex64],
+    nu: f64,
+    scaling: Scaling,
+) -> Result<Vec<Complex64>, BesselError> {
+    let results: Vec<_> = zs
+        .par_iter()
+        .map(|&z| bessel(kind, nu, z, scaling))
+        .collect();
+    results.into_iter().collect()
+}
+
+/// Parallel [`bessel_j_slice`]: J_ν at every point of `zs`
+///
+/// # Parameters
+/// * `zs` - Complex arguments
+/// * `nu` - Order (real number)
+/// * `scaling` - Scaling option
+pub fn par_bessel_j_slice(
+    zs: &[Complex64],
+    nu: f64,
+    scaling: Scaling,
+) -> Result<Vec<Complex64>, BesselError> {
+    let chunks: Vec<_> = zs
+        .par_chunks(PAR_CHUNK)
+        .map(|chunk| bessel_j_slice(chunk, nu, scaling))
+        .collect();
+    let mut out = Vec::with_capacity(zs.len());
+    for chunk in chunks {
+        out.extend(chunk?);
+    }
+    Ok(out)
+}
+
+/// Evaluate the orders ν, ..., ν+n-1 at every point of `zs` in parallel
+///
+/// The result is row-major with one row of `n` values per argument: element
+/// `j * n + k` is F_{ν+k}(zs\[j\]).
+///
+/// # Parameters
+/// * `kind` - Kind of Bessel function
+/// * `zs` - Complex arguments
+/// * `nu` - Starting order (real number)
+/// * `n` - Number of orders per argument
+/// * `scaling` - Scaling option
+pub fn par_grid(
+    kind: BesselKind,
+    zs: &[Complex64],
+    nu: f64,
+    n: usize,
+    scaling: Scaling,
+) -> Result<Vec<Complex64>, BesselError> {
+    if n == 0 {
+        return Err(BesselError::InvalidParameter(
+            "n must be greater than 0".to_string(),
+        ));
+    }
+    let len = zs.len().checked_mul(n).ok_or_else(|| {
+        BesselError::InvalidParameter(format!("grid of {} x {} values is too large", zs.len(), n))
+    })?;
+    let mut out = vec![Complex64::new(0.0, 0.0); len];
+    let results: Vec<_> = out
+        .par_chunks_mut(n)
+        .zip(zs.par_iter())
+        .map(|(row, &z)| bessel_into(kind, z, nu, scaling, row).map(|_| ()))
+        .collect();
+    results.into_iter().collect::<Result<(), _>>()?;
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bessel_k;
+
+    #[test]
+    fn test_parallel_matches_serial() {
+        let zs: Vec<Complex64> = (0..5000)
+            .map(|k| Complex64::new(0.02 * k as f64 - 20.0, 0.3))
+            .collect();
+        // Lanes are grouped per task, so the last bit may differ from the serial call
+        let parallel = par_bessel_j_slice(&zs, 1.5, Scaling::Unscaled).unwrap();
+        let serial = bessel_j_slice(&zs, 1.5, Scaling::Unscaled).unwrap();
+        for (p, s) in parallel.iter().zip(&serial) {
+            assert!((p - s).norm() <= 1e-14 * s.norm());
+        }
+        let y = par_bessel_slice(BesselKind::Y, &zs, 0.0, Scaling::Scaled).unwrap();
+        assert_eq!(
+            y[1234],
+            bessel(BesselKind::Y, 0.0, zs[1234], Scaling::Scaled).unwrap()
+        );
+
+        let grid = par_grid(BesselKind::K, &zs[..100], 0.5, 3, Scaling::Unscaled).unwrap();
+        let row = bessel_k(zs[42], 0.5, 1, 3).unwrap();
+        assert_eq!(&grid[42 * 3..43 * 3], &row.values[..]);
+    }
+
+    #[test]
+    fn test_first_error_is_reported() {
+        let mut zs = vec![Complex64::new(1.0, 0.0); 4000];
+        zs[3000] = Complex64::new(f64::NAN, 0.0);
+        zs[100] = Complex64::new(f64::INFINITY, 0.0);
+        let error = par_bessel_slice(BesselKind::K, &zs, 0.0, Scaling::Unscaled).unwrap_err();
+        assert!(error.is_invalid_input());
+        assert_eq!(
+            error,
+            bessel(BesselKind::K, 0.0, zs[100], Scaling::Unscaled).unwrap_err()
+        );
+    }
+}
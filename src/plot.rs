@@ -0,0 +1,298 @@
+//! PNG images of functions over a complex rectangle
+//!
+//! A [`Plot`] evaluates a function at the centre of every pixel of a
+//! rectangle in the complex plane and writes the result as an RGB PNG. The
+//! two [`PlotStyle`]s show complementary features:
+//!
+//! * domain coloring: hue follows the phase (red for positive real values,
+//!   then yellow, green, cyan, blue and magenta counter-clockwise) and the
+//!   brightness repeats with every doubling of the magnitude, so zeros are
+//!   points where all colors meet, branch cuts are lines where the colors
+//!   jump, and the magnitude contours bunch up along Stokes lines
+//! * magnitude heatmap: log10 |w| on the viridis color map, stretched
+//!   between the 1st and 99th percentiles of the image
+//!
+//! Pixels where the function fails (at branch points, or on overflow) are
+//! drawn gray.
+//!
+//! ```rust,no_run
+//! use std::fs::File;
+//! use zbessel_rs::{BesselKind, Plot, PlotStyle, Scaling};
+//!
+//! fn main() -> std::io::Result<()> {
+//!     // The branch cut of Y_0 along the negative real axis and its zeros
+//!     let plot = Plot::new((-10.0, 10.0), (-5.0, 5.0)).size(800, 400);
+//!     plot.render_bessel(BesselKind::Y, 0.0, Scaling::Unscaled, File::create("y0.png")?)?;
+//!
+//!     // |Ai(z)| over the same region
+//!     let heatmap = plot.style(PlotStyle::Magnitude);
+//!     heatmap.render(|z| Ok(zbessel_rs::airy_ai(z, 0, 1)?.value), File::create("ai.png")?)
+//! }
+//! ```
+
+use crate::{bessel, BesselError, BesselKind, Scaling};
+use num_complex::Complex64;
+use std::f64::consts::PI;
+use std::io::{self, Write};
+
+/// Largest accepted width or height in pixels
+const MAX_SIDE: u32 = 16384;
+
+/// Width used when [`Plot::size`] is not called
+const DEFAULT_WIDTH: u32 = 800;
+
+/// Color of pixels where the function failed or is NaN
+const GRAY: [u8; 3] = [128, 128, 128];
+
+/// Samples of the viridis color map at 0, 1/8, ..., 1
+const VIRIDIS: [[f64; 3]; 9] = [
+    [68.0, 1.0, 84.0],
+    [71.0, 44.0, 122.0],
+    [59.0, 81.0, 139.0],
+    [44.0, 113.0, 142.0],
+    [33.0, 144.0, 141.0],
+    [39.0, 173.0, 129.0],
+    [92.0, 200.0, 99.0],
+    [170.0, 220.0, 50.0],
+    [253.0, 231.0, 37.0],
+];
+
+/// How function values are turned into colors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PlotStyle {
+    /// Hue from the phase, brightness cycling with log2 of the magnitude
+    #[default]
+    DomainColoring,
+    /// log10 of the magnitude on the viridis color map
+    Magnitude,
+}
+
+/// Image of a function over the rectangle `re` × i`im`
+///
+/// Created with [`Plot::new`], configured with [`size`](Plot::size) and
+/// [`style`](Plot::style), and drawn with [`render`](Plot::render) or
+/// [`render_bessel`](Plot::render_bessel). The top row of the image is the
+/// largest imaginary part.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Plot {
+    re: (f64, f64),
+    im: (f64, f64),
+    size: Option<(u32, u32)>,
+    style: PlotStyle,
+}
+
+impl Plot {
+    /// Plot over real parts `re` and imaginary parts `im`, each `(start, end)`
+    ///
+    /// By default the image is 800 pixels wide with square pixels, drawn
+    /// with domain coloring.
+    pub fn new(re: (f64, f64), im: (f64, f64)) -> Self {
+        Plot {
+            re,
+            im,
+            size: None,
+            style: PlotStyle::default(),
+        }
+    }
+
+    /// Image size in pixels
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.size = Some((width, height));
+        self
+    }
+
+    /// Coloring of the image
+    pub fn style(mut self, style: PlotStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Width and height, checking the rectangle and the size
+    fn dimensions(&self) -> io::Result<(u32, u32)> {
+        let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidInput, message);
+        for (name, (start, end)) in [("re", self.re), ("im", self.im)] {
+            if !(start.is_finite() && end.is_finite() && start < end) {
+                return Err(invalid(format!(
+                    "{} range ({}, {}) must be finite and increasing",
+                    name, start, end
+                )));
+            }
+        }
+        let (width, height) = self.size.unwrap_or_else(|| {
+            let aspect = (self.im.1 - self.im.0) / (self.re.1 - self.re.0);
+            let height = (DEFAULT_WIDTH as f64 * aspect).round();
+            (DEFAULT_WIDTH, height.clamp(1.0, MAX_SIDE as f64) as u32)
+        });
+        if !(1..=MAX_SIDE).contains(&width) || !(1..=MAX_SIDE).contains(&height) {
+            return Err(invalid(format!(
+                "image size {} x {} must be between 1 and {} pixels a side",
+                width, height, MAX_SIDE
+            )));
+        }
+        Ok((width, height))
+    }
+
+    /// Draw `f` and write the PNG to `writer`
+    ///
+    /// # Parameters
+    /// * `f` - Function to draw; points where it returns an error are gray
+    /// * `writer` - Destination of the PNG data
+    ///
+    /// # Errors
+    /// `InvalidInput` for an empty or non-finite rectangle or a size outside
+    /// 1 to 16384 pixels a side, and any error of the writer
+    pub fn render(
+        &self,
+        f: impl Fn(Complex64) -> Result<Complex64, BesselError>,
+        writer: impl Write,
+    ) -> io::Result<()> {
+        let (width, height) = self.dimensions()?;
+        let dx = (self.re.1 - self.re.0) / width as f64;
+        let dy = (self.im.1 - self.im.0) / height as f64;
+        let values: Vec<Option<Complex64>> = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(|(row, col)| {
+                let z = Complex64::new(
+                    self.re.0 + (col as f64 + 0.5) * dx,
+                    self.im.1 - (row as f64 + 0.5) * dy,
+                );
+                f(z).ok().filter(|w| !w.re.is_nan() && !w.im.is_nan())
+            })
+            .collect();
+        let pixels = match self.style {
+            PlotStyle::DomainColoring => values.iter().map(|&w| domain_color(w)).collect(),
+            PlotStyle::Magnitude => heatmap(&values),
+        };
+        encode(width, height, &pixels, writer)
+    }
+
+    /// Draw `kind` of order `nu` and write the PNG to `writer`
+    ///
+    /// # Parameters
+    /// * `kind` - Which function to draw
+    /// * `nu` - Order (real number)
+    /// * `scaling` - Whether to apply the exponential scaling factor
+    /// * `writer` - Destination of the PNG data
+    ///
+    /// # Errors
+    /// As for [`render`](Plot::render)
+    pub fn render_bessel(
+        &self,
+        kind: BesselKind,
+        nu: f64,
+        scaling: Scaling,
+        writer: impl Write,
+    ) -> io::Result<()> {
+        self.render(|z| bessel(kind, nu, z, scaling), writer)
+    }
+}
+
+/// RGB color of hue `h` (in turns), saturation `s` and value `v`
+fn hsv(h: f64, s: f64, v: f64) -> [u8; 3] {
+    let h = h.rem_euclid(1.0) * 6.0;
+    let c = v * s;
+    let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let m = v - c;
+    [r, g, b].map(|channel| ((channel + m) * 255.0).round() as u8)
+}
+
+fn domain_color(w: Option<Complex64>) -> [u8; 3] {
+    let Some(w) = w else {
+        return GRAY;
+    };
+    let magnitude = w.norm();
+    if magnitude == 0.0 {
+        return [0, 0, 0];
+    }
+    if magnitude.is_infinite() {
+        return [255, 255, 255];
+    }
+    let contour = magnitude.log2().rem_euclid(1.0);
+    hsv(w.arg() / (2.0 * PI), 0.9, 0.6 + 0.4 * contour)
+}
+
+fn viridis(t: f64) -> [u8; 3] {
+    let x = t.clamp(0.0, 1.0) * 8.0;
+    let i = (x as usize).min(7);
+    let frac = x - i as f64;
+    let (a, b) = (VIRIDIS[i], VIRIDIS[i + 1]);
+    [0, 1, 2].map(|k| (a[k] + (b[k] - a[k]) * frac).round() as u8)
+}
+
+fn heatmap(values: &[Option<Complex64>]) -> Vec<[u8; 3]> {
+    let logs: Vec<Option<f64>> = values.iter().map(|w| w.map(|w| w.norm().log10())).collect();
+    let mut finite: Vec<f64> = logs
+        .iter()
+        .flatten()
+        .copied()
+        .filter(|x| x.is_finite())
+        .collect();
+    finite.sort_by(f64::total_cmp);
+    let (low, high) = match finite.len() {
+        0 => (0.0, 1.0),
+        n => (finite[n / 100], finite[(n - 1) - n / 100]),
+    };
+    let span = if high > low { high - low } else { 1.0 };
+    logs.iter()
+        .map(|log| match log {
+            Some(log) => viridis((log - low) / span),
+            None => GRAY,
+        })
+        .collect()
+}
+
+fn encode(width: u32, height: u32, pixels: &[[u8; 3]], writer: impl Write) -> io::Result<()> {
+    let mut encoder = png::Encoder::new(writer, width, height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(pixels.as_flattened())?;
+    writer.finish()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_colors() {
+        assert_eq!(hsv(0.0, 1.0, 1.0), [255, 0, 0]);
+        assert_eq!(hsv(1.0 / 3.0, 1.0, 1.0), [0, 255, 0]);
+        assert_eq!(domain_color(Some(Complex64::new(0.0, 0.0))), [0, 0, 0]);
+        assert_eq!(domain_color(None), GRAY);
+        assert_eq!(viridis(0.0), [68, 1, 84]);
+        assert_eq!(viridis(1.0), [253, 231, 37]);
+    }
+
+    #[test]
+    fn test_render() {
+        let mut png = Vec::new();
+        let plot = Plot::new((-2.0, 2.0), (-1.0, 1.0)).size(40, 20);
+        plot.render_bessel(BesselKind::K, 0.0, Scaling::Unscaled, &mut png)
+            .unwrap();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        // IHDR holds the width and height
+        assert_eq!(&png[16..24], &[0, 0, 0, 40, 0, 0, 0, 20]);
+
+        let mut heatmap = Vec::new();
+        let plot = plot.style(PlotStyle::Magnitude);
+        plot.render(|z| Ok(z.exp()), &mut heatmap).unwrap();
+        assert!(heatmap.len() > 8);
+        assert!(Plot::new((1.0, 0.0), (0.0, 1.0))
+            .render(Ok, Vec::new())
+            .is_err());
+        assert!(Plot::new((0.0, 1.0), (0.0, 1.0))
+            .size(0, 5)
+            .render(Ok, Vec::new())
+            .is_err());
+    }
+}
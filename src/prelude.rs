@@ -0,0 +1,20 @@
+//! Convenience re-exports for glob import
+//!
+//! ```rust
+//! use zbessel_rs::prelude::*;
+//!
+//! fn main() -> Result<(), BesselError> {
+//!     let z = Complex64::new(1.0, 0.5);
+//!     let j0 = J(0.0, z)?;
+//!     let k1 = z.bessel_k(1.0)?;
+//!     let h = bessel(BesselKind::H1, 0.5, z, Scaling::Scaled)?;
+//!     println!("{} {} {}", j0, k1, h);
+//!     Ok(())
+//! }
+//! ```
+
+pub use crate::{
+    bessel, Ai, Ai_scaled, BesselError, BesselExt, BesselKind, BesselResult, Bi, Bi_scaled,
+    I_scaled, In, J_scaled, Jn, K_scaled, Kn, Scaling, Y_scaled, Yn, I, J, K, Y,
+};
+pub use num_complex::Complex64;
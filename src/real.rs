@@ -0,0 +1,227 @@
+//! Real-argument functions returning real results
+//!
+//! Each function takes a real `x` and returns `f64`. When the value is
+//! genuinely complex (for example Y_ν(x) or K_ν(x) for x < 0, or J_ν(x) for
+//! x < 0 and non-integer ν) an `InvalidParameter` error is returned; use the
+//! complex API for those arguments.
+//!
+//! ```rust
+//! use zbessel_rs::real;
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let j0 = real::J(0.0, 2.404825557695773)?; // first zero of J_0
+//!     assert!(j0.abs() < 1e-14);
+//!
+//!     let k = real::K(0.5, 1.0)?;
+//!     println!("K_0.5(1) = {}", k);
+//!
+//!     assert!(real::Y(0.0, -1.0).is_err());
+//!     Ok(())
+//! }
+//! ```
+
+use crate::BesselError;
+use num_complex::Complex64;
+
+fn complex_result_error(function: &str, nu: f64, x: f64) -> BesselError {
+    // -∞ is reported as non-finite like any other NaN or infinite input
+    if let Err(e) = crate::validate_inputs(Complex64::new(x, 0.0), nu, 1) {
+        return e;
+    }
+    BesselError::InvalidParameter(format!(
+        "{}_{}({}) is complex; use the complex API",
+        function, nu, x
+    ))
+}
+
+/// Calculate Bessel function J_ν(x) for real x
+///
+/// # Parameters
+/// * `nu` - Order (real number)
+/// * `x` - Real argument (x < 0 requires an integer order)
+///
+/// # Returns
+/// Real value of J_ν(x)
+#[allow(non_snake_case)]
+pub fn J(nu: f64, x: f64) -> Result<f64, BesselError> {
+    if x < 0.0 && nu.fract() != 0.0 {
+        return Err(complex_result_error("J", nu, x));
+    }
+    Ok(crate::J(nu, Complex64::new(x, 0.0))?.re)
+}
+
+/// Calculate Bessel function Y_ν(x) for real x
+///
+/// # Parameters
+/// * `nu` - Order (real number)
+/// * `x` - Real argument (x >= 0; the value at 0 is infinite)
+///
+/// # Returns
+/// Real value of Y_ν(x)
+#[allow(non_snake_case)]
+pub fn Y(nu: f64, x: f64) -> Result<f64, BesselError> {
+    if x < 0.0 {
+        return Err(complex_result_error("Y", nu, x));
+    }
+    Ok(crate::Y(nu, Complex64::new(x, 0.0))?.re)
+}
+
+/// Calculate modified Bessel function I_ν(x) for real x
+///
+/// # Parameters
+/// * `nu` - Order (real number)
+/// * `x` - Real argument (x < 0 requires an integer order)
+///
+/// # Returns
+/// Real value of I_ν(x)
+#[allow(non_snake_case)]
+pub fn I(nu: f64, x: f64) -> Result<f64, BesselError> {
+    if x < 0.0 && nu.fract() != 0.0 {
+        return Err(complex_result_error("I", nu, x));
+    }
+    Ok(crate::I(nu, Complex64::new(x, 0.0))?.re)
+}
+
+/// Calculate modified Bessel function K_ν(x) for real x
+///
+/// # Parameters
+/// * `nu` - Order (real number)
+/// * `x` - Real argument (x >= 0; the value at 0 is infinite)
+///
+/// # Returns
+/// Real value of K_ν(x)
+#[allow(non_snake_case)]
+pub fn K(nu: f64, x: f64) -> Result<f64, BesselError> {
+    if x < 0.0 {
+        return Err(complex_result_error("K", nu, x));
+    }
+    Ok(crate::K(nu, Complex64::new(x, 0.0))?.re)
+}
+
+/// Calculate Airy function Ai(x) for real x
+///
+/// # Parameters
+/// * `x` - Real argument
+///
+/// # Returns
+/// Real value of Ai(x)
+#[allow(non_snake_case)]
+pub fn Ai(x: f64) -> Result<f64, BesselError> {
+    Ok(crate::Ai(Complex64::new(x, 0.0))?.re)
+}
+
+/// Calculate Airy function Bi(x) for real x
+///
+/// # Parameters
+/// * `x` - Real argument
+///
+/// # Returns
+/// Real value of Bi(x)
+#[allow(non_snake_case)]
+pub fn Bi(x: f64) -> Result<f64, BesselError> {
+    Ok(crate::Bi(Complex64::new(x, 0.0))?.re)
+}
+
+/// Calculate modified Bessel function I_ν(x) with scaling for real x
+///
+/// # Parameters
+/// * `nu` - Order (real number)
+/// * `x` - Real argument (x < 0 requires an integer order)
+///
+/// # Returns
+/// Real value of I_ν(x) with exp(-abs(x)) scaling
+#[allow(non_snake_case)]
+pub fn I_scaled(nu: f64, x: f64) -> Result<f64, BesselError> {
+    if x < 0.0 && nu.fract() != 0.0 {
+        return Err(complex_result_error("I", nu, x));
+    }
+    Ok(crate::I_scaled(nu, Complex64::new(x, 0.0))?.re)
+}
+
+/// Calculate modified Bessel function K_ν(x) with scaling for real x
+///
+/// # Parameters
+/// * `nu` - Order (real number)
+/// * `x` - Real argument (x >= 0; the value at 0 is infinite)
+///
+/// # Returns
+/// Real value of K_ν(x) with exp(x) scaling
+#[allow(non_snake_case)]
+pub fn K_scaled(nu: f64, x: f64) -> Result<f64, BesselError> {
+    if x < 0.0 {
+        return Err(complex_result_error("K", nu, x));
+    }
+    Ok(crate::K_scaled(nu, Complex64::new(x, 0.0))?.re)
+}
+
+/// Calculate Airy function Ai(x) with scaling for real x
+///
+/// # Parameters
+/// * `x` - Real argument
+///
+/// # Returns
+/// Real value of Ai(x) with exp(zeta) scaling where zeta=(2/3)*x^(3/2)
+/// (no scaling is applied for x < 0, where zeta is purely imaginary)
+#[allow(non_snake_case)]
+pub fn Ai_scaled(x: f64) -> Result<f64, BesselError> {
+    if x < 0.0 {
+        // exp(zeta) has unit modulus here and would only rotate the value off the real axis
+        return Ai(x);
+    }
+    Ok(crate::Ai_scaled(Complex64::new(x, 0.0))?.re)
+}
+
+/// Calculate Airy function Bi(x) with scaling for real x
+///
+/// # Parameters
+/// * `x` - Real argument
+///
+/// # Returns
+/// Real value of Bi(x) with exp(-abs(zeta)) scaling where zeta=(2/3)*x^(3/2)
+/// (no scaling is applied for x < 0, where zeta is purely imaginary)
+#[allow(non_snake_case)]
+pub fn Bi_scaled(x: f64) -> Result<f64, BesselError> {
+    Ok(crate::Bi_scaled(Complex64::new(x, 0.0))?.re)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_real_values() {
+        // Reference values from the DLMF / A&S tables
+        assert!((J(0.0, 1.0).unwrap() - 0.7651976865579666).abs() < 1e-15);
+        assert!((Y(1.0, 2.0).unwrap() - -0.10703243154093754).abs() < 1e-15);
+        assert!((I(0.0, 1.0).unwrap() - 1.2660658777520082).abs() < 1e-15);
+        assert!((K(0.0, 1.0).unwrap() - 0.42102443824070834).abs() < 1e-15);
+        assert!((Ai(0.0).unwrap() - 0.3550280538878172).abs() < 1e-15);
+        assert!((Bi(0.0).unwrap() - 0.6149266274460007).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_negative_argument_integer_order() {
+        // J_n(-x) = (-1)^n J_n(x), I_n(-x) = (-1)^n I_n(x)
+        let x = 2.5;
+        assert!((J(3.0, -x).unwrap() + J(3.0, x).unwrap()).abs() < 1e-15);
+        assert!((I(2.0, -x).unwrap() - I(2.0, x).unwrap()).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_complex_valued_arguments_are_rejected() {
+        assert!(J(0.5, -1.0).is_err());
+        assert!(I(0.5, -1.0).is_err());
+        assert!(Y(0.0, -1.0).is_err());
+        assert!(K(0.0, -1.0).is_err());
+        assert!(K_scaled(1.0, -2.0).is_err());
+        assert_eq!(K(0.0, 0.0).unwrap(), f64::INFINITY);
+        assert_eq!(Y(1.0, 0.0).unwrap(), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_airy_scaled_negative_argument() {
+        let x = -3.0;
+        assert_eq!(Ai_scaled(x).unwrap(), Ai(x).unwrap());
+        assert!((Bi_scaled(x).unwrap() - Bi(x).unwrap()).abs() < 1e-15);
+    }
+}
@@ -0,0 +1,180 @@
+//! Integer-order sequences by three-term recurrence
+//!
+//! For integer ν and long sequences only two backend values are needed; the
+//! rest follows from the recurrence (2k/z) F_k = F_{k-1} + F_{k+1} (J, Y),
+//! F_{k-1} - F_{k+1} (I) or F_{k+1} - F_{k-1} (K), run in its stable direction:
+//!
+//! * Y, K grow with the order and are recurred forward from F_ν, F_{ν+1}
+//!   (K only for Re z >= 0)
+//! * J, I decay with the order and are recurred backward from a higher start
+//!   order with arbitrary seeds (Miller's algorithm), then normalized by one
+//!   backend value at the order of largest magnitude
+//!
+//! Any backend error or non-finite intermediate makes the caller fall back to
+//! the generic path, which reports the error.
+
+use crate::backend::{ActiveBackend, Backend};
+use crate::{
+    clear_real_axis, lost_digits, miller_backward, BesselError, BesselKind, BesselResult,
+    MillerNormalization,
+};
+use num_complex::Complex64;
+
+/// Shortest sequence for which the recurrence is used
+pub(crate) const MIN_N: usize = 64;
+
+/// Orders ν, ..., ν+n-1 by recurrence, or `None` if it does not apply
+pub(crate) fn integer_sequence(
+    kind: BesselKind,
+    z: Complex64,
+    nu: f64,
+    kode: i32,
+    n: usize,
+) -> Option<BesselResult> {
+    if n < MIN_N || nu < 0.0 || nu.fract() != 0.0 || (z.re == 0.0 && z.im == 0.0) {
+        return None;
+    }
+    let values = match kind {
+        // In the left half-plane K picks up an I component (DLMF 10.34.2)
+        // and the forward recurrence loses accuracy
+        BesselKind::K if z.re < 0.0 => return None,
+        BesselKind::Y | BesselKind::K => forward(kind, z, nu, kode, n)?,
+        BesselKind::J | BesselKind::I => backward(kind, z, nu, kode, n)?,
+        BesselKind::H1 | BesselKind::H2 => return None,
+    };
+    if !values.iter().all(|v| v.re.is_finite() && v.im.is_finite()) {
+        return None;
+    }
+    let underflow_count = values.iter().filter(|v| v.norm_sqr() == 0.0).count() as i32;
+    Some(BesselResult {
+        values,
+        underflow_count,
+        overflow_count: 0,
+        lost_digits: lost_digits(z, nu, n),
+    })
+}
+
+/// Up to two consecutive backend values starting at `nu`
+fn seed(
+    kind: BesselKind,
+    z: Complex64,
+    nu: f64,
+    kode: i32,
+    count: usize,
+) -> Option<[Complex64; 2]> {
+    let (mut re, mut im) = ([0.0; 2], [0.0; 2]);
+    let (ierr, _) = ActiveBackend::bessel(kind, z, nu, kode, &mut re[..count], &mut im[..count]);
+    clear_real_axis(kind, z, &mut im);
+    (ierr == 0).then(|| [Complex64::new(re[0], im[0]), Complex64::new(re[1], im[1])])
+}
+
+/// Sign s with F_{k+1} = (2k/z) F_k + s F_{k-1} (Y, K) or
+/// F_{k-1} = (2k/z) F_k + s F_{k+1} (J, I)
+fn sign(kind: BesselKind) -> f64 {
+    match kind {
+        BesselKind::I | BesselKind::K => 1.0,
+        _ => -1.0,
+    }
+}
+
+fn forward(kind: BesselKind, z: Complex64, nu: f64, kode: i32, n: usize) -> Option<Vec<Complex64>> {
+    let [first, second] = seed(kind, z, nu, kode, 2)?;
+    let two_over_z = 2.0 / z;
+    let s = sign(kind);
+    let mut values = Vec::with_capacity(n);
+    values.push(first);
+    values.push(second);
+    for k in 1..n - 1 {
+        let next = two_over_z * (nu + k as f64) * values[k] + s * values[k - 1];
+        values.push(next);
+    }
+    Some(values)
+}
+
+fn backward(
+    kind: BesselKind,
+    z: Complex64,
+    nu: f64,
+    kode: i32,
+    n: usize,
+) -> Option<Vec<Complex64>> {
+    let top = nu + (n - 1) as f64;
+    let reach = top.max(z.norm());
+    let start = (reach + 30.0 + 4.0 * reach.sqrt()).ceil() - nu;
+    let two_over_z = 2.0 / z;
+    let s = Complex64::new(sign(kind), 0.0);
+    let coefficients = |k: usize| (two_over_z * (nu + k as f64), s);
+    let anchor = MillerNormalization::Peak(Box::new(|index| {
+        seed(kind, z, nu + index as f64, kode, 1)
+            .map(|[value, _]| value)
+            .ok_or_else(|| BesselError::InvalidParameter("anchor failed".to_string()))
+    }));
+    miller_backward(n, start as usize, coefficients, anchor).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recurrence_matches_backend() {
+        for kind in [BesselKind::J, BesselKind::Y, BesselKind::I, BesselKind::K] {
+            for &(re, im) in &[
+                (0.5, 0.0),
+                (3.0, 2.0),
+                (40.0, -5.0),
+                (-12.0, 1.0),
+                (150.0, 0.0),
+            ] {
+                let z = Complex64::new(re, im);
+                for &nu in &[0.0, 3.0] {
+                    for kode in 1..=2 {
+                        let n = 120;
+                        let Some(fast) = integer_sequence(kind, z, nu, kode, n) else {
+                            continue;
+                        };
+                        let (mut cyr, mut cyi) = (vec![0.0; n], vec![0.0; n]);
+                        ActiveBackend::bessel(kind, z, nu, kode, &mut cyr, &mut cyi);
+                        assert_relative(&fast.values, &cyr, &cyi, kind, z, nu);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_recurrence_decaying_orders() {
+        // J_n(1) falls by a factor of about n/2 per order, to 1e-107 at n = 63
+        let z = Complex64::new(1.0, 0.0);
+        let n = MIN_N;
+        let fast = integer_sequence(BesselKind::J, z, 0.0, 1, n).unwrap();
+        let (mut cyr, mut cyi) = (vec![0.0; n], vec![0.0; n]);
+        ActiveBackend::bessel(BesselKind::J, z, 0.0, 1, &mut cyr, &mut cyi);
+        assert!(cyr[n - 1] < 1e-100);
+        assert_relative(&fast.values, &cyr, &cyi, BesselKind::J, z, 0.0);
+    }
+
+    /// Compare each value with the backend by its own relative error
+    fn assert_relative(
+        values: &[Complex64],
+        cyr: &[f64],
+        cyi: &[f64],
+        kind: BesselKind,
+        z: Complex64,
+        nu: f64,
+    ) {
+        for (k, value) in values.iter().enumerate() {
+            let expected = Complex64::new(cyr[k], cyi[k]);
+            let error = (value - expected).norm() / expected.norm().max(f64::MIN_POSITIVE);
+            assert!(
+                error < 1e-10,
+                "{:?} z={} nu={} k={} {:e}",
+                kind,
+                z,
+                nu,
+                k,
+                error
+            );
+        }
+    }
+}
@@ -0,0 +1,294 @@
+//! Negative-order support via the reflection formulas
+//!
+//! The Amos routines only accept ν >= 0. Sequences starting at a negative
+//! order are split into the negative orders, evaluated at |ν| and reflected
+//! (DLMF 10.4.1, 10.4.2, 10.4.6, 10.27.2, 10.27.3), and the non-negative
+//! remainder, which is passed through unchanged:
+//!
+//! * J_{-ν}(z) = cos(νπ) J_ν(z) − sin(νπ) Y_ν(z)
+//! * Y_{-ν}(z) = sin(νπ) J_ν(z) + cos(νπ) Y_ν(z)
+//! * I_{-ν}(z) = I_ν(z) + (2/π) sin(νπ) K_ν(z)
+//! * K_{-ν}(z) = K_ν(z)
+//! * H^(1)_{-ν}(z) = exp(iνπ) H^(1)_ν(z), H^(2)_{-ν}(z) = exp(−iνπ) H^(2)_ν(z)
+//!
+//! It also places arguments on the branch cut along the negative real axis.
+//! The Amos routines return the limit from above there whatever the sign of
+//! Im z; an imaginary part of -0.0 instead selects the limit from below, as in
+//! SciPy, through the Schwarz reflection for real ν (DLMF 10.11.9, 10.34.7):
+//!
+//! * F_ν(z̄) = conj(F_ν(z)) for F = J, Y, I, K
+//! * H^(1)_ν(z̄) = conj(H^(2)_ν(z)), H^(2)_ν(z̄) = conj(H^(1)_ν(z))
+
+use crate::{BesselError, BesselKind, BesselResult};
+use num_complex::Complex64;
+use std::f64::consts::{FRAC_2_PI, PI};
+
+/// sin(πx) and cos(πx), exact at multiples of 1/2
+pub(crate) fn sin_cos_pi(x: f64) -> (f64, f64) {
+    let r = x.rem_euclid(2.0);
+    if r == 0.0 {
+        (0.0, 1.0)
+    } else if r == 0.5 {
+        (1.0, 0.0)
+    } else if r == 1.0 {
+        (0.0, -1.0)
+    } else if r == 1.5 {
+        (-1.0, 0.0)
+    } else {
+        (r * PI).sin_cos()
+    }
+}
+
+/// Whether `z` is on the negative real axis with Im z = -0.0
+pub(crate) fn below_cut(z: Complex64) -> bool {
+    z.re < 0.0 && z.im == 0.0 && z.im.is_sign_negative()
+}
+
+/// Kind whose conjugate gives `kind` at the conjugate argument
+pub(crate) fn conjugate_kind(kind: BesselKind) -> BesselKind {
+    match kind {
+        BesselKind::H1 => BesselKind::H2,
+        BesselKind::H2 => BesselKind::H1,
+        kind => kind,
+    }
+}
+
+/// Evaluate the sequence ν, ν+1, ..., ν+n-1 below the branch cut, or `None`
+/// if `z` is not there
+///
+/// The scaling factors are real on the real axis, or swap with the Hankel
+/// kinds, so the reflection holds for scaled values too.
+pub(crate) fn below_cut_sequence(
+    kind: BesselKind,
+    z: Complex64,
+    nu: f64,
+    kode: i32,
+    n: usize,
+    allow_accuracy_loss: bool,
+) -> Option<Result<BesselResult, BesselError>> {
+    if !below_cut(z) {
+        return None;
+    }
+    let above = conjugate_kind(kind).eval_impl(z.conj(), nu, kode, n, allow_accuracy_loss);
+    Some(above.map(|mut result| {
+        for value in &mut result.values {
+            *value = value.conj();
+        }
+        result
+    }))
+}
+
+/// Evaluate the sequence ν, ν+1, ..., ν+n-1 for ν < 0
+pub(crate) fn negative_order_sequence(
+    kind: BesselKind,
+    z: Complex64,
+    nu: f64,
+    kode: i32,
+    n: usize,
+    allow_accuracy_loss: bool,
+) -> Result<BesselResult, BesselError> {
+    debug_assert!(nu < 0.0);
+
+    // Orders nu + k < 0 for k < m; their reflections |nu| - k form the
+    // contiguous sequence mu0, mu0 + 1, ..., -nu
+    let m = ((-nu).ceil() as usize).min(n);
+    let mu0 = -(nu + (m - 1) as f64);
+
+    let primary = kind.eval_impl(z, mu0, kode, m, allow_accuracy_loss)?;
+
+    // The partner function is only needed when sin(νπ) != 0
+    let partner_kind = match kind {
+        BesselKind::J => Some(BesselKind::Y),
+        BesselKind::Y => Some(BesselKind::J),
+        BesselKind::I => Some(BesselKind::K),
+        BesselKind::K | BesselKind::H1 | BesselKind::H2 => None,
+    };
+    let partner = match partner_kind {
+        Some(partner_kind) if nu.fract() != 0.0 => {
+            Some(partner_kind.eval_impl(z, mu0, kode, m, allow_accuracy_loss)?)
+        }
+        _ => None,
+    };
+
+    // Relative scaling of K against I when both are exponentially scaled
+    let k_to_i_scale = if kode == 2 {
+        (-z - z.re.abs()).exp()
+    } else {
+        Complex64::new(1.0, 0.0)
+    };
+
+    let mut values = Vec::with_capacity(n);
+    for k in 0..m {
+        let idx = m - 1 - k;
+        let mu = mu0 + idx as f64;
+        let (s, c) = sin_cos_pi(mu);
+        let f = primary.values[idx];
+        let g = partner
+            .as_ref()
+            .map_or(Complex64::new(0.0, 0.0), |p| p.values[idx]);
+
+        let value = match kind {
+            BesselKind::J => c * f - s * g,
+            BesselKind::Y => s * g + c * f,
+            BesselKind::I if s != 0.0 => f + FRAC_2_PI * s * g * k_to_i_scale,
+            BesselKind::I | BesselKind::K => f,
+            BesselKind::H1 => Complex64::new(c, s) * f,
+            BesselKind::H2 => Complex64::new(c, -s) * f,
+        };
+        values.push(value);
+    }
+
+    // As many digits are lost as by the worst of the evaluations reflected
+    let mut lost_digits = partner.as_ref().map_or(primary.lost_digits, |p| {
+        p.lost_digits.max(primary.lost_digits)
+    });
+    // Underflows and overflows of every evaluation show in the result
+    let mut underflow_count = primary.underflow_count;
+    let mut overflow_count = primary.overflow_count;
+    if let Some(partner) = &partner {
+        underflow_count += partner.underflow_count;
+        overflow_count += partner.overflow_count;
+    }
+    if n > m {
+        let rest = kind.eval_impl(z, nu + m as f64, kode, n - m, allow_accuracy_loss)?;
+        values.extend(rest.values);
+        underflow_count += rest.underflow_count;
+        overflow_count += rest.overflow_count;
+        lost_digits = lost_digits.max(rest.lost_digits);
+    }
+
+    Ok(BesselResult {
+        values,
+        underflow_count,
+        overflow_count,
+        lost_digits,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bessel, bessel_array, bessel_h, bessel_h_with_workspace, bessel_i, bessel_i_with_workspace,
+        bessel_into, bessel_j, bessel_j_with_workspace, bessel_k, bessel_k_with_workspace,
+        bessel_y, bessel_y_with_workspace, Scaling, Workspace, I, J, K, Y,
+    };
+
+    #[test]
+    fn test_sin_cos_pi_exact() {
+        assert_eq!(sin_cos_pi(3.0), (0.0, -1.0));
+        assert_eq!(sin_cos_pi(-2.5), (-1.0, 0.0));
+        assert_eq!(sin_cos_pi(0.5), (1.0, 0.0));
+    }
+
+    #[test]
+    fn test_negative_half_integer_order() {
+        // J_{-1/2}(z) = sqrt(2/(πz)) cos(z)
+        let z = Complex64::new(2.0, 0.5);
+        let expected = (2.0 / (PI * z)).sqrt() * z.cos();
+        let diff = (J(-0.5, z).unwrap() - expected).norm();
+        assert!(diff < 1e-14, "J_-1/2 failed: diff = {}", diff);
+
+        // Y_{-1/2}(z) = J_{1/2}(z)
+        let diff = (Y(-0.5, z).unwrap() - J(0.5, z).unwrap()).norm();
+        assert!(diff < 1e-14, "Y_-1/2 failed: diff = {}", diff);
+    }
+
+    #[test]
+    fn test_negative_integer_order() {
+        let z = Complex64::new(1.5, -0.7);
+        assert!((J(-3.0, z).unwrap() + J(3.0, z).unwrap()).norm() < 1e-15);
+        assert!((I(-2.0, z).unwrap() - I(2.0, z).unwrap()).norm() < 1e-15);
+        assert_eq!(K(-1.7, z).unwrap(), K(1.7, z).unwrap());
+    }
+
+    #[test]
+    fn test_underflows_are_counted() {
+        // K_{-1.5}, K_{-0.5} are reflected and K_{0.5}, K_{1.5} passed through,
+        // all underflowing
+        let result = bessel_k(Complex64::new(800.0, 0.0), -1.5, 1, 4).unwrap();
+        assert_eq!(result.underflow_count, 4);
+        assert_eq!(result.overflow_count, 0);
+    }
+
+    #[test]
+    fn test_sequence_crossing_zero() {
+        let z = Complex64::new(3.0, 1.0);
+        let result = bessel_j(z, -1.5, 1, 4).unwrap();
+        assert_eq!(result.values.len(), 4);
+        for (k, value) in result.values.iter().enumerate() {
+            let single = J(-1.5 + k as f64, z).unwrap();
+            assert!((value - single).norm() < 1e-14 * single.norm());
+        }
+    }
+
+    #[test]
+    fn test_scaled_negative_order_i() {
+        let z = Complex64::new(5.0, 2.0);
+        let nu = -0.3;
+        let unscaled = bessel_i(z, nu, 1, 1).unwrap().values[0];
+        let scaled = bessel_i(z, nu, 2, 1).unwrap().values[0];
+        let expected = unscaled * (-z.re.abs()).exp();
+        assert!((scaled - expected).norm() < 1e-14 * expected.norm());
+    }
+
+    #[test]
+    fn test_signed_zero_on_cut() {
+        let nu = 0.3;
+        for x in [-0.5, -4.0, -30.0] {
+            let above = Complex64::new(x, 0.0);
+            let below = Complex64::new(x, -0.0);
+            let near_above = Complex64::new(x, 1e-14);
+            let near_below = Complex64::new(x, -1e-14);
+            for kode in [1, 2] {
+                let evals: [&dyn Fn(Complex64) -> Complex64; 4] = [
+                    &|z| bessel_y(z, nu, kode, 1).unwrap().values[0],
+                    &|z| bessel_k(z, nu, kode, 1).unwrap().values[0],
+                    &|z| bessel_h(z, nu, kode, 1, 1).unwrap().values[0],
+                    &|z| bessel_h(z, nu, kode, 2, 1).unwrap().values[0],
+                ];
+                for f in evals {
+                    let tol = 1e-10 * f(above).norm();
+                    assert!((f(above) - f(near_above)).norm() < tol);
+                    assert!((f(below) - f(near_below)).norm() < tol);
+                    assert!((f(above) - f(below)).norm() > tol);
+                }
+            }
+        }
+        // Sequences and negative orders are reflected as a whole
+        let z = Complex64::new(-2.0, -0.0);
+        let below = bessel_j(z, -1.5, 1, 3).unwrap();
+        let above = bessel_j(z.conj(), -1.5, 1, 3).unwrap();
+        for (b, a) in below.values.iter().zip(&above.values) {
+            assert_eq!(*b, a.conj());
+        }
+        assert_eq!(K(nu, z).unwrap(), K(nu, z.conj()).unwrap().conj());
+        // Every sequence and workspace API reflects too
+        let z = Complex64::new(-1.0, -0.0);
+        let mut ws = Workspace::new();
+        for kind in BesselKind::ALL {
+            let above = conjugate_kind(kind).eval_impl(z.conj(), nu, 1, 3, false);
+            let expected: Vec<_> = above.unwrap().values.iter().map(|v| v.conj()).collect();
+            assert_eq!(expected[0], bessel(kind, nu, z, Scaling::Unscaled).unwrap());
+            let sequence = kind.eval_impl(z, nu, 1, 3, false).unwrap().values;
+            let mut out = [Complex64::new(0.0, 0.0); 3];
+            bessel_into(kind, z, nu, Scaling::Unscaled, &mut out).unwrap();
+            let array: [Complex64; 3] = bessel_array(kind, z, nu, Scaling::Unscaled).unwrap();
+            let workspace = match kind {
+                BesselKind::J => bessel_j_with_workspace(z, nu, 1, 3, &mut ws),
+                BesselKind::Y => bessel_y_with_workspace(z, nu, 1, 3, &mut ws),
+                BesselKind::I => bessel_i_with_workspace(z, nu, 1, 3, &mut ws),
+                BesselKind::K => bessel_k_with_workspace(z, nu, 1, 3, &mut ws),
+                BesselKind::H1 => bessel_h_with_workspace(z, nu, 1, 1, 3, &mut ws),
+                BesselKind::H2 => bessel_h_with_workspace(z, nu, 1, 2, 3, &mut ws),
+            };
+            let workspace = workspace.unwrap().to_vec();
+            for values in [&sequence[..], &out[..], &array[..], &workspace[..]] {
+                assert_eq!(values, &expected[..], "{:?}", kind);
+            }
+        }
+        // Off the negative real axis the sign of zero has no effect
+        let z = Complex64::new(2.0, -0.0);
+        assert_eq!(Y(nu, z).unwrap(), Y(nu, z.conj()).unwrap());
+    }
+}
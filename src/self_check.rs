@@ -0,0 +1,183 @@
+//! Verification of every evaluation against the Wronskian identities
+//!
+//! With the `self-check` feature each sequence or single-value evaluation is
+//! repeated together with its partner function and checked against the
+//! Wronskians, written without derivatives through the recurrences
+//! (DLMF 10.5.5, 10.28.2):
+//!
+//! * J_{ν+1}(z) Y_ν(z) − J_ν(z) Y_{ν+1}(z) = 2/(πz)
+//! * I_ν(z) K_{ν+1}(z) + I_{ν+1}(z) K_ν(z) = 1/z
+//! * H^(1)_{ν+1}(z) H^(2)_ν(z) − H^(1)_ν(z) H^(2)_{ν+1}(z) = −4i/(πz)
+//!
+//! A violation is logged with `log::warn!` and counted in
+//! [`self_check_violations`]. Rounding stays far below the tolerance, so a
+//! violation points at a miscompiled backend or a broken platform math
+//! library rather than at a hard argument. The checks cost about three
+//! times the evaluation itself and are meant for canary deployments and
+//! debugging; the `fast` module is not checked.
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{bessel_k, self_check_violations};
+//!
+//! fn main() -> Result<(), zbessel_rs::BesselError> {
+//!     bessel_k(Complex64::new(2.0, 1.0), 0.5, 1, 4)?;
+//!     assert_eq!(self_check_violations(), 0);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{BesselError, BesselKind, BesselResult};
+use num_complex::Complex64;
+use std::cell::Cell;
+use std::f64::consts::PI;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Largest accepted residual relative to the size of the products
+const TOLERANCE: f64 = 1e-8;
+
+static VIOLATIONS: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    /// Set while an evaluation is being checked, so the evaluations the
+    /// check makes are not checked in turn
+    static CHECKING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Number of evaluations that failed a Wronskian check in this process
+pub fn self_check_violations() -> u64 {
+    VIOLATIONS.load(Ordering::Relaxed)
+}
+
+/// Evaluate and check the sequence ν, ..., ν+n-1, or `None` when called from
+/// within a check
+pub(crate) fn checked(
+    kind: BesselKind,
+    z: Complex64,
+    nu: f64,
+    kode: i32,
+    n: usize,
+    allow_accuracy_loss: bool,
+) -> Option<Result<BesselResult, BesselError>> {
+    if CHECKING.with(|checking| checking.replace(true)) {
+        return None;
+    }
+    let result = kind.eval_impl(z, nu, kode, n, allow_accuracy_loss);
+    if result.is_ok() {
+        verify(kind, z, nu, kode, n);
+    }
+    CHECKING.with(|checking| checking.set(false));
+    Some(result)
+}
+
+/// Check each order of the sequence and record the failures
+fn verify(kind: BesselKind, z: Complex64, nu: f64, kode: i32, n: usize) {
+    if z == Complex64::new(0.0, 0.0) {
+        return;
+    }
+    let (first, second) = pair(kind);
+    let eval = |kind: BesselKind| kind.eval_impl(z, nu, kode, n + 1, true).ok();
+    let (Some(f), Some(g)) = (eval(first), eval(second)) else {
+        return;
+    };
+    for (k, residual, expected) in failures(kind, z, kode, &f.values, &g.values) {
+        VIOLATIONS.fetch_add(1, Ordering::Relaxed);
+        log::warn!(
+            "Wronskian check failed for {:?} at z = {}, nu = {}, kode = {}: residual {:e} against {:e}",
+            kind,
+            z,
+            nu + k as f64,
+            kode,
+            residual,
+            expected
+        );
+    }
+}
+
+/// The kinds whose Wronskian checks `kind`, in the order of the identity
+fn pair(kind: BesselKind) -> (BesselKind, BesselKind) {
+    match kind {
+        BesselKind::J | BesselKind::Y => (BesselKind::J, BesselKind::Y),
+        BesselKind::I | BesselKind::K => (BesselKind::I, BesselKind::K),
+        BesselKind::H1 | BesselKind::H2 => (BesselKind::H1, BesselKind::H2),
+    }
+}
+
+/// Orders k whose values f_k, f_{k+1}, g_k, g_{k+1} fail the identity, with
+/// the residual and the size of the Wronskian; orders where either function
+/// underflowed or overflowed are skipped
+fn failures(
+    kind: BesselKind,
+    z: Complex64,
+    kode: i32,
+    f: &[Complex64],
+    g: &[Complex64],
+) -> Vec<(usize, f64, f64)> {
+    let (first, second) = pair(kind);
+    let (sign, wronskian) = match first {
+        BesselKind::J => (-1.0, 2.0 / (PI * z)),
+        BesselKind::I => (1.0, 1.0 / z),
+        _ => (-1.0, Complex64::new(0.0, -4.0) / (PI * z)),
+    };
+    // The scaled functions carry the product of both scaling factors
+    let expected = if kode == 2 {
+        wronskian * (first.log_scale(z) + second.log_scale(z)).exp()
+    } else {
+        wronskian
+    };
+    let mut failed = Vec::new();
+    for k in 0..f.len().min(g.len()).saturating_sub(1) {
+        let values = [f[k], f[k + 1], g[k], g[k + 1]];
+        if !values
+            .iter()
+            .all(|v| v.is_finite() && *v != Complex64::new(0.0, 0.0))
+        {
+            continue;
+        }
+        let (a, b) = (f[k + 1] * g[k], f[k] * g[k + 1]);
+        let residual = (a + sign * b - expected).norm();
+        if residual > TOLERANCE * (a.norm() + b.norm()).max(expected.norm()) {
+            failed.push((k, residual, expected.norm()));
+        }
+    }
+    failed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bessel, bessel_h, bessel_i, bessel_j, bessel_k, bessel_y, Scaling};
+
+    #[test]
+    fn test_evaluations_pass() {
+        let zs = [
+            Complex64::new(0.3, 0.1),
+            Complex64::new(5.0, -2.0),
+            Complex64::new(-12.0, 30.0),
+        ];
+        let before = self_check_violations();
+        for z in zs {
+            for kode in [1, 2] {
+                bessel_y(z, 0.7, kode, 5).unwrap();
+                bessel_i(z, -1.5, kode, 3).unwrap();
+                bessel_h(z, 2.0, kode, 2, 2).unwrap();
+            }
+            bessel(BesselKind::K, 40.0, z, Scaling::Scaled).unwrap();
+        }
+        assert_eq!(self_check_violations(), before);
+        assert!(!CHECKING.with(Cell::get));
+    }
+
+    #[test]
+    fn test_detects_violation() {
+        let z = Complex64::new(1.5, 0.5);
+        let i = bessel_i(z, 0.5, 2, 3).unwrap();
+        let k = bessel_k(z, 0.5, 2, 3).unwrap();
+        assert!(failures(BesselKind::K, z, 2, &i.values, &k.values).is_empty());
+        // Mismatched scaling, and J against Y one order off
+        assert_eq!(failures(BesselKind::K, z, 1, &i.values, &k.values).len(), 2);
+        let j = bessel_j(z, 0.0, 1, 2).unwrap();
+        let y = bessel_y(z, 1.0, 1, 2).unwrap();
+        assert_eq!(failures(BesselKind::Y, z, 1, &j.values, &y.values).len(), 1);
+    }
+}
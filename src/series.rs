@@ -0,0 +1,253 @@
+//! Ascending power series near the origin
+//!
+//! For |z| <= [`RADIUS`] the functions are summed directly in Rust:
+//!
+//! * J_ν, I_ν = (z/2)^ν Σ_k (∓z²/4)^k / (k! Γ(ν+k+1))
+//! * Y_ν, K_ν for non-integer ν from J_{±ν}, I_{±ν} (DLMF 10.2.3, 10.27.4)
+//! * Y_n, K_n for integer n from the logarithmic series (DLMF 10.8.1, 10.31.1)
+//! * H1,2_ν = J_ν ± i Y_ν
+//!
+//! A value is only used when [`direct::accept`] takes it. At z = 0, J and I
+//! are exact (1 for ν = 0, otherwise 0); Y, K and H are left to the backend,
+//! which reports the singularity.
+
+use crate::gamma::ln_gamma;
+use crate::{direct, BesselKind};
+use num_complex::Complex64;
+use std::f64::consts::PI;
+
+/// Largest |z| summed by the series
+pub(crate) const RADIUS: f64 = 2.0;
+
+/// Largest order summed by the series
+const MAX_ORDER: f64 = 100.0;
+
+/// Terms before a series is considered not to converge
+const MAX_TERMS: usize = 60;
+
+const EULER_GAMMA: f64 = 0.577_215_664_901_532_9;
+
+/// A value with the sum of the magnitudes of its terms
+#[derive(Clone, Copy)]
+struct Sum {
+    value: Complex64,
+    size: f64,
+}
+
+impl Sum {
+    const ZERO: Sum = Sum {
+        value: Complex64::new(0.0, 0.0),
+        size: 0.0,
+    };
+
+    fn add(&mut self, term: Complex64) {
+        self.value += term;
+        self.size += term.norm();
+    }
+
+    fn scale(self, factor: Complex64) -> Sum {
+        Sum {
+            value: self.value * factor,
+            size: self.size * factor.norm(),
+        }
+    }
+
+    fn plus(self, other: Sum) -> Sum {
+        Sum {
+            value: self.value + other.value,
+            size: self.size + other.size,
+        }
+    }
+}
+
+/// Σ_k w^k / (k! (μ+1)_k), or `None` if it does not converge
+fn ascending(w: Complex64, mu: f64) -> Option<Sum> {
+    let mut term = Complex64::new(1.0, 0.0);
+    let mut sum = Sum::ZERO;
+    sum.add(term);
+    for k in 1..MAX_TERMS {
+        let k = k as f64;
+        term *= w / (k * (mu + k));
+        sum.add(term);
+        if term.norm() <= f64::EPSILON * sum.value.norm() {
+            return Some(sum);
+        }
+    }
+    None
+}
+
+/// (z/2)^ν / Γ(ν+1), exact products for integer ν
+fn leading(half: Complex64, nu: f64) -> Complex64 {
+    if nu.fract() == 0.0 {
+        (1..=nu as usize).fold(Complex64::new(1.0, 0.0), |p, j| p * half / j as f64)
+    } else {
+        (nu * half.ln() - ln_gamma(nu + 1.0)).exp()
+    }
+}
+
+/// J_ν (sign -1) or I_ν (sign +1)
+fn regular(half: Complex64, nu: f64, sign: f64) -> Option<Sum> {
+    Some(ascending(sign * half * half, nu)?.scale(leading(half, nu)))
+}
+
+/// Y_ν (sign -1) or K_ν (sign +1) for non-integer ν
+fn irregular_fractional(half: Complex64, nu: f64, sign: f64) -> Option<Sum> {
+    let w = sign * half * half;
+    let regular = regular(half, nu, sign)?;
+    // π J_{-ν} / sin νπ, resp. π I_{-ν} / sin νπ, using 1/Γ(1-ν) = sin(νπ) Γ(ν) / π
+    let reflected = ascending(w, -nu)?.scale((ln_gamma(nu) - nu * half.ln()).exp());
+    let (sin, cos) = (PI * (nu % 2.0)).sin_cos();
+    if sign < 0.0 {
+        Some(
+            regular
+                .scale(Complex64::new(cos / sin, 0.0))
+                .plus(reflected.scale(Complex64::new(-1.0 / PI, 0.0))),
+        )
+    } else {
+        Some(
+            reflected
+                .scale(Complex64::new(0.5, 0.0))
+                .plus(regular.scale(Complex64::new(-0.5 * PI / sin, 0.0))),
+        )
+    }
+}
+
+/// Y_n (sign -1) or K_n (sign +1) for integer n
+fn irregular_integer(half: Complex64, n: usize, sign: f64) -> Option<Sum> {
+    let w = sign * half * half;
+    let log = half.ln();
+
+    // Σ_{k<n} (n-k-1)!/k! (-w)^k (z/2)^{-n}
+    let mut finite = Sum::ZERO;
+    if n > 0 {
+        let mut term = (1..n).fold(1.0 / half, |p, j| p * j as f64 / half);
+        finite.add(term);
+        for k in 0..n - 1 {
+            term *= -w / ((k + 1) * (n - k - 1)) as f64;
+            finite.add(term);
+        }
+    }
+
+    // Σ_k (ψ(k+1) + ψ(n+k+1)) w^k / (k! (n+1)_k), and the plain sum
+    let mut psi = -EULER_GAMMA;
+    let mut psi_n = -EULER_GAMMA + (1..=n).map(|j| 1.0 / j as f64).sum::<f64>();
+    let mut term = Complex64::new(1.0, 0.0);
+    let mut plain = Sum::ZERO;
+    let mut digamma = Sum::ZERO;
+    plain.add(term);
+    digamma.add(term * (psi + psi_n));
+    let mut converged = false;
+    for k in 1..MAX_TERMS {
+        term *= w / (k * (n + k)) as f64;
+        psi += 1.0 / k as f64;
+        psi_n += 1.0 / (n + k) as f64;
+        plain.add(term);
+        digamma.add(term * (psi + psi_n));
+        if term.norm() * (psi + psi_n).abs().max(1.0) <= f64::EPSILON * digamma.value.norm() {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return None;
+    }
+    let power = leading(half, n as f64);
+    let regular = plain.scale(power);
+    let digamma = digamma.scale(power);
+
+    if sign < 0.0 {
+        Some(
+            finite
+                .scale(Complex64::new(-1.0 / PI, 0.0))
+                .plus(regular.scale(2.0 / PI * log))
+                .plus(digamma.scale(Complex64::new(-1.0 / PI, 0.0))),
+        )
+    } else {
+        let parity = if n.is_multiple_of(2) { 1.0 } else { -1.0 };
+        Some(
+            finite
+                .scale(Complex64::new(0.5, 0.0))
+                .plus(regular.scale(-parity * log))
+                .plus(digamma.scale(Complex64::new(0.5 * parity, 0.0))),
+        )
+    }
+}
+
+fn irregular(half: Complex64, nu: f64, sign: f64) -> Option<Sum> {
+    if nu.fract() == 0.0 {
+        irregular_integer(half, nu as usize, sign)
+    } else {
+        irregular_fractional(half, nu, sign)
+    }
+}
+
+/// F_ν(z) by its power series, or `None` if it does not apply
+pub(crate) fn value(kind: BesselKind, z: Complex64, nu: f64, kode: i32) -> Option<Complex64> {
+    if !(0.0..=MAX_ORDER).contains(&nu) || z.norm() > RADIUS {
+        return None;
+    }
+    if z.re == 0.0 && z.im == 0.0 {
+        return match kind {
+            BesselKind::J | BesselKind::I => {
+                Some(Complex64::new(if nu == 0.0 { 1.0 } else { 0.0 }, 0.0))
+            }
+            _ => None,
+        };
+    }
+    // Put the negative real axis on the upper side of the cut, as Amos does
+    let z = Complex64::new(z.re, if z.im == 0.0 { 0.0 } else { z.im });
+    let half = 0.5 * z;
+    let i = Complex64::i();
+    let sum = match kind {
+        BesselKind::J => regular(half, nu, -1.0)?,
+        BesselKind::I => regular(half, nu, 1.0)?,
+        BesselKind::Y => irregular(half, nu, -1.0)?,
+        BesselKind::K => irregular(half, nu, 1.0)?,
+        BesselKind::H1 => regular(half, nu, -1.0)?.plus(irregular(half, nu, -1.0)?.scale(i)),
+        BesselKind::H2 => regular(half, nu, -1.0)?.plus(irregular(half, nu, -1.0)?.scale(-i)),
+    };
+    let sum = if kode == 2 {
+        sum.scale(kind.log_scale(z).exp())
+    } else {
+        sum
+    };
+    direct::accept(sum.value, sum.size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::direct::check_against_backend;
+
+    #[test]
+    fn test_series_matches_backend() {
+        let zs = [
+            Complex64::new(0.01, 0.0),
+            Complex64::new(0.5, 0.25),
+            Complex64::new(-1.2, 0.0),
+            Complex64::new(-0.7, -1.1),
+            Complex64::new(0.0, 1.9),
+            Complex64::new(1.4, -1.4),
+        ];
+        let nus = [0.0, 0.3, 1.0, 2.0, 3.7, 12.0];
+        let used = check_against_backend(value, &BesselKind::ALL, &zs, &nus, 1e-12, |_, exact| {
+            exact.norm()
+        });
+        assert!(used > 300, "{}", used);
+    }
+
+    #[test]
+    fn test_origin() {
+        let zero = Complex64::new(0.0, 0.0);
+        assert_eq!(
+            value(BesselKind::J, zero, 0.0, 1),
+            Some(Complex64::new(1.0, 0.0))
+        );
+        assert_eq!(
+            value(BesselKind::I, zero, 2.5, 2),
+            Some(Complex64::new(0.0, 0.0))
+        );
+        assert!(value(BesselKind::K, zero, 0.0, 1).is_none());
+        assert!(value(BesselKind::J, Complex64::new(2.5, 0.0), 0.0, 1).is_none());
+    }
+}
@@ -0,0 +1,525 @@
+//! Batched evaluation of one order over many arguments
+//!
+//! [`bessel_slice`] evaluates one function of one order at every point of a
+//! slice. Points inside the power-series region (|z| <= 2, J and I only) and
+//! the Hankel asymptotic region (|z| >= max(25, ν²), Re z > 0) are evaluated
+//! four at a time by a lane-wise kernel that shares the order-dependent
+//! constants; with the `simd` feature the lanes are `wide::f64x4` vectors,
+//! otherwise plain arrays the compiler can vectorize. Real points of orders 0
+//! and 1 use the dedicated real-axis kernels instead. All other points,
+//! points beyond |z| or ν = sqrt(0.5·`i32::MAX`), where [`bessel`] has its own
+//! paths, and any point whose kernel sum is rejected by the checks of the
+//! direct paths (not finite, underflowed or cancelled) go through [`bessel`]
+//! one by one.
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{bessel_j_slice, Scaling, J};
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let zs: Vec<Complex64> = (0..1000)
+//!         .map(|k| Complex64::new(0.05 * k as f64, 0.5))
+//!         .collect();
+//!     let values = bessel_j_slice(&zs, 0.0, Scaling::Unscaled)?;
+//!     assert!((values[500] - J(0.0, zs[500])?).norm() < 1e-12);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::asymptotic::{argument_limit, hankel_phase};
+use crate::gamma::ln_gamma;
+use crate::{bessel, validate_inputs, BesselError, BesselKind, Scaling};
+use crate::{direct, modified, rational};
+use num_complex::Complex64;
+use std::f64::consts::{FRAC_2_PI, PI};
+
+const LANES: usize = 4;
+const MAX_TERMS: usize = 80;
+
+#[cfg(feature = "simd")]
+use wide::f64x4 as F64x4;
+
+/// Four f64 lanes, the portable stand-in for `wide::f64x4`
+#[cfg(not(feature = "simd"))]
+#[derive(Clone, Copy)]
+struct F64x4([f64; LANES]);
+
+#[cfg(not(feature = "simd"))]
+impl F64x4 {
+    fn splat(x: f64) -> Self {
+        F64x4([x; LANES])
+    }
+
+    fn to_array(self) -> [f64; LANES] {
+        self.0
+    }
+
+    fn zip(self, other: Self, f: impl Fn(f64, f64) -> f64) -> Self {
+        F64x4(std::array::from_fn(|k| f(self.0[k], other.0[k])))
+    }
+}
+
+#[cfg(not(feature = "simd"))]
+impl From<[f64; LANES]> for F64x4 {
+    fn from(values: [f64; LANES]) -> Self {
+        F64x4(values)
+    }
+}
+
+#[cfg(not(feature = "simd"))]
+impl std::ops::Add for F64x4 {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        self.zip(other, |a, b| a + b)
+    }
+}
+
+#[cfg(not(feature = "simd"))]
+impl std::ops::Sub for F64x4 {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        self.zip(other, |a, b| a - b)
+    }
+}
+
+#[cfg(not(feature = "simd"))]
+impl std::ops::Mul for F64x4 {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        self.zip(other, |a, b| a * b)
+    }
+}
+
+/// Four complex numbers in split form
+#[derive(Clone, Copy)]
+struct ComplexLanes {
+    re: F64x4,
+    im: F64x4,
+}
+
+impl ComplexLanes {
+    fn splat(re: f64, im: f64) -> Self {
+        ComplexLanes {
+            re: F64x4::splat(re),
+            im: F64x4::splat(im),
+        }
+    }
+
+    fn from_fn(f: impl Fn(usize) -> Complex64) -> Self {
+        let values: [Complex64; LANES] = std::array::from_fn(f);
+        ComplexLanes {
+            re: F64x4::from(values.map(|v| v.re)),
+            im: F64x4::from(values.map(|v| v.im)),
+        }
+    }
+
+    fn lane(self, k: usize) -> Complex64 {
+        Complex64::new(self.re.to_array()[k], self.im.to_array()[k])
+    }
+
+    fn add(self, other: Self) -> Self {
+        ComplexLanes {
+            re: self.re + other.re,
+            im: self.im + other.im,
+        }
+    }
+
+    fn sub(self, other: Self) -> Self {
+        ComplexLanes {
+            re: self.re - other.re,
+            im: self.im - other.im,
+        }
+    }
+
+    fn mul(self, other: Self) -> Self {
+        ComplexLanes {
+            re: self.re * other.re - self.im * other.im,
+            im: self.re * other.im + self.im * other.re,
+        }
+    }
+
+    fn scale(self, c: f64) -> Self {
+        let c = F64x4::splat(c);
+        ComplexLanes {
+            re: self.re * c,
+            im: self.im * c,
+        }
+    }
+
+    /// |z| in every lane
+    fn norm(self) -> [f64; LANES] {
+        let (re, im) = (self.re.to_array(), self.im.to_array());
+        std::array::from_fn(|k| re[k].hypot(im[k]))
+    }
+
+    /// Whether |term| <= EPS |sum| in every lane (L1 norms)
+    fn negligible_against(self, sum: Self) -> bool {
+        let (tr, ti) = (self.re.to_array(), self.im.to_array());
+        let (sr, si) = (sum.re.to_array(), sum.im.to_array());
+        (0..LANES).all(|k| tr[k].abs() + ti[k].abs() <= f64::EPSILON * (sr[k].abs() + si[k].abs()))
+    }
+}
+
+/// Adds |term| to the sizes of every lane
+fn accumulate(size: &mut [f64; LANES], term: ComplexLanes) {
+    for (size, norm) in size.iter_mut().zip(term.norm()) {
+        *size += norm;
+    }
+}
+
+/// Σ (±z²/4)^k / (k! (ν+1)_k) in every lane, and Σ of the term magnitudes
+fn series_kernel(z: ComplexLanes, nu: f64, sign: f64) -> (ComplexLanes, [f64; LANES]) {
+    let q = z.mul(z).scale(0.25 * sign);
+    let mut term = ComplexLanes::splat(1.0, 0.0);
+    let mut sum = term;
+    let mut size = [1.0; LANES];
+    for k in 1..MAX_TERMS {
+        let k = k as f64;
+        term = term.mul(q).scale(1.0 / (k * (nu + k)));
+        sum = sum.add(term);
+        accumulate(&mut size, term);
+        if term.negligible_against(sum) {
+            break;
+        }
+    }
+    (sum, size)
+}
+
+/// Σ a_k(ν) u^k and Σ a_k(ν) (-u)^k in every lane, and Σ of the term
+/// magnitudes, the same for both
+fn asymptotic_kernel(u: ComplexLanes, nu: f64) -> (ComplexLanes, ComplexLanes, [f64; LANES]) {
+    let mu = 4.0 * nu * nu;
+    let mut term = ComplexLanes::splat(1.0, 0.0);
+    let (mut plus, mut minus) = (term, term);
+    let mut size = [1.0; LANES];
+    for k in 1..MAX_TERMS {
+        let odd = (2 * k - 1) as f64;
+        let c = (mu - odd * odd) / (8.0 * k as f64);
+        if c == 0.0 {
+            break;
+        }
+        term = term.mul(u).scale(c);
+        accumulate(&mut size, term);
+        plus = plus.add(term);
+        minus = if k % 2 == 0 {
+            minus.add(term)
+        } else {
+            minus.sub(term)
+        };
+        if term.negligible_against(plus) && term.negligible_against(minus) {
+            break;
+        }
+    }
+    (plus, minus, size)
+}
+
+/// Region of a point for the lane-wise kernels
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Region {
+    Series,
+    Asymptotic,
+    Fallback,
+}
+
+fn region(kind: BesselKind, z: Complex64, nu: f64) -> Region {
+    let r = z.norm();
+    let on_cut = z.im == 0.0 && z.re <= 0.0;
+    let series = matches!(kind, BesselKind::J | BesselKind::I);
+    // Beyond the limit `bessel` switches to paths of its own
+    let limit = argument_limit();
+    if !(z.re.is_finite() && z.im.is_finite()) || on_cut || r > limit || nu > limit {
+        Region::Fallback
+    } else if r <= 2.0 && series {
+        Region::Series
+    } else if z.re > 0.0 && r >= (nu * nu).max(25.0) {
+        Region::Asymptotic
+    } else {
+        Region::Fallback
+    }
+}
+
+/// Evaluate `kind` of order `nu` at every point of `zs`
+///
+/// Produces the same values as calling [`bessel`] for each point, up to
+/// rounding, but computes the order-dependent constants once and evaluates
+/// points in the power-series region (J and I) and the Hankel asymptotic
+/// region (all kinds) with the lane-wise kernels. The order and every point
+/// are validated first, as by [`bessel`]; the first point that fails is
+/// reported as the error.
+///
+/// # Parameters
+/// * `kind` - Kind of Bessel function
+/// * `zs` - Complex arguments
+/// * `nu` - Order (real number)
+/// * `scaling` - Scaling option, with the factor of [`bessel`] for `kind`
+pub fn bessel_slice(
+    kind: BesselKind,
+    zs: &[Complex64],
+    nu: f64,
+    scaling: Scaling,
+) -> Result<Vec<Complex64>, BesselError> {
+    let mut out = vec![Complex64::new(0.0, 0.0); zs.len()];
+    fill_slice(kind, zs, nu, scaling, &mut out)?;
+    Ok(out)
+}
+
+/// [`bessel_slice`] writing into `out`, which must have the length of `zs`
+pub(crate) fn fill_slice(
+    kind: BesselKind,
+    zs: &[Complex64],
+    nu: f64,
+    scaling: Scaling,
+    out: &mut [Complex64],
+) -> Result<(), BesselError> {
+    for &z in zs {
+        validate_inputs(z, nu, scaling.kode())?;
+    }
+    let vectorized = nu >= 0.0;
+    let mut series = Vec::new();
+    let mut asymptotic = Vec::new();
+    let mut fallback = Vec::new();
+    for (index, &z) in zs.iter().enumerate() {
+        // Orders 0 and 1 on the real axis have cheaper dedicated kernels
+        if vectorized {
+            let kode = scaling.kode();
+            let real =
+                rational::value(kind, z, nu, kode).or_else(|| modified::value(kind, z, nu, kode));
+            if let Some(value) = real {
+                out[index] = value;
+                continue;
+            }
+        }
+        let region = if vectorized {
+            region(kind, z, nu)
+        } else {
+            Region::Fallback
+        };
+        match region {
+            Region::Series => series.push(index),
+            Region::Asymptotic => asymptotic.push(index),
+            Region::Fallback => fallback.push(index),
+        }
+    }
+
+    let scaled = scaling == Scaling::Scaled;
+    let shift = |z: Complex64| {
+        if scaled {
+            kind.log_scale(z)
+        } else {
+            Complex64::new(0.0, 0.0)
+        }
+    };
+    let log_norm = if vectorized { -ln_gamma(nu + 1.0) } else { 0.0 };
+    let sign = if kind == BesselKind::I { 1.0 } else { -1.0 };
+    for chunk in series.chunks(LANES) {
+        let lanes = ComplexLanes::from_fn(|k| zs[chunk[k.min(chunk.len() - 1)]]);
+        let (sums, sizes) = series_kernel(lanes, nu, sign);
+        for (k, &index) in chunk.iter().enumerate() {
+            let z = zs[index];
+            // (z/2)^ν / Γ(ν+1), times the scaling factor
+            let log = nu * (0.5 * z).ln() + log_norm + shift(z);
+            let factor = log.exp();
+            let value = sums.lane(k) * factor;
+            store(out, &mut fallback, index, value, factor.norm() * sizes[k]);
+        }
+    }
+
+    let i = Complex64::i();
+    // H1,2 ~ sqrt(2/(πz)) e^{±iχ} Σ (±i)^k a_k / z^k; K and I use u = 1/z
+    let hankel = !matches!(kind, BesselKind::I | BesselKind::K);
+    let phase = if hankel { i } else { Complex64::new(1.0, 0.0) };
+    let (sin, cos) = (PI * (nu % 2.0)).sin_cos();
+    for chunk in asymptotic.chunks(LANES) {
+        let lanes = ComplexLanes::from_fn(|k| phase / zs[chunk[k.min(chunk.len() - 1)]]);
+        let (plus, minus, sizes) = asymptotic_kernel(lanes, nu);
+        for (k, &index) in chunk.iter().enumerate() {
+            let z = zs[index];
+            let s = shift(z);
+            let (plus, minus) = (plus.lane(k), minus.lane(k));
+            let root = (FRAC_2_PI / z).sqrt();
+            let h1 = || root * hankel_phase(z, nu, 1.0, s);
+            let h2 = || root * hankel_phase(z, nu, -1.0, s);
+            // The value, and the magnitude of the pieces it is summed from
+            let (value, size) = match kind {
+                BesselKind::J => {
+                    let (h1, h2) = (h1(), h2());
+                    (
+                        0.5 * (h1 * plus + h2 * minus),
+                        0.5 * (h1.norm() + h2.norm()),
+                    )
+                }
+                BesselKind::Y => {
+                    let (h1, h2) = (h1(), h2());
+                    let value = -0.5 * i * (h1 * plus - h2 * minus);
+                    (value, 0.5 * (h1.norm() + h2.norm()))
+                }
+                BesselKind::H1 => {
+                    let h1 = h1();
+                    (h1 * plus, h1.norm())
+                }
+                BesselKind::H2 => {
+                    let h2 = h2();
+                    (h2 * minus, h2.norm())
+                }
+                BesselKind::K => {
+                    let factor = 0.5 * PI * root * (s - z).exp();
+                    (factor * plus, factor.norm())
+                }
+                BesselKind::I => {
+                    // DLMF 10.40.5 with the upper sign for Im z >= 0
+                    let side = if z.im >= 0.0 {
+                        i * Complex64::new(cos, sin)
+                    } else {
+                        -i * Complex64::new(cos, -sin)
+                    };
+                    let growing = 0.5 * root * (z + s).exp();
+                    let decaying = 0.5 * root * side * (s - z).exp();
+                    let value = growing * minus + decaying * plus;
+                    (value, growing.norm() + decaying.norm())
+                }
+            };
+            store(out, &mut fallback, index, value, size * sizes[k]);
+        }
+    }
+
+    fallback.sort_unstable();
+    for index in fallback {
+        out[index] = bessel(kind, nu, zs[index], scaling)?;
+    }
+    Ok(())
+}
+
+/// Evaluate J_ν at every point of `zs`
+///
+/// See [`bessel_slice`].
+///
+/// # Parameters
+/// * `zs` - Complex arguments
+/// * `nu` - Order (real number)
+/// * `scaling` - Scaling option; the scaled J is multiplied by exp(-|Im(z)|)
+pub fn bessel_j_slice(
+    zs: &[Complex64],
+    nu: f64,
+    scaling: Scaling,
+) -> Result<Vec<Complex64>, BesselError> {
+    bessel_slice(BesselKind::J, zs, nu, scaling)
+}
+
+/// Keep a kernel value summed from terms of total magnitude `size` where
+/// [`direct::accept`] does, otherwise leave the point to the fallback
+fn store(
+    out: &mut [Complex64],
+    fallback: &mut Vec<usize>,
+    index: usize,
+    value: Complex64,
+    size: f64,
+) {
+    match direct::accept(value, size) {
+        Some(value) => out[index] = value,
+        None => fallback.push(index),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slice_matches_single_calls() {
+        let mut zs = Vec::new();
+        for a in 0..40 {
+            for b in -3..4 {
+                zs.push(Complex64::new(-30.0 + 2.3 * a as f64, 1.7 * b as f64));
+            }
+        }
+        zs.extend([
+            Complex64::new(0.0, 0.0),
+            Complex64::new(-1.5, 0.0),
+            Complex64::new(3000.0, 2.0),
+            Complex64::new(0.1, -1e-3),
+        ]);
+        for &nu in &[0.0, 0.5, 1.0, 2.3, 6.0] {
+            for scaling in [Scaling::Unscaled, Scaling::Scaled] {
+                let values = bessel_j_slice(&zs, nu, scaling).unwrap();
+                for (z, value) in zs.iter().zip(&values) {
+                    let expected = bessel(BesselKind::J, nu, *z, scaling).unwrap();
+                    let error = (value - expected).norm() / expected.norm().max(1e-300);
+                    assert!(error < 1e-11, "nu={} z={} {:e}", nu, z, error);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_slice_of_every_kind() {
+        let zs: Vec<Complex64> = (0..60)
+            .map(|k| Complex64::from_polar(0.1 + 0.75 * k as f64, 0.37 * k as f64))
+            .collect();
+        for kind in [
+            BesselKind::Y,
+            BesselKind::I,
+            BesselKind::K,
+            BesselKind::H1,
+            BesselKind::H2,
+        ] {
+            for &nu in &[0.0, 1.0, 2.3] {
+                for scaling in [Scaling::Unscaled, Scaling::Scaled] {
+                    let values = bessel_slice(kind, &zs, nu, scaling).unwrap();
+                    for (z, value) in zs.iter().zip(&values) {
+                        let expected = bessel(kind, nu, *z, scaling).unwrap();
+                        let error = (value - expected).norm() / expected.norm();
+                        assert!(error < 1e-11, "{:?} nu={} z={} {:e}", kind, nu, z, error);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_slice_errors_and_negative_orders() {
+        let zs = [Complex64::new(1.0, 0.0), Complex64::new(f64::NAN, 0.0)];
+        assert!(bessel_j_slice(&zs, 0.0, Scaling::Unscaled).is_err());
+
+        let zs = [Complex64::new(1.0, 0.5), Complex64::new(40.0, 1.0)];
+        let values = bessel_j_slice(&zs, -1.5, Scaling::Unscaled).unwrap();
+        assert_eq!(
+            values[1],
+            bessel(BesselKind::J, -1.5, zs[1], Scaling::Unscaled).unwrap()
+        );
+        assert!(bessel_j_slice(&[], 0.0, Scaling::Scaled)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_slice_agrees_with_bessel_at_the_limits() {
+        let cases = [
+            // Beyond the backend range, and underflowed in the kernel
+            (BesselKind::K, 0.3, Complex64::new(1e17, 0.0)),
+            // Orders beyond MAX_ORDER
+            (BesselKind::J, 6e9, Complex64::new(0.5, 0.0)),
+            (BesselKind::I, 6e9, Complex64::new(0.5, 0.0)),
+            (BesselKind::J, 1e10, Complex64::new(2e10, 1.0)),
+            (BesselKind::Y, 1e10, Complex64::new(2e10, 1.0)),
+            // Left to the Hankel expansion of `bessel`
+            (BesselKind::J, 0.3, Complex64::new(1e300, 0.0)),
+            (BesselKind::H1, 2.5, Complex64::new(1e6, 3.0)),
+            // Cancelling near a zero of J in the kernel region
+            (
+                BesselKind::J,
+                0.0,
+                Complex64::new(30.634_606_468_431_98, 1e-10),
+            ),
+        ];
+        for (kind, nu, z) in cases {
+            for scaling in [Scaling::Unscaled, Scaling::Scaled] {
+                let expected = bessel(kind, nu, z, scaling);
+                let values = bessel_slice(kind, &[Complex64::new(40.0, 1.0), z], nu, scaling);
+                match expected {
+                    Ok(expected) => assert_eq!(values.unwrap()[1], expected, "{:?} {}", kind, z),
+                    Err(_) => assert!(values.is_err(), "{:?} nu={} z={}", kind, nu, z),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,515 @@
+//! Bessel quantities for probability distributions
+//!
+//! Directional and spatial statistics need a handful of Bessel quantities
+//! over ranges where the plain functions overflow or underflow:
+//!
+//! * [`log_bessel_i0`] and [`log_bessel_i`], the normalizers of the von
+//!   Mises and von Mises–Fisher densities
+//! * [`bessel_i_ratio`] and [`mean_resultant_length`], the mean resultant
+//!   length A_d(κ) = I_{d/2}(κ) / I_{d/2-1}(κ), and
+//!   [`mean_resultant_length_inverse`], the maximum-likelihood κ
+//! * [`log_bessel_k`] and [`matern`], the Matérn correlation function
+//!
+//! Up to sqrt(ν² + x²) = 256 the values come from the Amos routines, with
+//! the ascending series, a forward ratio recurrence and Gauss's continued
+//! fraction where those underflow or overflow. Beyond it they come from the
+//! Debye expansions (DLMF 10.41.3-4) with terms up to u_6, written in terms
+//! of 1/sqrt(ν² + x²) so that they hold uniformly from ν = 0 to ν ≫ x. All
+//! results are accurate to a few ulps for κ up to 1e8 and beyond.
+//!
+//! ```rust
+//! use zbessel_rs::stats_support::{log_bessel_i, mean_resultant_length, mean_resultant_length_inverse};
+//!
+//! fn main() -> Result<(), zbessel_rs::BesselError> {
+//!     // log normalizer of a von Mises–Fisher density on the sphere S^999
+//!     let (d, kappa) = (1000.0, 1e8);
+//!     let log_i = log_bessel_i(d / 2.0 - 1.0, kappa)?;
+//!     assert!((log_i - 99_999_989.869_476_1).abs() < 1e-6);
+//!
+//!     // κ from a sample mean resultant length, and back
+//!     let kappa = mean_resultant_length_inverse(3.0, 0.9)?;
+//!     assert!((mean_resultant_length(3.0, kappa)? - 0.9).abs() < 1e-15);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::continued_fraction::lentz;
+use crate::gamma::ln_gamma;
+use crate::real;
+use crate::BesselError;
+use num_complex::Complex64;
+use std::f64::consts::{LN_2, PI};
+
+/// sqrt(ν² + x²) from which the Debye expansions are used
+const DEBYE_RADIUS: f64 = 256.0;
+
+/// Smallest scaled Amos value accepted before falling back to the series
+const MIN_SCALED: f64 = 1e-290;
+
+/// Numerators of u_k(t) / t^k as polynomials in t² (DLMF 10.41.10 and
+/// Abramowitz & Stegun 9.3.9-10), and their common denominators
+const DEBYE_NUMERATORS: [&[f64]; 7] = [
+    &[1.0],
+    &[3.0, -5.0],
+    &[81.0, -462.0, 385.0],
+    &[30375.0, -369603.0, 765765.0, -425425.0],
+    &[
+        4465125.0,
+        -94121676.0,
+        349922430.0,
+        -446185740.0,
+        185910725.0,
+    ],
+    &[
+        1519035525.0,
+        -49286948607.0,
+        284499769554.0,
+        -614135872350.0,
+        566098157625.0,
+        -188699385875.0,
+    ],
+    &[
+        2757049477875.0,
+        -127577298354750.0,
+        1050760774457901.0,
+        -3369032068261860.0,
+        5104696716244125.0,
+        -3685299006138750.0,
+        1023694168371875.0,
+    ],
+];
+const DEBYE_DENOMINATORS: [f64; 7] = [
+    1.0,
+    24.0,
+    1152.0,
+    414720.0,
+    39813120.0,
+    6688604160.0,
+    4815794995200.0,
+];
+
+fn invalid(message: String) -> BesselError {
+    BesselError::InvalidParameter(message)
+}
+
+/// Common pieces of the Debye expansions for order `nu` >= 0 and x > 0
+struct Debye {
+    /// sqrt(ν² + x²)
+    radius: f64,
+    /// (R - x) + ν ln(x / (ν + R)), the exponent of e^{-x} I_ν(x)
+    exponent: f64,
+    /// ln Σ u_k(t) / ν^k and ln Σ (-1)^k u_k(t) / ν^k
+    ln_sums: (f64, f64),
+}
+
+impl Debye {
+    fn new(nu: f64, x: f64) -> Self {
+        let radius = nu.hypot(x);
+        // R - x and ln(x / (ν + R)) without cancellation
+        let excess = nu * nu / (radius + x);
+        let exponent = excess - nu * ((nu + excess) / x).ln_1p();
+        // u_k(t) / ν^k = Q_k(t²) / R^k with t = ν / R
+        let t2 = (nu / radius) * (nu / radius);
+        let (mut plus, mut minus, mut power) = (0.0, 0.0, 1.0);
+        for (k, (numerator, denominator)) in DEBYE_NUMERATORS
+            .iter()
+            .zip(DEBYE_DENOMINATORS)
+            .enumerate()
+            .skip(1)
+        {
+            power /= radius;
+            let q = numerator.iter().rev().fold(0.0, |acc, c| acc * t2 + c) / denominator;
+            plus += q * power;
+            minus += if k % 2 == 0 { q * power } else { -q * power };
+        }
+        Debye {
+            radius,
+            exponent,
+            ln_sums: (plus.ln_1p(), minus.ln_1p()),
+        }
+    }
+
+    /// ln(e^{-x} I_ν(x))
+    fn ln_i_scaled(&self) -> f64 {
+        self.exponent - 0.5 * (2.0 * PI * self.radius).ln() + self.ln_sums.0
+    }
+
+    /// ln(e^x K_ν(x))
+    fn ln_k_scaled(&self) -> f64 {
+        -self.exponent + 0.5 * (PI / (2.0 * self.radius)).ln() + self.ln_sums.1
+    }
+}
+
+/// ln(e^{-x} I_ν(x)) from the ascending series, for any x > 0 and ν > -1
+fn ln_i_series(nu: f64, x: f64) -> f64 {
+    let y = 0.25 * x * x;
+    let (mut sum, mut term, mut offset) = (1.0, 1.0, 0.0);
+    let mut k = 1.0;
+    loop {
+        term *= y / (k * (nu + k));
+        sum += term;
+        if term <= 0.5 * f64::EPSILON * sum {
+            break;
+        }
+        // Keep the running sum in range for large x
+        if sum > 1e250 {
+            sum *= 1e-250;
+            term *= 1e-250;
+            offset += 250.0 * std::f64::consts::LN_10;
+        }
+        k += 1.0;
+    }
+    nu * (0.5 * x).ln() - ln_gamma(nu + 1.0) + sum.ln() + offset - x
+}
+
+/// ln(e^{-x} I_ν(x)) for x > 0 and ν > -1
+fn ln_i_scaled(nu: f64, x: f64) -> Result<f64, BesselError> {
+    if nu.hypot(x) >= DEBYE_RADIUS {
+        // For -1 < ν < 0 here, I_ν - I_{-ν} is below e^{-2x} I_ν
+        return Ok(Debye::new(nu.abs(), x).ln_i_scaled());
+    }
+    match real::I_scaled(nu, x) {
+        Ok(value) if value >= MIN_SCALED && value.is_finite() => Ok(value.ln()),
+        _ => Ok(ln_i_series(nu, x)),
+    }
+}
+
+/// ln(e^x K_ν(x)) for x > 0 and ν >= 0
+fn ln_k_scaled(nu: f64, x: f64) -> Result<f64, BesselError> {
+    if nu.hypot(x) >= DEBYE_RADIUS {
+        return Ok(Debye::new(nu, x).ln_k_scaled());
+    }
+    match real::K_scaled(nu, x) {
+        Ok(value) if value > 0.0 && value.is_finite() => return Ok(value.ln()),
+        Ok(_) => {}
+        Err(error) if error.is_overflow() => {}
+        Err(error) => return Err(error),
+    }
+    // K_{f+n} = K_f Π r_j with r_j = K_{f+j+1} / K_{f+j}, recurring forward
+    // as x r_j = 2(f + j) + x² / (x r_{j-1}) and starting from
+    // x r_0 = 2f + x K_{1-f} / K_f, so that no step overflows
+    let f = nu.fract();
+    let (base, other) = (real::K_scaled(f, x)?, real::K_scaled(1.0 - f, x)?);
+    let mut q = 2.0 * f + x * other / base;
+    let mut sum = base.ln();
+    let ln_x = x.ln();
+    for j in 0..nu.trunc() as u64 {
+        if j > 0 {
+            q = 2.0 * (f + j as f64) + x * x / q;
+        }
+        sum += q.ln() - ln_x;
+    }
+    Ok(sum)
+}
+
+fn check_order_i(nu: f64) -> Result<f64, BesselError> {
+    if nu.is_nan() || nu.is_infinite() {
+        return Err(invalid(format!("order must be finite, got {}", nu)));
+    }
+    if nu <= -1.0 {
+        // I_{-n} = I_n; other orders below -1 can make I negative
+        if nu.fract() == 0.0 {
+            return Ok(-nu);
+        }
+        return Err(invalid(format!(
+            "log I_ν needs ν > -1 or an integer ν, got {}",
+            nu
+        )));
+    }
+    Ok(nu)
+}
+
+fn check_argument(x: f64) -> Result<(), BesselError> {
+    if x.is_nan() || x.is_infinite() || x < 0.0 {
+        return Err(invalid(format!(
+            "argument must be finite and nonnegative, got {}",
+            x
+        )));
+    }
+    Ok(())
+}
+
+/// Calculate ln I_0(κ), the log normalizer of the von Mises distribution
+///
+/// # Parameters
+/// * `kappa` - Concentration (κ >= 0)
+pub fn log_bessel_i0(kappa: f64) -> Result<f64, BesselError> {
+    log_bessel_i(0.0, kappa)
+}
+
+/// Calculate ln I_ν(x) without overflow or underflow
+///
+/// # Parameters
+/// * `nu` - Order (ν > -1, or an integer)
+/// * `x` - Real argument (x >= 0)
+///
+/// # Returns
+/// ln I_ν(x); at x = 0 this is 0 for ν = 0, -∞ for ν > 0 and +∞ for ν < 0
+pub fn log_bessel_i(nu: f64, x: f64) -> Result<f64, BesselError> {
+    let nu = check_order_i(nu)?;
+    check_argument(x)?;
+    if x == 0.0 {
+        return Ok(if nu == 0.0 {
+            0.0
+        } else if nu > 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            f64::INFINITY
+        });
+    }
+    Ok(ln_i_scaled(nu, x)? + x)
+}
+
+/// Calculate ln K_ν(x) without overflow or underflow
+///
+/// # Parameters
+/// * `nu` - Order (real number)
+/// * `x` - Real argument (x >= 0)
+///
+/// # Returns
+/// ln K_ν(x); +∞ at x = 0
+pub fn log_bessel_k(nu: f64, x: f64) -> Result<f64, BesselError> {
+    if nu.is_nan() || nu.is_infinite() {
+        return Err(invalid(format!("order must be finite, got {}", nu)));
+    }
+    check_argument(x)?;
+    if x == 0.0 {
+        return Ok(f64::INFINITY);
+    }
+    // K_{-ν} = K_ν
+    Ok(ln_k_scaled(nu.abs(), x)? - x)
+}
+
+/// Calculate the ratio I_{ν+1}(x) / I_ν(x)
+///
+/// # Parameters
+/// * `nu` - Order (ν > -1)
+/// * `x` - Real argument (x >= 0)
+pub fn bessel_i_ratio(nu: f64, x: f64) -> Result<f64, BesselError> {
+    if nu.is_nan() || nu.is_infinite() || nu <= -1.0 {
+        return Err(invalid(format!("ratio needs a finite ν > -1, got {}", nu)));
+    }
+    check_argument(x)?;
+    if x == 0.0 {
+        return Ok(0.0);
+    }
+    if x < DEBYE_RADIUS {
+        // Gauss: I_{ν+1}/I_ν = 1 / (2(ν+1)/x + 1 / (2(ν+2)/x + ...))
+        let fraction = lentz(Complex64::new(0.0, 0.0), 0.5 * f64::EPSILON, 100_000, |j| {
+            (
+                Complex64::new(1.0, 0.0),
+                Complex64::new(2.0 * (nu + j as f64) / x, 0.0),
+            )
+        });
+        if !fraction.converged {
+            return Err(BesselError::amos(
+                "zbesi",
+                5,
+                Complex64::new(x, 0.0),
+                Some(nu),
+                2,
+                2,
+            ));
+        }
+        return Ok(fraction.value.re);
+    }
+    if nu < 0.0 {
+        // Both logarithms are small here, so their difference keeps its digits
+        return Ok((ln_i_scaled(nu + 1.0, x)? - ln_i_scaled(nu, x)?).exp());
+    }
+    // The difference of the Debye expansions at ν + 1 and ν, with the parts
+    // of size ν taken apart analytically
+    let (lower, upper) = (Debye::new(nu, x), Debye::new(nu + 1.0, x));
+    let step = (2.0 * nu + 1.0) / (upper.radius + lower.radius);
+    let upper_excess = (nu + 1.0) * (nu + 1.0) / (upper.radius + x);
+    let ln_ratio = step
+        - ((nu + 1.0 + upper_excess) / x).ln_1p()
+        - nu * ((1.0 + step) / (nu + lower.radius)).ln_1p()
+        - 0.5 * (step / lower.radius).ln_1p()
+        + (upper.ln_sums.0 - lower.ln_sums.0);
+    Ok(ln_ratio.exp())
+}
+
+/// Calculate the mean resultant length A_d(κ) = I_{d/2}(κ) / I_{d/2-1}(κ)
+/// of a von Mises–Fisher distribution
+///
+/// # Parameters
+/// * `d` - Dimension of the ambient space (d > 0; 2 for von Mises)
+/// * `kappa` - Concentration (κ >= 0)
+pub fn mean_resultant_length(d: f64, kappa: f64) -> Result<f64, BesselError> {
+    if !(d > 0.0 && d.is_finite()) {
+        return Err(invalid(format!("dimension must be positive, got {}", d)));
+    }
+    bessel_i_ratio(0.5 * d - 1.0, kappa)
+}
+
+/// Solve A_d(κ) = r for the concentration κ
+///
+/// Starts from the approximation of Banerjee et al. (2005) and refines it
+/// by Newton's method with A'_d(κ) = 1 - A² - (d - 1) A / κ; since A_d is
+/// increasing and concave the iteration converges from any start.
+///
+/// # Parameters
+/// * `d` - Dimension of the ambient space (d > 0; 2 for von Mises)
+/// * `r` - Mean resultant length (0 <= r < 1)
+pub fn mean_resultant_length_inverse(d: f64, r: f64) -> Result<f64, BesselError> {
+    if !(d > 0.0 && d.is_finite()) {
+        return Err(invalid(format!("dimension must be positive, got {}", d)));
+    }
+    if !(0.0..1.0).contains(&r) {
+        return Err(invalid(format!(
+            "mean resultant length must be in [0, 1), got {}",
+            r
+        )));
+    }
+    if r == 0.0 {
+        return Ok(0.0);
+    }
+    let mut kappa = r * (d - r * r) / (1.0 - r * r);
+    for _ in 0..100 {
+        let a = mean_resultant_length(d, kappa)?;
+        let slope = 1.0 - a * a - (d - 1.0) * a / kappa;
+        let next = kappa - (a - r) / slope;
+        let next = if next > 0.0 && next.is_finite() {
+            next
+        } else {
+            0.5 * kappa
+        };
+        if (next - kappa).abs() <= 4.0 * f64::EPSILON * kappa {
+            return Ok(next);
+        }
+        kappa = next;
+    }
+    Err(BesselError::amos(
+        "zbesi",
+        5,
+        Complex64::new(kappa, 0.0),
+        Some(0.5 * d - 1.0),
+        2,
+        2,
+    ))
+}
+
+/// Calculate the Matérn correlation 2^{1-ν} / Γ(ν) x^ν K_ν(x)
+///
+/// The value is 1 at x = 0 and decreases to 0; with x = sqrt(2ν) d / ρ it
+/// is the Matérn covariance at distance d for length scale ρ and unit
+/// variance.
+///
+/// # Parameters
+/// * `nu` - Smoothness (ν > 0)
+/// * `x` - Scaled distance (x >= 0)
+pub fn matern(nu: f64, x: f64) -> Result<f64, BesselError> {
+    if !(nu > 0.0 && nu.is_finite()) {
+        return Err(invalid(format!("smoothness must be positive, got {}", nu)));
+    }
+    check_argument(x)?;
+    if x == 0.0 {
+        return Ok(1.0);
+    }
+    let ln_value = (1.0 - nu) * LN_2 - ln_gamma(nu) + nu * x.ln() + log_bessel_k(nu, x)?;
+    Ok(ln_value.exp().min(1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(value: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (value - expected).abs() <= tolerance * expected.abs(),
+            "{} != {}",
+            value,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_logarithms() {
+        // mpmath references
+        assert_close(log_bessel_i0(0.5).unwrap(), 0.061_549_719_185_481_31, 1e-15);
+        assert_close(log_bessel_i0(1e8).unwrap(), 99_999_989.870_721_1, 1e-15);
+        assert_close(
+            log_bessel_i(499.0, 1e8).unwrap(),
+            99_999_989.869_476_1,
+            1e-15,
+        );
+        assert_close(
+            log_bessel_i(1e4, 1.0).unwrap(),
+            -89_040.399_617_416_3,
+            1e-15,
+        );
+        assert_close(
+            log_bessel_i(2.5, 30.0).unwrap(),
+            27.278_799_122_187_75,
+            1e-15,
+        );
+        assert_close(
+            log_bessel_i(300.0, 300.0).unwrap(),
+            155.907_924_884_116_74,
+            1e-15,
+        );
+        assert_close(
+            log_bessel_k(0.0, 1e8).unwrap(),
+            -100_000_008.984_549_02,
+            1e-15,
+        );
+        assert_close(
+            log_bessel_k(50.0, 1e-3).unwrap(),
+            523.917_719_737_787,
+            1e-14,
+        );
+        assert_close(
+            log_bessel_k(-2.5, 1e-200).unwrap(),
+            1_152.616_950_138_335_6,
+            1e-15,
+        );
+        assert_close(
+            log_bessel_k(120.25, 3.0).unwrap(),
+            404.751_794_533_219_7,
+            1e-14,
+        );
+        assert_eq!(log_bessel_i(1.0, 0.0).unwrap(), f64::NEG_INFINITY);
+        assert!(log_bessel_i(-1.5, 1.0).is_err());
+        assert!(log_bessel_k(1.0, -1.0).is_err());
+    }
+
+    #[test]
+    fn test_ratios() {
+        assert_close(
+            mean_resultant_length(3.0, 10.0).unwrap(),
+            0.900_000_004_122_307_3,
+            1e-15,
+        );
+        assert_close(
+            mean_resultant_length(3.0, 1e8).unwrap(),
+            0.999_999_99,
+            1e-15,
+        );
+        assert_close(mean_resultant_length(3.0, 1000.0).unwrap(), 0.999, 1e-15);
+        assert_close(
+            mean_resultant_length(1000.0, 300.0).unwrap(),
+            0.277_017_777_352_625_84,
+            1e-14,
+        );
+        assert_close(
+            mean_resultant_length(2.0, 1e-3).unwrap(),
+            4.999_999_375_000_105e-4,
+            1e-15,
+        );
+        for (d, kappa) in [(2.0, 0.3), (3.0, 25.0), (100.0, 1e5)] {
+            let r = mean_resultant_length(d, kappa).unwrap();
+            assert_close(mean_resultant_length_inverse(d, r).unwrap(), kappa, 1e-9);
+        }
+        assert!(mean_resultant_length_inverse(3.0, 1.0).is_err());
+    }
+
+    #[test]
+    fn test_matern() {
+        assert_close(matern(2.5, 2.5).unwrap(), 0.458_307_908_983_434_93, 1e-14);
+        // ν = 1/2 is the exponential correlation
+        assert_close(matern(0.5, 40.0).unwrap(), 4.248_354_255_291_589e-18, 1e-13);
+        assert_eq!(matern(1.5, 0.0).unwrap(), 1.0);
+        assert_close(matern(1.5, 1e-12).unwrap(), 1.0, 1e-15);
+    }
+}
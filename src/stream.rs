@@ -0,0 +1,206 @@
+//! Streaming evaluation over iterators of arguments
+//!
+//! Parameter sweeps with billions of points cannot hold all arguments and
+//! values at once. A [`BesselStream`] pulls arguments from any iterator in
+//! chunks, evaluates each chunk with the kernels of
+//! [`bessel_slice`](crate::bessel_slice), and hands the values back either
+//! as borrowed slices or copied into caller-provided buffers, so memory use is
+//! bounded by the chunk size. [`bessel_for_each_chunk`] wraps the same loop
+//! around a callback.
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{bessel_for_each_chunk, BesselKind, Scaling};
+//!
+//! fn main() -> Result<(), zbessel_rs::BesselError> {
+//!     // The points are generated on the fly; at most 4096 are held at once
+//!     let points = (0..100_000u64).map(|k| Complex64::new(1e-3 * k as f64, 0.5));
+//!     let mut largest = 0.0_f64;
+//!     let count = bessel_for_each_chunk(BesselKind::K, 0.0, points, Scaling::Scaled, 4096, |_, values| {
+//!         largest = values.iter().fold(largest, |m, v| m.max(v.norm()));
+//!     })?;
+//!     println!("max |K_0| over {} points: {}", count, largest);
+//!     Ok(())
+//! }
+//! ```
+
+use crate::slice::fill_slice;
+use crate::{BesselError, BesselKind, Scaling};
+use num_complex::Complex64;
+
+/// Arguments and values of one evaluated chunk
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Chunk<'a> {
+    /// Arguments of the chunk, in stream order
+    pub points: &'a [Complex64],
+    /// Function values at `points`
+    pub values: &'a [Complex64],
+}
+
+/// Chunked evaluation of one function over an iterator of arguments
+#[derive(Debug, Clone)]
+pub struct BesselStream<I> {
+    kind: BesselKind,
+    nu: f64,
+    scaling: Scaling,
+    points: I,
+    zs: Vec<Complex64>,
+    values: Vec<Complex64>,
+    chunk_size: usize,
+}
+
+impl<I: Iterator<Item = Complex64>> BesselStream<I> {
+    /// Stream `kind` of order `nu` over `points`, `chunk_size` at a time
+    ///
+    /// # Parameters
+    /// * `kind` - Kind of Bessel function
+    /// * `nu` - Order (real number)
+    /// * `points` - Complex arguments, consumed lazily
+    /// * `scaling` - Scaling option
+    /// * `chunk_size` - Largest number of points evaluated and held at once
+    pub fn new(
+        kind: BesselKind,
+        nu: f64,
+        points: impl IntoIterator<IntoIter = I>,
+        scaling: Scaling,
+        chunk_size: usize,
+    ) -> Result<Self, BesselError> {
+        if chunk_size == 0 {
+            return Err(BesselError::InvalidParameter(
+                "chunk_size must be greater than 0".to_string(),
+            ));
+        }
+        Ok(BesselStream {
+            kind,
+            nu,
+            scaling,
+            points: points.into_iter(),
+            zs: Vec::with_capacity(chunk_size),
+            values: Vec::with_capacity(chunk_size),
+            chunk_size,
+        })
+    }
+
+    /// Pull up to `n` points into the argument buffer
+    fn pull(&mut self, n: usize) -> usize {
+        self.zs.clear();
+        self.zs.extend(self.points.by_ref().take(n));
+        self.zs.len()
+    }
+
+    /// Evaluate the next chunk, returning its arguments and values
+    ///
+    /// Returns `Ok(None)` once the points are exhausted. If a point fails,
+    /// its chunk is consumed and the error of the first failing point in it
+    /// is returned; the stream can continue with the next chunk.
+    pub fn next_chunk(&mut self) -> Result<Option<Chunk<'_>>, BesselError> {
+        let n = self.pull(self.chunk_size);
+        if n == 0 {
+            return Ok(None);
+        }
+        self.values.resize(n, Complex64::new(0.0, 0.0));
+        fill_slice(self.kind, &self.zs, self.nu, self.scaling, &mut self.values)?;
+        Ok(Some(Chunk {
+            points: &self.zs,
+            values: &self.values,
+        }))
+    }
+
+    /// Evaluate the next `out.len()` points into `out`
+    ///
+    /// Returns the number of values written, which is less than `out.len()`
+    /// only when the points run out (0 once they are exhausted).
+    pub fn fill(&mut self, out: &mut [Complex64]) -> Result<usize, BesselError> {
+        let n = self.pull(out.len());
+        fill_slice(self.kind, &self.zs, self.nu, self.scaling, &mut out[..n])?;
+        Ok(n)
+    }
+}
+
+/// Evaluate `kind` of order `nu` over `points` and pass each chunk to `f`
+///
+/// `f` receives the arguments and values of one chunk at a time. Evaluation
+/// stops at the first failing point.
+///
+/// # Parameters
+/// * `kind` - Kind of Bessel function
+/// * `nu` - Order (real number)
+/// * `points` - Complex arguments, consumed lazily
+/// * `scaling` - Scaling option
+/// * `chunk_size` - Largest number of points evaluated and held at once
+/// * `f` - Callback for each chunk
+///
+/// # Returns
+/// Total number of points evaluated
+pub fn bessel_for_each_chunk(
+    kind: BesselKind,
+    nu: f64,
+    points: impl IntoIterator<Item = Complex64>,
+    scaling: Scaling,
+    chunk_size: usize,
+    mut f: impl FnMut(&[Complex64], &[Complex64]),
+) -> Result<u64, BesselError> {
+    let mut stream = BesselStream::new(kind, nu, points, scaling, chunk_size)?;
+    let mut count = 0;
+    while let Some(chunk) = stream.next_chunk()? {
+        count += chunk.points.len() as u64;
+        f(chunk.points, chunk.values);
+    }
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bessel_slice;
+
+    #[test]
+    fn test_stream_matches_slice() {
+        let zs: Vec<Complex64> = (0..1000)
+            .map(|k| Complex64::new(0.05 * k as f64 - 10.0, 0.3))
+            .collect();
+        let expected = bessel_slice(BesselKind::Y, &zs, 1.5, Scaling::Unscaled).unwrap();
+
+        let mut streamed = Vec::new();
+        let count = bessel_for_each_chunk(
+            BesselKind::Y,
+            1.5,
+            zs.iter().copied(),
+            Scaling::Unscaled,
+            97,
+            |chunk, values| {
+                assert!(chunk.len() <= 97);
+                streamed.extend_from_slice(values);
+            },
+        )
+        .unwrap();
+        assert_eq!(count, 1000);
+        assert_eq!(streamed, expected);
+
+        let mut stream =
+            BesselStream::new(BesselKind::Y, 1.5, zs.iter().copied(), Scaling::Unscaled, 1)
+                .unwrap();
+        let mut out = [Complex64::new(0.0, 0.0); 300];
+        let mut offset = 0;
+        loop {
+            let n = stream.fill(&mut out).unwrap();
+            if n == 0 {
+                break;
+            }
+            assert_eq!(&out[..n], &expected[offset..offset + n]);
+            offset += n;
+        }
+        assert_eq!(offset, 1000);
+    }
+
+    #[test]
+    fn test_stream_errors() {
+        let points = [Complex64::new(1.0, 0.0), Complex64::new(f64::NAN, 0.0)];
+        let mut stream =
+            BesselStream::new(BesselKind::K, 0.0, points, Scaling::Unscaled, 1).unwrap();
+        assert!(stream.next_chunk().unwrap().is_some());
+        assert!(stream.next_chunk().is_err());
+        assert!(stream.next_chunk().unwrap().is_none());
+        assert!(BesselStream::new(BesselKind::K, 0.0, points, Scaling::Unscaled, 0).is_err());
+    }
+}
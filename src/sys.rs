@@ -0,0 +1,152 @@
+//! Raw bindings to the Amos routines
+//!
+//! These are the C entry points declared in `zbessel.h`. The declarations
+//! are checked in, so building the crate needs neither bindgen nor libclang;
+//! with the `bindgen` feature the build also generates them from the header
+//! and fails if the two disagree. They follow the original Fortran interface:
+//! real and imaginary parts are passed separately, results are written to
+//! caller buffers, and the return value is the Amos IERR code.
+//!
+//! | Function | Computes | Output buffers |
+//! |----------|----------|----------------|
+//! | `zbesj` | J_{fnu+k}(z), k = 0..n-1 | `cyr`, `cyi` of length `n` |
+//! | `zbesy` | Y_{fnu+k}(z) | `cyr`, `cyi` and work arrays `cwrkr`, `cwrki`, all of length `n` |
+//! | `zbesi` | I_{fnu+k}(z) | `cyr`, `cyi` of length `n` |
+//! | `zbesk` | K_{fnu+k}(z) | `cyr`, `cyi` of length `n` |
+//! | `zbesh` | H^(m)_{fnu+k}(z), m = 1 or 2 | `cyr`, `cyi` of length `n` |
+//! | `zairy` | Ai(z) (`id` = 0) or Ai'(z) (`id` = 1) | `air`, `aii` |
+//! | `zbiry` | Bi(z) (`id` = 0) or Bi'(z) (`id` = 1) | `bir`, `bii` |
+//!
+//! `kode` selects unscaled (1) or scaled (2) values, and `nz` receives the
+//! number of values set to zero by underflow. IERR codes:
+//!
+//! * 0 - normal return
+//! * 1 - input error
+//! * 2 - overflow
+//! * 3 - loss of more than half of the significant digits (values returned)
+//! * 4 - complete loss of significance (no values returned)
+//! * 5 - algorithm did not terminate
+//!
+//! # Safety
+//!
+//! All pointers must be valid for writes of the documented lengths; nothing
+//! is checked. Prefer the safe wrappers in the crate root unless you need
+//! custom batching.
+//!
+//! ```rust
+//! use zbessel_rs::sys;
+//!
+//! let (mut cyr, mut cyi, mut nz) = ([0.0; 3], [0.0; 3], 0);
+//! let ierr = unsafe {
+//!     sys::zbesj(1.0, 0.5, 0.0, 1, 3, cyr.as_mut_ptr(), cyi.as_mut_ptr(), &mut nz)
+//! };
+//! assert_eq!(ierr, 0);
+//! ```
+
+use std::os::raw::c_int;
+
+extern "C" {
+    pub fn zbesh(
+        zr: f64,
+        zi: f64,
+        fnu: f64,
+        kode: c_int,
+        m: c_int,
+        n: c_int,
+        cyr: *mut f64,
+        cyi: *mut f64,
+        nz: *mut c_int,
+    ) -> c_int;
+
+    pub fn zbesi(
+        zr: f64,
+        zi: f64,
+        fnu: f64,
+        kode: c_int,
+        n: c_int,
+        cyr: *mut f64,
+        cyi: *mut f64,
+        nz: *mut c_int,
+    ) -> c_int;
+
+    pub fn zbesj(
+        zr: f64,
+        zi: f64,
+        fnu: f64,
+        kode: c_int,
+        n: c_int,
+        cyr: *mut f64,
+        cyi: *mut f64,
+        nz: *mut c_int,
+    ) -> c_int;
+
+    pub fn zbesk(
+        zr: f64,
+        zi: f64,
+        fnu: f64,
+        kode: c_int,
+        n: c_int,
+        cyr: *mut f64,
+        cyi: *mut f64,
+        nz: *mut c_int,
+    ) -> c_int;
+
+    pub fn zbesy(
+        zr: f64,
+        zi: f64,
+        fnu: f64,
+        kode: c_int,
+        n: c_int,
+        cyr: *mut f64,
+        cyi: *mut f64,
+        nz: *mut c_int,
+        cwrkr: *mut f64,
+        cwrki: *mut f64,
+    ) -> c_int;
+
+    pub fn zairy(
+        zr: f64,
+        zi: f64,
+        id: c_int,
+        kode: c_int,
+        air: *mut f64,
+        aii: *mut f64,
+        nz: *mut c_int,
+    ) -> c_int;
+
+    pub fn zbiry(zr: f64, zi: f64, id: c_int, kode: c_int, bir: *mut f64, bii: *mut f64) -> c_int;
+}
+
+/// Declarations generated from `zbessel.h` by bindgen
+#[cfg(feature = "bindgen")]
+#[allow(dead_code)]
+mod generated {
+    include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+}
+
+// Each pair of function items only coerces to one pointer type if the
+// checked-in declaration has the generated signature
+#[cfg(feature = "bindgen")]
+const _: () = {
+    type Sequence =
+        unsafe extern "C" fn(f64, f64, f64, c_int, c_int, *mut f64, *mut f64, *mut c_int) -> c_int;
+    let _: [Sequence; 2] = [zbesi, generated::zbesi];
+    let _: [Sequence; 2] = [zbesj, generated::zbesj];
+    let _: [Sequence; 2] = [zbesk, generated::zbesk];
+    let _ = [
+        zbesh as unsafe extern "C" fn(_, _, _, _, _, _, _, _, _) -> _,
+        generated::zbesh,
+    ];
+    let _ = [
+        zbesy as unsafe extern "C" fn(_, _, _, _, _, _, _, _, _, _) -> _,
+        generated::zbesy,
+    ];
+    let _ = [
+        zairy as unsafe extern "C" fn(_, _, _, _, _, _, _) -> _,
+        generated::zairy,
+    ];
+    let _ = [
+        zbiry as unsafe extern "C" fn(_, _, _, _, _, _) -> _,
+        generated::zbiry,
+    ];
+};
@@ -0,0 +1,375 @@
+//! Precomputed tables for real-time evaluation
+//!
+//! A [`TableEvaluator`] samples one function (kind, order and scaling fixed)
+//! on a real interval or a complex rectangle and answers later queries by
+//! piecewise Chebyshev interpolation. The domain is split into equal cells,
+//! each holding a degree-15 interpolant (tensor-product on rectangles), so a
+//! lookup is an index computation plus a fixed number of multiply-adds. Cells
+//! are halved until the interpolation error, checked against direct
+//! evaluation between the nodes, is below the requested tolerance relative to
+//! the largest magnitude on each cell.
+//!
+//! Scaled functions are smoother and need far fewer cells for I, K and the
+//! Hankel functions away from the real axis.
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{BesselKind, Scaling, TableEvaluator, J};
+//!
+//! fn main() -> Result<(), zbessel_rs::BesselError> {
+//!     let j0 = TableEvaluator::interval(BesselKind::J, 0.0, Scaling::Unscaled, (0.0, 50.0), 1e-12)?;
+//!     let x = 17.3;
+//!     assert!((j0.eval(x) - J(0.0, Complex64::new(x, 0.0))?).norm() < 1e-11);
+//!     assert!(j0.eval(60.0).is_nan());
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{bessel, BesselError, BesselKind, Scaling};
+use num_complex::Complex64;
+use std::f64::consts::PI;
+
+/// Chebyshev nodes per dimension and cell
+const NODES: usize = 16;
+
+/// Most cells before construction gives up
+const MAX_CELLS: usize = 4096;
+
+/// Smallest tolerance accepted; below it rounding in the table dominates
+const MIN_TOLERANCE: f64 = 1e-14;
+
+/// One axis of the domain, split into `cells` equal cells
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Axis {
+    start: f64,
+    width: f64,
+    cells: usize,
+    nodes: usize,
+}
+
+impl Axis {
+    /// Axis over `range`, or a single point when `nodes` is 1
+    fn new(range: (f64, f64), nodes: usize) -> Result<Self, BesselError> {
+        let (start, end) = range;
+        if !start.is_finite() || !end.is_finite() || (nodes > 1 && start >= end) {
+            return Err(BesselError::InvalidParameter(format!(
+                "table range must be finite and increasing, got ({}, {})",
+                start, end
+            )));
+        }
+        Ok(Axis {
+            start,
+            width: end - start,
+            cells: 1,
+            nodes,
+        })
+    }
+
+    /// Cell index and position in [-1, 1] within it, or `None` outside
+    fn locate(&self, x: f64) -> Option<(usize, f64)> {
+        if self.nodes == 1 {
+            return (x == self.start).then_some((0, 0.0));
+        }
+        let s = (x - self.start) / self.width * self.cells as f64;
+        if !(0.0..=self.cells as f64).contains(&s) {
+            return None;
+        }
+        let cell = (s as usize).min(self.cells - 1);
+        Some((cell, 2.0 * (s - cell as f64) - 1.0))
+    }
+
+    /// Coordinate of position `t` in [-1, 1] within `cell`
+    fn point(&self, cell: usize, t: f64) -> f64 {
+        let h = self.width / self.cells as f64;
+        self.start + h * (cell as f64 + 0.5 * (t + 1.0))
+    }
+
+    /// Interpolation nodes in [-1, 1]
+    fn nodes(&self) -> Vec<f64> {
+        let n = self.nodes as f64;
+        (0..self.nodes)
+            .map(|j| (PI * (j as f64 + 0.5) / n).cos())
+            .collect()
+    }
+
+    /// Points between the nodes and at the cell edges, for the error check
+    fn checks(&self) -> Vec<f64> {
+        if self.nodes == 1 {
+            return vec![0.0];
+        }
+        let n = self.nodes as f64;
+        (0..=self.nodes)
+            .map(|j| (PI * j as f64 / n).cos())
+            .collect()
+    }
+}
+
+/// Σ_k c_k T_k(t) by Clenshaw's recurrence
+fn clenshaw(c: &[Complex64], t: f64) -> Complex64 {
+    let zero = Complex64::new(0.0, 0.0);
+    let (mut b1, mut b2) = (zero, zero);
+    for &c in c[1..].iter().rev() {
+        let b = c + 2.0 * t * b1 - b2;
+        b2 = b1;
+        b1 = b;
+    }
+    c[0] + t * b1 - b2
+}
+
+/// Chebyshev coefficients from samples at the `n` nodes, read and written
+/// with spacing `stride`
+fn transform(values: &mut [Complex64], n: usize, stride: usize) {
+    let samples: Vec<Complex64> = (0..n).map(|j| values[j * stride]).collect();
+    for k in 0..n {
+        let weight = if k == 0 { 1.0 } else { 2.0 } / n as f64;
+        let sum: Complex64 = samples
+            .iter()
+            .enumerate()
+            .map(|(j, &f)| f * (PI * k as f64 * (j as f64 + 0.5) / n as f64).cos())
+            .sum();
+        values[k * stride] = weight * sum;
+    }
+}
+
+/// A function tabulated for fast interpolated lookups
+#[derive(Debug, Clone)]
+pub struct TableEvaluator {
+    re: Axis,
+    im: Axis,
+    coefficients: Vec<Complex64>,
+    max_error: f64,
+}
+
+impl TableEvaluator {
+    /// Tabulate `kind` of order `nu` on the real interval `range`
+    ///
+    /// # Parameters
+    /// * `kind` - Which function to tabulate
+    /// * `nu` - Order (real number)
+    /// * `scaling` - Whether to apply the exponential scaling factor
+    /// * `range` - Interval `(start, end)` of real arguments
+    /// * `tolerance` - Error bound relative to the largest magnitude on each cell
+    pub fn interval(
+        kind: BesselKind,
+        nu: f64,
+        scaling: Scaling,
+        range: (f64, f64),
+        tolerance: f64,
+    ) -> Result<Self, BesselError> {
+        let re = Axis::new(range, NODES)?;
+        let im = Axis::new((0.0, 0.0), 1)?;
+        Self::build(kind, nu, scaling, re, im, tolerance)
+    }
+
+    /// Tabulate `kind` of order `nu` on the rectangle `re` × i`im`
+    ///
+    /// # Parameters
+    /// * `kind` - Which function to tabulate
+    /// * `nu` - Order (real number)
+    /// * `scaling` - Whether to apply the exponential scaling factor
+    /// * `re` - Range `(start, end)` of real parts
+    /// * `im` - Range `(start, end)` of imaginary parts
+    /// * `tolerance` - Error bound relative to the largest magnitude on each cell
+    pub fn rectangle(
+        kind: BesselKind,
+        nu: f64,
+        scaling: Scaling,
+        re: (f64, f64),
+        im: (f64, f64),
+        tolerance: f64,
+    ) -> Result<Self, BesselError> {
+        let re = Axis::new(re, NODES)?;
+        let im = Axis::new(im, NODES)?;
+        Self::build(kind, nu, scaling, re, im, tolerance)
+    }
+
+    fn build(
+        kind: BesselKind,
+        nu: f64,
+        scaling: Scaling,
+        mut re: Axis,
+        mut im: Axis,
+        tolerance: f64,
+    ) -> Result<Self, BesselError> {
+        if !(MIN_TOLERANCE..1.0).contains(&tolerance) {
+            return Err(BesselError::InvalidParameter(format!(
+                "tolerance must be in [{:e}, 1), got {:e}",
+                MIN_TOLERANCE, tolerance
+            )));
+        }
+        loop {
+            let mut table = TableEvaluator {
+                re,
+                im,
+                coefficients: Vec::new(),
+                max_error: 0.0,
+            };
+            if table.fill(kind, nu, scaling, tolerance)? {
+                return Ok(table);
+            }
+            re.cells *= 2;
+            if im.nodes > 1 {
+                im.cells *= 2;
+            }
+            if re.cells * im.cells > MAX_CELLS {
+                return Err(BesselError::InvalidParameter(format!(
+                    "tolerance {:e} not reached with {} cells",
+                    tolerance, MAX_CELLS
+                )));
+            }
+        }
+    }
+
+    /// Interpolate every cell; `false` as soon as one misses the tolerance
+    fn fill(
+        &mut self,
+        kind: BesselKind,
+        nu: f64,
+        scaling: Scaling,
+        tolerance: f64,
+    ) -> Result<bool, BesselError> {
+        let (re, im) = (self.re, self.im);
+        let f = |x: f64, y: f64| {
+            let z = Complex64::new(x, y);
+            let value = bessel(kind, nu, z, scaling)?;
+            if !value.re.is_finite() || !value.im.is_finite() {
+                return Err(BesselError::InvalidParameter(format!(
+                    "{:?}_{} is infinite at {} and cannot be tabulated",
+                    kind, nu, z
+                )));
+            }
+            Ok(value)
+        };
+        let (re_nodes, im_nodes) = (re.nodes(), im.nodes());
+        let (re_checks, im_checks) = (re.checks(), im.checks());
+        let size = re.nodes * im.nodes;
+        self.coefficients = Vec::with_capacity(re.cells * im.cells * size);
+        for a in 0..re.cells {
+            for b in 0..im.cells {
+                let mut c = Vec::with_capacity(size);
+                let mut scale = 0.0_f64;
+                for &s in &re_nodes {
+                    for &t in &im_nodes {
+                        let value = f(re.point(a, s), im.point(b, t))?;
+                        scale = scale.max(value.norm());
+                        c.push(value);
+                    }
+                }
+                for j in 0..re.nodes {
+                    transform(&mut c[j * im.nodes..], im.nodes, 1);
+                }
+                for k in 0..im.nodes {
+                    transform(&mut c[k..], re.nodes, im.nodes);
+                }
+
+                let mut error = 0.0_f64;
+                for &s in &re_checks {
+                    for &t in &im_checks {
+                        let exact = f(re.point(a, s), im.point(b, t))?;
+                        scale = scale.max(exact.norm());
+                        error = error.max((Self::interpolate(&c, im.nodes, s, t) - exact).norm());
+                    }
+                }
+                let error = if scale > 0.0 { error / scale } else { 0.0 };
+                if error > tolerance {
+                    return Ok(false);
+                }
+                self.max_error = self.max_error.max(error);
+                self.coefficients.extend_from_slice(&c);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Tensor-product Chebyshev sum of one cell at (s, t)
+    fn interpolate(c: &[Complex64], columns: usize, s: f64, t: f64) -> Complex64 {
+        let mut rows = [Complex64::new(0.0, 0.0); NODES];
+        let rows = &mut rows[..c.len() / columns];
+        for (row, chunk) in rows.iter_mut().zip(c.chunks_exact(columns)) {
+            *row = clenshaw(chunk, t);
+        }
+        clenshaw(rows, s)
+    }
+
+    /// Interpolated value at `z`, NaN + iNaN outside the tabulated domain
+    ///
+    /// Interval tables accept real arguments (`f64` converts) and complex
+    /// ones with a zero imaginary part.
+    pub fn eval(&self, z: impl Into<Complex64>) -> Complex64 {
+        let z = z.into();
+        let (Some((a, s)), Some((b, t))) = (self.re.locate(z.re), self.im.locate(z.im)) else {
+            return Complex64::new(f64::NAN, f64::NAN);
+        };
+        let size = self.re.nodes * self.im.nodes;
+        let cell = (a * self.im.cells + b) * size;
+        Self::interpolate(&self.coefficients[cell..cell + size], self.im.nodes, s, t)
+    }
+
+    /// Largest relative error found in the check against direct evaluation
+    pub fn max_error(&self) -> f64 {
+        self.max_error
+    }
+
+    /// Number of interpolation cells
+    pub fn cell_count(&self) -> usize {
+        self.re.cells * self.im.cells
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interval_table() {
+        let table =
+            TableEvaluator::interval(BesselKind::Y, 1.5, Scaling::Unscaled, (1.0, 40.0), 1e-12)
+                .unwrap();
+        assert!(table.max_error() <= 1e-12);
+        for k in 0..=390 {
+            let x = 1.0 + 0.1 * k as f64;
+            let exact = bessel(
+                BesselKind::Y,
+                1.5,
+                Complex64::new(x, 0.0),
+                Scaling::Unscaled,
+            )
+            .unwrap();
+            assert!((table.eval(x) - exact).norm() < 1e-11, "x={}", x);
+        }
+        assert!(table.eval(0.5).is_nan());
+        assert!(table.eval(Complex64::new(2.0, 0.1)).is_nan());
+
+        // Singular points inside the range and unreachable tolerances are errors
+        let k =
+            |range, tol| TableEvaluator::interval(BesselKind::K, 0.0, Scaling::Scaled, range, tol);
+        assert!(k((0.0, 1.0), 1e-10).is_err());
+        assert!(k((1.0, 2.0), 1e-16).is_err());
+        assert!(k((2.0, 1.0), 1e-10).is_err());
+    }
+
+    #[test]
+    fn test_rectangle_table() {
+        let table = TableEvaluator::rectangle(
+            BesselKind::K,
+            0.3,
+            Scaling::Scaled,
+            (0.5, 6.0),
+            (-3.0, 3.0),
+            1e-10,
+        )
+        .unwrap();
+        for j in 0..=22 {
+            for k in 0..=24 {
+                let z = Complex64::new(0.5 + 0.25 * j as f64, -3.0 + 0.25 * k as f64);
+                let exact = bessel(BesselKind::K, 0.3, z, Scaling::Scaled).unwrap();
+                assert!(
+                    (table.eval(z) - exact).norm() < 1e-9 * exact.norm(),
+                    "z={}",
+                    z
+                );
+            }
+        }
+        assert!(table.cell_count() >= 1);
+    }
+}
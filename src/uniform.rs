@@ -0,0 +1,371 @@
+//! Uniform asymptotic expansions for very large orders
+//!
+//! Beyond ν = [`argument_limit`] Amos reports loss of precision, and beyond
+//! about 10⁹ it stops altogether. For such orders and |ph z| <= π/4 the
+//! functions are evaluated from Olver's expansions instead, writing the
+//! argument as νw:
+//!
+//! * J, Y, H1, H2 from the Airy-type expansion (DLMF 10.20.4-6) with the
+//!   crate's own scaled Ai and Bi, keeping A_0, A_1, B_0 and B_1
+//! * I, K from the Debye expansion (DLMF 10.41.3-4) with U_0, ..., U_3
+//!
+//! The omitted terms are of relative size ν⁻⁴ < 10⁻¹⁸. Since the functions
+//! themselves are conditioned like ν in their argument, the results carry a
+//! relative error of roughly ν·ε. Near the turning point w = 1 the Airy-type
+//! coefficients are summed from their Taylor series in u = 1 - w², where the
+//! closed forms cancel.
+
+use crate::asymptotic::argument_limit;
+use crate::backend::{ActiveBackend, Backend};
+use crate::{direct, BesselKind};
+use num_complex::Complex64;
+use std::f64::consts::PI;
+
+/// |u| below which the Taylor series replace the closed forms
+const SERIES_RADIUS: f64 = 0.25;
+
+// Coefficients of the Debye polynomials (DLMF 10.41.10) and of
+// (2/3)ζ^{3/2} (DLMF 10.20.10-11)
+const U_1: f64 = 5.0 / 72.0;
+const U_2: f64 = 385.0 / 10368.0;
+const U_3: f64 = 85085.0 / 2239488.0;
+const V_1: f64 = -7.0 / 72.0;
+const V_2: f64 = -455.0 / 10368.0;
+
+const B0_SERIES: [f64; 24] = [
+    0.01799887214135533,
+    0.005599649110643881,
+    0.0028850140223113277,
+    0.0018009660676105393,
+    0.001247531105891992,
+    0.0009228788765729383,
+    0.0007144304217272874,
+    0.0005717872817897049,
+    0.00046943100760648155,
+    0.00039323283546291665,
+    0.0003348188893182977,
+    0.00028895214849575154,
+    0.0002522116155495733,
+    0.00022228058079888332,
+    0.0001975418380330625,
+    0.00017683685501971802,
+    0.0001593168996618211,
+    0.00014434793019733397,
+    0.0001314480681199654,
+    0.00012024544494930288,
+    0.0001104491445045994,
+    0.00010182877074056726,
+    9.419982242042375e-05,
+    8.741305457538345e-05,
+];
+const A1_SERIES: [f64; 24] = [
+    -0.0044444444444444444,
+    -0.000922077922077922,
+    -8.848928848928849e-05,
+    0.00016592768783244973,
+    0.0002466913727417929,
+    0.0002659955893462548,
+    0.00026182429706150096,
+    0.0002487304373446556,
+    0.00023272104008323209,
+    0.00021636248571236508,
+    0.00020073885876275234,
+    0.00018626763663754517,
+    0.0001730607759178765,
+    0.00016109170592901574,
+    0.00015027477416090814,
+    0.0001405034973912698,
+    0.0001316688165459228,
+    0.00012366744559825325,
+    0.00011640527147473791,
+    0.00010979829837271337,
+    0.00010377241042299283,
+    9.826260783693634e-05,
+    9.321205172495032e-05,
+    8.857108524787117e-05,
+];
+const B1_SERIES: [f64; 24] = [
+    -0.0014928295321342917,
+    -0.0008782047095463894,
+    -0.0005029165495720346,
+    -0.000294822138512746,
+    -0.00017546399697078284,
+    -0.00010400855046081644,
+    -5.961419530464579e-05,
+    -3.1203892907609836e-05,
+    -1.2608973598023005e-05,
+    -2.4289260857573037e-07,
+    8.059961654142736e-06,
+    1.3650700926214739e-05,
+    1.7396412547292627e-05,
+    1.9867297884213378e-05,
+    2.1446326379082263e-05,
+    2.2395465923245652e-05,
+    2.2896778381471263e-05,
+    2.307853898111778e-05,
+    2.3032197608090914e-05,
+    2.2823607372034874e-05,
+    2.250058811052924e-05,
+    2.2098101536199144e-05,
+    2.164184274481039e-05,
+    2.1150764925622083e-05,
+];
+
+/// Debye polynomials U_0(p), ..., U_3(p)
+fn debye(p: Complex64) -> [Complex64; 4] {
+    let p2 = p * p;
+    [
+        Complex64::new(1.0, 0.0),
+        p * (3.0 - 5.0 * p2) / 24.0,
+        p2 * (81.0 + p2 * (-462.0 + 385.0 * p2)) / 1152.0,
+        p * p2 * (30375.0 + p2 * (-369603.0 + p2 * (765765.0 - 425425.0 * p2))) / 414720.0,
+    ]
+}
+
+fn horner(coefficients: &[f64], u: Complex64) -> Complex64 {
+    coefficients
+        .iter()
+        .rev()
+        .fold(Complex64::new(0.0, 0.0), |acc, &c| acc * u + c)
+}
+
+/// ζ, (4ζ/(1-w²))^{1/4} and A_1(ζ), B_0(ζ), B_1(ζ) of the Airy-type expansion
+struct Olver {
+    zeta: Complex64,
+    phi: Complex64,
+    a1: Complex64,
+    b0: Complex64,
+    b1: Complex64,
+}
+
+impl Olver {
+    fn new(w: Complex64) -> Self {
+        // ζ = u g(u) with g = ((3/2) f)^{2/3}, f = (atanh √u - √u) / u^{3/2}
+        let u = (1.0 - w) * (1.0 + w);
+        let small = u.norm() < SERIES_RADIUS;
+        let f = if small {
+            let mut f = Complex64::new(0.0, 0.0);
+            for k in (0..30).rev() {
+                f = f * u + 1.0 / (2 * k + 3) as f64;
+            }
+            f
+        } else {
+            let root = u.sqrt();
+            (root.atanh() - root) / (root * u)
+        };
+        let g = (1.5 * f).powf(2.0 / 3.0);
+        let zeta = u * g;
+        let phi = (4.0 * g).powf(0.25);
+        if small {
+            return Olver {
+                zeta,
+                phi,
+                a1: horner(&A1_SERIES, u),
+                b0: horner(&B0_SERIES, u),
+                b1: horner(&B1_SERIES, u),
+            };
+        }
+        // ζ^{1/2} and p = (1-w²)^{-1/2} on the same branch of √u
+        let root = u.sqrt();
+        let half = root * g.sqrt();
+        let inv = 1.0 / (1.5 * half * zeta);
+        let [u0, u1, u2, u3] = debye(1.0 / root);
+        Olver {
+            zeta,
+            phi,
+            a1: u2 + inv * (V_1 * u1 + inv * V_2 * u0),
+            b0: -(u1 + inv * U_1 * u0) / half,
+            b1: -(u3 + inv * (U_1 * u2 + inv * (U_2 * u1 + inv * U_3 * u0))) / half,
+        }
+    }
+}
+
+/// Scaled Ai(t), Ai'(t) (`bi` false) or Bi(t), Bi'(t) with the exponent
+/// removed by the scaling
+fn airy(t: Complex64, bi: bool) -> Option<(Complex64, Complex64, Complex64)> {
+    let xi = 2.0 / 3.0 * t * t.sqrt();
+    let (values, exponent) = if bi {
+        let (e0, v) = ActiveBackend::airy_bi(t, 0, 2);
+        let (e1, d) = ActiveBackend::airy_bi(t, 1, 2);
+        ([(e0, v), (e1, d)], Complex64::new(xi.re.abs(), 0.0))
+    } else {
+        let (e0, v, _) = ActiveBackend::airy_ai(t, 0, 2);
+        let (e1, d, _) = ActiveBackend::airy_ai(t, 1, 2);
+        ([(e0, v), (e1, d)], -xi)
+    };
+    // IERR 3 only flags the size of the Airy argument, not of our result
+    values
+        .iter()
+        .all(|&(ierr, _)| ierr == 0 || ierr == 3)
+        .then_some((values[0].1, values[1].1, exponent))
+}
+
+/// c (F(t) S_A + ρ F'(t) S_B / ν^{4/3}) ν^{-1/3} φ for F = Ai or Bi, with
+/// the value and its term magnitudes
+fn airy_type(
+    olver: &Olver,
+    nu: f64,
+    rotation: Complex64,
+    factor: Complex64,
+    bi: bool,
+    shift: Complex64,
+) -> Option<(Complex64, f64)> {
+    let nu2 = nu * nu;
+    let t = rotation * nu.powf(2.0 / 3.0) * olver.zeta;
+    let (f, df, exponent) = airy(t, bi)?;
+    let sum_a = 1.0 + olver.a1 / nu2;
+    let sum_b = (olver.b0 + olver.b1 / nu2) / nu.powf(4.0 / 3.0);
+    let scale = factor * olver.phi / nu.cbrt() * (exponent + shift).exp();
+    let first = f * sum_a;
+    let second = rotation * df * sum_b;
+    Some((
+        scale * (first + second),
+        scale.norm() * (first.norm() + second.norm()),
+    ))
+}
+
+/// Whether ν is beyond [`argument_limit`] and |ph z| <= π/4, where [`value`]
+/// applies
+pub(crate) fn applies(z: Complex64, nu: f64) -> bool {
+    nu > argument_limit() && z.re > 0.0 && z.im.abs() <= z.re
+}
+
+/// F_ν(z) for ν > [`argument_limit`], or `None` if it does not apply
+pub(crate) fn value(kind: BesselKind, z: Complex64, nu: f64, kode: i32) -> Option<Complex64> {
+    if !applies(z, nu) {
+        return None;
+    }
+    let shift = if kode == 2 {
+        kind.log_scale(z)
+    } else {
+        Complex64::new(0.0, 0.0)
+    };
+    let w = z / nu;
+    let one = Complex64::new(1.0, 0.0);
+    let (value, size) = match kind {
+        BesselKind::J | BesselKind::Y | BesselKind::H1 | BesselKind::H2 => {
+            let olver = Olver::new(w);
+            let third = Complex64::from_polar(1.0, PI / 3.0);
+            match kind {
+                BesselKind::J => airy_type(&olver, nu, one, one, false, shift)?,
+                BesselKind::Y => airy_type(&olver, nu, one, -one, true, shift)?,
+                BesselKind::H1 => {
+                    airy_type(&olver, nu, third * third, 2.0 * third.conj(), false, shift)?
+                }
+                _ => airy_type(
+                    &olver,
+                    nu,
+                    (third * third).conj(),
+                    2.0 * third,
+                    false,
+                    shift,
+                )?,
+            }
+        }
+        BesselKind::I | BesselKind::K => {
+            let root = (1.0 + w * w).sqrt();
+            let eta = root + (w / (1.0 + root)).ln();
+            let terms = debye(1.0 / root);
+            let (sign, exponent, prefactor) = if kind == BesselKind::I {
+                (1.0, nu * eta, 1.0 / (2.0 * PI * nu).sqrt())
+            } else {
+                (-1.0, -nu * eta, (0.5 * PI / nu).sqrt())
+            };
+            let mut sum = Complex64::new(0.0, 0.0);
+            let mut power = 1.0;
+            for term in terms {
+                sum += power * term;
+                power *= sign / nu;
+            }
+            let value = prefactor / root.sqrt() * (exponent + shift).exp() * sum;
+            (value, value.norm())
+        }
+    };
+    direct::accept(value, size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Points νw with w near the turning point, where J and Y are representable
+    fn turning_points(nu: f64) -> Vec<Complex64> {
+        let d = nu.powf(-2.0 / 3.0);
+        [
+            (-3.0, 0.0),
+            (0.0, 0.0),
+            (5.0, 0.0),
+            (2.0, 2.0),
+            (20.0, -0.5),
+        ]
+        .iter()
+        .map(|&(re, im)| nu * Complex64::new(1.0 + re * d, im * d))
+        .collect()
+    }
+
+    #[test]
+    fn test_wronskians_at_huge_orders() {
+        for &nu in &[4e4, 2.5e6, 3e9] {
+            // The expansions lose about log10(ν) digits
+            let tolerance = 10.0 * nu * f64::EPSILON;
+            for z in turning_points(nu) {
+                let get = |kind, order| value(kind, z, order, 1).unwrap();
+                // J_{ν+1} Y_ν - J_ν Y_{ν+1} = 2/(πz)
+                let jy = get(BesselKind::J, nu + 1.0) * get(BesselKind::Y, nu)
+                    - get(BesselKind::J, nu) * get(BesselKind::Y, nu + 1.0);
+                let expected = 2.0 / (PI * z);
+                assert!(
+                    (jy - expected).norm() < tolerance * expected.norm(),
+                    "nu={} z={}",
+                    nu,
+                    z
+                );
+                let h1 = get(BesselKind::H1, nu);
+                let h2 = get(BesselKind::H2, nu);
+                let j = get(BesselKind::J, nu);
+                assert!(
+                    (h1 + h2 - 2.0 * j).norm() < tolerance * h1.norm(),
+                    "nu={} z={}",
+                    nu,
+                    z
+                );
+            }
+
+            // I and K are only representable, scaled, far beyond the turning point:
+            // I_ν K_{ν+1} + I_{ν+1} K_ν = 1/z
+            let z = Complex64::new(nu * nu / 50.0, 0.0);
+            let get = |kind, order| value(kind, z, order, 2).unwrap();
+            let ik = get(BesselKind::I, nu) * get(BesselKind::K, nu + 1.0)
+                + get(BesselKind::I, nu + 1.0) * get(BesselKind::K, nu);
+            assert!((ik * z - 1.0).norm() < tolerance, "nu={}", nu);
+        }
+    }
+
+    #[cfg(feature = "amos")]
+    #[test]
+    fn test_matches_amos_with_precision_loss() {
+        // Amos still returns values up to ν ≈ 10⁹, flagged with IERR 3
+        let nu = 5e4;
+        let kinds = [BesselKind::J, BesselKind::Y, BesselKind::H1, BesselKind::H2];
+        let mut cases: Vec<_> = turning_points(nu)
+            .into_iter()
+            .flat_map(|z| kinds.map(|kind| (kind, z)))
+            .collect();
+        let far = Complex64::new(nu * nu / 50.0, nu);
+        cases.extend([(BesselKind::I, far), (BesselKind::K, far)]);
+        for (kind, z) in cases {
+            for kode in 1..=2 {
+                if kode == 1 && (kind == BesselKind::I || kind == BesselKind::K) {
+                    continue;
+                }
+                let fast = value(kind, z, nu, kode).unwrap();
+                let (mut cr, mut ci) = ([0.0], [0.0]);
+                let (ierr, _) = ActiveBackend::bessel(kind, z, nu, kode, &mut cr, &mut ci);
+                assert_eq!(ierr, 3);
+                let expected = Complex64::new(cr[0], ci[0]);
+                let error = (fast - expected).norm() / expected.norm();
+                assert!(error < 1e-9, "{:?} z={} kode={} {:e}", kind, z, kode, error);
+            }
+        }
+    }
+}
@@ -0,0 +1,111 @@
+//! Real-argument functions of dimensionless `uom` quantities
+//!
+//! Each function mirrors [`real`](crate::real) but takes and returns
+//! [`Ratio`], so unit-checked code forms the argument (kr, ωa/c, ...) as a
+//! quantity and never strips units at the call:
+//!
+//! ```rust
+//! use uom::si::f64::{Length, Ratio};
+//! use uom::si::length::{meter, millimeter};
+//! use uom::si::ratio::ratio;
+//! use zbessel_rs::units;
+//!
+//! fn main() -> Result<(), zbessel_rs::BesselError> {
+//!     // k = 2π / λ
+//!     let wavelength = Length::new::<millimeter>(2.0 * std::f64::consts::PI * 500.0);
+//!     let r = Length::new::<meter>(1.5);
+//!     let kr: Ratio = 2.0 * std::f64::consts::PI * r / wavelength;
+//!     let j1 = units::J(Ratio::new::<ratio>(1.0), kr)?;
+//!     assert!((j1.get::<ratio>() - zbessel_rs::real::J(1.0, 3.0)?).abs() < 1e-15);
+//!     Ok(())
+//! }
+//! ```
+
+#![allow(non_snake_case)]
+
+use crate::{real, BesselError};
+use uom::si::f64::Ratio;
+use uom::si::ratio::ratio;
+
+/// Apply a real-argument function of order and argument to quantities
+fn with_order(
+    f: fn(f64, f64) -> Result<f64, BesselError>,
+    nu: Ratio,
+    x: Ratio,
+) -> Result<Ratio, BesselError> {
+    Ok(Ratio::new::<ratio>(f(nu.get::<ratio>(), x.get::<ratio>())?))
+}
+
+/// Apply a real-argument Airy function to a quantity
+fn airy(f: fn(f64) -> Result<f64, BesselError>, x: Ratio) -> Result<Ratio, BesselError> {
+    Ok(Ratio::new::<ratio>(f(x.get::<ratio>())?))
+}
+
+/// Calculate J_ν(x) for dimensionless ν and x; see [`real::J`]
+pub fn J(nu: Ratio, x: Ratio) -> Result<Ratio, BesselError> {
+    with_order(real::J, nu, x)
+}
+
+/// Calculate Y_ν(x) for dimensionless ν and x; see [`real::Y`]
+pub fn Y(nu: Ratio, x: Ratio) -> Result<Ratio, BesselError> {
+    with_order(real::Y, nu, x)
+}
+
+/// Calculate I_ν(x) for dimensionless ν and x; see [`real::I`]
+pub fn I(nu: Ratio, x: Ratio) -> Result<Ratio, BesselError> {
+    with_order(real::I, nu, x)
+}
+
+/// Calculate K_ν(x) for dimensionless ν and x; see [`real::K`]
+pub fn K(nu: Ratio, x: Ratio) -> Result<Ratio, BesselError> {
+    with_order(real::K, nu, x)
+}
+
+/// Calculate exp(-abs(x)) I_ν(x) for dimensionless ν and x; see [`real::I_scaled`]
+pub fn I_scaled(nu: Ratio, x: Ratio) -> Result<Ratio, BesselError> {
+    with_order(real::I_scaled, nu, x)
+}
+
+/// Calculate exp(x) K_ν(x) for dimensionless ν and x; see [`real::K_scaled`]
+pub fn K_scaled(nu: Ratio, x: Ratio) -> Result<Ratio, BesselError> {
+    with_order(real::K_scaled, nu, x)
+}
+
+/// Calculate Ai(x) for dimensionless x; see [`real::Ai`]
+pub fn Ai(x: Ratio) -> Result<Ratio, BesselError> {
+    airy(real::Ai, x)
+}
+
+/// Calculate Bi(x) for dimensionless x; see [`real::Bi`]
+pub fn Bi(x: Ratio) -> Result<Ratio, BesselError> {
+    airy(real::Bi, x)
+}
+
+/// Calculate scaled Ai(x) for dimensionless x; see [`real::Ai_scaled`]
+pub fn Ai_scaled(x: Ratio) -> Result<Ratio, BesselError> {
+    airy(real::Ai_scaled, x)
+}
+
+/// Calculate scaled Bi(x) for dimensionless x; see [`real::Bi_scaled`]
+pub fn Bi_scaled(x: Ratio) -> Result<Ratio, BesselError> {
+    airy(real::Bi_scaled, x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uom::si::f64::Length;
+    use uom::si::length::{centimeter, meter};
+    use uom::si::ratio::percent;
+
+    #[test]
+    fn test_quantities() {
+        // x = a / b is dimensionless whatever the length units
+        let x: Ratio = Length::new::<meter>(2.0) / Length::new::<centimeter>(100.0);
+        let order = Ratio::new::<percent>(50.0);
+        let k = K(order, x).unwrap().get::<ratio>();
+        assert_eq!(k, real::K(0.5, 2.0).unwrap());
+        assert_eq!(Ai(x).unwrap().get::<ratio>(), real::Ai(2.0).unwrap());
+        assert!(Y(order, -x).is_err());
+    }
+}
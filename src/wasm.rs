@@ -0,0 +1,173 @@
+//! wasm-bindgen exports for JavaScript
+//!
+//! The crate builds for `wasm32-unknown-unknown` with the pure-Rust backend.
+//! A `cdylib` crate that depends on it with
+//!
+//! ```toml
+//! zbessel-rs = { version = "0.1", default-features = false, features = ["backend-rust", "wasm"] }
+//! ```
+//!
+//! carries these exports into its `.wasm` file, ready for `wasm-bindgen`.
+//!
+//! The functions below are exported under camel-case names (`besselJ`,
+//! `airyAi`, ...). Complex numbers cross the boundary as `Float64Array`s:
+//! a single value is `[re, im]` and a batch is interleaved
+//! `[re0, im0, re1, im1, ...]`. Errors are thrown as JavaScript `Error`s
+//! carrying the [`BesselError`] message.
+//!
+//! ```js
+//! import init, { besselJ, besselKBatch } from "./pkg/zbessel_rs.js";
+//!
+//! await init();
+//! const [re, im] = besselJ(0.5, 2.0, 1.0, false);
+//! const k0 = besselKBatch(0.0, new Float64Array([1, 0, 2, 0, 3, 0]), true);
+//! ```
+
+use crate::{airy_ai, airy_bi, bessel, bessel_slice, BesselError, BesselKind, Scaling};
+use num_complex::Complex64;
+use wasm_bindgen::prelude::*;
+
+fn scaling(scaled: bool) -> Scaling {
+    if scaled {
+        Scaling::Scaled
+    } else {
+        Scaling::Unscaled
+    }
+}
+
+fn to_js(error: BesselError) -> JsError {
+    JsError::new(&error.to_string())
+}
+
+/// A Bessel function at one point as `[re, im]`
+pub fn point(
+    kind: BesselKind,
+    nu: f64,
+    re: f64,
+    im: f64,
+    scaled: bool,
+) -> Result<Vec<f64>, BesselError> {
+    let value = bessel(kind, nu, Complex64::new(re, im), scaling(scaled))?;
+    Ok(vec![value.re, value.im])
+}
+
+/// A Bessel function at interleaved points `[re0, im0, re1, im1, ...]`
+pub fn batch(kind: BesselKind, nu: f64, zs: &[f64], scaled: bool) -> Result<Vec<f64>, BesselError> {
+    if !zs.len().is_multiple_of(2) {
+        return Err(BesselError::InvalidParameter(format!(
+            "interleaved arguments need an even length, got {}",
+            zs.len()
+        )));
+    }
+    let zs: Vec<Complex64> = zs
+        .chunks_exact(2)
+        .map(|z| Complex64::new(z[0], z[1]))
+        .collect();
+    let values = bessel_slice(kind, &zs, nu, scaling(scaled))?;
+    Ok(values.iter().flat_map(|v| [v.re, v.im]).collect())
+}
+
+/// Ai, or Bi if `bi`, or their derivative if `derivative`, as `[re, im]`
+pub fn airy(
+    bi: bool,
+    re: f64,
+    im: f64,
+    derivative: bool,
+    scaled: bool,
+) -> Result<Vec<f64>, BesselError> {
+    let (z, id, kode) = (
+        Complex64::new(re, im),
+        derivative as i32,
+        scaling(scaled).kode(),
+    );
+    let value = if bi {
+        airy_bi(z, id, kode)?
+    } else {
+        airy_ai(z, id, kode)?.value
+    };
+    Ok(vec![value.re, value.im])
+}
+
+/// J_ν(re + i·im) as `[re, im]`
+#[wasm_bindgen(js_name = besselJ)]
+pub fn bessel_j(nu: f64, re: f64, im: f64, scaled: bool) -> Result<Vec<f64>, JsError> {
+    point(BesselKind::J, nu, re, im, scaled).map_err(to_js)
+}
+
+/// Y_ν(re + i·im) as `[re, im]`
+#[wasm_bindgen(js_name = besselY)]
+pub fn bessel_y(nu: f64, re: f64, im: f64, scaled: bool) -> Result<Vec<f64>, JsError> {
+    point(BesselKind::Y, nu, re, im, scaled).map_err(to_js)
+}
+
+/// I_ν(re + i·im) as `[re, im]`
+#[wasm_bindgen(js_name = besselI)]
+pub fn bessel_i(nu: f64, re: f64, im: f64, scaled: bool) -> Result<Vec<f64>, JsError> {
+    point(BesselKind::I, nu, re, im, scaled).map_err(to_js)
+}
+
+/// K_ν(re + i·im) as `[re, im]`
+#[wasm_bindgen(js_name = besselK)]
+pub fn bessel_k(nu: f64, re: f64, im: f64, scaled: bool) -> Result<Vec<f64>, JsError> {
+    point(BesselKind::K, nu, re, im, scaled).map_err(to_js)
+}
+
+/// J_ν at interleaved points
+#[wasm_bindgen(js_name = besselJBatch)]
+pub fn bessel_j_batch(nu: f64, zs: &[f64], scaled: bool) -> Result<Vec<f64>, JsError> {
+    batch(BesselKind::J, nu, zs, scaled).map_err(to_js)
+}
+
+/// Y_ν at interleaved points
+#[wasm_bindgen(js_name = besselYBatch)]
+pub fn bessel_y_batch(nu: f64, zs: &[f64], scaled: bool) -> Result<Vec<f64>, JsError> {
+    batch(BesselKind::Y, nu, zs, scaled).map_err(to_js)
+}
+
+/// I_ν at interleaved points
+#[wasm_bindgen(js_name = besselIBatch)]
+pub fn bessel_i_batch(nu: f64, zs: &[f64], scaled: bool) -> Result<Vec<f64>, JsError> {
+    batch(BesselKind::I, nu, zs, scaled).map_err(to_js)
+}
+
+/// K_ν at interleaved points
+#[wasm_bindgen(js_name = besselKBatch)]
+pub fn bessel_k_batch(nu: f64, zs: &[f64], scaled: bool) -> Result<Vec<f64>, JsError> {
+    batch(BesselKind::K, nu, zs, scaled).map_err(to_js)
+}
+
+/// Ai(z), or Ai'(z) if `derivative`, as `[re, im]`
+#[wasm_bindgen(js_name = airyAi)]
+pub fn airy_ai_js(re: f64, im: f64, derivative: bool, scaled: bool) -> Result<Vec<f64>, JsError> {
+    airy(false, re, im, derivative, scaled).map_err(to_js)
+}
+
+/// Bi(z), or Bi'(z) if `derivative`, as `[re, im]`
+#[wasm_bindgen(js_name = airyBi)]
+pub fn airy_bi_js(re: f64, im: f64, derivative: bool, scaled: bool) -> Result<Vec<f64>, JsError> {
+    airy(true, re, im, derivative, scaled).map_err(to_js)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interleaved_layout() {
+        let zs = [1.0, 0.5, -2.0, 0.0, 3.0, -1.0];
+        let values = batch(BesselKind::Y, 1.5, &zs, false).unwrap();
+        assert_eq!(values.len(), 6);
+        let single = point(BesselKind::Y, 1.5, -2.0, 0.0, false).unwrap();
+        assert!((values[2] - single[0]).abs() <= 1e-14 * single[0].abs());
+        assert!((values[3] - single[1]).abs() <= 1e-14 * single[1].abs());
+        assert!(batch(BesselKind::J, 0.0, &[1.0, 2.0, 3.0], false).is_err());
+    }
+
+    #[test]
+    fn test_airy() {
+        let ai = airy(false, 1.0, 0.0, false, false).unwrap();
+        assert!((ai[0] - 0.135_292_416_312_881_4).abs() < 1e-15);
+        let bi_prime = airy(true, 1.0, 0.0, true, false).unwrap();
+        assert!((bi_prime[0] - 0.932_435_933_392_775_1).abs() < 1e-14);
+    }
+}
@@ -0,0 +1,207 @@
+//! Reusable buffers for repeated sequence evaluations
+//!
+//! Every call of [`bessel_j`](crate::bessel_j) and friends allocates its
+//! output, and Y allocates work arrays on top. A [`Workspace`] owns these
+//! buffers instead; the `*_with_workspace` functions grow them as needed and
+//! return the values as a slice borrowed from the workspace, so a solver that
+//! calls them in a loop stops allocating after the first iteration.
+//!
+//! Negative orders and arguments below the branch cut (Im z = -0.0) go through
+//! the reflection formulas, which still allocate.
+//!
+//! ```rust
+//! use num_complex::Complex64;
+//! use zbessel_rs::{bessel_y_with_workspace, Workspace};
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let mut ws = Workspace::with_capacity(50);
+//!     let mut total = Complex64::new(0.0, 0.0);
+//!     for k in 1..=100 {
+//!         let z = Complex64::new(0.1 * k as f64, 0.5);
+//!         let values = bessel_y_with_workspace(z, 0.0, 1, 50, &mut ws)?;
+//!         total += values[49];
+//!     }
+//!     println!("{} ({} underflows in the last call)", total, ws.underflow_count());
+//!     Ok(())
+//! }
+//! ```
+
+use crate::backend::{ActiveBackend, Backend};
+use crate::into_slice::{as_f64_slice, error_for, interleave_in_place};
+use crate::{
+    check_amos_len, check_backend_range, check_max_n, clear_real_axis, validate_inputs,
+    BesselError, BesselKind, DEFAULT_MAX_N,
+};
+use crate::{direct, reflection};
+use num_complex::Complex64;
+
+/// Output and work buffers reused across evaluations
+#[derive(Debug, Clone, Default)]
+pub struct Workspace {
+    values: Vec<Complex64>,
+    work: Vec<f64>,
+    underflow_count: i32,
+}
+
+impl Workspace {
+    /// Create an empty workspace; buffers grow on first use
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a workspace sized for sequences of up to `n` values
+    pub fn with_capacity(n: usize) -> Self {
+        Workspace {
+            values: Vec::with_capacity(n),
+            work: Vec::with_capacity(2 * n),
+            underflow_count: 0,
+        }
+    }
+
+    /// Number of underflowed values in the last successful evaluation
+    pub fn underflow_count(&self) -> i32 {
+        self.underflow_count
+    }
+
+    fn eval(
+        &mut self,
+        kind: BesselKind,
+        z: Complex64,
+        nu: f64,
+        kode: i32,
+        n: usize,
+    ) -> Result<&[Complex64], BesselError> {
+        if n == 0 {
+            return Err(BesselError::InvalidParameter(
+                "n must be greater than 0".to_string(),
+            ));
+        }
+        check_max_n(n, DEFAULT_MAX_N)?;
+        check_amos_len(n)?;
+        validate_inputs(z, nu, kode)?;
+
+        if nu < 0.0 || reflection::below_cut(z) {
+            let result = kind.eval_impl(z, nu, kode, n, false)?;
+            self.values.clear();
+            self.values.extend_from_slice(&result.values);
+            self.underflow_count = result.underflow_count;
+            return Ok(&self.values);
+        }
+
+        self.values.resize(n, Complex64::new(0.0, 0.0));
+        if direct::fill(kind, z, nu, kode, &mut self.values).is_some() {
+            self.underflow_count = 0;
+            return Ok(&self.values);
+        }
+        check_backend_range(z, nu, n)?;
+
+        let data = as_f64_slice(&mut self.values);
+        let (cyr, cyi) = data.split_at_mut(n);
+        let (ierr, nz) = if kind == BesselKind::Y {
+            self.work.resize(2 * n, 0.0);
+            ActiveBackend::bessel_with_work(kind, z, nu, kode, cyr, cyi, &mut self.work)
+        } else {
+            ActiveBackend::bessel(kind, z, nu, kode, cyr, cyi)
+        };
+        if ierr != 0 {
+            return Err(error_for(kind, ierr, z, nu, kode, n));
+        }
+        clear_real_axis(kind, z, cyi);
+        interleave_in_place(data);
+        self.underflow_count = nz;
+        Ok(&self.values)
+    }
+}
+
+/// [`bessel_j`](crate::bessel_j) using the buffers of `ws`
+pub fn bessel_j_with_workspace(
+    z: Complex64,
+    nu: f64,
+    kode: i32,
+    n: usize,
+    ws: &mut Workspace,
+) -> Result<&[Complex64], BesselError> {
+    ws.eval(BesselKind::J, z, nu, kode, n)
+}
+
+/// [`bessel_y`](crate::bessel_y) using the buffers of `ws`
+pub fn bessel_y_with_workspace(
+    z: Complex64,
+    nu: f64,
+    kode: i32,
+    n: usize,
+    ws: &mut Workspace,
+) -> Result<&[Complex64], BesselError> {
+    ws.eval(BesselKind::Y, z, nu, kode, n)
+}
+
+/// [`bessel_i`](crate::bessel_i) using the buffers of `ws`
+pub fn bessel_i_with_workspace(
+    z: Complex64,
+    nu: f64,
+    kode: i32,
+    n: usize,
+    ws: &mut Workspace,
+) -> Result<&[Complex64], BesselError> {
+    ws.eval(BesselKind::I, z, nu, kode, n)
+}
+
+/// [`bessel_k`](crate::bessel_k) using the buffers of `ws`
+pub fn bessel_k_with_workspace(
+    z: Complex64,
+    nu: f64,
+    kode: i32,
+    n: usize,
+    ws: &mut Workspace,
+) -> Result<&[Complex64], BesselError> {
+    ws.eval(BesselKind::K, z, nu, kode, n)
+}
+
+/// [`bessel_h`](crate::bessel_h) using the buffers of `ws`
+pub fn bessel_h_with_workspace(
+    z: Complex64,
+    nu: f64,
+    kode: i32,
+    m: i32,
+    n: usize,
+    ws: &mut Workspace,
+) -> Result<&[Complex64], BesselError> {
+    let kind = match m {
+        1 => BesselKind::H1,
+        2 => BesselKind::H2,
+        _ => {
+            return Err(BesselError::InvalidParameter(format!(
+                "m must be 1 or 2, got {}",
+                m
+            )))
+        }
+    };
+    ws.eval(kind, z, nu, kode, n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bessel_h, bessel_y};
+
+    #[test]
+    fn test_workspace_matches_allocating_calls() {
+        let mut ws = Workspace::new();
+        for &(n, nu) in &[(3, 0.5), (40, 1.0), (2, -1.5), (64, 0.0)] {
+            let z = Complex64::new(1.5, -0.5);
+            let expected = bessel_y(z, nu, 2, n).unwrap();
+            let values = bessel_y_with_workspace(z, nu, 2, n, &mut ws).unwrap();
+            // Long integer-order sequences take the recurrence path in bessel_y
+            for (value, expected) in values.iter().zip(&expected.values) {
+                assert!((value - expected).norm() <= 1e-12 * expected.norm());
+            }
+            assert_eq!(ws.underflow_count(), expected.underflow_count);
+
+            let expected = bessel_h(z, nu, 1, 2, n).unwrap();
+            let values = bessel_h_with_workspace(z, nu, 1, 2, n, &mut ws).unwrap();
+            assert_eq!(values, &expected.values[..]);
+        }
+        assert!(bessel_h_with_workspace(Complex64::new(1.0, 0.0), 0.0, 1, 3, 1, &mut ws).is_err());
+        assert!(bessel_j_with_workspace(Complex64::new(1.0, 0.0), 0.0, 1, 0, &mut ws).is_err());
+    }
+}
@@ -0,0 +1,131 @@
+#!/usr/bin/env python3
+"""Generate golden.csv, the reference values of tests/golden.rs
+
+Every value is computed with mpmath at 60 significant digits, or 500 for the
+large orders, and rounded to the nearest double. (At 60 digits mpmath's
+Bessel functions of order around 400 are wrong from the fourth digit on;
+its precision control misses the cancellation in
+(I_-nu - I_nu) / sin(nu pi).) The points are drawn from a fixed seed, so
+running the script again reproduces the file exactly:
+
+    python3 tests/data/generate_golden.py > tests/data/golden.csv
+
+Columns: function, nu, Re z, Im z, scaled (0 or 1), region, Re value, Im value.
+The region selects the tolerance tier in tests/golden.rs. Points whose value
+overflows or underflows a double are left out.
+"""
+
+import random
+
+from mpmath import mp, mpc, mpf, exp, fabs, besselj, bessely, besseli, besselk
+from mpmath import hankel1, hankel2, airyai, airybi, sqrt
+
+mp.dps = 60
+
+I = mpc(0, 1)
+
+
+def zeta(z):
+    return mpf(2) / 3 * z * sqrt(z)
+
+
+def bessel_value(function, nu, z, scaled):
+    nu = mpf(nu)
+    if function == "J":
+        value, factor = besselj(nu, z), exp(-fabs(z.imag))
+    elif function == "Y":
+        value, factor = bessely(nu, z), exp(-fabs(z.imag))
+    elif function == "I":
+        value, factor = besseli(nu, z), exp(-fabs(z.real))
+    elif function == "K":
+        value, factor = besselk(nu, z), exp(z)
+    elif function == "H1":
+        value, factor = hankel1(nu, z), exp(-I * z)
+    else:
+        value, factor = hankel2(nu, z), exp(I * z)
+    return value * factor if scaled else value
+
+
+def airy_value(function, z, scaled):
+    derivative = 1 if function.endswith("'") else 0
+    if function.startswith("Ai"):
+        value, factor = airyai(z, derivative), exp(zeta(z))
+    else:
+        value, factor = airybi(z, derivative), exp(-fabs(zeta(z).real))
+    return value * factor if scaled else value
+
+
+def polar(rng, low, high):
+    """Random point with log-uniform modulus in [low, high] and any argument"""
+    modulus = 10 ** rng.uniform(low, high)
+    angle = rng.uniform(-3.14159, 3.14159)
+    return complex(modulus * mp.cos(angle), modulus * mp.sin(angle))
+
+
+def order(rng, low, high):
+    """Random order, a third of them integers and a sixth half-integers"""
+    nu = rng.uniform(low, high)
+    kind = rng.randrange(6)
+    if kind < 2:
+        return float(round(nu))
+    if kind == 2:
+        return round(nu) + 0.5
+    return nu
+
+
+BESSEL = ["J", "Y", "I", "K", "H1", "H2"]
+AIRY = ["Ai", "Ai'", "Bi", "Bi'"]
+
+
+def points(rng):
+    """(function, nu, z, region) for every row"""
+    for function in BESSEL:
+        for _ in range(24):
+            yield function, order(rng, -6, 12), polar(rng, -1, 1.3), "core"
+        for _ in range(6):
+            yield function, order(rng, -3, 3), polar(rng, -6, -1), "small"
+        for _ in range(8):
+            # Both sides of the cut along the negative real axis
+            x = rng.uniform(0.2, 15)
+            side = rng.choice([-1, 1]) * 10 ** rng.uniform(-10, -3)
+            yield function, rng.uniform(-4, 4), complex(-x, side), "branch"
+        for _ in range(6):
+            yield function, order(rng, -2, 8), polar(rng, 1.7, 3), "large_z"
+        for _ in range(6):
+            nu = rng.uniform(50, 500)
+            # Around the turning point |z| = nu, where the Debye expansions meet
+            z = nu * rng.uniform(0.5, 1.5)
+            angle = rng.uniform(-1.5, 1.5)
+            yield function, nu, complex(z * mp.cos(angle), z * mp.sin(angle)), "large_nu"
+    for function in AIRY:
+        for _ in range(24):
+            yield function, 0.0, polar(rng, -1, 1), "core"
+        for _ in range(6):
+            yield function, 0.0, polar(rng, -6, -1), "small"
+        for _ in range(8):
+            yield function, 0.0, polar(rng, 1, 2), "large_z"
+
+
+def main():
+    rng = random.Random(639)
+    print("function,nu,z_re,z_im,scaled,region,value_re,value_im")
+    for function, nu, z, region in points(rng):
+        for scaled in (0, 1):
+            with mp.workdps(500 if region == "large_nu" else 60):
+                w = mpc(z.real, z.imag)
+                if function in BESSEL:
+                    value = bessel_value(function, nu, w, scaled)
+                else:
+                    value = airy_value(function, w, scaled)
+            value = complex(value)
+            magnitude = abs(value)
+            if not 1e-290 < magnitude < 1e290:
+                continue
+            print(
+                f"{function},{nu!r},{z.real!r},{z.imag!r},{scaled},{region},"
+                f"{value.real!r},{value.imag!r}"
+            )
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,145 @@
+#!/usr/bin/env python3
+"""Generate hardening.csv, the dense reference values of tests/hardening.rs
+
+Three batteries, on fixed grids so that running the script again reproduces
+the file exactly:
+
+* transition: |z| = nu + c nu^(1/3) for c in [-4, 4], the width of the
+  turning-point region of the uniform asymptotic expansions, and
+  |z| = nu (1 +- 0.3) a little further out, at angles from the positive real
+  axis to just short of the cut, above and below it
+* near_cut: z = -x + i s with s = +-1e-1 ... +-1e-15, just above and below the
+  negative real axis, across orders of both signs, integer and not
+* corner: both at once, x = |nu| + c |nu|^(1/3) for c in [-3, 3] with
+  s = +-1e-2, +-1e-8 and +-1e-14
+
+    python3 tests/data/generate_hardening.py > tests/data/hardening.csv
+
+Each value is computed with mpmath at two working precisions (60 and 90
+digits, or 90 and 130 from order 40 on, where mpmath's cancellation control
+falls short) and kept only when both round to the same double. Next to it is
+the condition number |z f'(z) / f(z)| to three digits, which bounds the error
+any method makes from the rounding of z alone.
+
+Columns: function, nu, Re z, Im z, scaled (0 or 1), region, Re value,
+Im value, condition. Points whose value overflows or underflows a double are
+left out.
+"""
+
+from mpmath import mp, mpc, mpf, arg, exp, fabs, cbrt, cos, sin, inf, pi
+from mpmath import besselj, bessely, besseli, besselk
+
+I = mpc(0, 1)
+
+BESSEL = ["J", "Y", "I", "K", "H1", "H2"]
+
+
+def bessel_values(nu, z):
+    """J, Y, I, K, H1, H2 of order nu at z"""
+    j, y = besselj(nu, z), bessely(nu, z)
+    # The Hankel functions from K (DLMF 10.27.8) where J +- iY would cancel
+    phase = exp(I * pi * nu / 2)
+    if -pi / 2 < arg(z) <= pi:
+        h1 = 2 / (pi * I) / phase * besselk(nu, -I * z)
+    else:
+        h1 = j + I * y
+    if -pi < arg(z) <= pi / 2:
+        h2 = -2 / (pi * I) * phase * besselk(nu, I * z)
+    else:
+        h2 = j - I * y
+    return {"J": j, "Y": y, "I": besseli(nu, z), "K": besselk(nu, z), "H1": h1, "H2": h2}
+
+
+def scale_factor(function, z):
+    if function in ("J", "Y"):
+        return exp(-fabs(z.imag))
+    if function == "I":
+        return exp(-fabs(z.real))
+    if function == "K":
+        return exp(z)
+    if function == "H1":
+        return exp(-I * z)
+    return exp(I * z)
+
+
+def rows(nu, z):
+    """(function, scaled, value, condition) of every function at one point"""
+    low, high = (90, 130) if abs(nu) >= 40 else (60, 90)
+    with mp.workdps(low):
+        w = mpc(z.real, z.imag)
+        values = bessel_values(mpf(nu), w)
+        lower = bessel_values(mpf(nu) - 1, w)
+        upper = bessel_values(mpf(nu) + 1, w)
+        conditions = {}
+        for function, value in values.items():
+            # The derivative from the neighbouring orders (DLMF 10.6.1, 10.29.1)
+            if function == "I":
+                derivative = (lower["I"] + upper["I"]) / 2
+            elif function == "K":
+                derivative = -(lower["K"] + upper["K"]) / 2
+            else:
+                derivative = (lower[function] - upper[function]) / 2
+            conditions[function] = float(fabs(w * derivative / value)) if value != 0 else inf
+    with mp.workdps(high):
+        w = mpc(z.real, z.imag)
+        checks = bessel_values(mpf(nu), w)
+        factors = {function: scale_factor(function, w) for function in BESSEL}
+    for function in BESSEL:
+        for scaled in (0, 1):
+            factor = factors[function] if scaled else 1
+            value = complex(values[function] * factor)
+            # Kept only when both precisions round to the same double
+            if value != complex(checks[function] * factor):
+                continue
+            if not 1e-290 < abs(value) < 1e290:
+                continue
+            yield function, scaled, value, conditions[function]
+
+
+def transition():
+    for nu in [2.5, 8.0, 17.3, 40.5, 96.5, 250.0]:
+        radii = [nu + c * cbrt(nu) for c in (-4, -2, -1, 0, 1, 2, 4)]
+        radii += [nu * (1 + t) for t in (-0.3, 0.3)]
+        # The smallest order has no room for the innermost radii
+        for r in filter(lambda r: r > 0, radii):
+            for angle in (0.0, 0.2, 0.7845, 1.5708, 2.4, 3.1):
+                z = complex(float(r * cos(angle)), float(r * sin(angle)))
+                yield nu, z
+                # Below the real axis through the conjugate point
+                if angle != 0.0:
+                    yield nu, z.conjugate()
+
+
+def near_cut():
+    for nu in [0.0, 0.3, 1.0, 2.5, 7.2, 20.0, -1.7, -3.0, -6.5]:
+        for x in (0.05, 0.6, 1.9, 4.3, 11.0, 35.0, 90.0):
+            for exponent in (1, 3, 6, 10, 15):
+                for side in (1, -1):
+                    yield nu, complex(-x, side * 10.0 ** -exponent)
+
+
+def corner():
+    for nu in [5.5, 30.2, 75.0, 150.3, -5.5, -30.2, -75.0]:
+        r = abs(nu)
+        for c in (-3, -1, 0, 1, 3):
+            x = float(r + c * cbrt(r))
+            for exponent in (2, 8, 14):
+                for side in (1, -1):
+                    yield nu, complex(-x, side * 10.0 ** -exponent)
+
+
+def main():
+    print("function,nu,z_re,z_im,scaled,region,value_re,value_im,condition")
+    regions = (("transition", transition()), ("near_cut", near_cut()), ("corner", corner()))
+    for region, points in regions:
+        for nu, z in points:
+            for function, scaled, value, condition in rows(nu, z):
+                print(
+                    f"{function},{nu!r},{z.real!r},{z.imag!r},{scaled},{region},"
+                    f"{value.real!r},{value.imag!r},{condition:.3g}",
+                    flush=True,
+                )
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,98 @@
+//! Bitwise reproducibility of the results
+//!
+//! Within one build every evaluation must give the same bits whatever the
+//! thread or the order of the calls. Across machines, build with the
+//! `deterministic` feature and compare the fingerprint this test prints:
+//!
+//! ```text
+//! cargo test --features deterministic --test determinism -- --nocapture
+//! ZBESSEL_FINGERPRINT=<value from a reference machine> cargo test --features deterministic --test determinism
+//! ```
+//!
+//! The feature fixes the compiler's floating-point code generation for the
+//! Amos sources; the Rust code never contracts or reassociates. Both call
+//! the platform math library for exp, log, sin and cos, so machines must
+//! also share it (the same libc or CRT version) for the fingerprints to match.
+
+// The crate is marked deprecated in favour of its successor
+#![allow(deprecated)]
+
+use num_complex::Complex64;
+use zbessel_rs::{airy_ai, airy_bi, bessel, BesselKind, Scaling};
+
+/// Every function at orders and arguments covering the main algorithm paths
+fn sample() -> Vec<Complex64> {
+    let orders = [0.0, 0.5, 1.0, 2.7, 13.0, 60.25, -1.3];
+    let arguments = [
+        Complex64::new(1e-3, 2e-3),
+        Complex64::new(0.7, -0.4),
+        Complex64::new(-3.2, 1.1),
+        Complex64::new(12.0, 0.0),
+        Complex64::new(-40.0, -25.0),
+        Complex64::new(150.0, 3.0),
+        Complex64::new(0.0, 800.0),
+    ];
+    let mut values = Vec::new();
+    for kind in BesselKind::ALL {
+        for scaling in [Scaling::Unscaled, Scaling::Scaled] {
+            for nu in orders {
+                for z in arguments {
+                    // Overflows are part of the fingerprint as NaN
+                    let value = bessel(kind, nu, z, scaling);
+                    values.push(value.unwrap_or(Complex64::new(f64::NAN, f64::NAN)));
+                }
+            }
+        }
+    }
+    for z in arguments {
+        for (id, kode) in [(0, 1), (1, 1), (0, 2), (1, 2)] {
+            values.push(airy_ai(z, id, kode).map_or(Complex64::new(f64::NAN, 0.0), |r| r.value));
+            values.push(airy_bi(z, id, kode).unwrap_or(Complex64::new(f64::NAN, 0.0)));
+        }
+    }
+    values
+}
+
+/// FNV-1a over the bits of each real and imaginary part
+fn fingerprint(values: &[Complex64]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for value in values {
+        for part in [value.re, value.im] {
+            // Every NaN hashes alike; their payloads are not specified
+            let bits = if part.is_nan() {
+                f64::NAN.to_bits()
+            } else {
+                part.to_bits()
+            };
+            for byte in bits.to_le_bytes() {
+                hash ^= u64::from(byte);
+                hash = hash.wrapping_mul(0x0100_0000_01b3);
+            }
+        }
+    }
+    hash
+}
+
+#[test]
+fn test_same_bits_on_every_thread() {
+    let reference = fingerprint(&sample());
+    let threads: Vec<_> = (0..4)
+        .map(|_| std::thread::spawn(|| fingerprint(&sample())))
+        .collect();
+    for thread in threads {
+        assert_eq!(thread.join().unwrap(), reference);
+    }
+}
+
+#[test]
+fn test_fingerprint() {
+    let fingerprint = format!("{:016x}", fingerprint(&sample()));
+    println!("ZBESSEL_FINGERPRINT={}", fingerprint);
+    if let Ok(expected) = std::env::var("ZBESSEL_FINGERPRINT") {
+        assert_eq!(
+            fingerprint,
+            expected.trim(),
+            "results differ from the reference machine"
+        );
+    }
+}
@@ -0,0 +1,70 @@
+//! Pre-flight classification against the evaluations it predicts
+//!
+//! Over every kind, orders of both signs and arguments from next to the origin
+//! to past the validated range, at phases around the plane, a point classified
+//! [`Feasibility::Feasible`] evaluates, one that needs scaling evaluates
+//! scaled, if past the precision limit with IERR=3, and the others fail as
+//! classified, except that the crate's expansions may still evaluate points
+//! beyond the validated range.
+
+// The crate is marked deprecated in favour of its successor
+#![allow(deprecated)]
+
+use num_complex::Complex64;
+use std::f64::consts::PI;
+use zbessel_rs::{bessel, can_evaluate, machine_parameters, BesselKind, Feasibility, Scaling};
+
+const ORDERS: [f64; 12] = [
+    0.0, 0.3, 1.0, 2.5, 10.0, 120.0, 1000.0, 4e4, 1e6, -0.7, -3.0, -60.3,
+];
+
+const RADII: [f64; 14] = [
+    1e-300, 1e-20, 0.1, 5.0, 100.0, 600.0, 690.0, 700.0, 720.0, 1500.0, 4e4, 1e5, 1e6, 2e9,
+];
+
+fn beyond_range(nu: f64, z: Complex64) -> bool {
+    let limit = machine_parameters().range_limit;
+    z.norm() > limit || nu.abs() > limit
+}
+
+#[test]
+fn test_classification_predicts_outcome() {
+    // The Rust backend overflows only past f64::MAX, later than the Amos tests
+    let amos = machine_parameters().backend == "amos";
+    for kind in BesselKind::ALL {
+        for nu in ORDERS {
+            for r in RADII {
+                for k in 0..16 {
+                    let z = Complex64::from_polar(r, PI * (k as f64 / 8.0 - 1.0) + 0.01);
+                    for scaling in [Scaling::Unscaled, Scaling::Scaled] {
+                        let feasibility = can_evaluate(kind, nu, z, scaling);
+                        let result = bessel(kind, nu, z, scaling);
+                        let context =
+                            format!("{:?}_{}({}) {:?}: {:?}", kind, nu, z, scaling, result);
+                        match feasibility {
+                            Feasibility::Feasible => assert!(result.is_ok(), "{}", context),
+                            Feasibility::NeedsScaling => {
+                                let scaled = bessel(kind, nu, z, Scaling::Scaled);
+                                assert!(
+                                    scaled.map_or_else(|e| e.ierr() == Some(3), |_| true),
+                                    "{}",
+                                    context
+                                );
+                            }
+                            Feasibility::ReducedPrecision => {
+                                let error = result.unwrap_err();
+                                assert_eq!(error.ierr(), Some(3), "{}", context);
+                            }
+                            // The expansions may still reach points beyond the range
+                            Feasibility::Impossible => assert!(
+                                result.is_err() || !amos || beyond_range(nu, z),
+                                "{}",
+                                context
+                            ),
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
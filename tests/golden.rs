@@ -0,0 +1,109 @@
+//! Regression tests against reference values computed with mpmath
+//!
+//! `data/golden.csv` holds several hundred values of every Bessel and Airy
+//! function, unscaled and scaled, in all four quadrants, on both sides of
+//! the branch cut and at large orders and arguments. It is generated by
+//! `data/generate_golden.py`. Each row names a region, and the relative
+//! error allowed there is a tier of [`tolerance`].
+
+// The crate is marked deprecated in favour of its successor
+#![allow(deprecated)]
+
+use num_complex::Complex64;
+use zbessel_rs::{airy_ai, airy_bi, bessel, BesselError, BesselKind, Scaling};
+
+const GOLDEN: &str = include_str!("data/golden.csv");
+
+/// Largest relative error |computed - reference| / |reference| in a region
+fn tolerance(region: &str) -> f64 {
+    match region {
+        "core" | "small" => 1e-13,
+        // The angle of z near ±π enters through exp(±iνπ) and z^ν
+        "branch" => 2e-13,
+        // Argument reduction of the trigonometric factors loses about
+        // log10(|z|) digits
+        "large_z" => 1e-12,
+        // Uniform asymptotic expansions near the turning point |z| = ν
+        "large_nu" => 2e-12,
+        _ => panic!("unknown region '{}'", region),
+    }
+}
+
+struct Row<'a> {
+    function: &'a str,
+    nu: f64,
+    z: Complex64,
+    scaling: Scaling,
+    region: &'a str,
+    value: Complex64,
+}
+
+fn rows() -> impl Iterator<Item = Row<'static>> {
+    GOLDEN.lines().skip(1).map(|line| {
+        let fields: Vec<&str> = line.split(',').collect();
+        let number = |i: usize| -> f64 { fields[i].parse().unwrap() };
+        Row {
+            function: fields[0],
+            nu: number(1),
+            z: Complex64::new(number(2), number(3)),
+            scaling: if fields[4] == "1" {
+                Scaling::Scaled
+            } else {
+                Scaling::Unscaled
+            },
+            region: fields[5],
+            value: Complex64::new(number(6), number(7)),
+        }
+    })
+}
+
+fn compute(row: &Row) -> Result<Complex64, BesselError> {
+    let kode = row.scaling.kode();
+    let kind = match row.function {
+        "J" => BesselKind::J,
+        "Y" => BesselKind::Y,
+        "I" => BesselKind::I,
+        "K" => BesselKind::K,
+        "H1" => BesselKind::H1,
+        "H2" => BesselKind::H2,
+        "Ai" => return Ok(airy_ai(row.z, 0, kode)?.value),
+        "Ai'" => return Ok(airy_ai(row.z, 1, kode)?.value),
+        "Bi" => return airy_bi(row.z, 0, kode),
+        "Bi'" => return airy_bi(row.z, 1, kode),
+        other => panic!("unknown function '{}'", other),
+    };
+    bessel(kind, row.nu, row.z, row.scaling)
+}
+
+#[test]
+fn test_golden_values() {
+    let mut failures = Vec::new();
+    let mut count = 0;
+    for row in rows() {
+        count += 1;
+        let describe = format!(
+            "{}({}, {}) {:?} [{}]",
+            row.function, row.nu, row.z, row.scaling, row.region
+        );
+        match compute(&row) {
+            Ok(value) => {
+                let error = (value - row.value).norm() / row.value.norm();
+                if error.is_nan() || error > tolerance(row.region) {
+                    failures.push(format!(
+                        "{} = {}, expected {} (relative error {:.1e})",
+                        describe, value, row.value, error
+                    ));
+                }
+            }
+            Err(error) => failures.push(format!("{}: {}", describe, error)),
+        }
+    }
+    assert!(count > 800, "golden.csv has only {} rows", count);
+    assert!(
+        failures.is_empty(),
+        "{} of {} golden values failed:\n{}",
+        failures.len(),
+        count,
+        failures.join("\n")
+    );
+}
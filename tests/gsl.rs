@@ -0,0 +1,234 @@
+//! Differential tests of the real-argument functions against GSL
+//!
+//! Built only with the `gsl` feature, which needs GSL (`libgsl` and
+//! `libgslcblas`) on the linker path; add `-L` to `RUSTFLAGS` for a
+//! non-standard prefix. Each test draws random orders and arguments, compares
+//! `real::*` with the matching `gsl_sf_*_e` routine and reports the largest
+//! difference in ULPs:
+//!
+//! ```text
+//! cargo test --features gsl --test gsl -- --nocapture
+//! ZBESSEL_GSL_SEED=7 ZBESSEL_GSL_SAMPLES=100000 cargo test --features gsl --test gsl -- --nocapture
+//! ```
+//!
+//! Where a function oscillates (J and Y for x > ν, Ai and Bi for x < 0) the
+//! difference is measured in ULPs of the modulus sqrt(J² + Y²) or
+//! sqrt(Ai² + Bi²) instead of the value, so that the sweeps do not fail on
+//! the absolute rounding error near zeros. Points where either side reports
+//! an error or a non-finite value are skipped and counted.
+
+// The crate is marked deprecated in favour of its successor
+#![allow(deprecated)]
+
+use std::os::raw::{c_int, c_uint};
+use zbessel_rs::{real, BesselError};
+
+#[repr(C)]
+#[derive(Default)]
+struct GslResult {
+    val: f64,
+    err: f64,
+}
+
+/// `GSL_PREC_DOUBLE` for the `gsl_mode_t` argument of the Airy functions
+const GSL_PREC_DOUBLE: c_uint = 0;
+
+#[link(name = "gsl")]
+#[link(name = "gslcblas")]
+extern "C" {
+    fn gsl_set_error_handler_off() -> *const std::ffi::c_void;
+    fn gsl_sf_bessel_Jnu_e(nu: f64, x: f64, result: *mut GslResult) -> c_int;
+    fn gsl_sf_bessel_Ynu_e(nu: f64, x: f64, result: *mut GslResult) -> c_int;
+    fn gsl_sf_bessel_Inu_e(nu: f64, x: f64, result: *mut GslResult) -> c_int;
+    fn gsl_sf_bessel_Knu_e(nu: f64, x: f64, result: *mut GslResult) -> c_int;
+    fn gsl_sf_bessel_Inu_scaled_e(nu: f64, x: f64, result: *mut GslResult) -> c_int;
+    fn gsl_sf_bessel_Knu_scaled_e(nu: f64, x: f64, result: *mut GslResult) -> c_int;
+    fn gsl_sf_airy_Ai_e(x: f64, mode: c_uint, result: *mut GslResult) -> c_int;
+    fn gsl_sf_airy_Bi_e(x: f64, mode: c_uint, result: *mut GslResult) -> c_int;
+    fn gsl_sf_airy_Ai_scaled_e(x: f64, mode: c_uint, result: *mut GslResult) -> c_int;
+    fn gsl_sf_airy_Bi_scaled_e(x: f64, mode: c_uint, result: *mut GslResult) -> c_int;
+}
+
+/// Largest accepted difference in ULPs; far above the accuracy of either
+/// library, so failures point at wrong wrappers or miscompiled kernels
+const MAX_ULPS: f64 = 65536.0;
+
+/// Value of a GSL routine, or `None` on a GSL error or non-finite result
+fn gsl(routine: impl FnOnce(*mut GslResult) -> c_int) -> Option<f64> {
+    let mut result = GslResult::default();
+    // The default handler aborts the process on domain errors and overflow
+    unsafe { gsl_set_error_handler_off() };
+    let status = routine(&mut result);
+    (status == 0 && result.val.is_finite()).then_some(result.val)
+}
+
+fn gsl_nu(
+    routine: unsafe extern "C" fn(f64, f64, *mut GslResult) -> c_int,
+    nu: f64,
+    x: f64,
+) -> Option<f64> {
+    gsl(|result| unsafe { routine(nu, x, result) })
+}
+
+fn gsl_airy(
+    routine: unsafe extern "C" fn(f64, c_uint, *mut GslResult) -> c_int,
+    x: f64,
+) -> Option<f64> {
+    gsl(|result| unsafe { routine(x, GSL_PREC_DOUBLE, result) })
+}
+
+/// Distance between `a` and `b` in ULPs of `scale`
+fn ulps(a: f64, b: f64, scale: f64) -> f64 {
+    let scale = scale.abs().max(f64::MIN_POSITIVE);
+    let ulp = f64::from_bits(scale.to_bits() + 1) - scale;
+    (a - b).abs() / ulp
+}
+
+/// xorshift64*, so that a seed reproduces a sweep on every platform
+struct Rng(u64);
+
+impl Rng {
+    fn from_env() -> Self {
+        let seed = std::env::var("ZBESSEL_GSL_SEED")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(0x5EED_2B35_5E11);
+        Rng(seed | 1)
+    }
+
+    /// Uniform on [0, 1)
+    fn next(&mut self) -> f64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        (self.0.wrapping_mul(0x2545_F491_4F6C_DD1D) >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn uniform(&mut self, low: f64, high: f64) -> f64 {
+        low + (high - low) * self.next()
+    }
+
+    /// Log-uniform on [low, high]
+    fn log_uniform(&mut self, low: f64, high: f64) -> f64 {
+        self.uniform(low.ln(), high.ln()).exp()
+    }
+}
+
+fn samples() -> usize {
+    std::env::var("ZBESSEL_GSL_SAMPLES")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(2000)
+}
+
+/// Largest difference over a sweep, with the point where it occurred
+#[derive(Default)]
+struct Sweep {
+    worst: f64,
+    at: (f64, f64),
+    compared: usize,
+    skipped: usize,
+}
+
+impl Sweep {
+    fn record(
+        &mut self,
+        ours: Result<f64, BesselError>,
+        theirs: Option<f64>,
+        scale: f64,
+        at: (f64, f64),
+    ) {
+        match (ours, theirs) {
+            (Ok(a), Some(b)) if a.is_finite() => {
+                let d = ulps(a, b, scale.max(b.abs()));
+                if d > self.worst {
+                    self.worst = d;
+                    self.at = at;
+                }
+                self.compared += 1;
+            }
+            _ => self.skipped += 1,
+        }
+    }
+
+    fn check(&self, name: &str) {
+        println!(
+            "{:>10}: max {:.0} ULPs at (nu, x) = ({:e}, {:e}); {} compared, {} skipped",
+            name, self.worst, self.at.0, self.at.1, self.compared, self.skipped
+        );
+        assert!(self.compared > 0, "{}: no points compared", name);
+        assert!(
+            self.worst <= MAX_ULPS,
+            "{}: {:.0} ULPs from GSL at (nu, x) = ({:e}, {:e})",
+            name,
+            self.worst,
+            self.at.0,
+            self.at.1
+        );
+    }
+}
+
+#[test]
+fn test_j_y() {
+    let mut rng = Rng::from_env();
+    let (mut j, mut y) = (Sweep::default(), Sweep::default());
+    for _ in 0..samples() {
+        let nu = rng.uniform(0.0, 50.0);
+        let x = rng.log_uniform(1e-3, 1e3);
+        let gsl_j = gsl_nu(gsl_sf_bessel_Jnu_e, nu, x);
+        let gsl_y = gsl_nu(gsl_sf_bessel_Ynu_e, nu, x);
+        let modulus = match (gsl_j, gsl_y) {
+            (Some(a), Some(b)) if x > nu => a.hypot(b),
+            _ => 0.0,
+        };
+        j.record(real::J(nu, x), gsl_j, modulus, (nu, x));
+        y.record(real::Y(nu, x), gsl_y, modulus, (nu, x));
+    }
+    j.check("J");
+    y.check("Y");
+}
+
+#[test]
+fn test_i_k() {
+    let mut rng = Rng::from_env();
+    let mut sweeps: [Sweep; 4] = Default::default();
+    for _ in 0..samples() {
+        let nu = rng.uniform(0.0, 50.0);
+        let x = rng.log_uniform(1e-3, 1e3);
+        let at = (nu, x);
+        sweeps[0].record(real::I(nu, x), gsl_nu(gsl_sf_bessel_Inu_e, nu, x), 0.0, at);
+        sweeps[1].record(real::K(nu, x), gsl_nu(gsl_sf_bessel_Knu_e, nu, x), 0.0, at);
+        let gsl_i = gsl_nu(gsl_sf_bessel_Inu_scaled_e, nu, x);
+        sweeps[2].record(real::I_scaled(nu, x), gsl_i, 0.0, at);
+        let gsl_k = gsl_nu(gsl_sf_bessel_Knu_scaled_e, nu, x);
+        sweeps[3].record(real::K_scaled(nu, x), gsl_k, 0.0, at);
+    }
+    for (sweep, name) in sweeps.iter().zip(["I", "K", "I_scaled", "K_scaled"]) {
+        sweep.check(name);
+    }
+}
+
+#[test]
+fn test_airy() {
+    let mut rng = Rng::from_env();
+    let mut sweeps: [Sweep; 4] = Default::default();
+    for _ in 0..samples() {
+        let x = rng.uniform(-50.0, 50.0);
+        let ai = gsl_airy(gsl_sf_airy_Ai_e, x);
+        let bi = gsl_airy(gsl_sf_airy_Bi_e, x);
+        let ai_scaled = gsl_airy(gsl_sf_airy_Ai_scaled_e, x);
+        let bi_scaled = gsl_airy(gsl_sf_airy_Bi_scaled_e, x);
+        let modulus = |a: Option<f64>, b: Option<f64>| match (a, b) {
+            (Some(a), Some(b)) if x < 0.0 => a.hypot(b),
+            _ => 0.0,
+        };
+        let (m, m_scaled) = (modulus(ai, bi), modulus(ai_scaled, bi_scaled));
+        sweeps[0].record(real::Ai(x), ai, m, (0.0, x));
+        sweeps[1].record(real::Bi(x), bi, m, (0.0, x));
+        sweeps[2].record(real::Ai_scaled(x), ai_scaled, m_scaled, (0.0, x));
+        sweeps[3].record(real::Bi_scaled(x), bi_scaled, m_scaled, (0.0, x));
+    }
+    for (sweep, name) in sweeps.iter().zip(["Ai", "Bi", "Ai_scaled", "Bi_scaled"]) {
+        sweep.check(name);
+    }
+}
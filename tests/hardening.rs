@@ -0,0 +1,150 @@
+//! Dense accuracy battery around the turning point and the branch cut
+//!
+//! `data/hardening.csv` holds about 17000 reference values from
+//! `data/generate_hardening.py` on fixed grids in three regions:
+//!
+//! * `transition`: |z| within a few ν^(1/3) of ν, where the uniform
+//!   asymptotic expansions change form, at angles up to just short of the cut
+//! * `near_cut`: z = -x ± is with s from 1e-1 down to 1e-15
+//! * `corner`: both at once, just off the negative real axis at |z| ≈ |ν|
+//!
+//! Each row carries the condition number κ = |z f'(z) / f(z)|, and an error
+//! of ε·κ is unavoidable for any method. Every value, from the single-value
+//! and the sequence functions, must stay within [`MAX_DIGITS_LOST`] digits of
+//! that. Run with `--nocapture` for the worst case of each region.
+
+// The crate is marked deprecated in favour of its successor
+#![allow(deprecated)]
+
+use num_complex::Complex64;
+use zbessel_rs::{bessel, bessel_h, bessel_i, bessel_j, bessel_k, bessel_y, BesselKind, Scaling};
+
+const HARDENING: &str = include_str!("data/hardening.csv");
+
+/// Decimal digits an evaluation may lose beyond the condition number
+const MAX_DIGITS_LOST: f64 = 3.0;
+
+struct Row<'a> {
+    kind: BesselKind,
+    nu: f64,
+    z: Complex64,
+    scaling: Scaling,
+    region: &'a str,
+    value: Complex64,
+    condition: f64,
+}
+
+fn rows() -> impl Iterator<Item = Row<'static>> {
+    HARDENING.lines().skip(1).map(|line| {
+        let fields: Vec<&str> = line.split(',').collect();
+        let number = |i: usize| -> f64 { fields[i].parse().unwrap() };
+        Row {
+            kind: match fields[0] {
+                "J" => BesselKind::J,
+                "Y" => BesselKind::Y,
+                "I" => BesselKind::I,
+                "K" => BesselKind::K,
+                "H1" => BesselKind::H1,
+                "H2" => BesselKind::H2,
+                other => panic!("unknown function '{}'", other),
+            },
+            nu: number(1),
+            z: Complex64::new(number(2), number(3)),
+            scaling: if fields[4] == "1" {
+                Scaling::Scaled
+            } else {
+                Scaling::Unscaled
+            },
+            region: fields[5],
+            value: Complex64::new(number(6), number(7)),
+            condition: number(8),
+        }
+    })
+}
+
+/// First value of the sequence function of `kind`
+fn sequence_value(row: &Row) -> Option<Complex64> {
+    let (z, nu, kode) = (row.z, row.nu, row.scaling.kode());
+    let result = match row.kind {
+        BesselKind::J => bessel_j(z, nu, kode, 2),
+        BesselKind::Y => bessel_y(z, nu, kode, 2),
+        BesselKind::I => bessel_i(z, nu, kode, 2),
+        BesselKind::K => bessel_k(z, nu, kode, 2),
+        BesselKind::H1 => bessel_h(z, nu, kode, 1, 2),
+        BesselKind::H2 => bessel_h(z, nu, kode, 2, 2),
+    };
+    result.ok().map(|result| result.values[0])
+}
+
+/// Digits lost beyond those the condition number accounts for
+fn digits_lost(row: &Row, value: Complex64) -> f64 {
+    let error = (value - row.value).norm() / row.value.norm();
+    let unavoidable = f64::EPSILON * row.condition.max(1.0);
+    (error / unavoidable).max(1.0).log10()
+}
+
+#[test]
+fn test_digits_lost() {
+    let mut worst: Vec<(&str, f64, String)> = Vec::new();
+    let mut failures = Vec::new();
+    let mut count = 0;
+    for row in rows() {
+        count += 1;
+        let describe = format!(
+            "{:?}_{}({}) {:?} [{}, κ = {:.1e}]",
+            row.kind, row.nu, row.z, row.scaling, row.region, row.condition
+        );
+        let single = bessel(row.kind, row.nu, row.z, row.scaling).ok();
+        for (api, value) in [("single", single), ("sequence", sequence_value(&row))] {
+            let Some(value) = value else {
+                failures.push(format!("{} {}: error", api, describe));
+                continue;
+            };
+            let lost = digits_lost(&row, value);
+            if lost.is_nan() || lost > MAX_DIGITS_LOST {
+                failures.push(format!(
+                    "{} {} = {}, expected {} ({:.1} digits lost)",
+                    api, describe, value, row.value, lost
+                ));
+            }
+            match worst.iter_mut().find(|(region, ..)| *region == row.region) {
+                Some(entry) if entry.1 >= lost => {}
+                Some(entry) => *entry = (row.region, lost, describe.clone()),
+                None => worst.push((row.region, lost, describe.clone())),
+            }
+        }
+    }
+    for (region, lost, describe) in &worst {
+        println!("{}: worst {:.1} digits lost, {}", region, lost, describe);
+    }
+    assert!(count > 15000, "hardening.csv has only {} rows", count);
+    assert!(
+        failures.is_empty(),
+        "{} of {} values failed:\n{}",
+        failures.len(),
+        count,
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn test_real_on_positive_axis() {
+    // J, Y, I, K and their scaling factors are real for z > 0, so the
+    // backend's rounding in the imaginary part must not show
+    for row in rows() {
+        let real = !matches!(row.kind, BesselKind::H1 | BesselKind::H2);
+        if !(real && row.z.im == 0.0 && row.z.re > 0.0) {
+            continue;
+        }
+        let single = bessel(row.kind, row.nu, row.z, row.scaling).unwrap();
+        let first = sequence_value(&row).unwrap();
+        assert_eq!(
+            (single.im, first.im),
+            (0.0, 0.0),
+            "{:?}_{}({})",
+            row.kind,
+            row.nu,
+            row.z
+        );
+    }
+}
@@ -0,0 +1,132 @@
+//! Large orders up to the validated range and beyond
+//!
+//! From ν = 1e4 to 1e9 every evaluation returns a value or an error about the
+//! function itself, overflow or reduced precision (IERR=2 or 3), and the
+//! values satisfy the Wronskians to the expected ν·ε. Past the validated
+//! range the error is `OutOfValidatedRange` with its limit, never the
+//! backend's IERR=4 or 5.
+
+// The crate is marked deprecated in favour of its successor
+#![allow(deprecated)]
+
+use num_complex::Complex64;
+use std::f64::consts::PI;
+use zbessel_rs::{
+    bessel, bessel_h, bessel_into, bessel_j, bessel_k, bessel_y, BesselError, BesselKind, Scaling,
+    J, MAX_ORDER,
+};
+
+const ORDERS: [f64; 6] = [1e4, 1e5, 1e6, 1e7, 1e8, 1e9];
+
+/// Arguments below, at and beyond the turning point, off the real axis and
+/// next to the cut
+fn arguments(nu: f64) -> [Complex64; 6] {
+    [
+        Complex64::new(1.0, 0.0),
+        Complex64::new(0.5 * nu, 0.0),
+        Complex64::new(nu, 0.0),
+        Complex64::new(2.0 * nu, 0.0),
+        Complex64::new(0.3 * nu, nu),
+        Complex64::new(-nu, 1.0),
+    ]
+}
+
+#[track_caller]
+fn assert_understood(result: Result<Complex64, BesselError>, context: &str) {
+    match result {
+        Ok(value) => assert!(value.re.is_finite() && value.im.is_finite(), "{}", context),
+        Err(BesselError::OutOfValidatedRange { value, limit, .. }) => {
+            assert!(value > limit, "{}", context)
+        }
+        Err(error) => assert!(
+            error.is_overflow() || error.ierr() == Some(3),
+            "{}: {}",
+            context,
+            error
+        ),
+    }
+}
+
+#[test]
+fn test_no_opaque_errors() {
+    for nu in ORDERS {
+        for z in arguments(nu) {
+            for kind in BesselKind::ALL {
+                for scaling in [Scaling::Unscaled, Scaling::Scaled] {
+                    for order in [nu, -nu] {
+                        let context = format!("{:?}_{}({}) {:?}", kind, order, z, scaling);
+                        assert_understood(bessel(kind, order, z, scaling), &context);
+                        let mut out = [Complex64::new(0.0, 0.0); 3];
+                        let sequence = bessel_into(kind, z, order, scaling, &mut out);
+                        assert_understood(sequence.map(|_| out[0]), &context);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_wronskians_at_large_orders() {
+    for nu in ORDERS {
+        // The functions are conditioned like ν in their argument
+        let tolerance = 10.0 * nu * f64::EPSILON;
+        for z in [Complex64::new(nu, 0.0), Complex64::new(2.0 * nu, 0.0)] {
+            let j = bessel_j(z, nu, 1, 2).unwrap().values;
+            let y = bessel_y(z, nu, 1, 2).unwrap().values;
+            // J_{ν+1} Y_ν - J_ν Y_{ν+1} = 2/(πz)
+            let expected = 2.0 / (PI * z);
+            let wronskian = j[1] * y[0] - j[0] * y[1];
+            assert!(
+                (wronskian - expected).norm() < tolerance * expected.norm(),
+                "nu={} z={}",
+                nu,
+                z
+            );
+            let h1 = bessel_h(z, nu, 1, 1, 1).unwrap().values[0];
+            let h2 = bessel_h(z, nu, 1, 2, 1).unwrap().values[0];
+            assert!((h1 + h2 - 2.0 * j[0]).norm() < tolerance * h1.norm());
+            assert_eq!((j[0].im, y[0].im), (0.0, 0.0));
+        }
+    }
+}
+
+#[test]
+fn test_beyond_validated_range() {
+    let z = Complex64::new(1.0, 1.0);
+    for nu in [2.0 * MAX_ORDER, -2.0 * MAX_ORDER] {
+        assert_eq!(
+            J(nu, z).unwrap_err(),
+            BesselError::OutOfValidatedRange {
+                quantity: "|nu|".to_string(),
+                value: 2.0 * MAX_ORDER,
+                limit: MAX_ORDER,
+            }
+        );
+    }
+
+    // Left of the imaginary axis only the backend applies
+    let far = Complex64::new(-2e9, 1.0);
+    let error = bessel(BesselKind::J, 0.0, far, Scaling::Unscaled).unwrap_err();
+    let limit = match &error {
+        BesselError::OutOfValidatedRange {
+            quantity,
+            value,
+            limit,
+        } => {
+            assert_eq!((quantity.as_str(), *value), ("|z|", far.norm()));
+            *limit
+        }
+        other => panic!("expected OutOfValidatedRange, got {:?}", other),
+    };
+    assert!(error.is_accuracy_loss() && error.ierr().is_none());
+    assert!(error.to_string().contains(&format!("{:e}", limit)));
+
+    // A sequence whose last order crosses the limit
+    let error = bessel_k(z, limit - 1.0, 1, 3).unwrap_err();
+    assert!(
+        matches!(&error, BesselError::OutOfValidatedRange { quantity, .. } if quantity == "nu+n-1"),
+        "{:?}",
+        error
+    );
+}
@@ -0,0 +1,248 @@
+//! Differential tests of the real-argument functions against `libm`
+//!
+//! `libm` is a pure-Rust port of the musl C library, with J_0, J_1, J_n, Y_0,
+//! Y_1 and Y_n for integer orders. Each test sweeps random orders and
+//! arguments (seed from `ZBESSEL_LIBM_SEED`, count from
+//! `ZBESSEL_LIBM_SAMPLES`) and reports the largest difference in ULPs:
+//!
+//! ```text
+//! cargo test --test libm -- --nocapture
+//! cargo test --features interval --test libm -- --nocapture
+//! ```
+//!
+//! As in `tests/gsl.rs`, the difference is measured in ULPs of the modulus
+//! sqrt(J² + Y²) where the functions oscillate (x > |n|). Points above the
+//! limit are listed; with the `interval` feature each is attributed to the
+//! side that falls outside a rigorous enclosure from `interval::Jn` or
+//! `interval::Yn`.
+//!
+//! Known divergences, allowed for in [`limit`]:
+//!
+//! * for large x up to 32768 the crate loses up to about 10x ULPs of the
+//!   modulus in the argument reduction of the Amos algorithms, where musl
+//!   combines sin x and cos x, whose argument reduction is exact
+//! * for large orders and small arguments the crate forms the value as the
+//!   exponential of its logarithm, so the rounding error of a logarithm of
+//!   several hundred becomes a relative error of a few hundred ULPs, while
+//!   musl multiplies the series terms directly
+//!
+//! Above x = 32768 the Amos routines report IERR 3, and the crate evaluates
+//! orders up to 40 by the Hankel expansion instead, with the phase reduced in
+//! double-double arithmetic. [`test_large_arguments`] sweeps that range up to
+//! 1e15 with a flat limit of 64 ULPs of the modulus; points near a zero, where
+//! the expansion cancels and the crate falls back to Amos and its error, are
+//! skipped.
+
+// The crate is marked deprecated in favour of its successor
+#![allow(deprecated)]
+
+use zbessel_rs::real;
+
+/// Largest accepted difference in ULPs of the value or modulus
+fn limit(x: f64, value: f64) -> f64 {
+    if x > HANKEL_THRESHOLD {
+        return 64.0;
+    }
+    512.0 + 16.0 * x + 4.0 * value.abs().ln().abs()
+}
+
+/// sqrt(0.5·`i32::MAX`), above which the crate uses the Hankel expansion
+const HANKEL_THRESHOLD: f64 = 32768.0;
+
+/// Smallest magnitude compared; below it one side may have underflowed
+const MIN_MAGNITUDE: f64 = 1e-290;
+
+/// Distance between `a` and `b` in ULPs of `scale`
+fn ulps(a: f64, b: f64, scale: f64) -> f64 {
+    let scale = scale.abs().max(f64::MIN_POSITIVE);
+    let ulp = f64::from_bits(scale.to_bits() + 1) - scale;
+    (a - b).abs() / ulp
+}
+
+/// xorshift64*, so that a seed reproduces a sweep on every platform
+struct Rng(u64);
+
+impl Rng {
+    fn from_env() -> Self {
+        let seed = std::env::var("ZBESSEL_LIBM_SEED")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(0x11B3_5EED);
+        Rng(seed | 1)
+    }
+
+    /// Uniform on [0, 1)
+    fn next(&mut self) -> f64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        (self.0.wrapping_mul(0x2545_F491_4F6C_DD1D) >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Log-uniform on [low, high]
+    fn log_uniform(&mut self, low: f64, high: f64) -> f64 {
+        (low.ln() + (high.ln() - low.ln()) * self.next()).exp()
+    }
+
+    /// Uniform integer on [low, high]
+    fn integer(&mut self, low: i32, high: i32) -> i32 {
+        low + (self.next() * (high - low + 1) as f64) as i32
+    }
+}
+
+fn samples() -> usize {
+    std::env::var("ZBESSEL_LIBM_SAMPLES")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(5000)
+}
+
+/// The side of a disagreement outside a rigorous enclosure
+#[cfg(feature = "interval")]
+fn attribute(function: &str, n: i32, x: f64, ours: f64, theirs: f64) -> &'static str {
+    use zbessel_rs::interval::{Interval, Jn, Yn};
+    let enclosure = match function {
+        "J" => Jn(n, Interval::point(x)),
+        _ => Yn(n, Interval::point(x)),
+    };
+    match enclosure {
+        Ok(e) => match (e.contains(ours), e.contains(theirs)) {
+            (true, false) => "libm is outside the enclosure",
+            (false, true) => "zbessel-rs is outside the enclosure",
+            (false, false) => "both are outside the enclosure",
+            (true, true) => "the enclosure is too wide to tell",
+        },
+        Err(_) => "no enclosure",
+    }
+}
+
+#[cfg(not(feature = "interval"))]
+fn attribute(_: &str, _: i32, _: f64, _: f64, _: f64) -> &'static str {
+    "enable the `interval` feature to attribute"
+}
+
+/// Largest difference over a sweep and the points above [`limit`]
+#[derive(Default)]
+struct Sweep {
+    worst: f64,
+    at: (i32, f64),
+    compared: usize,
+    skipped: usize,
+    failures: Vec<String>,
+}
+
+impl Sweep {
+    fn record(&mut self, function: &str, n: i32, x: f64, ours: f64, theirs: f64, modulus: f64) {
+        let compared = [ours, theirs]
+            .iter()
+            .all(|v| v.is_finite() && v.abs() >= MIN_MAGNITUDE);
+        if !compared {
+            self.skipped += 1;
+            return;
+        }
+        self.compared += 1;
+        let d = ulps(ours, theirs, modulus.max(theirs.abs()));
+        if d > self.worst {
+            self.worst = d;
+            self.at = (n, x);
+        }
+        if d > limit(x, theirs) {
+            self.failures.push(format!(
+                "{}_{}({:e}): zbessel-rs {:e}, libm {:e}, {:.0} ULPs; {}",
+                function,
+                n,
+                x,
+                ours,
+                theirs,
+                d,
+                attribute(function, n, x, ours, theirs)
+            ));
+        }
+    }
+
+    fn check(&self, name: &str) {
+        println!(
+            "{:>4}: max {:.0} ULPs at (n, x) = ({}, {:e}); {} compared, {} skipped",
+            name, self.worst, self.at.0, self.at.1, self.compared, self.skipped
+        );
+        assert!(self.compared > 0, "{}: no points compared", name);
+        assert!(
+            self.failures.is_empty(),
+            "{}: {} points differ from libm by more than the limit:\n{}",
+            name,
+            self.failures.len(),
+            self.failures.join("\n")
+        );
+    }
+}
+
+/// J_n(x) and Y_n(x) from the crate, NaN where it reports an error
+fn ours(n: i32, x: f64) -> (f64, f64) {
+    let j = real::J(n as f64, x).unwrap_or(f64::NAN);
+    let y = real::Y(n as f64, x).unwrap_or(f64::NAN);
+    (j, y)
+}
+
+/// Modulus sqrt(J² + Y²) where the functions oscillate, otherwise 0
+fn modulus(n: i32, x: f64, j: f64, y: f64) -> f64 {
+    if x > n.unsigned_abs() as f64 {
+        j.hypot(y)
+    } else {
+        0.0
+    }
+}
+
+#[test]
+fn test_orders_zero_and_one() {
+    let mut rng = Rng::from_env();
+    let (mut j, mut y) = (Sweep::default(), Sweep::default());
+    for _ in 0..samples() {
+        let x = rng.log_uniform(1e-3, 3e4);
+        for n in 0..2 {
+            let (oj, oy) = ours(n, x);
+            let (lj, ly) = match n {
+                0 => (libm::j0(x), libm::y0(x)),
+                _ => (libm::j1(x), libm::y1(x)),
+            };
+            let m = modulus(n, x, lj, ly);
+            j.record("J", n, x, oj, lj, m);
+            y.record("Y", n, x, oy, ly, m);
+        }
+    }
+    j.check("J0,1");
+    y.check("Y0,1");
+}
+
+#[test]
+fn test_integer_orders() {
+    let mut rng = Rng::from_env();
+    let (mut j, mut y) = (Sweep::default(), Sweep::default());
+    for _ in 0..samples() {
+        let n = rng.integer(-200, 200);
+        let x = rng.log_uniform(1e-2, 3e4);
+        let (oj, oy) = ours(n, x);
+        let (lj, ly) = (libm::jn(n, x), libm::yn(n, x));
+        let m = modulus(n, x, lj, ly);
+        j.record("J", n, x, oj, lj, m);
+        y.record("Y", n, x, oy, ly, m);
+    }
+    j.check("Jn");
+    y.check("Yn");
+}
+
+#[test]
+fn test_large_arguments() {
+    let mut rng = Rng::from_env();
+    let (mut j, mut y) = (Sweep::default(), Sweep::default());
+    for _ in 0..samples() {
+        let n = rng.integer(-40, 40);
+        let x = rng.log_uniform(HANKEL_THRESHOLD, 1e15);
+        let (oj, oy) = ours(n, x);
+        let (lj, ly) = (libm::jn(n, x), libm::yn(n, x));
+        let m = modulus(n, x, lj, ly);
+        j.record("J", n, x, oj, lj, m);
+        y.record("Y", n, x, oy, ly, m);
+    }
+    j.check("Jbig");
+    y.check("Ybig");
+}
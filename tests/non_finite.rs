@@ -0,0 +1,181 @@
+//! NaN and infinite arguments and orders across the public API
+//!
+//! Every function returning `Result` must reject a NaN or ±∞ in z or ν with
+//! `InvalidParameter`, for every kind, scaling and sequence length, and the
+//! NaN-returning functions must give NaN, without the inputs ever reaching
+//! the Amos routines.
+
+// The crate is marked deprecated in favour of its successor
+#![allow(deprecated)]
+
+use num_complex::{Complex32, Complex64};
+use zbessel_rs::{
+    airy_ai, airy_bi, bessel, bessel_continued, bessel_grid, bessel_h, bessel_i, bessel_iter,
+    bessel_j, bessel_k, bessel_orders, bessel_slice, bessel_with_derivative, bessel_y,
+    condition_number, fast, fast32, real, Ai, BesselError, BesselKind, J_or_nan, Scaling,
+};
+
+const BAD: [f64; 3] = [f64::NAN, f64::INFINITY, f64::NEG_INFINITY];
+
+/// Finite arguments on either side of the cut and at the origin
+const GOOD: [Complex64; 3] = [
+    Complex64::new(1.5, 0.5),
+    Complex64::new(-2.0, -0.0),
+    Complex64::new(0.0, 0.0),
+];
+
+/// Arguments with at least one non-finite part
+fn bad_arguments() -> Vec<Complex64> {
+    let mut zs = Vec::new();
+    for b in BAD {
+        zs.extend([
+            Complex64::new(b, 0.0),
+            Complex64::new(-1.0, b),
+            Complex64::new(b, b),
+        ]);
+    }
+    zs
+}
+
+#[track_caller]
+fn assert_invalid<T: std::fmt::Debug>(result: Result<T, BesselError>) {
+    match result {
+        Err(BesselError::InvalidParameter(message)) => {
+            assert!(
+                message.contains("finite"),
+                "unexpected message: {}",
+                message
+            )
+        }
+        other => panic!("expected InvalidParameter, got {:?}", other),
+    }
+}
+
+fn sequence(
+    kind: BesselKind,
+    z: Complex64,
+    nu: f64,
+    kode: i32,
+    n: usize,
+) -> Result<(), BesselError> {
+    let result = match kind {
+        BesselKind::J => bessel_j(z, nu, kode, n),
+        BesselKind::Y => bessel_y(z, nu, kode, n),
+        BesselKind::I => bessel_i(z, nu, kode, n),
+        BesselKind::K => bessel_k(z, nu, kode, n),
+        BesselKind::H1 => bessel_h(z, nu, kode, 1, n),
+        BesselKind::H2 => bessel_h(z, nu, kode, 2, n),
+    };
+    result.map(drop)
+}
+
+#[test]
+fn test_sequences_reject_non_finite() {
+    for kind in BesselKind::ALL {
+        for kode in [1, 2] {
+            for n in [1, 3] {
+                for z in bad_arguments() {
+                    for nu in [0.0, 0.5, -1.5] {
+                        assert_invalid(sequence(kind, z, nu, kode, n));
+                    }
+                }
+                for z in GOOD {
+                    for nu in BAD {
+                        assert_invalid(sequence(kind, z, nu, kode, n));
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_single_values_reject_non_finite() {
+    let good = Complex64::new(1.5, 0.5);
+    for kind in BesselKind::ALL {
+        for scaling in [Scaling::Unscaled, Scaling::Scaled] {
+            for z in bad_arguments() {
+                // Half-integer orders have a closed form that must not bypass the check
+                for nu in [0.0, 0.5, -2.0] {
+                    assert_invalid(bessel(kind, nu, z, scaling));
+                    assert_invalid(bessel_with_derivative(kind, nu, z, scaling));
+                }
+                assert_invalid(bessel_slice(kind, &[good, z], 1.0, scaling));
+                assert_invalid(bessel_iter(kind, z, 0.0, scaling).next().unwrap());
+            }
+            for nu in BAD {
+                assert_invalid(bessel(kind, nu, good, scaling));
+                assert_invalid(bessel_orders(kind, good, &[0.0, nu], scaling));
+                assert_invalid(bessel_grid(kind, &[nu], &[good], scaling));
+                assert_invalid(bessel_iter(kind, good, nu, scaling).next().unwrap());
+            }
+        }
+        for b in BAD {
+            assert_invalid(bessel_continued(kind, b, good, 1));
+            assert_invalid(bessel_continued(kind, 1.0, Complex64::new(b, 1.0), 1));
+            assert_invalid(condition_number(kind, b, good));
+        }
+    }
+}
+
+#[test]
+fn test_airy_and_real_reject_non_finite() {
+    for z in bad_arguments() {
+        for (id, kode) in [(0, 1), (1, 2)] {
+            assert_invalid(airy_ai(z, id, kode));
+            assert_invalid(airy_bi(z, id, kode));
+        }
+        assert_invalid(Ai(z));
+    }
+    for b in BAD {
+        // Negative infinity is not a complex-valued argument, just a bad one
+        for f in [
+            real::J,
+            real::Y,
+            real::I,
+            real::K,
+            real::I_scaled,
+            real::K_scaled,
+        ] {
+            assert_invalid(f(0.5, b));
+            assert_invalid(f(b, 1.0));
+            assert_invalid(f(b, -1.0));
+        }
+        for f in [real::Ai, real::Bi, real::Ai_scaled, real::Bi_scaled] {
+            assert_invalid(f(b));
+        }
+    }
+}
+
+#[test]
+fn test_nan_returning_functions_give_nan() {
+    let good = Complex64::new(1.5, 0.5);
+    let is_nan = |v: Complex64| v.re.is_nan() && v.im.is_nan();
+    for z in bad_arguments() {
+        assert!(is_nan(J_or_nan(0.0, z)));
+        for f in [
+            fast::J_unchecked,
+            fast::Y_unchecked,
+            fast::I_unchecked,
+            fast::K_unchecked,
+        ] {
+            assert!(is_nan(f(1.0, z)), "{}", z);
+        }
+        let z32 = Complex32::new(z.re as f32, z.im as f32);
+        for f in [fast32::J, fast32::Y, fast32::I, fast32::K] {
+            assert!(f(1.0, z32).re.is_nan(), "{}", z);
+        }
+    }
+    for nu in BAD {
+        assert!(is_nan(J_or_nan(nu, good)));
+        for f in [
+            fast::J_scaled_unchecked,
+            fast::Y_scaled_unchecked,
+            fast::I_scaled_unchecked,
+            fast::K_scaled_unchecked,
+        ] {
+            assert!(is_nan(f(nu, good)));
+        }
+        assert!(fast32::I(nu as f32, Complex32::new(1.5, 0.5)).re.is_nan());
+    }
+}
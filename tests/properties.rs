@@ -0,0 +1,143 @@
+//! Property-based tests of identities that hold for every order and argument
+//!
+//! Each property draws real orders and complex arguments over the supported
+//! domain, with |z| log-uniform in [0.1, 100] and arg z strictly inside the
+//! cut, and checks an identity that ties independent evaluations together:
+//!
+//! * the three-term recurrences in the order (DLMF 10.6.1, 10.29.1)
+//! * continuity of those recurrences through ν = 0, which exercises the
+//!   reflection formulas, and the reflections K_{-ν} = K_ν, H^(1)_{-ν} =
+//!   e^{iνπ} H^(1)_ν, J_{-n} = (-1)^n J_n
+//! * conjugate symmetry for real ν: F(z̄) = conj F(z) for J, Y, I, K and
+//!   H^(1)(z̄) = conj H^(2)(z)
+//! * the scaled functions against the unscaled ones times the scaling factor
+//!
+//! Failing cases are shrunk and printed by proptest; set `PROPTEST_CASES` to
+//! run more of them.
+
+// The crate is marked deprecated in favour of its successor
+#![allow(deprecated)]
+
+use num_complex::Complex64;
+use proptest::prelude::*;
+use std::f64::consts::PI;
+use zbessel_rs::{bessel, bessel_h, bessel_i, bessel_j, bessel_k, bessel_y, BesselKind, Scaling};
+
+/// Arguments with |z| in [0.1, 100] and |arg z| < π - 0.01
+fn argument() -> impl Strategy<Value = Complex64> {
+    (-1.0f64..2.0, -(PI - 0.01)..(PI - 0.01))
+        .prop_map(|(log_r, theta)| Complex64::from_polar(10f64.powf(log_r), theta))
+}
+
+/// The sequence of `kind` at orders ν, ..., ν+n-1, scaled so that none of it
+/// overflows
+fn sequence(kind: BesselKind, z: Complex64, nu: f64, n: usize) -> Vec<Complex64> {
+    let result = match kind {
+        BesselKind::J => bessel_j(z, nu, 2, n),
+        BesselKind::Y => bessel_y(z, nu, 2, n),
+        BesselKind::I => bessel_i(z, nu, 2, n),
+        BesselKind::K => bessel_k(z, nu, 2, n),
+        BesselKind::H1 => bessel_h(z, nu, 2, 1, n),
+        BesselKind::H2 => bessel_h(z, nu, 2, 2, n),
+    };
+    result.unwrap().values
+}
+
+/// Residual of the recurrence at each interior order, relative to the size
+/// of its terms
+fn recurrence_residuals(kind: BesselKind, z: Complex64, nu: f64, values: &[Complex64]) -> Vec<f64> {
+    values
+        .windows(3)
+        .enumerate()
+        .map(|(k, w)| {
+            let mid = 2.0 * (nu + 1.0 + k as f64) / z * w[1];
+            let residual = match kind {
+                BesselKind::I => w[0] - w[2] - mid,
+                BesselKind::K => w[2] - w[0] - mid,
+                _ => w[0] + w[2] - mid,
+            };
+            residual.norm() / (w[0].norm() + w[2].norm() + mid.norm())
+        })
+        .collect()
+}
+
+/// Scaling factor of each kind, so that scaled = unscaled * factor
+fn scale_factor(kind: BesselKind, z: Complex64) -> Complex64 {
+    let i = Complex64::i();
+    match kind {
+        BesselKind::J | BesselKind::Y => Complex64::new((-z.im.abs()).exp(), 0.0),
+        BesselKind::I => Complex64::new((-z.re.abs()).exp(), 0.0),
+        BesselKind::K => z.exp(),
+        BesselKind::H1 => (-i * z).exp(),
+        BesselKind::H2 => (i * z).exp(),
+    }
+}
+
+fn relative(a: Complex64, b: Complex64) -> f64 {
+    (a - b).norm() / a.norm().max(b.norm()).max(f64::MIN_POSITIVE)
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(256))]
+
+    #[test]
+    fn recurrence_in_order(nu in 0.0f64..50.0, z in argument()) {
+        for kind in BesselKind::ALL {
+            let values = sequence(kind, z, nu, 4);
+            for residual in recurrence_residuals(kind, z, nu, &values) {
+                prop_assert!(residual < 1e-11, "{:?}_{} at {}: {:e}", kind, nu, z, residual);
+            }
+        }
+    }
+
+    #[test]
+    fn reflection_through_zero(nu in -6.0f64..-0.01, z in argument()) {
+        // The recurrence holds across the negative orders, which come from
+        // the reflection formulas, and the non-negative ones
+        for kind in BesselKind::ALL {
+            let values = sequence(kind, z, nu, 10);
+            for residual in recurrence_residuals(kind, z, nu, &values) {
+                prop_assert!(residual < 1e-10, "{:?}_{} at {}: {:e}", kind, nu, z, residual);
+            }
+        }
+        let k = |nu| bessel(BesselKind::K, nu, z, Scaling::Scaled).unwrap();
+        prop_assert!(relative(k(nu), k(-nu)) < 1e-13);
+        let h1 = |nu| bessel(BesselKind::H1, nu, z, Scaling::Scaled).unwrap();
+        let phase = Complex64::from_polar(1.0, -nu * PI);
+        prop_assert!(relative(h1(nu), phase * h1(-nu)) < 1e-12);
+        let n = nu.round();
+        let j = |nu| bessel(BesselKind::J, nu, z, Scaling::Scaled).unwrap();
+        let sign = if n % 2.0 == 0.0 { 1.0 } else { -1.0 };
+        prop_assert!(relative(j(n), sign * j(-n)) < 1e-14);
+    }
+
+    #[test]
+    fn conjugate_symmetry(nu in -10.0f64..30.0, z in argument()) {
+        for kind in BesselKind::ALL {
+            let mirrored = match kind {
+                BesselKind::H1 => BesselKind::H2,
+                BesselKind::H2 => BesselKind::H1,
+                kind => kind,
+            };
+            let value = bessel(kind, nu, z.conj(), Scaling::Scaled).unwrap();
+            let expected = bessel(mirrored, nu, z, Scaling::Scaled).unwrap().conj();
+            prop_assert!(
+                relative(value, expected) < 1e-12,
+                "{:?}_{} at {}: {} vs {}", kind, nu, z, value, expected
+            );
+        }
+    }
+
+    #[test]
+    fn scaled_matches_unscaled(nu in 0.0f64..30.0, z in argument()) {
+        for kind in BesselKind::ALL {
+            let unscaled = bessel(kind, nu, z, Scaling::Unscaled).unwrap();
+            let scaled = bessel(kind, nu, z, Scaling::Scaled).unwrap();
+            let expected = unscaled * scale_factor(kind, z);
+            prop_assert!(
+                relative(scaled, expected) < 1e-11,
+                "{:?}_{} at {}: {} vs {}", kind, nu, z, scaled, expected
+            );
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! Concurrent calls from many threads
+//!
+//! Every family of functions is evaluated on many threads at once, each
+//! walking the inputs in its own order, and must return exactly the bits of
+//! a single-threaded run. A hidden mutable static in the backend, or shared
+//! scratch storage, would show up here as mismatched or corrupted values.
+
+// The crate is marked deprecated in favour of its successor
+#![allow(deprecated)]
+
+use num_complex::Complex64;
+use std::sync::Barrier;
+use zbessel_rs::{
+    airy_ai, airy_bi, bessel, bessel_grid, bessel_h, bessel_i, bessel_j, bessel_k, bessel_y, real,
+    BesselKind, Scaling,
+};
+
+const THREADS: usize = 16;
+
+/// Inputs spanning the series, asymptotic and uniform-expansion regions
+fn inputs() -> Vec<(f64, Complex64)> {
+    let mut inputs = Vec::new();
+    for nu in [0.0, 0.5, 1.0, 3.3, 25.0, 90.5, -2.7] {
+        for (re, im) in [
+            (0.01, 0.0),
+            (0.9, 0.4),
+            (-4.0, 2.0),
+            (30.0, -1.0),
+            (-60.0, -75.0),
+        ] {
+            inputs.push((nu, Complex64::new(re, im)));
+        }
+    }
+    inputs
+}
+
+/// Every value each input produces, with failures as NaN
+fn evaluate(nu: f64, z: Complex64) -> Vec<Complex64> {
+    let nan = Complex64::new(f64::NAN, f64::NAN);
+    let mut values = Vec::new();
+    for kode in [1, 2] {
+        for result in [
+            bessel_j(z, nu, kode, 3),
+            bessel_y(z, nu, kode, 3),
+            bessel_i(z, nu, kode, 3),
+            bessel_k(z, nu, kode, 3),
+            bessel_h(z, nu, kode, 1, 3),
+            bessel_h(z, nu, kode, 2, 3),
+        ] {
+            values.extend(result.map_or(vec![nan; 3], |r| r.values));
+        }
+        for id in [0, 1] {
+            values.push(airy_ai(z, id, kode).map_or(nan, |r| r.value));
+            values.push(airy_bi(z, id, kode).unwrap_or(nan));
+        }
+    }
+    for kind in BesselKind::ALL {
+        values.push(bessel(kind, nu, z, Scaling::Scaled).unwrap_or(nan));
+    }
+    let real_values = [
+        real::J(nu, z.re),
+        real::Y(nu, z.re.abs()),
+        real::K(nu, z.re.abs()),
+    ];
+    values.extend(real_values.map(|v| Complex64::new(v.unwrap_or(f64::NAN), 0.0)));
+    values
+}
+
+fn bits(values: &[Complex64]) -> Vec<(u64, u64)> {
+    values
+        .iter()
+        .map(|v| (v.re.to_bits(), v.im.to_bits()))
+        .collect()
+}
+
+#[test]
+fn test_stress_all_functions() {
+    let inputs = inputs();
+    let reference: Vec<_> = inputs
+        .iter()
+        .map(|&(nu, z)| bits(&evaluate(nu, z)))
+        .collect();
+    let barrier = Barrier::new(THREADS);
+    std::thread::scope(|scope| {
+        for t in 0..THREADS {
+            let (inputs, reference, barrier) = (&inputs, &reference, &barrier);
+            scope.spawn(move || {
+                barrier.wait();
+                for round in 0..4 {
+                    // Each thread starts at a different input
+                    for k in 0..inputs.len() {
+                        let i = (k * 7 + t * 5 + round) % inputs.len();
+                        let (nu, z) = inputs[i];
+                        assert_eq!(
+                            bits(&evaluate(nu, z)),
+                            reference[i],
+                            "ν = {}, z = {}",
+                            nu,
+                            z
+                        );
+                    }
+                }
+            });
+        }
+    });
+}
+
+#[test]
+fn test_concurrent_grids() {
+    let nus: Vec<f64> = (0..40).map(|k| 0.75 * k as f64).collect();
+    let zs: Vec<Complex64> = (0..40)
+        .map(|k| Complex64::from_polar(0.5 + k as f64, 0.07 * k as f64))
+        .collect();
+    let reference = bessel_grid(BesselKind::K, &nus, &zs, Scaling::Scaled).unwrap();
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..THREADS)
+            .map(|_| scope.spawn(|| bessel_grid(BesselKind::K, &nus, &zs, Scaling::Scaled)))
+            .collect();
+        for handle in handles {
+            assert_eq!(
+                bits(&handle.join().unwrap().unwrap().values),
+                bits(&reference.values)
+            );
+        }
+    });
+}